@@ -2,6 +2,7 @@ mod bootstrap;
 mod chart;
 mod commands;
 mod context;
+mod data;
 mod events;
 mod heatmap;
 mod keymaps;
@@ -11,6 +12,7 @@ mod mchart;
 mod plugins;
 mod registration;
 mod themes;
+mod tree;
 mod ui;
 use std::sync::{LazyLock, RwLock};
 
@@ -153,7 +155,9 @@ mod tests {
             vec![
                 ContentShowMode::Matrix,
                 ContentShowMode::Preview,
-                ContentShowMode::Heatmap
+                ContentShowMode::Heatmap,
+                ContentShowMode::Chunks,
+                ContentShowMode::Usage
             ]
         );
 
@@ -708,7 +712,9 @@ mod tests {
             vec![
                 ContentShowMode::Matrix,
                 ContentShowMode::Preview,
-                ContentShowMode::Heatmap
+                ContentShowMode::Heatmap,
+                ContentShowMode::Chunks,
+                ContentShowMode::Usage
             ]
         );
         assert_eq!(
@@ -1480,7 +1486,9 @@ return {
             vec![
                 ContentShowMode::Heatmap,
                 ContentShowMode::Preview,
-                ContentShowMode::Matrix
+                ContentShowMode::Matrix,
+                ContentShowMode::Chunks,
+                ContentShowMode::Usage
             ]
         );
         assert_eq!(
@@ -1488,7 +1496,9 @@ return {
             vec![
                 ContentShowMode::Heatmap.handle(),
                 ContentShowMode::Preview.handle(),
-                ContentShowMode::Matrix.handle()
+                ContentShowMode::Matrix.handle(),
+                ContentShowMode::Chunks.handle(),
+                ContentShowMode::Usage.handle()
             ]
         );
 
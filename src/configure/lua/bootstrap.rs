@@ -18,6 +18,7 @@ use crate::{
 use super::{
     chart::build_chart_table,
     commands::build_commands_table,
+    data::build_data_table,
     events::build_events_table,
     heatmap::build_heatmap_table,
     keymaps::{build_action_constants_table, build_keymaps_table, build_mode_constants_table},
@@ -25,6 +26,7 @@ use super::{
     mchart::build_multichart_table,
     plugins::build_plugins_table,
     themes::{build_empty_nested_table, build_symbol_theme_table, build_theme_table},
+    tree::build_tree_table,
     ui::build_ui_table,
 };
 use crate::configure::{errors::ConfigureErrors, loading};
@@ -53,6 +55,8 @@ pub(super) fn build_h5v_table(
 ) -> Result<Table, ConfigureErrors> {
     let h5v = lua.create_table()?;
     h5v.set("chart", build_chart_table(lua)?)?;
+    h5v.set("data", build_data_table(lua)?)?;
+    h5v.set("tree", build_tree_table(lua)?)?;
     let modes = build_mode_constants_table(lua)?;
     let actions = build_action_constants_table(lua)?;
     let keymaps = build_keymaps_table(lua)?;
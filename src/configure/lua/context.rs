@@ -138,6 +138,7 @@ fn selected_item_kind(state: &AppState<'_>) -> Option<&'static str> {
         Node::File(_) => "file",
         Node::Group(_, _) => "group",
         Node::Dataset(_, _) => "dataset",
+        Node::Datatype(_, _) => "datatype",
         Node::Broken(_) => "broken",
     })
 }
@@ -593,6 +594,7 @@ fn mode_label(mode: &Mode) -> &'static str {
     match mode {
         Mode::Normal => "normal",
         Mode::Search => "search",
+        Mode::Find => "find",
         Mode::Help => "help",
         Mode::Logs => "logs",
         Mode::Command => "command",
@@ -601,5 +603,6 @@ fn mode_label(mode: &Mode) -> &'static str {
         Mode::AttributeDeleteDialog => "attribute-delete-dialog",
         Mode::FixedStringOverflowDialog => "fixed-string-overflow-dialog",
         Mode::FixedStringResizeDialog => "fixed-string-resize-dialog",
+        Mode::StructureEditDialog => "structure-edit-dialog",
     }
 }
@@ -0,0 +1,157 @@
+use std::collections::BTreeMap;
+
+use mlua::{Lua, Table, Value};
+
+use crate::configure::errors::ConfigureErrors;
+use crate::configure::{self, DataSettings};
+use crate::h5f::TimeEncoding;
+
+pub(super) fn build_data_table(lua: &Lua) -> Result<Table, ConfigureErrors> {
+    let data = lua.create_table()?;
+    let settings = configure::current_data_settings();
+    data.set("cf_decoding", settings.cf_decoding)?;
+    data.set("netcdf", settings.netcdf)?;
+    data.set("matlab", settings.matlab)?;
+    let timestamps = lua.create_table()?;
+    for (path, spec) in settings.timestamps {
+        match spec {
+            Some(spec) => timestamps.set(path, spec)?,
+            None => timestamps.set(path, false)?,
+        }
+    }
+    data.set("timestamps", timestamps)?;
+    Ok(data)
+}
+
+pub(super) fn parse_data_config(h5v: &Table) -> Result<Option<DataSettings>, ConfigureErrors> {
+    let data = match h5v.get::<Value>("data")? {
+        Value::Nil => return Ok(None),
+        Value::Table(table) => table,
+        other => {
+            return Err(mlua::Error::runtime(format!(
+                "h5v.data must be a table, got {}",
+                other.type_name()
+            ))
+            .into())
+        }
+    };
+    let defaults = DataSettings::default();
+    Ok(Some(DataSettings {
+        cf_decoding: parse_bool(&data, "cf_decoding", defaults.cf_decoding)?,
+        netcdf: parse_bool(&data, "netcdf", defaults.netcdf)?,
+        matlab: parse_bool(&data, "matlab", defaults.matlab)?,
+        timestamps: parse_timestamps(&data)?,
+    }))
+}
+
+/// `h5v.data.timestamps = { ["/path"] = "ns" | "seconds since 2020-01-01" | false }`
+fn parse_timestamps(data: &Table) -> Result<BTreeMap<String, Option<String>>, ConfigureErrors> {
+    let table = match data.get::<Value>("timestamps")? {
+        Value::Nil => return Ok(BTreeMap::new()),
+        Value::Table(table) => table,
+        other => {
+            return Err(mlua::Error::runtime(format!(
+                "h5v.data.timestamps must be a table, got {}",
+                other.type_name()
+            ))
+            .into())
+        }
+    };
+    let mut timestamps = BTreeMap::new();
+    for pair in table.pairs::<String, Value>() {
+        let (path, value) = pair?;
+        let path = format!("/{}", path.trim_start_matches('/'));
+        let spec = match value {
+            Value::Boolean(false) => None,
+            Value::String(spec) => {
+                let spec = spec.to_str()?.to_string();
+                if TimeEncoding::parse(&spec).is_none() {
+                    return Err(mlua::Error::runtime(format!(
+                        "h5v.data.timestamps[\"{path}\"]: unknown time units '{spec}'. Expected a unit such as \"ns\" or \"seconds since 2020-01-01\""
+                    ))
+                    .into());
+                }
+                Some(spec)
+            }
+            other => {
+                return Err(mlua::Error::runtime(format!(
+                    "h5v.data.timestamps[\"{path}\"] must be a units string or false, got {}",
+                    other.type_name()
+                ))
+                .into())
+            }
+        };
+        timestamps.insert(path, spec);
+    }
+    Ok(timestamps)
+}
+
+fn parse_bool(table: &Table, field: &str, default: bool) -> Result<bool, ConfigureErrors> {
+    match table.get::<Value>(field)? {
+        Value::Nil => Ok(default),
+        Value::Boolean(value) => Ok(value),
+        other => Err(mlua::Error::runtime(format!(
+            "h5v.data.{field} must be a boolean, got {}",
+            other.type_name()
+        ))
+        .into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_data_config;
+
+    #[test]
+    fn parses_cf_decoding_flag() {
+        let lua = mlua::Lua::new();
+        let h5v = lua.create_table().expect("create h5v table");
+        assert_eq!(parse_data_config(&h5v).expect("parse empty config"), None);
+
+        let data = lua.create_table().expect("create data table");
+        data.set("cf_decoding", true).expect("set cf_decoding");
+        h5v.set("data", data).expect("set data table");
+        let settings = parse_data_config(&h5v)
+            .expect("parse data config")
+            .expect("data settings");
+        assert!(settings.cf_decoding);
+        assert!(settings.netcdf);
+        assert!(settings.matlab);
+
+        let data = lua.create_table().expect("create data table");
+        data.set("cf_decoding", "yes").expect("set cf_decoding");
+        h5v.set("data", data).expect("set data table");
+        assert!(parse_data_config(&h5v).is_err());
+    }
+
+    #[test]
+    fn parses_timestamp_overrides() {
+        let lua = mlua::Lua::new();
+        let h5v = lua.create_table().expect("create h5v table");
+        let data = lua.create_table().expect("create data table");
+        let timestamps = lua.create_table().expect("create timestamps table");
+        timestamps.set("/run/t", "ns").expect("set ns override");
+        timestamps
+            .set("run/elapsed", false)
+            .expect("set disabled override");
+        data.set("timestamps", timestamps).expect("set timestamps");
+        h5v.set("data", data).expect("set data table");
+        let settings = parse_data_config(&h5v)
+            .expect("parse data config")
+            .expect("data settings");
+        assert_eq!(
+            settings.timestamps.get("/run/t"),
+            Some(&Some("ns".to_string()))
+        );
+        assert_eq!(settings.timestamps.get("/run/elapsed"), Some(&None));
+
+        let data = lua.create_table().expect("create data table");
+        let timestamps = lua.create_table().expect("create timestamps table");
+        timestamps
+            .set("/run/t", "fortnights since launch")
+            .expect("set bad override");
+        data.set("timestamps", timestamps).expect("set timestamps");
+        h5v.set("data", data).expect("set data table");
+        assert!(parse_data_config(&h5v).is_err());
+    }
+}
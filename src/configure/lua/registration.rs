@@ -243,6 +243,8 @@ pub(super) fn apply_lua_config_with_snapshot(
 
 pub(super) fn apply_non_registry_lua_config(h5v: &Table) -> Result<(), ConfigureErrors> {
     let chart_config = super::chart::parse_chart_config(h5v)?;
+    let data_config = super::data::parse_data_config(h5v)?;
+    let tree_config = super::tree::parse_tree_config(h5v)?;
     let heatmap_config = parse_heatmap_config(h5v)?;
     let layout_config = parse_layout_config(h5v)?;
     let keymap_config = parse_keymaps_config(h5v)?;
@@ -250,6 +252,8 @@ pub(super) fn apply_non_registry_lua_config(h5v: &Table) -> Result<(), Configure
         configure::set_auto_layout_settings(&layout_settings);
     }
     configure::set_chart_settings(&chart_config.unwrap_or_default());
+    configure::set_data_settings(&data_config.unwrap_or_default());
+    configure::set_tree_settings(&tree_config.unwrap_or_default());
     if let Some((range_modes, default_settings)) = heatmap_config {
         configure::set_heatmap_ranges(&range_modes, &default_settings.range);
     }
@@ -0,0 +1,132 @@
+use mlua::{Lua, Table, Value};
+
+use crate::configure::errors::ConfigureErrors;
+use crate::configure::{self, TreeColumns, TreeSettings, TreeSortOrder};
+
+pub(super) fn build_tree_table(lua: &Lua) -> Result<Table, ConfigureErrors> {
+    let tree = lua.create_table()?;
+    let settings = configure::current_tree_settings();
+    tree.set("sort", settings.sort.label())?;
+    tree.set(
+        "columns",
+        lua.create_sequence_from(settings.columns.names())?,
+    )?;
+    Ok(tree)
+}
+
+pub(super) fn parse_tree_config(h5v: &Table) -> Result<Option<TreeSettings>, ConfigureErrors> {
+    let tree = match h5v.get::<Value>("tree")? {
+        Value::Nil => return Ok(None),
+        Value::Table(table) => table,
+        other => {
+            return Err(mlua::Error::runtime(format!(
+                "h5v.tree must be a table, got {}",
+                other.type_name()
+            ))
+            .into())
+        }
+    };
+    let sort = match tree.get::<Value>("sort")? {
+        Value::Nil => TreeSortOrder::default(),
+        Value::String(value) => TreeSortOrder::parse(&value.to_str()?).ok_or_else(|| {
+            mlua::Error::runtime("h5v.tree.sort must be file, name, creation, size, or type")
+        })?,
+        other => {
+            return Err(mlua::Error::runtime(format!(
+                "h5v.tree.sort must be a string, got {}",
+                other.type_name()
+            ))
+            .into())
+        }
+    };
+    Ok(Some(TreeSettings {
+        sort,
+        columns: parse_columns(&tree)?,
+    }))
+}
+
+/// `h5v.tree.columns = { "shape", "dtype", "size" }`, or `true` for all of them.
+fn parse_columns(tree: &Table) -> Result<TreeColumns, ConfigureErrors> {
+    let table = match tree.get::<Value>("columns")? {
+        Value::Nil | Value::Boolean(false) => return Ok(TreeColumns::default()),
+        Value::Boolean(true) => return Ok(TreeColumns::ALL),
+        Value::Table(table) => table,
+        other => {
+            return Err(mlua::Error::runtime(format!(
+                "h5v.tree.columns must be a list of column names or a boolean, got {}",
+                other.type_name()
+            ))
+            .into())
+        }
+    };
+    let mut columns = TreeColumns::default();
+    for name in table.sequence_values::<String>() {
+        let name = name?;
+        if !columns.enable(&name) {
+            return Err(mlua::Error::runtime(format!(
+                "h5v.tree.columns: unknown column '{name}'. Expected shape, dtype, or size"
+            ))
+            .into());
+        }
+    }
+    Ok(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_tree_config;
+    use crate::configure::{TreeColumns, TreeSortOrder};
+
+    #[test]
+    fn parses_sort_order_and_columns() {
+        let lua = mlua::Lua::new();
+        let h5v = lua.create_table().expect("create h5v table");
+        assert_eq!(parse_tree_config(&h5v).expect("parse empty config"), None);
+
+        let tree = lua.create_table().expect("create tree table");
+        tree.set("sort", "Name").expect("set sort");
+        tree.set(
+            "columns",
+            lua.create_sequence_from(["shape", "size"])
+                .expect("create columns"),
+        )
+        .expect("set columns");
+        h5v.set("tree", tree).expect("set tree table");
+        let settings = parse_tree_config(&h5v)
+            .expect("parse tree config")
+            .expect("tree settings");
+        assert_eq!(settings.sort, TreeSortOrder::Name);
+        assert_eq!(
+            settings.columns,
+            TreeColumns {
+                shape: true,
+                dtype: false,
+                size: true,
+            }
+        );
+
+        let tree = lua.create_table().expect("create tree table");
+        tree.set("columns", true).expect("set columns");
+        h5v.set("tree", tree).expect("set tree table");
+        let settings = parse_tree_config(&h5v)
+            .expect("parse tree config")
+            .expect("tree settings");
+        assert_eq!(settings.sort, TreeSortOrder::File);
+        assert_eq!(settings.columns, TreeColumns::ALL);
+
+        let tree = lua.create_table().expect("create tree table");
+        tree.set("sort", "mtime").expect("set sort");
+        h5v.set("tree", tree).expect("set tree table");
+        assert!(parse_tree_config(&h5v).is_err());
+
+        let tree = lua.create_table().expect("create tree table");
+        tree.set(
+            "columns",
+            lua.create_sequence_from(["chunks"])
+                .expect("create columns"),
+        )
+        .expect("set columns");
+        h5v.set("tree", tree).expect("set tree table");
+        assert!(parse_tree_config(&h5v).is_err());
+    }
+}
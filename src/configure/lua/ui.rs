@@ -109,6 +109,7 @@ pub(crate) fn available_content_mode_handles(
                         Node::File(_) => "file",
                         Node::Group(_, _) => "group",
                         Node::Dataset(_, _) => "dataset",
+                        Node::Datatype(_, _) => "datatype",
                         Node::Broken(_) => "broken",
                     }
                     .to_string(),
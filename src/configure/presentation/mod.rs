@@ -22,16 +22,17 @@ pub(crate) use state::themed_color;
 pub use state::{
     apply_registry_snapshot, current_auto_layout_settings, current_chart_settings,
     current_config_generation, current_content_mode_order, current_content_mode_order_handles,
-    current_heatmap_default_range, current_heatmap_default_settings, current_heatmap_range_modes,
-    current_keymaps, current_multichart_settings, current_symbol_theme_name, current_theme_handle,
-    current_theme_name, ordered_content_mode_handles, ordered_content_modes, prefers_strong_text,
-    reset_config, restore_config, set_auto_layout_settings, set_chart_settings, set_heatmap_ranges,
-    set_keymap_config, snapshot_config,
+    current_data_settings, current_heatmap_default_range, current_heatmap_default_settings,
+    current_heatmap_range_modes, current_keymaps, current_multichart_settings,
+    current_symbol_theme_name, current_theme_handle, current_theme_name, current_tree_settings,
+    ordered_content_mode_handles, ordered_content_modes, prefers_strong_text, reset_config,
+    restore_config, set_auto_layout_settings, set_chart_settings, set_data_settings,
+    set_heatmap_ranges, set_keymap_config, set_tree_settings, snapshot_config,
 };
 #[cfg(test)]
 pub use state::{set_color_override, set_content_mode_order};
 #[allow(unused_imports)]
 pub use types::{
-    AutoLayoutSettings, AxisNumberFormat, ChartSettings, ConfigSnapshot, LayoutSize,
-    MultiChartSettings, PanelLayoutSizes,
+    AutoLayoutSettings, AxisNumberFormat, ChartSettings, ConfigSnapshot, DataSettings, LayoutSize,
+    MultiChartSettings, PanelLayoutSizes, TreeColumns, TreeSettings, TreeSortOrder,
 };
@@ -376,13 +376,17 @@ impl UiSymbols {
                 dataset_link_icon: "󰈚🔗",
                 compound_container_icon: "󰆼 ",
                 compound_leaf_icon: "󰈚 ",
+                datatype_icon: "󰊄 ",
                 link_marker: "🔗",
+                alias_marker: "⇒",
                 broken_node_icon: "*- ",
                 load_more_label: "⤵ Load 50 more",
             },
             section: SectionSymbols {
                 properties_title: "󰜉 Properties",
                 attributes_title: "󰠱 Attributes",
+                storage_title: "󰋊 Storage",
+                virtual_sources_title: "󰈢 Virtual sources",
             },
             title: TitleSymbols {
                 preview: "📈 Preview",
@@ -396,8 +400,11 @@ impl UiSymbols {
                 delete_attribute: " 🗑 Delete attribute ",
                 fixed_string_overflow: " 🧵 Fixed string overflow ",
                 fixed_string_resize: " 📏 Change fixed string size ",
+                edit_structure: " 🧱 Edit file structure ",
                 help: " ❔ Help ",
                 matrix_tab: "🧮 Matrix",
+                chunks_tab: "󰕰 Chunks",
+                usage_tab: "󰋊 Usage",
             },
             badge: BadgeSymbols {
                 readonly: " 🔒 read-only ",
@@ -435,13 +442,17 @@ impl UiSymbols {
                 dataset_link_icon: "D@",
                 compound_container_icon: "C ",
                 compound_leaf_icon: "c ",
+                datatype_icon: "T ",
                 link_marker: "@",
+                alias_marker: "=>",
                 broken_node_icon: "*- ",
                 load_more_label: "Load 50 more",
             },
             section: SectionSymbols {
                 properties_title: "Properties",
                 attributes_title: "Attributes",
+                storage_title: "Storage",
+                virtual_sources_title: "Virtual sources",
             },
             title: TitleSymbols {
                 preview: "Preview",
@@ -455,8 +466,11 @@ impl UiSymbols {
                 delete_attribute: " Delete attribute ",
                 fixed_string_overflow: " Fixed string overflow ",
                 fixed_string_resize: " Change fixed string size ",
+                edit_structure: " Edit file structure ",
                 help: " Help ",
                 matrix_tab: "Matrix",
+                chunks_tab: "Chunks",
+                usage_tab: "Usage",
             },
             badge: BadgeSymbols {
                 readonly: " [ro] read-only ",
@@ -16,7 +16,10 @@ use super::catalog::available_color_names;
 use super::{
     palette::{SymbolThemeName, ThemeName},
     parsing::parse_color,
-    types::{ChartSettings, ConfigSnapshot, ConfigState, ThemeColors, UiSymbols},
+    types::{
+        ChartSettings, ConfigSnapshot, ConfigState, DataSettings, ThemeColors, TreeSettings,
+        UiSymbols,
+    },
 };
 
 const THEME_SETTING: &str = "builtin.setting.theme";
@@ -51,6 +54,8 @@ fn default_content_mode_order() -> Vec<ContentModeHandle> {
         ContentShowMode::Preview.handle(),
         ContentShowMode::Matrix.handle(),
         ContentShowMode::Heatmap.handle(),
+        ContentShowMode::Chunks.handle(),
+        ContentShowMode::Usage.handle(),
     ]
 }
 
@@ -69,6 +74,8 @@ static CONFIG_STATE: LazyLock<RwLock<ConfigState>> = LazyLock::new(|| {
         heatmap_default_settings: HeatmapSettings::default(),
         multichart_settings: super::types::MultiChartSettings::default(),
         chart_settings: ChartSettings::default(),
+        data_settings: DataSettings::default(),
+        tree_settings: TreeSettings::default(),
         keymap_config: KeymapConfig::default(),
         keymaps: EffectiveKeymaps::default(),
     })
@@ -89,6 +96,8 @@ pub fn reset_config(theme: ThemeName) {
         state.heatmap_default_settings = HeatmapSettings::default();
         state.multichart_settings = super::types::MultiChartSettings::default();
         state.chart_settings = ChartSettings::default();
+        state.data_settings = DataSettings::default();
+        state.tree_settings = TreeSettings::default();
         state.keymap_config = KeymapConfig::default();
         state.keymaps = EffectiveKeymaps::default();
     });
@@ -107,6 +116,8 @@ pub fn snapshot_config() -> ConfigSnapshot {
         heatmap_default_settings: state.heatmap_default_settings.clone(),
         multichart_settings: state.multichart_settings.clone(),
         chart_settings: state.chart_settings.clone(),
+        data_settings: state.data_settings.clone(),
+        tree_settings: state.tree_settings.clone(),
         keymap_config: state.keymap_config.clone(),
         keymaps: state.keymaps.clone(),
     })
@@ -126,6 +137,8 @@ pub fn restore_config(snapshot: ConfigSnapshot) {
         state.heatmap_default_settings = snapshot.heatmap_default_settings;
         state.multichart_settings = snapshot.multichart_settings;
         state.chart_settings = snapshot.chart_settings;
+        state.data_settings = snapshot.data_settings;
+        state.tree_settings = snapshot.tree_settings;
         state.keymap_config = snapshot.keymap_config;
         state.keymaps = snapshot.keymaps;
     });
@@ -189,6 +202,8 @@ pub fn apply_registry_snapshot(snapshot: &RegistrySnapshot) -> Result<(), String
         state.heatmap_default_settings = heatmap_default_settings;
         state.multichart_settings = multichart_settings;
         state.chart_settings = ChartSettings::default();
+        state.data_settings = DataSettings::default();
+        state.tree_settings = TreeSettings::default();
         state.keymap_config = KeymapConfig::default();
         state.keymaps = EffectiveKeymaps::default();
     });
@@ -224,6 +239,26 @@ pub fn set_chart_settings(settings: &ChartSettings) {
     });
 }
 
+pub fn current_data_settings() -> DataSettings {
+    with_config_read(|state| state.data_settings.clone())
+}
+
+pub fn set_data_settings(settings: &DataSettings) {
+    with_config_write(|state| {
+        state.data_settings = settings.clone();
+    });
+}
+
+pub fn current_tree_settings() -> TreeSettings {
+    with_config_read(|state| state.tree_settings.clone())
+}
+
+pub fn set_tree_settings(settings: &TreeSettings) {
+    with_config_write(|state| {
+        state.tree_settings = settings.clone();
+    });
+}
+
 pub fn current_theme_handle() -> String {
     with_config_read(|state| state.active_theme_handle.clone())
 }
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use macros::{ColorGroup, SymbolGroup, ThemeColorCatalog, ThemeSymbolCatalog};
 use ratatui::{layout::Constraint, prelude::Color};
 
@@ -189,6 +191,123 @@ impl Default for ChartSettings {
     }
 }
 
+/// How file contents are interpreted before they reach the tree and previews.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataSettings {
+    /// Apply CF `scale_factor`/`add_offset` and mask fill or out-of-range values.
+    pub cf_decoding: bool,
+    /// Present netCDF-4 files by their dimensions and hide library bookkeeping.
+    pub netcdf: bool,
+    /// Present v7.3 MAT-files the way MATLAB shows their variables.
+    pub matlab: bool,
+    /// Timestamp encodings by dataset path, overriding detection; `None`
+    /// shows the dataset as plain numbers.
+    pub timestamps: BTreeMap<String, Option<String>>,
+}
+
+impl Default for DataSettings {
+    fn default() -> Self {
+        Self {
+            cf_decoding: false,
+            netcdf: true,
+            matlab: true,
+            timestamps: BTreeMap::new(),
+        }
+    }
+}
+
+/// Order in which the tree lists the members of a group.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TreeSortOrder {
+    /// Groups, then datasets, then named datatypes, each in HDF5 iteration order.
+    #[default]
+    File,
+    /// Natural order of names, so `run2` comes before `run10`.
+    Name,
+    /// Link creation order, for groups that track it.
+    Creation,
+    /// Largest storage first; members whose size is not read yet come last.
+    Size,
+    /// Groups, then datasets by dtype, then named datatypes.
+    Type,
+}
+
+impl TreeSortOrder {
+    pub const ALL: [Self; 5] = [
+        Self::File,
+        Self::Name,
+        Self::Creation,
+        Self::Size,
+        Self::Type,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::File => "file",
+            Self::Name => "name",
+            Self::Creation => "creation",
+            Self::Size => "size",
+            Self::Type => "type",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        Self::ALL
+            .into_iter()
+            .find(|order| order.label().eq_ignore_ascii_case(value))
+    }
+}
+
+/// Dataset details shown after member names in the tree.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TreeColumns {
+    pub shape: bool,
+    pub dtype: bool,
+    pub size: bool,
+}
+
+impl TreeColumns {
+    pub const ALL: Self = Self {
+        shape: true,
+        dtype: true,
+        size: true,
+    };
+
+    pub fn any(self) -> bool {
+        self.shape || self.dtype || self.size
+    }
+
+    /// Turns on the column called `name`; `false` for unknown names.
+    pub fn enable(&mut self, name: &str) -> bool {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "shape" => self.shape = true,
+            "dtype" => self.dtype = true,
+            "size" => self.size = true,
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn names(self) -> Vec<&'static str> {
+        [
+            (self.shape, "shape"),
+            (self.dtype, "dtype"),
+            (self.size, "size"),
+        ]
+        .into_iter()
+        .filter_map(|(shown, name)| shown.then_some(name))
+        .collect()
+    }
+}
+
+/// How the tree orders and annotates group members.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TreeSettings {
+    pub sort: TreeSortOrder,
+    pub columns: TreeColumns,
+}
+
 impl Default for MultiChartSettings {
     fn default() -> Self {
         Self {
@@ -313,6 +432,8 @@ pub struct ConfigSnapshot {
     pub(crate) heatmap_default_settings: HeatmapSettings,
     pub(crate) multichart_settings: MultiChartSettings,
     pub(crate) chart_settings: ChartSettings,
+    pub(crate) data_settings: DataSettings,
+    pub(crate) tree_settings: TreeSettings,
     pub(crate) keymap_config: KeymapConfig,
     pub(crate) keymaps: EffectiveKeymaps,
 }
@@ -331,6 +452,8 @@ pub(crate) struct ConfigState {
     pub(crate) heatmap_default_settings: HeatmapSettings,
     pub(crate) multichart_settings: MultiChartSettings,
     pub(crate) chart_settings: ChartSettings,
+    pub(crate) data_settings: DataSettings,
+    pub(crate) tree_settings: TreeSettings,
     pub(crate) keymap_config: KeymapConfig,
     pub(crate) keymaps: EffectiveKeymaps,
 }
@@ -352,7 +475,9 @@ pub(crate) struct TreeSymbols {
     pub(crate) dataset_link_icon: &'static str,
     pub(crate) compound_container_icon: &'static str,
     pub(crate) compound_leaf_icon: &'static str,
+    pub(crate) datatype_icon: &'static str,
     pub(crate) link_marker: &'static str,
+    pub(crate) alias_marker: &'static str,
     pub(crate) broken_node_icon: &'static str,
     pub(crate) load_more_label: &'static str,
 }
@@ -361,6 +486,8 @@ pub(crate) struct TreeSymbols {
 pub(crate) struct SectionSymbols {
     pub(crate) properties_title: &'static str,
     pub(crate) attributes_title: &'static str,
+    pub(crate) storage_title: &'static str,
+    pub(crate) virtual_sources_title: &'static str,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, SymbolGroup)]
@@ -376,8 +503,11 @@ pub(crate) struct TitleSymbols {
     pub(crate) delete_attribute: &'static str,
     pub(crate) fixed_string_overflow: &'static str,
     pub(crate) fixed_string_resize: &'static str,
+    pub(crate) edit_structure: &'static str,
     pub(crate) help: &'static str,
     pub(crate) matrix_tab: &'static str,
+    pub(crate) chunks_tab: &'static str,
+    pub(crate) usage_tab: &'static str,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, SymbolGroup)]
@@ -338,6 +338,8 @@ fn seed_builtin_content_modes(builder: &mut RegistryBuilder) -> Result<(), Regis
         ContentShowMode::Preview,
         ContentShowMode::Matrix,
         ContentShowMode::Heatmap,
+        ContentShowMode::Chunks,
+        ContentShowMode::Usage,
     ] {
         builder.register_content_mode(ContentModeMetadata {
             handle: ContentModeHandle::new(format!("builtin.content_mode.{}", mode.as_str())),
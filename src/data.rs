@@ -1,7 +1,16 @@
 use core::f64;
 
 use hdf5_metno::{Dataset, Error, H5Type, Hyperslab, Selection, SliceOrIndex};
-use ndarray::{Array1, Array2};
+use ndarray::{Array, Array1, Array2, Dimension, Ix1, IxDyn};
+
+use crate::{
+    configure,
+    error::AppError,
+    h5f::{
+        read_bitfield_values, read_opaque_float16_values, read_ragged_element, BitfieldType,
+        CfDecoding, Float16Type,
+    },
+};
 
 pub(crate) const DEFAULT_CHART_PREVIEW_MAX_SAMPLES: usize = 4096;
 pub(crate) const DEFAULT_MCHART_OVERVIEW_MAX_SAMPLES: usize = DEFAULT_CHART_PREVIEW_MAX_SAMPLES;
@@ -158,6 +167,42 @@ pub(crate) fn plot_dataset_with_cap(
     selection: &PreviewSelection,
     max_samples: usize,
 ) -> Result<DatasetPlotingData, Error> {
+    let (hyperslab, length, step) = sampled_preview_hyperslab(dataset, selection, max_samples)?;
+    let mut data_to_show = read_f64_values::<Ix1>(dataset, hyperslab)?;
+    if let Some(decoding) = active_cf_decoding(dataset) {
+        data_to_show.mapv_inplace(|value| decoding.decode(value));
+    }
+    Ok(sampled_ploting_data(
+        data_to_show.iter().copied(),
+        length,
+        step,
+    ))
+}
+
+/// One bit of a bitfield dataset as 0/1 along the selected axis.
+pub(crate) fn plot_bit_plane(
+    dataset: &Dataset,
+    bitfield: &BitfieldType,
+    bit: usize,
+    selection: &PreviewSelection,
+) -> Result<DatasetPlotingData, AppError> {
+    let (hyperslab, length, step) =
+        sampled_preview_hyperslab(dataset, selection, DEFAULT_CHART_PREVIEW_MAX_SAMPLES)?;
+    let (values, _) = read_bitfield_values(dataset, bitfield, hyperslab)?;
+    Ok(sampled_ploting_data(
+        values.into_iter().map(|value| (value >> bit & 1) as f64),
+        length,
+        step,
+    ))
+}
+
+/// The strided hyperslab charted for `selection`, with the full length of the
+/// selected range and the sampling step.
+fn sampled_preview_hyperslab(
+    dataset: &Dataset,
+    selection: &PreviewSelection,
+    max_samples: usize,
+) -> Result<(Selection, usize, usize), Error> {
     let shape = dataset.shape();
     validate_preview_selection_shape(&shape, selection)?;
     let slice = match selection.slice {
@@ -180,24 +225,80 @@ pub(crate) fn plot_dataset_with_cap(
             slice_selections.push(SliceOrIndex::Index(selection.index[idx]));
         }
     }
+    Ok((
+        Selection::Hyperslab(Hyperslab::from(slice_selections)),
+        length,
+        step,
+    ))
+}
 
-    let selection = Selection::Hyperslab(Hyperslab::from(slice_selections));
-    let data_to_show = dataset.read_slice_1d(selection)?;
-    let data = data_to_show
+fn sampled_ploting_data(
+    values: impl Iterator<Item = f64>,
+    length: usize,
+    step: usize,
+) -> DatasetPlotingData {
+    let data = values
+        .enumerate()
+        .map(|(i, y)| ((i * step) as f64, y))
+        .collect::<Vec<_>>();
+    let max = data.iter().map(|(_, y)| *y).fold(f64::NAN, f64::max);
+    let min = data.iter().map(|(_, y)| *y).fold(f64::NAN, f64::min);
+    DatasetPlotingData {
+        data,
+        length,
+        max,
+        min,
+    }
+}
+
+/// Values of one element of a ragged dataset against their position in it.
+pub(crate) fn plot_ragged_element(
+    dataset: &Dataset,
+    indexes: &[usize],
+) -> Result<DatasetPlotingData, AppError> {
+    let values = read_ragged_element(dataset, indexes)?;
+    let step = plot_sampling_step_with_cap(values.len(), DEFAULT_CHART_PREVIEW_MAX_SAMPLES);
+    let data = values
         .iter()
         .enumerate()
-        .map(|(i, y)| ((i * step) as f64, *y))
+        .step_by(step)
+        .map(|(i, y)| (i as f64, *y))
         .collect::<Vec<_>>();
     let max = data.iter().map(|(_, y)| *y).fold(f64::NAN, f64::max);
     let min = data.iter().map(|(_, y)| *y).fold(f64::NAN, f64::min);
     Ok(DatasetPlotingData {
         data,
-        length,
+        length: values.len(),
         max,
         min,
     })
 }
 
+/// Values of a numeric selection as f64. HDF5 converts every numeric type,
+/// 16-bit floats included; 16-bit floats in opaque storage are decoded here.
+pub(crate) fn read_f64_values<D: Dimension>(
+    dataset: &Dataset,
+    selection: Selection,
+) -> Result<Array<f64, D>, Error> {
+    let Some(float16) = Float16Type::read(dataset).filter(|float16| float16.opaque) else {
+        return dataset.read_slice::<f64, _, D>(selection);
+    };
+    let (values, shape) = read_opaque_float16_values(dataset, float16, selection)
+        .map_err(|error| Error::from(error.to_string()))?;
+    Array::from_shape_vec(IxDyn(&shape), values)
+        .map_err(|error| Error::from(error.to_string()))?
+        .into_dimensionality::<D>()
+        .map_err(|error| Error::from(error.to_string()))
+}
+
+/// CF decoding to apply to values read from `dataset`, when enabled in the config.
+pub(crate) fn active_cf_decoding(dataset: &Dataset) -> Option<CfDecoding> {
+    if !configure::current_data_settings().cf_decoding {
+        return None;
+    }
+    CfDecoding::read(dataset)
+}
+
 pub(crate) fn plot_sampling_step_with_cap(length: usize, max_samples: usize) -> usize {
     length.div_ceil(max_samples.max(1)).max(1)
 }
@@ -1,14 +1,44 @@
+mod aliases;
+mod attribute_index;
 mod attrs;
+mod chunks;
 mod codec;
+mod complex;
 mod compound;
+mod dataset_index;
+mod dimension_scales;
+mod find;
+mod matlab;
 mod meta;
 mod model;
+mod netcdf;
+mod object_index;
+mod object_info;
+mod storage;
+mod structure;
 mod tree;
+mod usage;
+mod virtual_sources;
 
+pub use aliases::*;
+pub use attribute_index::*;
 pub use attrs::*;
+pub use chunks::*;
 pub use codec::*;
+pub use complex::*;
 pub use compound::*;
+pub use dataset_index::*;
+pub use dimension_scales::*;
+pub use find::*;
+pub use matlab::*;
 pub use meta::*;
 pub use model::*;
+pub use netcdf::*;
+pub use object_index::*;
+pub use object_info::*;
+pub use storage::*;
+pub use structure::*;
 #[allow(unused_imports)]
 pub use tree::*;
+pub use usage::*;
+pub use virtual_sources::*;
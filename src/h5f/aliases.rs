@@ -0,0 +1,256 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    ffi::{CStr, CString},
+    mem::{size_of, MaybeUninit},
+    os::raw::{c_char, c_void},
+    sync::Arc,
+};
+
+use hdf5_metno::{Group, Location};
+use hdf5_metno_sys::{
+    h5::{herr_t, H5_index_t, H5_iter_order_t},
+    h5i::hid_t,
+    h5o::{
+        H5O_info2_t, H5O_token_t, H5Oget_info3, H5Oget_info_by_name3, H5Ovisit3, H5O_INFO_BASIC,
+        H5O_MAX_TOKEN_SIZE,
+    },
+    h5p::H5P_DEFAULT,
+};
+
+/// An object HDF5 has open, told apart by file number and object token, so
+/// links to the same object compare equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObjectIdentity {
+    fileno: u64,
+    token: [u8; H5O_MAX_TOKEN_SIZE],
+}
+
+impl ObjectIdentity {
+    pub fn of(location: &Location) -> Option<Self> {
+        object_info(location.id(), None).map(|(identity, _)| identity)
+    }
+
+    pub fn by_name(location: &Location, name: &str) -> Option<Self> {
+        object_info(location.id(), Some(name)).map(|(identity, _)| identity)
+    }
+
+    pub fn same_file(&self, other: &Self) -> bool {
+        self.fileno == other.fileno
+    }
+
+    fn from_info(info: &H5O_info2_t) -> Self {
+        Self {
+            fileno: info.fileno as _,
+            token: token_bytes(&info.token),
+        }
+    }
+}
+
+const _: () = assert!(size_of::<H5O_token_t>() == H5O_MAX_TOKEN_SIZE);
+
+/// The bytes of `token`, which the bindings keep in a private field.
+fn token_bytes(token: &H5O_token_t) -> [u8; H5O_MAX_TOKEN_SIZE] {
+    let mut bytes = [0; H5O_MAX_TOKEN_SIZE];
+    // The token is a plain byte array of exactly this size.
+    unsafe {
+        std::ptr::copy_nonoverlapping(
+            (token as *const H5O_token_t).cast::<u8>(),
+            bytes.as_mut_ptr(),
+            H5O_MAX_TOKEN_SIZE,
+        );
+    }
+    bytes
+}
+
+/// The object and its hard link count.
+pub(super) fn object_links(
+    location: &Location,
+    name: Option<&str>,
+) -> Option<(ObjectIdentity, u32)> {
+    object_info(location.id(), name)
+}
+
+fn object_info(id: hid_t, name: Option<&str>) -> Option<(ObjectIdentity, u32)> {
+    let mut info = MaybeUninit::<H5O_info2_t>::uninit();
+    let status = match name {
+        Some(name) => {
+            let name = CString::new(name).ok()?;
+            unsafe {
+                H5Oget_info_by_name3(
+                    id,
+                    name.as_ptr(),
+                    info.as_mut_ptr(),
+                    H5O_INFO_BASIC,
+                    H5P_DEFAULT,
+                )
+            }
+        }
+        None => unsafe { H5Oget_info3(id, info.as_mut_ptr(), H5O_INFO_BASIC) },
+    };
+    if status < 0 {
+        return None;
+    }
+    let info = unsafe { info.assume_init() };
+    Some((ObjectIdentity::from_info(&info), info.rc))
+}
+
+/// A tree node reached through a link other than the object's canonical path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectAlias {
+    /// First path at which a depth-first walk of the file in name order
+    /// reaches the object, as `h5ls -r` lists it.
+    pub canonical_path: String,
+    /// Set when the link leads back to one of its own ancestor groups; such
+    /// nodes are not expanded.
+    pub cycle: bool,
+}
+
+impl ObjectAlias {
+    pub fn describe(&self) -> String {
+        if self.cycle {
+            format!("{} (cycle)", self.canonical_path)
+        } else {
+            self.canonical_path.clone()
+        }
+    }
+}
+
+/// Canonical path of every object of a file that a hard link reaches.
+pub type CanonicalPaths = Arc<HashMap<ObjectIdentity, String>>;
+
+/// Walks the file `root` belongs to for the canonical path of each of its
+/// objects. `keep_going` is asked between objects; `None` is returned when it
+/// stops the walk.
+pub fn read_canonical_paths(
+    root: &Location,
+    keep_going: &mut dyn FnMut() -> bool,
+) -> Option<CanonicalPaths> {
+    let mut walk = CanonicalPathWalk {
+        found: HashMap::new(),
+        keep_going,
+    };
+    let status = unsafe {
+        H5Ovisit3(
+            root.id(),
+            H5_index_t::H5_INDEX_NAME,
+            H5_iter_order_t::H5_ITER_INC,
+            Some(record_canonical_path),
+            (&mut walk as *mut CanonicalPathWalk).cast(),
+            H5O_INFO_BASIC,
+        )
+    };
+    (status == 0).then(|| Arc::new(walk.found))
+}
+
+struct CanonicalPathWalk<'a> {
+    found: HashMap<ObjectIdentity, String>,
+    keep_going: &'a mut dyn FnMut() -> bool,
+}
+
+extern "C" fn record_canonical_path(
+    _object: hid_t,
+    name: *const c_char,
+    info: *const H5O_info2_t,
+    op_data: *mut c_void,
+) -> herr_t {
+    let walk = unsafe { &mut *op_data.cast::<CanonicalPathWalk>() };
+    if !(walk.keep_going)() {
+        return 1;
+    }
+    let identity = ObjectIdentity::from_info(unsafe { &*info });
+    walk.found.entry(identity).or_insert_with(|| {
+        let name = unsafe { CStr::from_ptr(name) }.to_string_lossy();
+        if name == "." {
+            "/".to_string()
+        } else {
+            format!("/{name}")
+        }
+    });
+    0
+}
+
+/// Whether the object is one of the groups on the path leading to it.
+pub fn is_own_ancestor(root: &Location, path: &str, identity: ObjectIdentity) -> bool {
+    let segments = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+    (0..segments.len()).any(|depth| {
+        let ancestor = if depth == 0 {
+            ObjectIdentity::of(root)
+        } else {
+            ObjectIdentity::by_name(root, &format!("/{}", segments[..depth].join("/")))
+        };
+        ancestor == Some(identity)
+    })
+}
+
+/// Groups entered so far by a recursive walk, so hard-link and soft-link
+/// cycles are walked once.
+#[derive(Debug, Default)]
+pub struct VisitedGroups(RefCell<HashSet<ObjectIdentity>>);
+
+impl VisitedGroups {
+    /// Records the group and tells whether it was new. Groups whose identity
+    /// cannot be read are always entered.
+    pub fn enter(&self, group: &Group) -> bool {
+        ObjectIdentity::of(group).is_none_or(|identity| self.0.borrow_mut().insert(identity))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::{
+        is_own_ancestor, object_links, read_canonical_paths, ObjectIdentity, VisitedGroups,
+    };
+
+    #[test]
+    fn hard_links_share_an_identity_and_a_canonical_path() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let b = file.create_group("b").expect("failed to create group");
+        b.new_dataset::<i32>()
+            .shape([2])
+            .create("data")
+            .expect("failed to create dataset");
+        file.link_hard("b/data", "a_data")
+            .expect("failed to link dataset");
+        b.link_hard("/b", "loop").expect("failed to link group");
+
+        let data = ObjectIdentity::by_name(&file, "b/data").expect("data identity");
+        assert_eq!(ObjectIdentity::by_name(&file, "a_data"), Some(data));
+        assert_eq!(
+            object_links(&file, Some("a_data")).map(|(_, links)| links),
+            Some(2)
+        );
+        let group = ObjectIdentity::of(&b).expect("group identity");
+        assert_eq!(ObjectIdentity::by_name(&file, "b/loop/loop"), Some(group));
+
+        let paths = read_canonical_paths(&file, &mut || true).expect("walk the file");
+        assert_eq!(paths.get(&data).map(String::as_str), Some("/a_data"));
+        assert_eq!(paths.get(&group).map(String::as_str), Some("/b"));
+
+        file.link_hard("b/data", "0_data")
+            .expect("failed to link dataset");
+        let paths = read_canonical_paths(&file, &mut || true).expect("walk the file");
+        assert_eq!(paths.get(&data).map(String::as_str), Some("/0_data"));
+
+        let mut steps = 0;
+        assert!(read_canonical_paths(&file, &mut || {
+            steps += 1;
+            steps < 2
+        })
+        .is_none());
+
+        assert!(is_own_ancestor(&file, "/b/loop", group));
+        assert!(!is_own_ancestor(&file, "/a_data", data));
+
+        let visited = VisitedGroups::default();
+        assert!(visited.enter(&b));
+        assert!(!visited.enter(&file.group("b/loop").expect("open loop")));
+    }
+}
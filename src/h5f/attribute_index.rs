@@ -0,0 +1,136 @@
+use hdf5_metno::{
+    types::{TypeDescriptor, VarLenAscii, VarLenUnicode},
+    Attribute, Location,
+};
+
+use crate::ui::render::sprint_attribute;
+
+use super::codec::read_attr_memory_bytes;
+
+/// Attributes with more elements than this are indexed by name only.
+pub const MAX_INDEXED_ATTRIBUTE_ELEMENTS: usize = 4096;
+
+/// One element of an attribute value, as attribute search compares it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeValue {
+    Number(f64),
+    Text(String),
+    /// Enum member; `name` is `None` for values outside the member list.
+    Enum {
+        name: Option<String>,
+        value: u64,
+    },
+}
+
+/// An attribute read by [`index_objects`].
+///
+/// [`index_objects`]: super::index_objects
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedAttribute {
+    /// Path of the object carrying the attribute.
+    pub path: String,
+    pub name: String,
+    /// Elements that equality queries compare; empty for types without a
+    /// comparable value.
+    pub values: Vec<AttributeValue>,
+    /// The value as the attributes panel renders it.
+    pub display: String,
+}
+
+/// Attributes of the object at `path`, for search.
+pub(crate) fn read_indexed_attributes(path: &str, location: &Location) -> Vec<IndexedAttribute> {
+    location
+        .attr_names()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|name| {
+            let attr = location.attr(&name).ok()?;
+            Some(IndexedAttribute {
+                path: path.to_string(),
+                values: attribute_values(&attr),
+                display: sprint_attribute(&attr)
+                    .map(|line| line.to_string())
+                    .unwrap_or_else(|error| format!("Error: {error}")),
+                name,
+            })
+        })
+        .collect()
+}
+
+/// Comparable elements of `attr`. Unreadable values and values larger than
+/// [`MAX_INDEXED_ATTRIBUTE_ELEMENTS`] give no elements.
+pub fn attribute_values(attr: &Attribute) -> Vec<AttributeValue> {
+    if attr.size() > MAX_INDEXED_ATTRIBUTE_ELEMENTS {
+        return Vec::new();
+    }
+    let Ok(dtype) = attr.dtype() else {
+        return Vec::new();
+    };
+    let Ok(descriptor) = dtype.to_descriptor() else {
+        return Vec::new();
+    };
+    let values = match descriptor {
+        TypeDescriptor::Integer(_) | TypeDescriptor::Unsigned(_) | TypeDescriptor::Float(_) => {
+            attr.read_raw::<f64>().map(|values| {
+                values
+                    .into_iter()
+                    .map(AttributeValue::Number)
+                    .collect::<Vec<_>>()
+            })
+        }
+        TypeDescriptor::Boolean => attr.read_raw::<bool>().map(|values| {
+            values
+                .into_iter()
+                .map(|value| AttributeValue::Text(value.to_string()))
+                .collect()
+        }),
+        TypeDescriptor::Enum(enum_type) => attr.read_raw::<u64>().map(|values| {
+            values
+                .into_iter()
+                .map(|value| AttributeValue::Enum {
+                    name: enum_type
+                        .members
+                        .iter()
+                        .find(|member| member.value == value)
+                        .map(|member| member.name.clone()),
+                    value,
+                })
+                .collect()
+        }),
+        TypeDescriptor::VarLenAscii => attr.read_raw::<VarLenAscii>().map(|values| {
+            values
+                .into_iter()
+                .map(|value| AttributeValue::Text(value.as_str().to_string()))
+                .collect()
+        }),
+        TypeDescriptor::VarLenUnicode => attr.read_raw::<VarLenUnicode>().map(|values| {
+            values
+                .into_iter()
+                .map(|value| AttributeValue::Text(value.as_str().to_string()))
+                .collect()
+        }),
+        TypeDescriptor::FixedAscii(size) | TypeDescriptor::FixedUnicode(size) if size > 0 => {
+            return read_attr_memory_bytes(attr)
+                .map(|bytes| {
+                    bytes
+                        .chunks(size)
+                        .map(|chunk| AttributeValue::Text(fixed_string_text(chunk)))
+                        .collect()
+                })
+                .unwrap_or_default();
+        }
+        _ => return Vec::new(),
+    };
+    values.unwrap_or_default()
+}
+
+/// Text of a fixed-length string element, without the null or space padding.
+fn fixed_string_text(bytes: &[u8]) -> String {
+    let end = bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end])
+        .trim_end_matches(' ')
+        .to_string()
+}
@@ -15,11 +15,13 @@ use crate::{
 
 use super::{
     codec::{
-        copy_attr_to_group, create_scalar_attr_from_text, rewrite_fixed_string_attr,
-        write_attr_from_text, AttributeCreateType, FixedStringRewrite,
+        copy_attr_to_group, create_attr_from_text, retype_attr_from_text,
+        rewrite_fixed_string_attr, write_attr_from_text, AttributeSpec, FixedStringRewrite,
     },
-    meta::SYSTEM_PROPERTIES,
+    meta::{PROPERTY_ROW_NAMES, SYSTEM_PROPERTIES},
     model::{DatasetHandle, DatasetMetaState, H5FNode, Node},
+    netcdf::{is_netcdf_view, netcdf_visible_attributes, render_netcdf_properties},
+    object_info::ObjectHeader,
 };
 
 pub trait HasAttributes {
@@ -29,9 +31,15 @@ pub trait HasAttributes {
     fn create_attr(
         &self,
         name: &str,
-        attr_type: AttributeCreateType,
+        spec: &AttributeSpec,
         value: &str,
     ) -> Result<String, AppError>;
+    fn retype_attr(
+        &self,
+        name: &str,
+        spec: &AttributeSpec,
+        value: Option<&str>,
+    ) -> Result<String, AppError>;
     fn delete_attr(&self, name: &str) -> Result<(), AppError>;
     fn update_attr_name(&self, old_name: &str, new_name: &str) -> Result<(), AppError>;
 }
@@ -58,6 +66,7 @@ impl HasName for Node {
             Node::Group(_, meta) => meta.display_name.clone(),
             Node::Dataset(_, DatasetMetaState::Pending(identity)) => identity.display_name.clone(),
             Node::Dataset(_, DatasetMetaState::Loaded(meta)) => meta.display_name.clone(),
+            Node::Datatype(_, meta) => meta.display_name.clone(),
             Node::Broken(name) => name.clone(),
         }
     }
@@ -76,6 +85,7 @@ impl HasPath for Node {
             Node::Dataset(DatasetHandle::Pending { .. }, DatasetMetaState::Loaded(meta)) => {
                 meta.virtual_path().unwrap_or(&meta.filename).to_string()
             }
+            Node::Datatype(datatype, _) => datatype.name(),
             Node::Broken(n) => n.clone(),
         }
         .to_string()
@@ -98,6 +108,7 @@ impl HasAttributes for Node {
             Node::File(file) => Ok(file.attr_names()?),
             Node::Group(group, _) => Ok(group.attr_names()?),
             Node::Dataset(DatasetHandle::Loaded(dataset), _) => Ok(dataset.attr_names()?),
+            Node::Datatype(datatype, _) => Ok(datatype.attr_names()?),
             Node::Dataset(DatasetHandle::Pending { .. }, _) => Err(hdf5_metno::Error::Internal(
                 "Dataset metadata is pending".to_string(),
             )),
@@ -110,6 +121,7 @@ impl HasAttributes for Node {
             Node::File(file) => file.attr(name),
             Node::Group(group, _) => group.attr(name),
             Node::Dataset(DatasetHandle::Loaded(dataset), _) => dataset.attr(name),
+            Node::Datatype(datatype, _) => datatype.attr(name),
             Node::Dataset(DatasetHandle::Pending { .. }, _) => Err(hdf5_metno::Error::Internal(
                 "Dataset metadata is pending".to_string(),
             )),
@@ -122,7 +134,7 @@ impl HasAttributes for Node {
     fn create_attr(
         &self,
         name: &str,
-        attr_type: AttributeCreateType,
+        spec: &AttributeSpec,
         value: &str,
     ) -> Result<String, AppError> {
         let name = validate_user_attribute_name(name)?;
@@ -134,7 +146,25 @@ impl HasAttributes for Node {
                 name
             )));
         }
-        create_scalar_attr_from_text(&group, &name, attr_type, value)
+        create_attr_from_text(&group, &name, spec, value)
+    }
+
+    fn retype_attr(
+        &self,
+        name: &str,
+        spec: &AttributeSpec,
+        value: Option<&str>,
+    ) -> Result<String, AppError> {
+        let name = validate_user_attribute_name(name)?;
+        let group = node_attribute_group(self)?;
+        let existing = group.attr_names()?;
+        if !existing.iter().any(|existing_name| existing_name == &name) {
+            return Err(AppError::EditError(format!(
+                "Attribute '{}' does not exist",
+                name
+            )));
+        }
+        retype_attr_from_text(&group, &name, spec, value)
     }
 
     fn delete_attr(&self, name: &str) -> Result<(), AppError> {
@@ -158,6 +188,7 @@ impl HasAttributes for Node {
             Node::File(file) => file.as_group()?,
             Node::Group(group, _) => group.as_group()?,
             Node::Dataset(DatasetHandle::Loaded(dataset), _) => dataset.as_group()?,
+            Node::Datatype(datatype, _) => datatype.as_group()?,
             Node::Dataset(DatasetHandle::Pending { .. }, _) => {
                 return Err(AppError::Hdf5(hdf5_metno::Error::Internal(
                     "Dataset metadata is pending".to_string(),
@@ -197,6 +228,7 @@ fn node_attribute_group(node: &Node) -> Result<Group, AppError> {
         Node::Dataset(DatasetHandle::Loaded(dataset), _) => {
             dataset.as_group().map_err(AppError::from)
         }
+        Node::Datatype(datatype, _) => datatype.as_group().map_err(AppError::from),
         Node::Dataset(DatasetHandle::Pending { .. }, _) => {
             Err(hdf5_metno::Error::Internal("Dataset metadata is pending".to_string()).into())
         }
@@ -228,6 +260,7 @@ pub enum MetadataRowKind {
     SectionHeader,
     Property,
     Attribute,
+    VirtualSource(usize),
 }
 
 #[derive(Debug, Clone)]
@@ -254,6 +287,14 @@ impl RenderedAttributeRow {
                         configure::configured_symbol(|symbols| symbols.section.attributes_title)
                             .to_string()
                     }
+                    "Storage" => {
+                        configure::configured_symbol(|symbols| symbols.section.storage_title)
+                            .to_string()
+                    }
+                    "Virtual sources" => configure::configured_symbol(|symbols| {
+                        symbols.section.virtual_sources_title
+                    })
+                    .to_string(),
                     other => other.to_string(),
                 },
                 Style::default()
@@ -293,6 +334,26 @@ impl RenderedAttributeRow {
         }
     }
 
+    pub fn virtual_source(
+        index: usize,
+        cells: (Line<'static>, Line<'static>, Line<'static>),
+    ) -> Self {
+        let (name_line, value_line, type_line) = cells;
+        Self {
+            kind: MetadataRowKind::VirtualSource(index),
+            key: Some(format!("source[{index}]")),
+            name_line,
+            value_line,
+            type_line,
+        }
+    }
+
+    /// Whether this row shows the user attribute `name`, as opposed to a
+    /// built-in row of the same name.
+    pub fn is_attribute(&self, name: &str) -> bool {
+        matches!(self.kind, MetadataRowKind::Attribute) && self.key.as_deref() == Some(name)
+    }
+
     pub fn is_selectable(&self) -> bool {
         !matches!(self.kind, MetadataRowKind::SectionHeader)
     }
@@ -311,14 +372,28 @@ impl ComputedAttributes {
             node.ensure_dataset_meta()?;
         }
         let node = &node.node;
-        let attributes = node.attributes()?;
+        let netcdf_group = match node {
+            Node::File(file) => Some(file.as_group()?),
+            Node::Group(group, _) => Some(group.clone()),
+            _ => None,
+        };
+        let netcdf = match node {
+            Node::Dataset(DatasetHandle::Loaded(dataset), _) => {
+                dataset.file().is_ok_and(|file| is_netcdf_view(&file))
+            }
+            _ => netcdf_group.as_ref().is_some_and(is_netcdf_view),
+        };
+        let mut attributes = node.attributes()?;
+        if netcdf {
+            attributes = netcdf_visible_attributes(attributes);
+        }
         let longest_name_length = attributes
             .iter()
             .map(|(name, _)| name.len())
             .max()
             .unwrap_or(0)
             .max(
-                SYSTEM_PROPERTIES
+                PROPERTY_ROW_NAMES
                     .iter()
                     .map(|name| name.len())
                     .max()
@@ -330,16 +405,59 @@ impl ComputedAttributes {
             .chain(match node {
                 Node::Dataset(_, DatasetMetaState::Loaded(ds)) => ds.render(name_area_width),
                 Node::Group(_, grp_meta) => grp_meta.render(name_area_width),
+                Node::Datatype(_, type_meta) => type_meta.render(name_area_width),
+                _ => vec![],
+            })
+            .chain(match &netcdf_group {
+                Some(group) if netcdf => {
+                    render_netcdf_properties(group, matches!(node, Node::File(_)), name_area_width)
+                }
                 _ => vec![],
             })
             .collect::<Vec<_>>();
 
+        let (storage_rows, virtual_source_rows) = match node {
+            Node::Dataset(_, DatasetMetaState::Loaded(ds)) => (
+                ds.render_storage(name_area_width),
+                ds.render_virtual_sources(name_area_width),
+            ),
+            _ => (vec![], vec![]),
+        };
+        let object_header = match node {
+            Node::File(file) => ObjectHeader::read(file),
+            Node::Group(group, _) => ObjectHeader::read(group),
+            Node::Dataset(DatasetHandle::Loaded(dataset), _) => ObjectHeader::read(dataset),
+            Node::Datatype(datatype, _) => ObjectHeader::read(datatype),
+            _ => None,
+        };
+        let object_rows = object_header
+            .map(|header| header.render(name_area_width))
+            .unwrap_or_default();
+
         let rendered_custom_attributes =
             Self::render_attributes(&attributes, name_area_width as usize);
         let mut rendered_rows = vec![RenderedAttributeRow::section("Properties")];
         rendered_rows.extend(property_rows);
+        if !storage_rows.is_empty() {
+            rendered_rows.push(RenderedAttributeRow::section("Storage"));
+            rendered_rows.extend(storage_rows);
+        }
+        if !virtual_source_rows.is_empty() {
+            rendered_rows.push(RenderedAttributeRow::section("Virtual sources"));
+            rendered_rows.extend(virtual_source_rows);
+        }
+        if !object_rows.is_empty() {
+            rendered_rows.push(RenderedAttributeRow::section("Object"));
+            rendered_rows.extend(object_rows);
+        }
         if !rendered_custom_attributes.is_empty() {
-            rendered_rows.push(RenderedAttributeRow::section("Attributes"));
+            rendered_rows.push(RenderedAttributeRow::section(
+                if netcdf && matches!(node, Node::File(_)) {
+                    "Global attributes"
+                } else {
+                    "Attributes"
+                },
+            ));
             rendered_rows.extend(rendered_custom_attributes);
         }
 
@@ -386,6 +504,16 @@ impl ComputedAttributes {
                 ),
             };
             let type_desc_str = match attribute_type_description(attr) {
+                // Values of N-D attributes are listed flat, so the shape goes with the type.
+                Ok(type_desc) if attr.ndim() > 1 => format!(
+                    "{}, {}",
+                    type_desc,
+                    attr.shape()
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(" x ")
+                ),
                 Ok(type_desc) => type_desc,
                 Err(e) => format!("Error getting type descriptor: {}", e),
             };
@@ -451,16 +579,27 @@ impl H5FNode {
     pub fn create_attribute(
         &mut self,
         attr_name: &str,
-        attr_type: AttributeCreateType,
+        spec: &AttributeSpec,
         value: &str,
     ) -> Result<String, AppError> {
         let attr_name = validate_user_attribute_name(attr_name)?;
-        let created_type = self.node.create_attr(&attr_name, attr_type, value)?;
+        let created_type = self.node.create_attr(&attr_name, spec, value)?;
         self.recompute_attributes()?;
         self.attributes_view_cursor.attribute_view_selection = AttributeViewSelection::Value;
         Ok(created_type)
     }
 
+    pub fn retype_attribute(
+        &mut self,
+        attr_name: &str,
+        spec: &AttributeSpec,
+        value: Option<&str>,
+    ) -> Result<String, AppError> {
+        let new_type = self.node.retype_attr(attr_name, spec, value)?;
+        self.recompute_attributes()?;
+        Ok(new_type)
+    }
+
     pub fn delete_attribute(&mut self, attr_name: &str) -> Result<(), AppError> {
         let attr_name = validate_user_attribute_name(attr_name)?;
         let current_index = self.attributes_view_cursor.attribute_index;
@@ -468,7 +607,7 @@ impl H5FNode {
             attributes
                 .rendered_rows
                 .iter()
-                .position(|row| row.key.as_deref() == Some(attr_name.as_str()))
+                .position(|row| row.is_attribute(&attr_name))
         });
         self.node.delete_attr(&attr_name)?;
         self.recompute_attributes()?;
@@ -544,8 +683,8 @@ impl H5FNode {
 #[allow(clippy::unwrap_used)]
 #[allow(clippy::expect_used)]
 mod tests {
-    use super::{ComputedAttributes, RenderedAttributeRow};
-    use crate::h5f::{AttributeCreateType, H5FNode, Node};
+    use super::{validate_user_attribute_name, ComputedAttributes, RenderedAttributeRow};
+    use crate::h5f::{AttributeCreateType, AttributeSpec, H5FNode, Node};
     use ratatui::text::Line;
 
     fn line(text: &str) -> Line<'static> {
@@ -574,6 +713,28 @@ mod tests {
         assert_eq!(rows.normalize_row_index(3), Some(3));
     }
 
+    #[test]
+    fn attributes_may_share_names_with_rows_outside_the_core_properties() {
+        assert!(validate_user_attribute_name("shape").is_err());
+        assert_eq!(validate_user_attribute_name("layout").unwrap(), "layout");
+        assert_eq!(validate_user_attribute_name("mtime").unwrap(), "mtime");
+
+        let mut rows = test_rows();
+        rows.rendered_rows.insert(
+            2,
+            RenderedAttributeRow::property("layout", (line("layout"), line("chunked"), line(""))),
+        );
+        rows.rendered_rows.push(RenderedAttributeRow::attribute(
+            "layout",
+            (line("layout"), line("tiled"), line("(str)")),
+        ));
+        let found = rows
+            .rendered_rows
+            .iter()
+            .position(|row| row.is_attribute("layout"));
+        assert_eq!(found, Some(5));
+    }
+
     #[test]
     fn mutation_invalidation_drops_cached_attributes() {
         let mut node = H5FNode::new(Node::Broken("missing".to_string()));
@@ -594,12 +755,20 @@ mod tests {
         let mut node = H5FNode::new(Node::File(file));
 
         node.computed_attributes = Some(test_rows());
-        node.create_attribute("created", AttributeCreateType::I64, "1")
-            .expect("create attribute");
+        node.create_attribute(
+            "created",
+            &AttributeSpec::scalar(AttributeCreateType::I64),
+            "1",
+        )
+        .expect("create attribute");
         assert!(node.computed_attributes.is_none());
 
-        node.create_attribute("units", AttributeCreateType::String, "m")
-            .expect("create attribute");
+        node.create_attribute(
+            "units",
+            &AttributeSpec::scalar(AttributeCreateType::String),
+            "m",
+        )
+        .expect("create attribute");
         node.computed_attributes = Some(test_rows());
         node.attributes_view_cursor.attribute_index = 3;
         node.delete_attribute("units").expect("delete attribute");
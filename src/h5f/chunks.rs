@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use hdf5_metno::Dataset;
+
+use super::{read_dataset_storage, FilterSummary};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkRecord {
+    pub offset: Vec<u64>,
+    pub filter_mask: u32,
+    pub addr: u64,
+    pub size: u64,
+}
+
+/// Allocation state of every chunk in a chunked dataset, keyed by chunk grid
+/// coordinates rather than element offsets.
+#[derive(Debug, Clone)]
+pub struct ChunkMap {
+    pub shape: Vec<usize>,
+    pub chunk_shape: Vec<usize>,
+    pub grid: Vec<usize>,
+    /// Uncompressed size of one full chunk in bytes.
+    pub chunk_bytes: u64,
+    pub filters: Vec<FilterSummary>,
+    pub total_stored: u64,
+    pub max_stored: u64,
+    chunks: HashMap<Vec<usize>, ChunkRecord>,
+}
+
+impl ChunkMap {
+    pub fn new(
+        shape: Vec<usize>,
+        chunk_shape: Vec<usize>,
+        chunk_bytes: u64,
+        filters: Vec<FilterSummary>,
+    ) -> Self {
+        let grid = shape
+            .iter()
+            .zip(&chunk_shape)
+            .map(|(len, chunk)| len.div_ceil((*chunk).max(1)))
+            .collect();
+        Self {
+            shape,
+            chunk_shape,
+            grid,
+            chunk_bytes,
+            filters,
+            total_stored: 0,
+            max_stored: 0,
+            chunks: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, record: ChunkRecord) {
+        let coords = self.grid_coords(&record.offset);
+        self.total_stored += record.size;
+        self.max_stored = self.max_stored.max(record.size);
+        self.chunks.insert(coords, record);
+    }
+
+    pub fn grid_coords(&self, offset: &[u64]) -> Vec<usize> {
+        offset
+            .iter()
+            .zip(&self.chunk_shape)
+            .map(|(offset, chunk)| *offset as usize / (*chunk).max(1))
+            .collect()
+    }
+
+    pub fn chunk_at(&self, coords: &[usize]) -> Option<&ChunkRecord> {
+        self.chunks.get(coords)
+    }
+
+    pub fn element_origin(&self, coords: &[usize]) -> Vec<usize> {
+        coords
+            .iter()
+            .zip(&self.chunk_shape)
+            .map(|(coord, chunk)| coord * chunk)
+            .collect()
+    }
+
+    pub fn allocated_chunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    pub fn total_chunks(&self) -> usize {
+        self.grid.iter().product()
+    }
+
+    /// Stored size of a chunk relative to its uncompressed size, clamped to `0..=1`.
+    pub fn fill_fraction(&self, record: &ChunkRecord) -> f64 {
+        let scale = self.chunk_bytes.max(self.max_stored).max(1);
+        (record.size as f64 / scale as f64).clamp(0.0, 1.0)
+    }
+
+    /// Names of the pipeline filters a chunk's filter mask marks as skipped.
+    pub fn skipped_filters(&self, filter_mask: u32) -> Vec<String> {
+        (0..32)
+            .filter(|bit| filter_mask & (1 << bit) != 0)
+            .map(|bit| {
+                self.filters
+                    .get(bit)
+                    .map(FilterSummary::display_name)
+                    .unwrap_or_else(|| format!("filter #{bit}"))
+            })
+            .collect()
+    }
+}
+
+/// Reads where every written chunk of `dataset` is stored. `keep_going` is
+/// called before each chunk; returning `false` stops the walk and yields
+/// `None`, as do datasets without chunked storage.
+pub fn read_chunk_map(
+    dataset: &Dataset,
+    keep_going: &mut dyn FnMut() -> bool,
+) -> Result<Option<ChunkMap>, hdf5_metno::Error> {
+    let Some(chunk_shape) = dataset.chunk() else {
+        return Ok(None);
+    };
+    let element_size = dataset.dtype()?.size() as u64;
+    let chunk_bytes = chunk_shape.iter().product::<usize>() as u64 * element_size;
+    let filters = read_dataset_storage(dataset)
+        .map(|storage| storage.filters)
+        .unwrap_or_default();
+    let mut map = ChunkMap::new(dataset.shape(), chunk_shape, chunk_bytes, filters);
+    let mut stopped = false;
+    dataset.chunks_visit(|info| {
+        if !keep_going() {
+            stopped = true;
+            return 1;
+        }
+        map.insert(ChunkRecord {
+            offset: info.offset.to_vec(),
+            filter_mask: info.filter_mask,
+            addr: info.addr,
+            size: info.size,
+        });
+        0
+    })?;
+    Ok((!stopped).then_some(map))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::{read_chunk_map, ChunkMap, ChunkRecord};
+
+    #[test]
+    fn grid_covers_partial_edge_chunks() {
+        let mut map = ChunkMap::new(vec![10, 7], vec![4, 4], 64, vec![]);
+        map.insert(ChunkRecord {
+            offset: vec![8, 4],
+            filter_mask: 0,
+            addr: 4096,
+            size: 32,
+        });
+
+        assert_eq!(map.grid, vec![3, 2]);
+        assert_eq!(map.total_chunks(), 6);
+        assert_eq!(map.allocated_chunks(), 1);
+        assert_eq!(map.chunk_at(&[2, 1]).map(|chunk| chunk.addr), Some(4096));
+        assert!(map.chunk_at(&[0, 0]).is_none());
+        assert_eq!(map.element_origin(&[2, 1]), vec![8, 4]);
+        assert!((map.fill_fraction(map.chunk_at(&[2, 1]).unwrap()) - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn reads_only_written_chunks() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let dataset = file
+            .new_dataset::<u32>()
+            .shape(16)
+            .chunk(4)
+            .deflate(4)
+            .create("sparse")
+            .expect("failed to create dataset");
+        dataset
+            .write_slice(&[7_u32; 4], 8..12)
+            .expect("failed to write chunk");
+
+        let map = read_chunk_map(&dataset, &mut || true)
+            .expect("read chunk map")
+            .expect("dataset is chunked");
+
+        assert_eq!(map.grid, vec![4]);
+        assert_eq!(map.chunk_bytes, 16);
+        assert_eq!(map.allocated_chunks(), 1);
+        assert!(map.chunk_at(&[2]).is_some());
+        assert!(map.chunk_at(&[0]).is_none());
+        assert!(map.skipped_filters(0).is_empty());
+        assert_eq!(map.skipped_filters(1), vec!["deflate".to_string()]);
+
+        let stopped = read_chunk_map(&dataset, &mut || false).expect("read chunk map");
+        assert!(stopped.is_none());
+    }
+}
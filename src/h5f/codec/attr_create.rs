@@ -0,0 +1,542 @@
+use std::ffi::CString;
+
+use hdf5_metno::{
+    h5check,
+    types::{EnumMember, EnumType, IntSize, TypeDescriptor},
+    Attribute, Group,
+};
+use hdf5_metno_sys::h5a::H5Arename;
+
+use crate::{
+    error::AppError,
+    h5f::structure::{format_extents, parse_extents},
+};
+
+use super::{
+    fixed_string::unique_temp_attr_name, format_attr_values_for_edit, write_attr_values,
+    AttributeCreateType,
+};
+
+/// Dimensions of a new attribute, from the `[...]` suffix of its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttributeShape {
+    Scalar,
+    /// `[]`: one dimension, as long as the list of values.
+    List,
+    /// `[3]` or `[2x3]`, filled with the values in row-major order.
+    Fixed(Vec<usize>),
+}
+
+/// Type and shape of an attribute to create, such as `f64`, `f64[]`,
+/// `i32[2x3]` or `enum(off, on)[]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributeSpec {
+    pub type_desc: TypeDescriptor,
+    pub shape: AttributeShape,
+}
+
+impl AttributeSpec {
+    pub fn scalar(attr_type: AttributeCreateType) -> Self {
+        Self {
+            type_desc: attr_type.type_descriptor(),
+            shape: AttributeShape::Scalar,
+        }
+    }
+
+    pub fn list(attr_type: AttributeCreateType) -> Self {
+        Self {
+            type_desc: attr_type.type_descriptor(),
+            shape: AttributeShape::List,
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, AppError> {
+        let text = text.trim();
+        let (element, shape) = match text
+            .strip_suffix(']')
+            .and_then(|rest| rest.rsplit_once('['))
+        {
+            Some((element, dims)) if dims.trim().is_empty() => (element, AttributeShape::List),
+            Some((element, dims)) => match parse_extents(dims, "attribute shape")? {
+                dims if dims.is_empty() => (element, AttributeShape::Scalar),
+                dims => (element, AttributeShape::Fixed(dims)),
+            },
+            None => (text, AttributeShape::Scalar),
+        };
+        let element = element.trim();
+        let type_desc = match enum_members_text(element) {
+            Some(members) => TypeDescriptor::Enum(parse_enum_type(members)?),
+            None => AttributeCreateType::parse(element)?.type_descriptor(),
+        };
+        Ok(Self { type_desc, shape })
+    }
+
+    pub fn is_array(&self) -> bool {
+        self.shape != AttributeShape::Scalar
+    }
+
+    /// The values `text` holds for this attribute; scalar values are taken whole.
+    fn values(&self, text: &str) -> Result<Vec<String>, AppError> {
+        if self.is_array() {
+            split_attribute_values(text)
+        } else {
+            Ok(vec![text.to_string()])
+        }
+    }
+
+    fn extents(&self, count: usize) -> Result<Vec<usize>, AppError> {
+        let extents = match &self.shape {
+            AttributeShape::Scalar if count == 1 => return Ok(Vec::new()),
+            AttributeShape::Scalar => {
+                return Err(AppError::EditError(format!(
+                    "A scalar attribute holds 1 value, got {count}; add [] to the type for an array"
+                )))
+            }
+            AttributeShape::List => vec![count],
+            AttributeShape::Fixed(dims) => {
+                let size = dims.iter().product::<usize>();
+                if size != count {
+                    return Err(AppError::EditError(format!(
+                        "Shape {} holds {size} values, got {count}",
+                        format_extents(dims)
+                    )));
+                }
+                dims.clone()
+            }
+        };
+        if count == 0 {
+            return Err(AppError::EditError(
+                "Array attributes need at least one value".to_string(),
+            ));
+        }
+        Ok(extents)
+    }
+
+    fn describe(&self, extents: &[usize]) -> String {
+        if extents.is_empty() {
+            self.type_desc.to_string()
+        } else {
+            format!("{}[{}]", self.type_desc, format_extents(extents))
+        }
+    }
+}
+
+/// `enum(off, on)` or `enum(low=1, high=4)`.
+fn enum_members_text(element: &str) -> Option<&str> {
+    let open = element.find('(')?;
+    if !element[..open].trim().eq_ignore_ascii_case("enum") {
+        return None;
+    }
+    element[open + 1..].trim_end().strip_suffix(')')
+}
+
+/// Members without a value follow the previous one, starting at 0. The base
+/// type is the smallest integer holding every value.
+fn parse_enum_type(text: &str) -> Result<EnumType, AppError> {
+    let mut members: Vec<(String, i64)> = Vec::new();
+    let mut next = 0_i64;
+    for member in text.split(',') {
+        let (name, value) = match member.split_once('=') {
+            Some((name, value)) => {
+                let value = value.trim().parse::<i64>().map_err(|_| {
+                    AppError::InvalidCommand(format!(
+                        "Invalid value '{}' for enum member '{}'",
+                        value.trim(),
+                        name.trim()
+                    ))
+                })?;
+                (name.trim(), value)
+            }
+            None => (member.trim(), next),
+        };
+        if name.is_empty() {
+            return Err(AppError::InvalidCommand(
+                "Enum member names cannot be empty".to_string(),
+            ));
+        }
+        if let Some((existing, _)) = members
+            .iter()
+            .find(|(existing, existing_value)| existing == name || *existing_value == value)
+        {
+            return Err(AppError::InvalidCommand(if existing == name {
+                format!("Enum member '{name}' is listed twice")
+            } else {
+                format!("Enum members '{existing}' and '{name}' have the same value {value}")
+            }));
+        }
+        members.push((name.to_string(), value));
+        next = value.saturating_add(1);
+    }
+
+    let signed = members.iter().any(|(_, value)| *value < 0);
+    let size = [IntSize::U1, IntSize::U2, IntSize::U4]
+        .into_iter()
+        .find(|size| {
+            members
+                .iter()
+                .all(|(_, value)| enum_value_fits(*value, signed, *size))
+        })
+        .unwrap_or(IntSize::U8);
+    Ok(EnumType {
+        size,
+        signed,
+        members: members
+            .into_iter()
+            .map(|(name, value)| EnumMember {
+                name,
+                value: value as u64,
+            })
+            .collect(),
+    })
+}
+
+fn enum_value_fits(value: i64, signed: bool, size: IntSize) -> bool {
+    match (signed, size) {
+        (true, IntSize::U1) => i8::try_from(value).is_ok(),
+        (true, IntSize::U2) => i16::try_from(value).is_ok(),
+        (true, IntSize::U4) => i32::try_from(value).is_ok(),
+        (false, IntSize::U1) => u8::try_from(value).is_ok(),
+        (false, IntSize::U2) => u16::try_from(value).is_ok(),
+        (false, IntSize::U4) => u32::try_from(value).is_ok(),
+        (_, IntSize::U8) => true,
+    }
+}
+
+/// Values of an array attribute, separated by commas or line breaks.
+/// Unquoted values are trimmed. A value in double quotes is kept as written
+/// and may contain either separator; inside quotes `\n` is a line break and
+/// `\` escapes any other character.
+fn split_attribute_values(text: &str) -> Result<Vec<String>, AppError> {
+    if text.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if in_quotes => in_quotes = false,
+            '\\' if in_quotes => current.push(match chars.next() {
+                Some('n') => '\n',
+                Some(ch) => ch,
+                None => '\\',
+            }),
+            '"' if !quoted && current.trim().is_empty() => {
+                current.clear();
+                quoted = true;
+                in_quotes = true;
+            }
+            ',' | '\n' if !in_quotes => {
+                let value = std::mem::take(&mut current);
+                values.push(if quoted {
+                    value
+                } else {
+                    value.trim().to_string()
+                });
+                quoted = false;
+            }
+            ch if quoted && !in_quotes && ch.is_whitespace() => {}
+            ch if quoted && !in_quotes => {
+                return Err(AppError::InvalidCommand(format!(
+                    "Unexpected '{ch}' after a quoted attribute value"
+                )))
+            }
+            ch => current.push(ch),
+        }
+    }
+    if in_quotes {
+        return Err(AppError::InvalidCommand(
+            "Unterminated quote in attribute values".to_string(),
+        ));
+    }
+    values.push(if quoted {
+        current
+    } else {
+        current.trim().to_string()
+    });
+    Ok(values)
+}
+
+/// Creates the attribute and writes `values`, removing it again when they do
+/// not fit its type.
+fn write_new_attr(
+    group: &Group,
+    attr_name: &str,
+    spec: &AttributeSpec,
+    values: &[String],
+) -> Result<(Attribute, String), AppError> {
+    let extents = spec.extents(values.len())?;
+    let attr = group
+        .new_attr_builder()
+        .empty_as(&spec.type_desc)
+        .shape(extents.clone())
+        .create(attr_name)?;
+    let values = values.iter().map(String::as_str).collect::<Vec<_>>();
+    if let Err(err) = write_attr_values(&attr, &values) {
+        drop(attr);
+        let _ = group.delete_attr(attr_name);
+        return Err(err);
+    }
+    Ok((attr, spec.describe(&extents)))
+}
+
+pub fn create_attr_from_text(
+    group: &Group,
+    attr_name: &str,
+    spec: &AttributeSpec,
+    value: &str,
+) -> Result<String, AppError> {
+    let (_, created_type) = write_new_attr(group, attr_name, spec, &spec.values(value)?)?;
+    group.file()?.flush()?;
+    Ok(created_type)
+}
+
+/// Replaces an attribute with one of another type or shape. Without `value`
+/// its current values are converted to the new type.
+pub fn retype_attr_from_text(
+    group: &Group,
+    attr_name: &str,
+    spec: &AttributeSpec,
+    value: Option<&str>,
+) -> Result<String, AppError> {
+    let values = match value {
+        Some(value) => spec.values(value)?,
+        None => format_attr_values_for_edit(&group.attr(attr_name)?)?,
+    };
+    let temp_name = unique_temp_attr_name(group, attr_name)?;
+    let (temp_attr, new_type) = write_new_attr(group, &temp_name, spec, &values)?;
+    drop(temp_attr);
+
+    if let Err(err) = group.delete_attr(attr_name) {
+        let _ = group.delete_attr(temp_name.as_str());
+        return Err(err.into());
+    }
+    // Renamed rather than copied, so no step after the original is gone
+    // can lose the new values; if the rename fails they stay under the
+    // temporary name.
+    rename_attr(group, &temp_name, attr_name).map_err(|err| {
+        AppError::EditError(format!(
+            "Deleted '{attr_name}' but could not rename its replacement '{temp_name}': {err}"
+        ))
+    })?;
+    group.file()?.flush()?;
+    Ok(new_type)
+}
+
+fn rename_attr(group: &Group, old_name: &str, new_name: &str) -> Result<(), AppError> {
+    let old_name = CString::new(old_name)
+        .map_err(|_| AppError::EditError("Attribute name contains a NUL byte".to_string()))?;
+    let new_name = CString::new(new_name)
+        .map_err(|_| AppError::EditError("Attribute name contains a NUL byte".to_string()))?;
+    h5check(unsafe { H5Arename(group.id(), old_name.as_ptr(), new_name.as_ptr()) })?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use hdf5_metno::types::{FloatSize, IntSize, TypeDescriptor};
+
+    use super::{
+        create_attr_from_text, format_attr_values_for_edit, rename_attr, retype_attr_from_text,
+        split_attribute_values, AttributeShape, AttributeSpec,
+    };
+    use crate::h5f::format_attr_for_edit;
+
+    #[test]
+    fn parses_attribute_specs() {
+        assert_eq!(
+            AttributeSpec::parse("f64[]").expect("list"),
+            AttributeSpec {
+                type_desc: TypeDescriptor::Float(FloatSize::U8),
+                shape: AttributeShape::List,
+            }
+        );
+        assert_eq!(
+            AttributeSpec::parse("int32[2x3]").expect("fixed").shape,
+            AttributeShape::Fixed(vec![2, 3])
+        );
+        assert_eq!(
+            AttributeSpec::parse("string").expect("scalar").shape,
+            AttributeShape::Scalar
+        );
+
+        let TypeDescriptor::Enum(enum_type) = AttributeSpec::parse("enum(low=-1, mid, high)[]")
+            .expect("enum")
+            .type_desc
+        else {
+            panic!("expected an enum type");
+        };
+        assert!(enum_type.signed);
+        assert_eq!(enum_type.size, IntSize::U1);
+        assert_eq!(
+            enum_type
+                .members
+                .iter()
+                .map(|member| (member.name.as_str(), member.value as i64))
+                .collect::<Vec<_>>(),
+            vec![("low", -1), ("mid", 0), ("high", 1)]
+        );
+
+        assert!(AttributeSpec::parse("enum(a, b=0)").is_err());
+        assert!(AttributeSpec::parse("f64[2y]").is_err());
+        assert!(AttributeSpec::parse("compound[]").is_err());
+    }
+
+    #[test]
+    fn splits_attribute_values() {
+        assert_eq!(
+            split_attribute_values("400, 410,420").expect("commas"),
+            vec!["400", "410", "420"]
+        );
+        assert_eq!(
+            split_attribute_values(r#""a, b" , "say \"hi\"", c"#).expect("quotes"),
+            vec!["a, b", "say \"hi\"", "c"]
+        );
+        assert_eq!(
+            split_attribute_values("first\n second, third").expect("lines"),
+            vec!["first", "second", "third"]
+        );
+        assert_eq!(
+            split_attribute_values("\"two\nlines\"\n\"escaped\\nbreak\", \"a\\\\b\"")
+                .expect("quoted breaks"),
+            vec!["two\nlines", "escaped\nbreak", "a\\b"]
+        );
+        assert!(split_attribute_values("").expect("empty").is_empty());
+        assert!(split_attribute_values("\"open").is_err());
+        assert!(split_attribute_values("\"a\"b").is_err());
+    }
+
+    #[test]
+    fn string_values_with_separators_round_trip() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let dir = tempfile::tempdir().expect("failed to create temp dir");
+        let file = hdf5_metno::File::create(dir.path().join("strings.h5")).expect("create file");
+        let root = file.as_group().expect("root group");
+        let expected = vec![
+            "a, b".to_string(),
+            "two\nlines".to_string(),
+            "plain".to_string(),
+        ];
+
+        let spec = AttributeSpec::parse("string[]").expect("spec");
+        create_attr_from_text(&root, "notes", &spec, r#""a, b", "two\nlines", plain"#)
+            .expect("create notes");
+        let notes = file.attr("notes").expect("notes");
+        assert_eq!(notes.shape(), vec![3]);
+        assert_eq!(
+            format_attr_values_for_edit(&notes).expect("values"),
+            expected
+        );
+        drop(notes);
+
+        let spec = AttributeSpec::parse("ascii[3x1]").expect("spec");
+        retype_attr_from_text(&root, "notes", &spec, None).expect("retype notes");
+        let notes = file.attr("notes").expect("retyped notes");
+        assert_eq!(notes.shape(), vec![3, 1]);
+        assert_eq!(
+            format_attr_values_for_edit(&notes).expect("values"),
+            expected
+        );
+    }
+
+    #[test]
+    fn creates_and_retypes_array_attributes() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let dir = tempfile::tempdir().expect("failed to create temp dir");
+        let file = hdf5_metno::File::create(dir.path().join("attrs.h5")).expect("create file");
+        let root = file.as_group().expect("root group");
+
+        let spec = AttributeSpec::parse("f64[]").expect("spec");
+        let created = create_attr_from_text(&root, "wavelengths", &spec, "400, 410, 420")
+            .expect("create list");
+        assert_eq!(created, "float64[3]");
+        assert_eq!(
+            file.attr("wavelengths")
+                .expect("attr")
+                .read_raw::<f64>()
+                .expect("read"),
+            vec![400.0, 410.0, 420.0]
+        );
+
+        let spec = AttributeSpec::parse("u8[2x3]").expect("spec");
+        create_attr_from_text(&root, "grid", &spec, "1, 2, 3, 4, 5, 6").expect("create grid");
+        let grid = file.attr("grid").expect("grid");
+        assert_eq!(grid.shape(), vec![2, 3]);
+        assert_eq!(
+            format_attr_for_edit(&grid).expect("format grid"),
+            "1\n2\n3\n4\n5\n6"
+        );
+        drop(grid);
+        assert!(create_attr_from_text(&root, "short", &spec, "1, 2").is_err());
+        assert!(create_attr_from_text(&root, "bad", &spec, "1, 2, 3, 4, 5, x").is_err());
+        assert!(!file
+            .attr_names()
+            .expect("names")
+            .contains(&"bad".to_string()));
+
+        let spec = AttributeSpec::parse("enum(idle, running, done)[]").expect("spec");
+        create_attr_from_text(&root, "states", &spec, "idle, done").expect("create enum");
+        assert_eq!(
+            format_attr_for_edit(&file.attr("states").expect("states")).expect("format"),
+            "idle\ndone"
+        );
+
+        let spec = AttributeSpec::parse("i32[3x1]").expect("spec");
+        let retyped =
+            retype_attr_from_text(&root, "wavelengths", &spec, None).expect("retype list");
+        assert_eq!(retyped, "int32[3 x 1]");
+        let wavelengths = file.attr("wavelengths").expect("retyped attr");
+        assert_eq!(wavelengths.shape(), vec![3, 1]);
+        assert_eq!(
+            wavelengths.read_raw::<i32>().expect("read"),
+            vec![400, 410, 420]
+        );
+        drop(wavelengths);
+
+        let spec = AttributeSpec::parse("string").expect("spec");
+        retype_attr_from_text(&root, "grid", &spec, Some("replaced")).expect("retype grid");
+        assert_eq!(
+            format_attr_for_edit(&file.attr("grid").expect("grid")).expect("format"),
+            "replaced"
+        );
+        assert_eq!(file.attr_names().expect("names").len(), 3);
+    }
+
+    #[test]
+    fn failed_retypes_keep_the_original_attribute() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let dir = tempfile::tempdir().expect("failed to create temp dir");
+        let path = dir.path().join("retype.h5");
+        let file = hdf5_metno::File::create(&path).expect("failed to create file");
+        let root = file.as_group().expect("root group");
+        let string = AttributeSpec::parse("string").expect("spec");
+        create_attr_from_text(&root, "label", &string, "run one").expect("create label");
+
+        let number = AttributeSpec::parse("i32").expect("spec");
+        assert!(retype_attr_from_text(&root, "label", &number, None).is_err());
+        let pair = AttributeSpec::parse("i32[2]").expect("spec");
+        assert!(retype_attr_from_text(&root, "label", &pair, Some("1")).is_err());
+        assert_eq!(
+            format_attr_for_edit(&file.attr("label").expect("label")).expect("format"),
+            "run one"
+        );
+        assert_eq!(file.attr_names().expect("names"), vec!["label".to_string()]);
+
+        // A replacement that cannot be renamed into place is kept.
+        create_attr_from_text(&root, "replacement", &number, "7").expect("create replacement");
+        assert!(rename_attr(&root, "replacement", "label").is_err());
+        assert_eq!(
+            format_attr_for_edit(&file.attr("replacement").expect("replacement")).expect("format"),
+            "7"
+        );
+        rename_attr(&root, "replacement", "count").expect("rename replacement");
+        let mut names = file.attr_names().expect("names");
+        names.sort();
+        assert_eq!(names, vec!["count".to_string(), "label".to_string()]);
+    }
+}
@@ -0,0 +1,283 @@
+use std::ffi::CStr;
+
+use hdf5_metno::{Dataset, Datatype, Selection};
+use hdf5_metno_sys::{
+    h5::H5free_memory,
+    h5t::{
+        H5T_class_t::{H5T_BITFIELD, H5T_OPAQUE},
+        H5T_order_t::H5T_ORDER_BE,
+        H5Tget_class, H5Tget_order, H5Tget_tag,
+    },
+};
+use ndarray::{Array1, Array2};
+
+use crate::error::AppError;
+
+use super::{super::compound::read_selected_values_bytes, read_string_attr_values, Float16Type};
+
+/// Attribute naming each bit of a bitfield, least significant bit first.
+const BIT_NAMES_ATTR: &str = "BIT_NAMES";
+/// Opaque tags containing one of these are decoded as bit flags.
+const BITFIELD_TAG_MARKERS: [&str; 2] = ["bit", "flag"];
+
+/// A bitfield dataset: HDF5 bitfield types, and tagged opaque types that are
+/// named as flags by their tag or a `BIT_NAMES` attribute. Up to 64 bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitfieldType {
+    pub size: usize,
+    pub big_endian: bool,
+    /// Tag of an opaque datatype; `None` for HDF5 bitfields.
+    pub tag: Option<String>,
+    /// Name of each bit from `BIT_NAMES`; blank entries stay unnamed.
+    pub names: Vec<Option<String>>,
+}
+
+impl BitfieldType {
+    pub fn read(dataset: &Dataset) -> Option<Self> {
+        let dtype = dataset.dtype().ok()?;
+        let size = dtype.size();
+        if !(1..=8).contains(&size) {
+            return None;
+        }
+        let names = dataset
+            .attr(BIT_NAMES_ATTR)
+            .ok()
+            .and_then(|attr| read_string_attr_values(&attr).ok());
+        let (big_endian, tag) = match unsafe { H5Tget_class(dtype.id()) } {
+            H5T_BITFIELD => (unsafe { H5Tget_order(dtype.id()) } == H5T_ORDER_BE, None),
+            H5T_OPAQUE if Float16Type::read(dataset).is_none() => {
+                let tag = opaque_tag(&dtype).unwrap_or_default();
+                let lower = tag.to_ascii_lowercase();
+                if names.is_none()
+                    && !BITFIELD_TAG_MARKERS
+                        .iter()
+                        .any(|marker| lower.contains(marker))
+                {
+                    return None;
+                }
+                (false, Some(tag))
+            }
+            _ => return None,
+        };
+        Some(Self {
+            size,
+            big_endian,
+            tag,
+            names: names
+                .unwrap_or_default()
+                .into_iter()
+                .map(|name| {
+                    let name = name.trim();
+                    (!name.is_empty()).then(|| name.to_string())
+                })
+                .collect(),
+        })
+    }
+
+    pub fn bits(&self) -> usize {
+        self.size * 8
+    }
+
+    /// `bitfield[16]`, or `bitfield[8] (opaque "status")` for tagged opaque types.
+    pub fn name(&self) -> String {
+        match &self.tag {
+            Some(tag) if !tag.is_empty() => format!("bitfield[{}] (opaque {tag:?})", self.bits()),
+            Some(_) => format!("bitfield[{}] (opaque)", self.bits()),
+            None => format!("bitfield[{}]", self.bits()),
+        }
+    }
+
+    /// The `BIT_NAMES` entry for `bit`, or `bit3`.
+    pub fn bit_label(&self, bit: usize) -> String {
+        self.names
+            .get(bit)
+            .cloned()
+            .flatten()
+            .unwrap_or_else(|| format!("bit{bit}"))
+    }
+
+    pub fn decode(&self, bytes: &[u8]) -> u64 {
+        let mut word = [0_u8; 8];
+        if self.big_endian {
+            word[8 - bytes.len()..].copy_from_slice(bytes);
+            u64::from_be_bytes(word)
+        } else {
+            word[..bytes.len()].copy_from_slice(bytes);
+            u64::from_le_bytes(word)
+        }
+    }
+
+    /// `0x05 ready|armed`: the raw value, then the set bits by name.
+    pub fn format_flags(&self, value: u64) -> String {
+        let set = (0..self.bits())
+            .filter(|bit| value >> bit & 1 == 1)
+            .map(|bit| self.bit_label(bit))
+            .collect::<Vec<_>>();
+        let width = self.size * 2;
+        if set.is_empty() {
+            format!("0x{value:0width$x} -")
+        } else {
+            format!("0x{value:0width$x} {}", set.join("|"))
+        }
+    }
+}
+
+fn opaque_tag(dtype: &Datatype) -> Option<String> {
+    let tag = unsafe { H5Tget_tag(dtype.id()) };
+    if tag.is_null() {
+        return None;
+    }
+    let value = unsafe { CStr::from_ptr(tag) }
+        .to_string_lossy()
+        .trim()
+        .to_string();
+    unsafe {
+        H5free_memory(tag.cast());
+    }
+    Some(value)
+}
+
+/// Raw bitfield values of a selection, with the selection's output shape.
+pub fn read_bitfield_values(
+    dataset: &Dataset,
+    bitfield: &BitfieldType,
+    selection: Selection,
+) -> Result<(Vec<u64>, Vec<usize>), AppError> {
+    let (bytes, out_shape) = read_selected_values_bytes(dataset, selection)?;
+    let values = bytes
+        .chunks_exact(bitfield.size)
+        .map(|word| bitfield.decode(word))
+        .collect();
+    Ok((values, out_shape))
+}
+
+pub fn read_bitfield_matrix_values(
+    dataset: &Dataset,
+    bitfield: &BitfieldType,
+    selection: Selection,
+) -> Result<Array1<String>, AppError> {
+    let (values, _) = read_bitfield_values(dataset, bitfield, selection)?;
+    Ok(values
+        .into_iter()
+        .map(|value| bitfield.format_flags(value))
+        .collect())
+}
+
+pub fn read_bitfield_matrix_table(
+    dataset: &Dataset,
+    bitfield: &BitfieldType,
+    selection: Selection,
+) -> Result<Array2<String>, AppError> {
+    let (values, out_shape) = read_bitfield_values(dataset, bitfield, selection)?;
+    if out_shape.len() != 2 {
+        return Err(AppError::DrawingError(format!(
+            "Expected 2D bitfield selection, got shape {out_shape:?}"
+        )));
+    }
+    Array2::from_shape_vec(
+        (out_shape[0], out_shape[1]),
+        values
+            .into_iter()
+            .map(|value| bitfield.format_flags(value))
+            .collect(),
+    )
+    .map_err(|err| AppError::DrawingError(format!("Failed reshaping bitfield matrix: {err}")))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use hdf5_metno::Selection;
+
+    use super::{read_bitfield_values, BitfieldType};
+
+    fn status_word() -> BitfieldType {
+        BitfieldType {
+            size: 2,
+            big_endian: false,
+            tag: None,
+            names: vec![Some("ready".to_string()), None, Some("armed".to_string())],
+        }
+    }
+
+    #[test]
+    fn formats_set_bits_by_name() {
+        let bitfield = status_word();
+        assert_eq!(bitfield.format_flags(0), "0x0000 -");
+        assert_eq!(bitfield.format_flags(0b101), "0x0005 ready|armed");
+        assert_eq!(bitfield.format_flags(0x8002), "0x8002 bit1|bit15");
+        assert_eq!(bitfield.name(), "bitfield[16]");
+    }
+
+    #[test]
+    fn decodes_words_in_storage_order() {
+        let mut bitfield = status_word();
+        assert_eq!(bitfield.decode(&[0x01, 0x80]), 0x8001);
+        bitfield.big_endian = true;
+        assert_eq!(bitfield.decode(&[0x01, 0x80]), 0x0180);
+    }
+
+    #[test]
+    fn reads_hdf5_bitfields_with_bit_names() {
+        use hdf5_metno_sys::{
+            h5d::{H5Dclose, H5Dcreate2, H5Dwrite},
+            h5p::H5P_DEFAULT,
+            h5s::{H5Sclose, H5Screate_simple, H5S_ALL},
+            h5t::{H5T_NATIVE_B8, H5T_STD_B8LE},
+        };
+
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let values = [0b0000_0001_u8, 0b0000_0110, 0];
+        let name = std::ffi::CString::new("status").unwrap();
+        unsafe {
+            let dims = [values.len() as u64];
+            let space = H5Screate_simple(1, dims.as_ptr(), std::ptr::null());
+            let dataset = H5Dcreate2(
+                file.id(),
+                name.as_ptr(),
+                *H5T_STD_B8LE,
+                space,
+                H5P_DEFAULT,
+                H5P_DEFAULT,
+                H5P_DEFAULT,
+            );
+            assert!(dataset >= 0);
+            assert!(
+                H5Dwrite(
+                    dataset,
+                    *H5T_NATIVE_B8,
+                    H5S_ALL,
+                    H5S_ALL,
+                    H5P_DEFAULT,
+                    values.as_ptr().cast(),
+                ) >= 0
+            );
+            H5Dclose(dataset);
+            H5Sclose(space);
+        }
+        let dataset = file.dataset("status").expect("open bitfield dataset");
+        dataset
+            .new_attr::<hdf5_metno::types::VarLenUnicode>()
+            .shape(3)
+            .create("BIT_NAMES")
+            .expect("create BIT_NAMES")
+            .write(&[
+                "ready".parse::<hdf5_metno::types::VarLenUnicode>().unwrap(),
+                "armed".parse().unwrap(),
+                "fault".parse().unwrap(),
+            ])
+            .expect("write BIT_NAMES");
+
+        let bitfield = BitfieldType::read(&dataset).expect("bitfield dataset");
+        assert_eq!(bitfield.bits(), 8);
+        assert_eq!(bitfield.bit_label(2), "fault");
+        let (decoded, shape) =
+            read_bitfield_values(&dataset, &bitfield, Selection::All).expect("read bitfield");
+        assert_eq!(decoded, vec![1, 6, 0]);
+        assert_eq!(shape, vec![3]);
+        assert_eq!(bitfield.format_flags(decoded[1]), "0x06 armed|fault");
+    }
+}
@@ -0,0 +1,193 @@
+use hdf5_metno::Dataset;
+
+const SCALE_FACTOR_ATTR: &str = "scale_factor";
+const ADD_OFFSET_ATTR: &str = "add_offset";
+const FILL_VALUE_ATTR: &str = "_FillValue";
+const MISSING_VALUE_ATTR: &str = "missing_value";
+const VALID_RANGE_ATTR: &str = "valid_range";
+const VALID_MIN_ATTR: &str = "valid_min";
+const VALID_MAX_ATTR: &str = "valid_max";
+
+/// CF packing and masking attributes of a numeric dataset. Fill, missing and
+/// valid-range values are compared against the packed values, as the CF
+/// conventions define them in packed units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CfDecoding {
+    pub scale_factor: Option<f64>,
+    pub add_offset: Option<f64>,
+    pub fill_values: Vec<f64>,
+    pub valid_min: Option<f64>,
+    pub valid_max: Option<f64>,
+}
+
+fn read_numeric_attr(dataset: &Dataset, name: &str) -> Vec<f64> {
+    dataset
+        .attr(name)
+        .ok()
+        .and_then(|attr| attr.read_raw::<f64>().ok())
+        .unwrap_or_default()
+}
+
+impl CfDecoding {
+    /// Reads the CF attributes of `dataset`, or `None` when it has none of them.
+    pub fn read(dataset: &Dataset) -> Option<Self> {
+        let mut fill_values = read_numeric_attr(dataset, FILL_VALUE_ATTR);
+        fill_values.extend(read_numeric_attr(dataset, MISSING_VALUE_ATTR));
+        fill_values.dedup();
+        let (valid_min, valid_max) = match read_numeric_attr(dataset, VALID_RANGE_ATTR)[..] {
+            [min, max, ..] => (Some(min), Some(max)),
+            _ => (
+                read_numeric_attr(dataset, VALID_MIN_ATTR).first().copied(),
+                read_numeric_attr(dataset, VALID_MAX_ATTR).first().copied(),
+            ),
+        };
+        let decoding = Self {
+            scale_factor: read_numeric_attr(dataset, SCALE_FACTOR_ATTR)
+                .first()
+                .copied(),
+            add_offset: read_numeric_attr(dataset, ADD_OFFSET_ATTR).first().copied(),
+            fill_values,
+            valid_min,
+            valid_max,
+        };
+        (!decoding.is_identity()).then_some(decoding)
+    }
+
+    fn is_identity(&self) -> bool {
+        self.scale_factor.is_none()
+            && self.add_offset.is_none()
+            && self.fill_values.is_empty()
+            && self.valid_min.is_none()
+            && self.valid_max.is_none()
+    }
+
+    pub fn is_masked(&self, raw: f64) -> bool {
+        self.fill_values.contains(&raw)
+            || self.valid_min.is_some_and(|min| raw < min)
+            || self.valid_max.is_some_and(|max| raw > max)
+    }
+
+    /// Physical value of a packed value; masked values become NaN.
+    pub fn decode(&self, raw: f64) -> f64 {
+        if self.is_masked(raw) {
+            return f64::NAN;
+        }
+        raw * self.scale_factor.unwrap_or(1.0) + self.add_offset.unwrap_or(0.0)
+    }
+
+    pub fn decode_in_place(&self, values: &mut [f64]) {
+        for value in values {
+            *value = self.decode(*value);
+        }
+    }
+
+    /// One-line summary for the metadata panel, e.g.
+    /// `x * 0.01 + 273.15, fill -9999 → NaN, valid [0, 4000]`.
+    pub fn describe(&self) -> String {
+        let mut parts = vec![];
+        match (self.scale_factor, self.add_offset) {
+            (Some(scale), Some(offset)) => parts.push(format!("x * {scale} + {offset}")),
+            (Some(scale), None) => parts.push(format!("x * {scale}")),
+            (None, Some(offset)) => parts.push(format!("x + {offset}")),
+            (None, None) => {}
+        }
+        if !self.fill_values.is_empty() {
+            let fills = self
+                .fill_values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            parts.push(format!("fill {fills} → NaN"));
+        }
+        match (self.valid_min, self.valid_max) {
+            (Some(min), Some(max)) => parts.push(format!("valid [{min}, {max}]")),
+            (Some(min), None) => parts.push(format!("valid ≥ {min}")),
+            (None, Some(max)) => parts.push(format!("valid ≤ {max}")),
+            (None, None) => {}
+        }
+        parts.join(", ")
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::CfDecoding;
+
+    #[test]
+    fn decodes_packed_values_and_masks_fill_and_range() {
+        let decoding = CfDecoding {
+            scale_factor: Some(0.5),
+            add_offset: Some(10.0),
+            fill_values: vec![-9999.0],
+            valid_min: Some(0.0),
+            valid_max: Some(100.0),
+        };
+        let mut values = [4.0, -9999.0, -1.0, 101.0, 100.0];
+        decoding.decode_in_place(&mut values);
+        assert_eq!(values[0], 12.0);
+        assert!(values[1].is_nan());
+        assert!(values[2].is_nan());
+        assert!(values[3].is_nan());
+        assert_eq!(values[4], 60.0);
+        assert_eq!(
+            decoding.describe(),
+            "x * 0.5 + 10, fill -9999 → NaN, valid [0, 100]"
+        );
+    }
+
+    #[test]
+    fn reads_cf_attributes_from_dataset() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let dir = tempfile::tempdir().expect("failed to create temp dir");
+        let file = hdf5_metno::File::create(dir.path().join("cf.h5")).expect("create file");
+        let packed = file
+            .new_dataset_builder()
+            .with_data(&[100_i16, -32767, 250])
+            .create("temperature")
+            .expect("create dataset");
+        packed
+            .new_attr::<f32>()
+            .create("scale_factor")
+            .expect("create scale_factor")
+            .write_scalar(&0.01_f32)
+            .expect("write scale_factor");
+        packed
+            .new_attr::<f64>()
+            .create("add_offset")
+            .expect("create add_offset")
+            .write_scalar(&273.15)
+            .expect("write add_offset");
+        packed
+            .new_attr::<i16>()
+            .create("_FillValue")
+            .expect("create _FillValue")
+            .write_scalar(&-32767_i16)
+            .expect("write _FillValue");
+        packed
+            .new_attr::<i16>()
+            .shape(2)
+            .create("valid_range")
+            .expect("create valid_range")
+            .write(&[0_i16, 200])
+            .expect("write valid_range");
+
+        let decoding = CfDecoding::read(&packed).expect("cf attributes");
+
+        assert_eq!(decoding.fill_values, vec![-32767.0]);
+        assert_eq!(decoding.valid_min, Some(0.0));
+        assert_eq!(decoding.valid_max, Some(200.0));
+        assert!((decoding.decode(100.0) - 274.15).abs() < 1e-4);
+        assert!(decoding.decode(-32767.0).is_nan());
+        assert!(decoding.decode(250.0).is_nan());
+
+        let plain = file
+            .new_dataset_builder()
+            .with_data(&[1_u8, 2])
+            .create("plain")
+            .expect("create plain");
+        assert_eq!(CfDecoding::read(&plain), None);
+    }
+}
@@ -28,11 +28,24 @@ const STRING_PREVIEW_ELEMENTS: usize = 64;
 const STRING_PREVIEW_BYTES: usize = 64 * 1024;
 const MAX_FIXED_STRING_BYTES: usize = 32 * 1024;
 
+/// 16-bit floats in float storage are edited as f32 and narrowed by HDF5;
+/// opaque storage has no conversion path back.
+fn reject_opaque_float16_edit(meta: &DatasetMeta) -> Result<(), AppError> {
+    if meta.is_opaque_float16() {
+        return Err(AppError::EditError(format!(
+            "{} values are read-only",
+            meta.data_type
+        )));
+    }
+    Ok(())
+}
+
 pub fn format_dataset_value_for_edit(
     dataset: &Dataset,
     meta: &DatasetMeta,
     selection: Option<&Selection>,
 ) -> Result<String, AppError> {
+    reject_opaque_float16_edit(meta)?;
     if meta.is_opaque() {
         let bytes = read_selected_element_bytes(dataset, selection)?;
         return Ok(super::format_opaque_bytes_for_edit(&bytes));
@@ -114,6 +127,7 @@ pub fn write_dataset_value_from_text(
     selection: Option<&Selection>,
     new_value: &str,
 ) -> Result<String, AppError> {
+    reject_opaque_float16_edit(meta)?;
     if meta.is_opaque() {
         let bytes = parse_opaque_bytes_from_text(new_value, meta.data_bytesize)?;
         write_selected_element_bytes(dataset, selection, &bytes)?;
@@ -4,7 +4,6 @@ use hdf5_metno_sys::h5a::H5Awrite;
 
 use crate::error::AppError;
 
-use super::parse_1d_lines;
 use hdf5_metno::types::TypeDescriptor;
 
 pub(crate) fn copy_enum_to_group(
@@ -66,14 +65,14 @@ pub(crate) fn write_enum_scalar_attr_from_text(
     write_enum_value(attr, value, enum_type)
 }
 
-pub(crate) fn write_enum_1d_attr_from_text(
+pub(crate) fn write_enum_array_attr_values(
     attr: &Attribute,
-    new_value: &str,
+    values: &[&str],
     enum_type: &EnumType,
 ) -> Result<(), AppError> {
-    let values = parse_1d_lines(new_value, expected_1d_len(attr))?
-        .into_iter()
-        .map(|line| parse_enum_member_value(line, enum_type))
+    let values = values
+        .iter()
+        .map(|value| parse_enum_member_value(value, enum_type))
         .collect::<Result<Vec<_>, _>>()?;
     match enum_type.base_type() {
         TypeDescriptor::Integer(int_size) => match int_size {
@@ -133,50 +132,50 @@ pub(crate) fn read_scalar_enum_value(
     }
 }
 
-pub(crate) fn read_1d_enum_values(
+pub(crate) fn read_enum_values(
     attr: &Attribute,
     enum_type: &EnumType,
 ) -> Result<Vec<u64>, AppError> {
     match enum_type.base_type() {
         TypeDescriptor::Integer(int_size) => Ok(match int_size {
             hdf5_metno::types::IntSize::U1 => attr
-                .read_1d::<i8>()?
+                .read_raw::<i8>()?
                 .into_iter()
                 .map(|value| value as u64)
                 .collect(),
             hdf5_metno::types::IntSize::U2 => attr
-                .read_1d::<i16>()?
+                .read_raw::<i16>()?
                 .into_iter()
                 .map(|value| value as u64)
                 .collect(),
             hdf5_metno::types::IntSize::U4 => attr
-                .read_1d::<i32>()?
+                .read_raw::<i32>()?
                 .into_iter()
                 .map(|value| value as u64)
                 .collect(),
             hdf5_metno::types::IntSize::U8 => attr
-                .read_1d::<i64>()?
+                .read_raw::<i64>()?
                 .into_iter()
                 .map(|value| value as u64)
                 .collect(),
         }),
         TypeDescriptor::Unsigned(int_size) => Ok(match int_size {
             hdf5_metno::types::IntSize::U1 => attr
-                .read_1d::<u8>()?
+                .read_raw::<u8>()?
                 .into_iter()
                 .map(|value| value as u64)
                 .collect(),
             hdf5_metno::types::IntSize::U2 => attr
-                .read_1d::<u16>()?
+                .read_raw::<u16>()?
                 .into_iter()
                 .map(|value| value as u64)
                 .collect(),
             hdf5_metno::types::IntSize::U4 => attr
-                .read_1d::<u32>()?
+                .read_raw::<u32>()?
                 .into_iter()
                 .map(|value| value as u64)
                 .collect(),
-            hdf5_metno::types::IntSize::U8 => attr.read_1d::<u64>()?.into_iter().collect(),
+            hdf5_metno::types::IntSize::U8 => attr.read_raw::<u64>()?.into_iter().collect(),
         }),
         _ => Err(AppError::EditError(format!(
             "Unsupported enum base type: {}",
@@ -366,14 +365,10 @@ fn read_enum_values_as<T: H5Type + Clone>(attr: &Attribute) -> Result<Vec<T>, Ap
     if attr.is_scalar() {
         Ok(vec![attr.read_scalar::<T>()?])
     } else {
-        Ok(attr.read_1d::<T>()?.to_vec())
+        Ok(attr.read_raw::<T>()?)
     }
 }
 
-fn expected_1d_len(attr: &Attribute) -> usize {
-    attr.shape().first().copied().unwrap_or(0)
-}
-
 fn to_array<const N: usize>(bytes: &[u8]) -> Result<[u8; N], AppError> {
     bytes.try_into().map_err(|_| {
         AppError::EditError(format!(
@@ -7,7 +7,9 @@ use hdf5_metno_sys::h5a::H5Awrite;
 
 use crate::error::{AppError, FixedStringKind, FixedStringOverflow};
 
-use super::{copy_attr_to_group, parse_1d_lines, read_attr_memory_bytes, write_attr_from_text};
+use super::{
+    copy_attr_to_group, expected_value_count, read_attr_memory_bytes, write_attr_from_text,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FixedStringRewrite {
@@ -70,65 +72,34 @@ pub fn rewrite_fixed_string_attr(
     Ok(())
 }
 
+/// Reads the values of a string attribute of any rank, in row-major order.
 pub fn read_string_attr_values(attr: &Attribute) -> Result<Vec<String>, AppError> {
     let type_desc = attr.dtype()?.to_descriptor()?;
     match type_desc {
         TypeDescriptor::FixedAscii(size) => {
             if attr.is_scalar() {
                 Ok(vec![format_fixed_string_scalar(attr, size, true)?])
-            } else if attr.ndim() == 1 {
-                read_fixed_string_1d_values(attr, size, true)
             } else {
-                Err(AppError::EditError(format!(
-                    "Expected scalar or 1D string attribute, got {}D attribute",
-                    attr.ndim()
-                )))
+                read_fixed_string_values(attr, size, true)
             }
         }
         TypeDescriptor::FixedUnicode(size) => {
             if attr.is_scalar() {
                 Ok(vec![format_fixed_string_scalar(attr, size, false)?])
-            } else if attr.ndim() == 1 {
-                read_fixed_string_1d_values(attr, size, false)
-            } else {
-                Err(AppError::EditError(format!(
-                    "Expected scalar or 1D string attribute, got {}D attribute",
-                    attr.ndim()
-                )))
-            }
-        }
-        TypeDescriptor::VarLenAscii => {
-            if attr.is_scalar() {
-                Ok(vec![attr.read_scalar::<VarLenAscii>()?.to_string()])
-            } else if attr.ndim() == 1 {
-                Ok(attr
-                    .read_1d::<VarLenAscii>()?
-                    .into_iter()
-                    .map(|value| value.to_string())
-                    .collect())
-            } else {
-                Err(AppError::EditError(format!(
-                    "Expected scalar or 1D string attribute, got {}D attribute",
-                    attr.ndim()
-                )))
-            }
-        }
-        TypeDescriptor::VarLenUnicode => {
-            if attr.is_scalar() {
-                Ok(vec![attr.read_scalar::<VarLenUnicode>()?.to_string()])
-            } else if attr.ndim() == 1 {
-                Ok(attr
-                    .read_1d::<VarLenUnicode>()?
-                    .into_iter()
-                    .map(|value| value.to_string())
-                    .collect())
             } else {
-                Err(AppError::EditError(format!(
-                    "Expected scalar or 1D string attribute, got {}D attribute",
-                    attr.ndim()
-                )))
+                read_fixed_string_values(attr, size, false)
             }
         }
+        TypeDescriptor::VarLenAscii => Ok(attr
+            .read_raw::<VarLenAscii>()?
+            .into_iter()
+            .map(|value| value.to_string())
+            .collect()),
+        TypeDescriptor::VarLenUnicode => Ok(attr
+            .read_raw::<VarLenUnicode>()?
+            .into_iter()
+            .map(|value| value.to_string())
+            .collect()),
         other => Err(AppError::EditError(format!(
             "Expected string attribute values, got {}",
             other
@@ -145,14 +116,6 @@ pub(crate) fn format_fixed_string_scalar(
     decode_fixed_string_value(&data, size, is_ascii)
 }
 
-pub(crate) fn format_fixed_string_1d(
-    attr: &Attribute,
-    size: usize,
-    is_ascii: bool,
-) -> Result<String, AppError> {
-    read_fixed_string_1d_values(attr, size, is_ascii).map(|values| values.join("\n"))
-}
-
 pub(crate) fn write_fixed_string_scalar_attr_from_text(
     attr: &Attribute,
     new_value: &str,
@@ -163,16 +126,15 @@ pub(crate) fn write_fixed_string_scalar_attr_from_text(
     write_fixed_string_memory(attr, &bytes)
 }
 
-pub(crate) fn write_fixed_string_1d_attr_from_text(
+pub(crate) fn write_fixed_string_array_attr_values(
     attr: &Attribute,
-    new_value: &str,
+    values: &[&str],
     size: usize,
     is_ascii: bool,
 ) -> Result<(), AppError> {
-    let lines = parse_1d_lines(new_value, expected_1d_len(attr))?;
-    let mut bytes = Vec::with_capacity(lines.len() * size);
-    for line in lines {
-        bytes.extend(encode_fixed_string_value(line, size, is_ascii)?);
+    let mut bytes = Vec::with_capacity(values.len() * size);
+    for value in values {
+        bytes.extend(encode_fixed_string_value(value, size, is_ascii)?);
     }
     write_fixed_string_memory(attr, &bytes)
 }
@@ -272,7 +234,7 @@ fn replacement_fixed_string_type(
     }
 }
 
-fn unique_temp_attr_name(group: &Group, attr_name: &str) -> Result<String, AppError> {
+pub(crate) fn unique_temp_attr_name(group: &Group, attr_name: &str) -> Result<String, AppError> {
     let existing = group.attr_names()?;
     let mut index = 0usize;
     loop {
@@ -284,17 +246,13 @@ fn unique_temp_attr_name(group: &Group, attr_name: &str) -> Result<String, AppEr
     }
 }
 
-fn expected_1d_len(attr: &Attribute) -> usize {
-    attr.shape().first().copied().unwrap_or(0)
-}
-
-fn read_fixed_string_1d_values(
+pub(crate) fn read_fixed_string_values(
     attr: &Attribute,
     size: usize,
     is_ascii: bool,
 ) -> Result<Vec<String>, AppError> {
     let data = read_attr_memory_bytes(attr)?;
-    let len = expected_1d_len(attr);
+    let len = expected_value_count(attr);
     if size == 0 {
         return Ok(std::iter::repeat_n(String::new(), len).collect());
     }
@@ -315,7 +273,48 @@ fn read_fixed_string_1d_values(
 #[allow(clippy::unwrap_used)]
 #[allow(clippy::expect_used)]
 mod tests {
-    use super::{decode_fixed_string_value, encode_fixed_string_value};
+    use hdf5_metno::types::{FixedAscii, VarLenUnicode};
+
+    use super::{decode_fixed_string_value, encode_fixed_string_value, read_string_attr_values};
+
+    #[test]
+    fn reads_string_attributes_of_any_rank() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let names = ["a", "b", "c", "d", "e", "f"];
+        let varlen = names
+            .iter()
+            .map(|name| {
+                name.parse::<VarLenUnicode>()
+                    .expect("failed to build string")
+            })
+            .collect::<Vec<_>>();
+        file.new_attr::<VarLenUnicode>()
+            .shape([2, 3])
+            .create("labels")
+            .expect("failed to create attribute")
+            .write_raw(&varlen)
+            .expect("failed to write attribute");
+        let fixed = names
+            .iter()
+            .map(|name| FixedAscii::<4>::from_ascii(name).expect("failed to build string"))
+            .collect::<Vec<_>>();
+        file.new_attr::<FixedAscii<4>>()
+            .shape([3, 2])
+            .create("codes")
+            .expect("failed to create attribute")
+            .write_raw(&fixed)
+            .expect("failed to write attribute");
+
+        for name in ["labels", "codes"] {
+            let attr = file.attr(name).expect("failed to open attribute");
+            assert_eq!(
+                read_string_attr_values(&attr).expect("failed to read values"),
+                names
+            );
+        }
+    }
 
     #[test]
     fn fixed_ascii_runtime_encoding_and_decoding_roundtrip() {
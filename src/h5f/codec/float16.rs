@@ -0,0 +1,187 @@
+use hdf5_metno::{Dataset, Datatype, Selection};
+use hdf5_metno_sys::h5t::{
+    H5T_class_t::{H5T_FLOAT, H5T_OPAQUE},
+    H5Tget_class, H5Tget_fields,
+};
+
+use crate::error::AppError;
+
+use super::{super::compound::read_selected_values_bytes, read_string_attr_values};
+
+/// Attribute marking 2-byte opaque datasets as 16-bit floats, e.g. `bfloat16`.
+const FLOAT16_MARKER_ATTR: &str = "dtype";
+const HALF_MARKERS: [&str; 3] = ["float16", "f16", "half"];
+const BFLOAT16_MARKERS: [&str; 2] = ["bfloat16", "bf16"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Float16Format {
+    /// IEEE 754 binary16: 5 exponent and 10 mantissa bits.
+    Half,
+    /// bfloat16: the upper half of an f32, 8 exponent and 7 mantissa bits.
+    BFloat16,
+}
+
+impl Float16Format {
+    fn from_fields(exponent_bits: usize, mantissa_bits: usize) -> Option<Self> {
+        match (exponent_bits, mantissa_bits) {
+            (5, 10) => Some(Self::Half),
+            (8, 7) => Some(Self::BFloat16),
+            _ => None,
+        }
+    }
+
+    fn from_marker(marker: &str) -> Option<Self> {
+        let marker = marker.trim().to_ascii_lowercase();
+        if HALF_MARKERS.contains(&marker.as_str()) {
+            Some(Self::Half)
+        } else if BFLOAT16_MARKERS.contains(&marker.as_str()) {
+            Some(Self::BFloat16)
+        } else {
+            None
+        }
+    }
+}
+
+/// A 16-bit float dataset. HDF5 converts float-class storage to f64 itself;
+/// opaque storage is decoded here, little-endian.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Float16Type {
+    pub format: Float16Format,
+    pub opaque: bool,
+}
+
+impl Float16Type {
+    /// Recognizes 2-byte float datatypes by their bit layout, and 2-byte opaque
+    /// datatypes carrying a `dtype` attribute such as `bfloat16`.
+    pub fn read(dataset: &Dataset) -> Option<Self> {
+        let dtype = dataset.dtype().ok()?;
+        if dtype.size() != 2 {
+            return None;
+        }
+        match unsafe { H5Tget_class(dtype.id()) } {
+            H5T_FLOAT => float_fields(&dtype).map(|format| Self {
+                format,
+                opaque: false,
+            }),
+            H5T_OPAQUE => dataset
+                .attr(FLOAT16_MARKER_ATTR)
+                .ok()
+                .and_then(|attr| read_string_attr_values(&attr).ok())
+                .and_then(|values| values.into_iter().next())
+                .and_then(|marker| Float16Format::from_marker(&marker))
+                .map(|format| Self {
+                    format,
+                    opaque: true,
+                }),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match (self.format, self.opaque) {
+            (Float16Format::Half, false) => "f16",
+            (Float16Format::Half, true) => "f16 (opaque)",
+            (Float16Format::BFloat16, false) => "bf16",
+            (Float16Format::BFloat16, true) => "bf16 (opaque)",
+        }
+    }
+
+    pub fn decode(&self, bits: u16) -> f64 {
+        match self.format {
+            Float16Format::Half => decode_half(bits),
+            Float16Format::BFloat16 => f32::from_bits((bits as u32) << 16) as f64,
+        }
+    }
+}
+
+fn float_fields(dtype: &Datatype) -> Option<Float16Format> {
+    let (mut spos, mut epos, mut esize, mut mpos, mut msize) = (0, 0, 0, 0, 0);
+    let status = unsafe {
+        H5Tget_fields(
+            dtype.id(),
+            &mut spos,
+            &mut epos,
+            &mut esize,
+            &mut mpos,
+            &mut msize,
+        )
+    };
+    (status >= 0)
+        .then(|| Float16Format::from_fields(esize, msize))
+        .flatten()
+}
+
+fn decode_half(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = ((bits >> 10) & 0x1f) as i32;
+    let mantissa = (bits & 0x03ff) as f64;
+    match exponent {
+        0 => sign * mantissa * 2_f64.powi(-24),
+        0x1f if mantissa == 0.0 => sign * f64::INFINITY,
+        0x1f => f64::NAN,
+        _ => sign * (1.0 + mantissa / 1024.0) * 2_f64.powi(exponent - 15),
+    }
+}
+
+/// Values of an opaque 16-bit float selection as f64, with the selection's
+/// output shape.
+pub fn read_opaque_float16_values(
+    dataset: &Dataset,
+    float16: Float16Type,
+    selection: Selection,
+) -> Result<(Vec<f64>, Vec<usize>), AppError> {
+    let (bytes, out_shape) = read_selected_values_bytes(dataset, selection)?;
+    let values = bytes
+        .chunks_exact(2)
+        .map(|bits| float16.decode(u16::from_le_bytes([bits[0], bits[1]])))
+        .collect();
+    Ok((values, out_shape))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::{Float16Format, Float16Type};
+
+    #[test]
+    fn decodes_half_and_bfloat16_bits() {
+        let half = Float16Type {
+            format: Float16Format::Half,
+            opaque: false,
+        };
+        assert_eq!(half.decode(0x3c00), 1.0);
+        assert_eq!(half.decode(0xc000), -2.0);
+        assert_eq!(half.decode(0x7bff), 65504.0);
+        assert_eq!(half.decode(0x0001), 2_f64.powi(-24));
+        assert_eq!(half.decode(0x7c00), f64::INFINITY);
+        assert!(half.decode(0x7e00).is_nan());
+
+        let bfloat16 = Float16Type {
+            format: Float16Format::BFloat16,
+            opaque: true,
+        };
+        assert_eq!(bfloat16.decode(0x3f80), 1.0);
+        assert_eq!(bfloat16.decode(0xc0a0), -5.0);
+        assert_eq!(bfloat16.name(), "bf16 (opaque)");
+    }
+
+    #[test]
+    fn recognizes_float16_layouts_and_markers() {
+        assert_eq!(Float16Format::from_fields(5, 10), Some(Float16Format::Half));
+        assert_eq!(
+            Float16Format::from_fields(8, 7),
+            Some(Float16Format::BFloat16)
+        );
+        assert_eq!(Float16Format::from_fields(8, 23), None);
+        assert_eq!(
+            Float16Format::from_marker(" BFloat16 "),
+            Some(Float16Format::BFloat16)
+        );
+        assert_eq!(
+            Float16Format::from_marker("half"),
+            Some(Float16Format::Half)
+        );
+        assert_eq!(Float16Format::from_marker("uint16"), None);
+    }
+}
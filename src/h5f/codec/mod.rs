@@ -6,33 +6,58 @@ use hdf5_metno::{
     Attribute, Group, H5Type, ObjectReference2,
 };
 use hdf5_metno_sys::h5a::H5Aread;
-use ndarray::{Array1, IxDyn};
+use ndarray::IxDyn;
 
 use crate::error::AppError;
 
+mod attr_create;
+mod bitfield;
+mod cf;
 mod dataset;
 mod enum_codec;
 mod fixed_string;
+mod float16;
 mod opaque;
+mod ragged;
+mod references;
+mod timestamps;
 
+pub use attr_create::{create_attr_from_text, retype_attr_from_text, AttributeSpec};
+pub use bitfield::{
+    read_bitfield_matrix_table, read_bitfield_matrix_values, read_bitfield_values, BitfieldType,
+};
+pub use cf::CfDecoding;
 pub use dataset::{
     format_dataset_value_for_edit, read_single_value_dataset, read_string_dataset_preview,
     read_varlen_u8_matrix_table, read_varlen_u8_matrix_values, write_dataset_value_from_text,
 };
 pub use fixed_string::{read_string_attr_values, rewrite_fixed_string_attr, FixedStringRewrite};
+pub use float16::{read_opaque_float16_values, Float16Type};
 pub use opaque::{
     format_opaque_bytes_for_edit, read_opaque_dataset_preview, read_opaque_values_1d,
     read_opaque_values_2d,
 };
+pub use ragged::{
+    is_ragged_numeric_type, read_ragged_element, read_ragged_matrix_table,
+    read_ragged_matrix_values,
+};
+pub use references::{
+    read_reference_attr_target, read_reference_dataset_preview, read_reference_element,
+    read_reference_matrix_table, read_reference_matrix_values, DatasetRegionTarget,
+    ReferenceTarget,
+};
+pub use timestamps::{
+    format_unix_seconds, time_tick_step, time_ticks, TimeEncoding, TimestampValue,
+};
 
 use self::{
     enum_codec::{
-        copy_enum_to_group, format_enum_value_for_edit, read_1d_enum_values,
-        read_scalar_enum_value, write_enum_1d_attr_from_text, write_enum_scalar_attr_from_text,
+        copy_enum_to_group, format_enum_value_for_edit, read_enum_values, read_scalar_enum_value,
+        write_enum_array_attr_values, write_enum_scalar_attr_from_text,
     },
     fixed_string::{
-        copy_fixed_string_to_group, format_fixed_string_1d, format_fixed_string_scalar,
-        write_fixed_string_1d_attr_from_text, write_fixed_string_memory,
+        copy_fixed_string_to_group, format_fixed_string_scalar, read_fixed_string_values,
+        write_fixed_string_array_attr_values, write_fixed_string_memory,
         write_fixed_string_scalar_attr_from_text,
     },
     opaque::parse_opaque_bytes_from_text,
@@ -57,18 +82,32 @@ pub enum ScalarTextCodec {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AttributeCreateType {
     Bool,
+    I8,
+    I16,
+    I32,
     I64,
+    U8,
+    U16,
+    U32,
     U64,
+    F32,
     F64,
     String,
     Ascii,
 }
 
 impl AttributeCreateType {
-    pub const ALL: [Self; 6] = [
+    pub const ALL: [Self; 13] = [
         Self::Bool,
+        Self::I8,
+        Self::I16,
+        Self::I32,
         Self::I64,
+        Self::U8,
+        Self::U16,
+        Self::U32,
         Self::U64,
+        Self::F32,
         Self::F64,
         Self::String,
         Self::Ascii,
@@ -77,8 +116,15 @@ impl AttributeCreateType {
     pub fn label(self) -> &'static str {
         match self {
             Self::Bool => "bool",
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
             Self::I64 => "i64",
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::U32 => "u32",
             Self::U64 => "u64",
+            Self::F32 => "f32",
             Self::F64 => "f64",
             Self::String => "string",
             Self::Ascii => "ascii",
@@ -87,25 +133,47 @@ impl AttributeCreateType {
 
     pub fn description(self) -> &'static str {
         match self {
-            Self::Bool => "bool",
-            Self::I64 => "i64",
-            Self::U64 => "u64",
-            Self::F64 => "f64",
             Self::String => "VarLenUnicode",
             Self::Ascii => "VarLenAscii",
+            other => other.label(),
+        }
+    }
+
+    pub fn type_descriptor(self) -> TypeDescriptor {
+        match self {
+            Self::Bool => TypeDescriptor::Boolean,
+            Self::I8 => TypeDescriptor::Integer(hdf5_metno::types::IntSize::U1),
+            Self::I16 => TypeDescriptor::Integer(hdf5_metno::types::IntSize::U2),
+            Self::I32 => TypeDescriptor::Integer(hdf5_metno::types::IntSize::U4),
+            Self::I64 => TypeDescriptor::Integer(hdf5_metno::types::IntSize::U8),
+            Self::U8 => TypeDescriptor::Unsigned(hdf5_metno::types::IntSize::U1),
+            Self::U16 => TypeDescriptor::Unsigned(hdf5_metno::types::IntSize::U2),
+            Self::U32 => TypeDescriptor::Unsigned(hdf5_metno::types::IntSize::U4),
+            Self::U64 => TypeDescriptor::Unsigned(hdf5_metno::types::IntSize::U8),
+            Self::F32 => TypeDescriptor::Float(hdf5_metno::types::FloatSize::U4),
+            Self::F64 => TypeDescriptor::Float(hdf5_metno::types::FloatSize::U8),
+            Self::String => TypeDescriptor::VarLenUnicode,
+            Self::Ascii => TypeDescriptor::VarLenAscii,
         }
     }
 
     pub fn parse(value: &str) -> Result<Self, AppError> {
         match value.trim().to_ascii_lowercase().as_str() {
             "bool" | "boolean" => Ok(Self::Bool),
-            "i64" | "int" | "integer" => Ok(Self::I64),
-            "u64" | "uint" | "unsigned" => Ok(Self::U64),
-            "f64" | "float" | "double" => Ok(Self::F64),
+            "i8" | "int8" => Ok(Self::I8),
+            "i16" | "int16" => Ok(Self::I16),
+            "i32" | "int32" => Ok(Self::I32),
+            "i64" | "int64" | "int" | "integer" => Ok(Self::I64),
+            "u8" | "uint8" => Ok(Self::U8),
+            "u16" | "uint16" => Ok(Self::U16),
+            "u32" | "uint32" => Ok(Self::U32),
+            "u64" | "uint64" | "uint" | "unsigned" => Ok(Self::U64),
+            "f32" | "float32" | "single" => Ok(Self::F32),
+            "f64" | "float64" | "float" | "double" => Ok(Self::F64),
             "string" | "str" | "text" | "unicode" => Ok(Self::String),
             "ascii" => Ok(Self::Ascii),
             other => Err(AppError::InvalidCommand(format!(
-                "Unsupported attribute type '{}'. Expected one of: bool, i64, u64, f64, string, ascii",
+                "Unsupported attribute type '{}'. Expected one of: bool, i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, string, ascii, or enum(...), optionally followed by [] for an array",
                 other
             ))),
         }
@@ -244,48 +312,6 @@ fn copy_to_group<T: H5Type>(
     Ok(())
 }
 
-pub fn create_scalar_attr_from_text(
-    group: &Group,
-    attr_name: &str,
-    attr_type: AttributeCreateType,
-    value: &str,
-) -> Result<String, AppError> {
-    match attr_type {
-        AttributeCreateType::Bool => {
-            let attr = group.new_attr_builder().empty::<bool>().create(attr_name)?;
-            write_parsed_scalar::<bool>(&attr, value, "bool")?;
-        }
-        AttributeCreateType::I64 => {
-            let attr = group.new_attr_builder().empty::<i64>().create(attr_name)?;
-            write_parsed_scalar::<i64>(&attr, value, "i64")?;
-        }
-        AttributeCreateType::U64 => {
-            let attr = group.new_attr_builder().empty::<u64>().create(attr_name)?;
-            write_parsed_scalar::<u64>(&attr, value, "u64")?;
-        }
-        AttributeCreateType::F64 => {
-            let attr = group.new_attr_builder().empty::<f64>().create(attr_name)?;
-            write_parsed_scalar::<f64>(&attr, value, "f64")?;
-        }
-        AttributeCreateType::String => {
-            let attr = group
-                .new_attr_builder()
-                .empty::<VarLenUnicode>()
-                .create(attr_name)?;
-            write_scalar_attr_from_text(&attr, value)?;
-        }
-        AttributeCreateType::Ascii => {
-            let attr = group
-                .new_attr_builder()
-                .empty::<VarLenAscii>()
-                .create(attr_name)?;
-            write_scalar_attr_from_text(&attr, value)?;
-        }
-    }
-    group.file()?.flush()?;
-    Ok(attr_type.description().to_string())
-}
-
 pub fn ensure_attr_editable(attr: &Attribute) -> Result<(), AppError> {
     if !attr.is_valid() {
         return Err(AppError::EditError("Invalid attribute".to_string()));
@@ -293,28 +319,13 @@ pub fn ensure_attr_editable(attr: &Attribute) -> Result<(), AppError> {
 
     let dtype = attr.dtype()?;
     match dtype.to_descriptor() {
-        Ok(type_desc) => validate_attr_edit_support(&type_desc, attr.ndim()),
-        Err(err) if err.to_string() == "Unsupported datatype class" => {
-            if attr.ndim() > 1 {
-                Err(AppError::EditError(format!(
-                    "Only scalar and 1D attributes can be edited, got {}D attribute",
-                    attr.ndim()
-                )))
-            } else {
-                Ok(())
-            }
-        }
+        Ok(type_desc) => validate_attr_edit_support(&type_desc),
+        Err(err) if err.to_string() == "Unsupported datatype class" => Ok(()),
         Err(err) => Err(err.into()),
     }
 }
 
-fn validate_attr_edit_support(type_desc: &TypeDescriptor, ndim: usize) -> Result<(), AppError> {
-    if ndim > 1 {
-        return Err(AppError::EditError(format!(
-            "Only scalar and 1D attributes can be edited, got {ndim}D attribute"
-        )));
-    }
-
+fn validate_attr_edit_support(type_desc: &TypeDescriptor) -> Result<(), AppError> {
     if scalar_text_codec(type_desc).is_some()
         || matches!(
             type_desc,
@@ -343,29 +354,28 @@ fn validate_attr_edit_support(type_desc: &TypeDescriptor, ndim: usize) -> Result
     })
 }
 
+/// Array attributes are edited one value per line, in row-major order.
 pub fn format_attr_for_edit(attr: &Attribute) -> Result<String, AppError> {
+    Ok(format_attr_values_for_edit(attr)?.join("\n"))
+}
+
+/// Each value of an attribute as text, in row-major order; a scalar has one.
+pub(crate) fn format_attr_values_for_edit(attr: &Attribute) -> Result<Vec<String>, AppError> {
     ensure_attr_editable(attr)?;
     let dtype = attr.dtype()?;
     let type_desc = match dtype.to_descriptor() {
         Ok(type_desc) => type_desc,
         Err(err) if err.to_string() == "Unsupported datatype class" => {
-            return format_opaque_attr_for_edit(attr, dtype.size());
+            return format_opaque_attr_values(attr, dtype.size());
         }
         Err(err) => return Err(err.into()),
     };
 
     if attr.is_scalar() {
-        return format_scalar_attr_for_edit(attr, &type_desc);
-    }
-
-    if attr.ndim() == 1 {
-        return format_1d_attr_for_edit(attr, &type_desc);
+        return Ok(vec![format_scalar_attr_for_edit(attr, &type_desc)?]);
     }
 
-    Err(AppError::EditError(format!(
-        "Only scalar and 1D attributes can be edited, got {}D attribute",
-        attr.ndim()
-    )))
+    format_array_attr_values(attr, &type_desc)
 }
 
 fn format_scalar_attr_for_edit(
@@ -402,42 +412,37 @@ fn format_scalar_attr_for_edit(
     }
 }
 
-fn format_1d_attr_for_edit(
+fn format_array_attr_values(
     attr: &Attribute,
     type_desc: &TypeDescriptor,
-) -> Result<String, AppError> {
+) -> Result<Vec<String>, AppError> {
     match type_desc {
         TypeDescriptor::Integer(int_size) => Ok(match int_size {
-            hdf5_metno::types::IntSize::U1 => join_display_lines(attr.read_1d::<i8>()?.iter()),
-            hdf5_metno::types::IntSize::U2 => join_display_lines(attr.read_1d::<i16>()?.iter()),
-            hdf5_metno::types::IntSize::U4 => join_display_lines(attr.read_1d::<i32>()?.iter()),
-            hdf5_metno::types::IntSize::U8 => join_display_lines(attr.read_1d::<i64>()?.iter()),
+            hdf5_metno::types::IntSize::U1 => display_values(&attr.read_raw::<i8>()?),
+            hdf5_metno::types::IntSize::U2 => display_values(&attr.read_raw::<i16>()?),
+            hdf5_metno::types::IntSize::U4 => display_values(&attr.read_raw::<i32>()?),
+            hdf5_metno::types::IntSize::U8 => display_values(&attr.read_raw::<i64>()?),
         }),
         TypeDescriptor::Unsigned(int_size) => Ok(match int_size {
-            hdf5_metno::types::IntSize::U1 => join_display_lines(attr.read_1d::<u8>()?.iter()),
-            hdf5_metno::types::IntSize::U2 => join_display_lines(attr.read_1d::<u16>()?.iter()),
-            hdf5_metno::types::IntSize::U4 => join_display_lines(attr.read_1d::<u32>()?.iter()),
-            hdf5_metno::types::IntSize::U8 => join_display_lines(attr.read_1d::<u64>()?.iter()),
+            hdf5_metno::types::IntSize::U1 => display_values(&attr.read_raw::<u8>()?),
+            hdf5_metno::types::IntSize::U2 => display_values(&attr.read_raw::<u16>()?),
+            hdf5_metno::types::IntSize::U4 => display_values(&attr.read_raw::<u32>()?),
+            hdf5_metno::types::IntSize::U8 => display_values(&attr.read_raw::<u64>()?),
         }),
         TypeDescriptor::Float(float_size) => Ok(match float_size {
-            hdf5_metno::types::FloatSize::U4 => join_display_lines(attr.read_1d::<f32>()?.iter()),
-            hdf5_metno::types::FloatSize::U8 => join_display_lines(attr.read_1d::<f64>()?.iter()),
+            hdf5_metno::types::FloatSize::U4 => display_values(&attr.read_raw::<f32>()?),
+            hdf5_metno::types::FloatSize::U8 => display_values(&attr.read_raw::<f64>()?),
         }),
-        TypeDescriptor::Boolean => Ok(join_display_lines(attr.read_1d::<bool>()?.iter())),
-        TypeDescriptor::FixedAscii(size) => format_fixed_string_1d(attr, *size, true),
-        TypeDescriptor::FixedUnicode(size) => format_fixed_string_1d(attr, *size, false),
-        TypeDescriptor::VarLenAscii => {
-            Ok(join_display_lines(attr.read_1d::<VarLenAscii>()?.iter()))
-        }
-        TypeDescriptor::VarLenUnicode => {
-            Ok(join_display_lines(attr.read_1d::<VarLenUnicode>()?.iter()))
-        }
-        TypeDescriptor::Enum(enum_type) => Ok(read_1d_enum_values(attr, enum_type)?
+        TypeDescriptor::Boolean => Ok(display_values(&attr.read_raw::<bool>()?)),
+        TypeDescriptor::FixedAscii(size) => read_fixed_string_values(attr, *size, true),
+        TypeDescriptor::FixedUnicode(size) => read_fixed_string_values(attr, *size, false),
+        TypeDescriptor::VarLenAscii => Ok(display_values(&attr.read_raw::<VarLenAscii>()?)),
+        TypeDescriptor::VarLenUnicode => Ok(display_values(&attr.read_raw::<VarLenUnicode>()?)),
+        TypeDescriptor::Enum(enum_type) => Ok(read_enum_values(attr, enum_type)?
             .into_iter()
             .map(|value| format_enum_value_for_edit(value, enum_type))
-            .collect::<Vec<_>>()
-            .join("\n")),
-        _ => Err(validate_attr_edit_support(type_desc, 1)
+            .collect()),
+        _ => Err(validate_attr_edit_support(type_desc)
             .err()
             .unwrap_or_else(|| {
                 AppError::EditError(format!(
@@ -448,32 +453,27 @@ fn format_1d_attr_for_edit(
     }
 }
 
-fn join_display_lines<'a, T>(values: impl IntoIterator<Item = &'a T>) -> String
+fn display_values<'a, T>(values: impl IntoIterator<Item = &'a T>) -> Vec<String>
 where
     T: std::fmt::Display + 'a,
 {
-    values
-        .into_iter()
-        .map(ToString::to_string)
-        .collect::<Vec<_>>()
-        .join("\n")
+    values.into_iter().map(ToString::to_string).collect()
 }
 
-fn format_opaque_attr_for_edit(attr: &Attribute, item_size: usize) -> Result<String, AppError> {
+fn format_opaque_attr_values(attr: &Attribute, item_size: usize) -> Result<Vec<String>, AppError> {
     let bytes = read_attr_memory_bytes(attr)?;
     if attr.is_scalar() {
-        return Ok(format_opaque_bytes_for_edit(&bytes));
+        return Ok(vec![format_opaque_bytes_for_edit(&bytes)]);
     }
 
-    let lines = if item_size == 0 {
-        vec![String::new(); expected_1d_len(attr)]
+    Ok(if item_size == 0 {
+        vec![String::new(); expected_value_count(attr)]
     } else {
         bytes
             .chunks_exact(item_size)
             .map(format_opaque_bytes_for_edit)
-            .collect::<Vec<_>>()
-    };
-    Ok(lines.join("\n"))
+            .collect()
+    })
 }
 
 pub fn write_attr_from_text(attr: &Attribute, new_value: &str) -> Result<String, AppError> {
@@ -492,15 +492,27 @@ pub fn write_attr_from_text(attr: &Attribute, new_value: &str) -> Result<String,
         return write_scalar_attr_from_text(attr, new_value);
     }
 
-    if attr.ndim() == 1 {
-        write_1d_attr_from_text(attr, new_value, &type_desc)?;
-        return Ok(type_desc.to_string());
-    }
+    let values = parse_1d_lines(new_value, expected_value_count(attr))?;
+    write_array_attr_values(attr, &values, &type_desc)?;
+    Ok(type_desc.to_string())
+}
 
-    Err(AppError::EditError(format!(
-        "Only scalar and 1D attributes can be edited, got {}D attribute",
-        attr.ndim()
-    )))
+/// Writes one text value per element of an attribute, in row-major order,
+/// so values may contain line breaks.
+pub(crate) fn write_attr_values(attr: &Attribute, values: &[&str]) -> Result<(), AppError> {
+    ensure_attr_editable(attr)?;
+    let expected_len = expected_value_count(attr);
+    if values.len() != expected_len {
+        return Err(AppError::EditError(format!(
+            "Expected {expected_len} values, got {}",
+            values.len()
+        )));
+    }
+    if attr.is_scalar() {
+        write_scalar_attr_from_text(attr, values[0])?;
+        return Ok(());
+    }
+    write_array_attr_values(attr, values, &attr.dtype()?.to_descriptor()?)
 }
 
 fn write_opaque_attr_from_text(
@@ -513,7 +525,7 @@ fn write_opaque_attr_from_text(
         return write_fixed_string_memory(attr, &bytes);
     }
 
-    let lines = parse_1d_lines(new_value, expected_1d_len(attr))?;
+    let lines = parse_1d_lines(new_value, expected_value_count(attr))?;
     let mut bytes = Vec::with_capacity(lines.len() * item_size);
     for line in lines {
         bytes.extend(parse_opaque_bytes_from_text(line, item_size)?);
@@ -567,43 +579,39 @@ pub fn write_scalar_attr_from_text(attr: &Attribute, new_value: &str) -> Result<
     Ok(type_desc.to_string())
 }
 
-fn write_1d_attr_from_text(
+fn write_array_attr_values(
     attr: &Attribute,
-    new_value: &str,
+    values: &[&str],
     type_desc: &TypeDescriptor,
 ) -> Result<(), AppError> {
     match type_desc {
         TypeDescriptor::Integer(int_size) => match int_size {
-            hdf5_metno::types::IntSize::U1 => write_parsed_1d_array::<i8>(attr, new_value, "i8"),
-            hdf5_metno::types::IntSize::U2 => write_parsed_1d_array::<i16>(attr, new_value, "i16"),
-            hdf5_metno::types::IntSize::U4 => write_parsed_1d_array::<i32>(attr, new_value, "i32"),
-            hdf5_metno::types::IntSize::U8 => write_parsed_1d_array::<i64>(attr, new_value, "i64"),
+            hdf5_metno::types::IntSize::U1 => write_parsed_array::<i8>(attr, values, "i8"),
+            hdf5_metno::types::IntSize::U2 => write_parsed_array::<i16>(attr, values, "i16"),
+            hdf5_metno::types::IntSize::U4 => write_parsed_array::<i32>(attr, values, "i32"),
+            hdf5_metno::types::IntSize::U8 => write_parsed_array::<i64>(attr, values, "i64"),
         },
         TypeDescriptor::Unsigned(int_size) => match int_size {
-            hdf5_metno::types::IntSize::U1 => write_parsed_1d_array::<u8>(attr, new_value, "u8"),
-            hdf5_metno::types::IntSize::U2 => write_parsed_1d_array::<u16>(attr, new_value, "u16"),
-            hdf5_metno::types::IntSize::U4 => write_parsed_1d_array::<u32>(attr, new_value, "u32"),
-            hdf5_metno::types::IntSize::U8 => write_parsed_1d_array::<u64>(attr, new_value, "u64"),
+            hdf5_metno::types::IntSize::U1 => write_parsed_array::<u8>(attr, values, "u8"),
+            hdf5_metno::types::IntSize::U2 => write_parsed_array::<u16>(attr, values, "u16"),
+            hdf5_metno::types::IntSize::U4 => write_parsed_array::<u32>(attr, values, "u32"),
+            hdf5_metno::types::IntSize::U8 => write_parsed_array::<u64>(attr, values, "u64"),
         },
         TypeDescriptor::Float(float_size) => match float_size {
-            hdf5_metno::types::FloatSize::U4 => {
-                write_parsed_1d_array::<f32>(attr, new_value, "f32")
-            }
-            hdf5_metno::types::FloatSize::U8 => {
-                write_parsed_1d_array::<f64>(attr, new_value, "f64")
-            }
+            hdf5_metno::types::FloatSize::U4 => write_parsed_array::<f32>(attr, values, "f32"),
+            hdf5_metno::types::FloatSize::U8 => write_parsed_array::<f64>(attr, values, "f64"),
         },
-        TypeDescriptor::Boolean => write_parsed_1d_array::<bool>(attr, new_value, "bool"),
+        TypeDescriptor::Boolean => write_parsed_array::<bool>(attr, values, "bool"),
         TypeDescriptor::FixedAscii(size) => {
-            write_fixed_string_1d_attr_from_text(attr, new_value, *size, true)
+            write_fixed_string_array_attr_values(attr, values, *size, true)
         }
         TypeDescriptor::FixedUnicode(size) => {
-            write_fixed_string_1d_attr_from_text(attr, new_value, *size, false)
+            write_fixed_string_array_attr_values(attr, values, *size, false)
         }
-        TypeDescriptor::VarLenAscii => write_ascii_1d_array(attr, new_value),
-        TypeDescriptor::VarLenUnicode => write_unicode_1d_array(attr, new_value),
-        TypeDescriptor::Enum(enum_type) => write_enum_1d_attr_from_text(attr, new_value, enum_type),
-        _ => Err(validate_attr_edit_support(type_desc, 1)
+        TypeDescriptor::VarLenAscii => write_ascii_array(attr, values),
+        TypeDescriptor::VarLenUnicode => write_unicode_array(attr, values),
+        TypeDescriptor::Enum(enum_type) => write_enum_array_attr_values(attr, values, enum_type),
+        _ => Err(validate_attr_edit_support(type_desc)
             .err()
             .unwrap_or_else(|| {
                 AppError::EditError(format!(
@@ -629,17 +637,13 @@ where
         .map_err(|e| AppError::EditError(format!("Failed to write attribute: {}", e)))
 }
 
-fn write_parsed_1d_array<T>(
-    attr: &Attribute,
-    new_value: &str,
-    type_name: &str,
-) -> Result<(), AppError>
+fn write_parsed_array<T>(attr: &Attribute, values: &[&str], type_name: &str) -> Result<(), AppError>
 where
     T: H5Type + FromStr,
     <T as FromStr>::Err: std::fmt::Display,
 {
-    let parsed = parse_1d_lines(new_value, expected_1d_len(attr))?
-        .into_iter()
+    let parsed = values
+        .iter()
         .map(|line| {
             T::from_str(line.trim()).map_err(|e| {
                 AppError::EditError(format!("Failed to convert to {}: {}", type_name, e))
@@ -647,13 +651,13 @@ where
         })
         .collect::<Result<Vec<_>, _>>()?;
 
-    attr.write(Array1::from(parsed).view())
+    attr.write_raw(&parsed)
         .map_err(|e| AppError::EditError(format!("Failed to write attribute: {}", e)))
 }
 
-fn write_ascii_1d_array(attr: &Attribute, new_value: &str) -> Result<(), AppError> {
-    let parsed = parse_1d_lines(new_value, expected_1d_len(attr))?
-        .into_iter()
+fn write_ascii_array(attr: &Attribute, values: &[&str]) -> Result<(), AppError> {
+    let parsed = values
+        .iter()
         .map(|line| {
             VarLenAscii::from_ascii(line).map_err(|e| {
                 AppError::EditError(format!("Failed to convert to VarLenAscii: {}", e))
@@ -661,13 +665,13 @@ fn write_ascii_1d_array(attr: &Attribute, new_value: &str) -> Result<(), AppErro
         })
         .collect::<Result<Vec<_>, _>>()?;
 
-    attr.write(Array1::from(parsed).view())
+    attr.write_raw(&parsed)
         .map_err(|e| AppError::EditError(format!("Failed to write attribute: {}", e)))
 }
 
-fn write_unicode_1d_array(attr: &Attribute, new_value: &str) -> Result<(), AppError> {
-    let parsed = parse_1d_lines(new_value, expected_1d_len(attr))?
-        .into_iter()
+fn write_unicode_array(attr: &Attribute, values: &[&str]) -> Result<(), AppError> {
+    let parsed = values
+        .iter()
         .map(|line| {
             VarLenUnicode::from_str(line).map_err(|e| {
                 AppError::EditError(format!("Failed to convert to VarLenUnicode: {}", e))
@@ -675,7 +679,7 @@ fn write_unicode_1d_array(attr: &Attribute, new_value: &str) -> Result<(), AppEr
         })
         .collect::<Result<Vec<_>, _>>()?;
 
-    attr.write(Array1::from(parsed).view())
+    attr.write_raw(&parsed)
         .map_err(|e| AppError::EditError(format!("Failed to write attribute: {}", e)))
 }
 
@@ -710,8 +714,9 @@ pub(crate) fn parse_1d_lines(new_value: &str, expected_len: usize) -> Result<Vec
     Ok(lines)
 }
 
-fn expected_1d_len(attr: &Attribute) -> usize {
-    attr.shape().first().copied().unwrap_or(0)
+/// Number of values an array attribute holds across all of its dimensions.
+pub(crate) fn expected_value_count(attr: &Attribute) -> usize {
+    attr.shape().iter().product()
 }
 
 pub fn non_editable_scalar_error(type_desc: &TypeDescriptor) -> AppError {
@@ -754,8 +759,8 @@ mod tests {
 
     use super::opaque::{format_opaque_bytes_for_edit, parse_opaque_bytes_from_text};
     use super::{
-        create_scalar_attr_from_text, parse_1d_lines, validate_attr_edit_support,
-        AttributeCreateType,
+        create_attr_from_text, parse_1d_lines, validate_attr_edit_support, AttributeCreateType,
+        AttributeSpec,
     };
 
     fn temp_hdf5_path(name: &str) -> std::path::PathBuf {
@@ -789,15 +794,20 @@ mod tests {
     }
 
     #[test]
-    fn supports_scalar_enums_and_1d_arrays_only() {
+    fn supports_enums_and_arrays_of_any_rank() {
         let enum_desc = TypeDescriptor::Enum(hdf5_metno::types::EnumType {
             size: hdf5_metno::types::IntSize::U1,
             signed: false,
             members: vec![],
         });
-        assert!(validate_attr_edit_support(&enum_desc, 0).is_ok());
-        assert!(validate_attr_edit_support(&TypeDescriptor::Boolean, 1).is_ok());
-        assert!(validate_attr_edit_support(&TypeDescriptor::Boolean, 2).is_err());
+        assert!(validate_attr_edit_support(&enum_desc).is_ok());
+        assert!(validate_attr_edit_support(&TypeDescriptor::Boolean).is_ok());
+        assert!(
+            validate_attr_edit_support(&TypeDescriptor::VarLenArray(Box::new(
+                TypeDescriptor::Boolean
+            )))
+            .is_err()
+        );
     }
 
     #[test]
@@ -814,8 +824,13 @@ mod tests {
         let path = temp_hdf5_path("codec-create-attr");
         let file = File::create(&path).expect("failed creating temp hdf5 file");
         let root = file.as_group().expect("failed opening root as group");
-        create_scalar_attr_from_text(&root, "title", AttributeCreateType::String, "hello")
-            .expect("failed creating attribute");
+        create_attr_from_text(
+            &root,
+            "title",
+            &AttributeSpec::scalar(AttributeCreateType::String),
+            "hello",
+        )
+        .expect("failed creating attribute");
         let attr = file.attr("title").expect("failed reading created attr");
         let rendered = super::format_attr_for_edit(&attr).expect("failed formatting created attr");
         assert_eq!(rendered, "hello");
@@ -834,6 +849,10 @@ mod tests {
             AttributeCreateType::parse("text").expect("text alias"),
             AttributeCreateType::String
         );
+        assert_eq!(
+            AttributeCreateType::parse("float32").expect("numpy name"),
+            AttributeCreateType::F32
+        );
         assert!(AttributeCreateType::parse("compound").is_err());
     }
 }
@@ -264,6 +264,16 @@ mod tests {
             is_link: false,
             filename: String::new(),
             compound_projection: None,
+            storage: None,
+            virtual_sources: vec![],
+            cf_decoding: None,
+            dimension_names: None,
+            matlab: None,
+            float16: None,
+            bitfield: None,
+            time: None,
+            committed_type: None,
+            alias: None,
         }
     }
 
@@ -0,0 +1,194 @@
+use hdf5_metno::{
+    types::{FloatSize, IntSize, TypeDescriptor, VarLenArray},
+    Dataset, Hyperslab, Selection, SliceOrIndex,
+};
+use ndarray::{Array, Array1, Array2, Dimension, Ix1, Ix2, IxDyn};
+
+use crate::error::AppError;
+
+/// Values listed in a ragged matrix cell before it is cut off with `…`.
+const RAGGED_CELL_VALUES: usize = 32;
+
+/// Variable-length arrays of numbers, such as per-event hit lists.
+pub fn is_ragged_numeric_type(type_desc: &TypeDescriptor) -> bool {
+    matches!(
+        type_desc,
+        TypeDescriptor::VarLenArray(inner)
+            if matches!(
+                inner.as_ref(),
+                TypeDescriptor::Integer(_)
+                    | TypeDescriptor::Unsigned(_)
+                    | TypeDescriptor::Float(_)
+                    | TypeDescriptor::Boolean
+            )
+    )
+}
+
+/// Elements of a ragged selection, each widened to f64.
+fn read_ragged_elements<D: Dimension>(
+    dataset: &Dataset,
+    selection: Selection,
+) -> Result<Array<Vec<f64>, D>, AppError> {
+    let type_desc = dataset.dtype()?.to_descriptor()?;
+    let TypeDescriptor::VarLenArray(inner) = &type_desc else {
+        return Err(AppError::DrawingError(format!(
+            "{type_desc} is not a variable-length array"
+        )));
+    };
+    macro_rules! read {
+        ($t:ty) => {
+            dataset
+                .read_slice::<VarLenArray<$t>, _, D>(selection)?
+                .mapv(|element| element.iter().map(|value| *value as f64).collect())
+        };
+    }
+    Ok(match inner.as_ref() {
+        TypeDescriptor::Integer(IntSize::U1) => read!(i8),
+        TypeDescriptor::Integer(IntSize::U2) => read!(i16),
+        TypeDescriptor::Integer(IntSize::U4) => read!(i32),
+        TypeDescriptor::Integer(IntSize::U8) => read!(i64),
+        TypeDescriptor::Unsigned(IntSize::U1) => read!(u8),
+        TypeDescriptor::Unsigned(IntSize::U2) => read!(u16),
+        TypeDescriptor::Unsigned(IntSize::U4) => read!(u32),
+        TypeDescriptor::Unsigned(IntSize::U8) => read!(u64),
+        TypeDescriptor::Float(FloatSize::U4) => read!(f32),
+        TypeDescriptor::Float(FloatSize::U8) => read!(f64),
+        TypeDescriptor::Boolean => dataset
+            .read_slice::<VarLenArray<bool>, _, D>(selection)?
+            .mapv(|element| {
+                element
+                    .iter()
+                    .map(|value| f64::from(*value as u8))
+                    .collect()
+            }),
+        _ => {
+            return Err(AppError::DrawingError(format!(
+                "{type_desc} is not a numeric variable-length array"
+            )))
+        }
+    })
+}
+
+/// One element of a ragged dataset, addressed by an index per dimension.
+pub fn read_ragged_element(dataset: &Dataset, indexes: &[usize]) -> Result<Vec<f64>, AppError> {
+    let shape = dataset.shape();
+    if indexes.len() != shape.len() {
+        return Err(AppError::DrawingError(format!(
+            "Element index {indexes:?} does not match the dataset rank {}",
+            shape.len()
+        )));
+    }
+    if let Some((dim, (index, len))) = indexes
+        .iter()
+        .zip(&shape)
+        .enumerate()
+        .find(|(_, (index, len))| index >= len)
+    {
+        return Err(AppError::DrawingError(format!(
+            "Element index {index} is out of bounds for dim {dim} with length {len}"
+        )));
+    }
+    let selection = if shape.is_empty() {
+        Selection::All
+    } else {
+        Selection::Hyperslab(Hyperslab::from(
+            indexes
+                .iter()
+                .map(|index| SliceOrIndex::Index(*index))
+                .collect::<Vec<_>>(),
+        ))
+    };
+    read_ragged_elements::<IxDyn>(dataset, selection)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| AppError::DrawingError("Ragged element is unavailable".to_string()))
+}
+
+/// `(3) [1.5, 2, 4]`: the element length, then its values.
+pub fn format_ragged_element(values: &[f64]) -> String {
+    let mut rendered = values
+        .iter()
+        .take(RAGGED_CELL_VALUES)
+        .map(f64::to_string)
+        .collect::<Vec<_>>();
+    if values.len() > RAGGED_CELL_VALUES {
+        rendered.push("…".to_string());
+    }
+    format!("({}) [{}]", values.len(), rendered.join(", "))
+}
+
+pub fn read_ragged_matrix_values(
+    dataset: &Dataset,
+    selection: Selection,
+) -> Result<Array1<String>, AppError> {
+    read_ragged_elements::<Ix1>(dataset, selection)
+        .map(|elements| elements.mapv(|element| format_ragged_element(&element)))
+}
+
+pub fn read_ragged_matrix_table(
+    dataset: &Dataset,
+    selection: Selection,
+) -> Result<Array2<String>, AppError> {
+    read_ragged_elements::<Ix2>(dataset, selection)
+        .map(|elements| elements.mapv(|element| format_ragged_element(&element)))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use hdf5_metno::types::{IntSize, TypeDescriptor, VarLenArray};
+
+    use super::{format_ragged_element, is_ragged_numeric_type, read_ragged_element};
+
+    #[test]
+    fn formats_ragged_elements_with_their_length() {
+        assert_eq!(format_ragged_element(&[]), "(0) []");
+        assert_eq!(format_ragged_element(&[1.5, 2.0, -4.0]), "(3) [1.5, 2, -4]");
+        let long = (0..40).map(f64::from).collect::<Vec<_>>();
+        let rendered = format_ragged_element(&long);
+        assert!(rendered.starts_with("(40) [0, 1, "));
+        assert!(rendered.ends_with(", 31, …]"));
+    }
+
+    #[test]
+    fn recognizes_numeric_variable_length_arrays() {
+        let vlen = |inner| TypeDescriptor::VarLenArray(Box::new(inner));
+        assert!(is_ragged_numeric_type(&vlen(TypeDescriptor::Unsigned(
+            IntSize::U4
+        ))));
+        assert!(is_ragged_numeric_type(&vlen(TypeDescriptor::Boolean)));
+        assert!(!is_ragged_numeric_type(&vlen(
+            TypeDescriptor::VarLenUnicode
+        )));
+        assert!(!is_ragged_numeric_type(&TypeDescriptor::Unsigned(
+            IntSize::U4
+        )));
+    }
+
+    #[test]
+    fn reads_single_ragged_elements_as_f64() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let events = [
+            VarLenArray::from_slice(&[1.5_f32, 2.5]),
+            VarLenArray::from_slice(&[]),
+            VarLenArray::from_slice(&[-1.0_f32, 0.0, 1.0]),
+        ];
+        let dataset = file
+            .new_dataset_builder()
+            .with_data(&events)
+            .create("events")
+            .expect("failed to create dataset");
+
+        assert_eq!(
+            read_ragged_element(&dataset, &[2]).expect("read element"),
+            vec![-1.0, 0.0, 1.0]
+        );
+        assert!(read_ragged_element(&dataset, &[1])
+            .expect("read empty element")
+            .is_empty());
+        assert!(read_ragged_element(&dataset, &[3]).is_err());
+    }
+}
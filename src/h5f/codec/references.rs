@@ -0,0 +1,511 @@
+use std::{ffi::CStr, fmt, os::raw::c_char, ptr};
+
+use hdf5_metno::{
+    types::{Reference, TypeDescriptor},
+    Attribute, Dataset, Hyperslab, Selection, SliceOrIndex,
+};
+use hdf5_metno_sys::{
+    h5::hsize_t,
+    h5i::hid_t,
+    h5p::H5P_DEFAULT,
+    h5r::{
+        hdset_reg_ref_t, hobj_ref_t, H5R_ref_t, H5Rdestroy, H5Rget_attr_name, H5Rget_name,
+        H5Rget_obj_name, H5Rget_region, H5Rget_type, H5Ropen_region, H5R_ATTR, H5R_BADTYPE,
+        H5R_DATASET_REGION1, H5R_DATASET_REGION2, H5R_OBJECT1, H5R_OBJECT2,
+    },
+    h5s::{
+        H5Sclose, H5Sget_select_bounds, H5Sget_select_elem_npoints, H5Sget_select_hyper_nblocks,
+        H5Sget_select_type, H5Sget_simple_extent_ndims, H5S_SEL_ALL, H5S_SEL_HYPERSLABS,
+        H5S_SEL_POINTS,
+    },
+};
+use ndarray::{Array1, Array2};
+
+use crate::error::AppError;
+
+use super::{
+    super::compound::read_selected_values_bytes, dataset::bounded_preview_selection,
+    read_attr_memory_bytes,
+};
+
+const REFERENCE_PREVIEW_ELEMENTS: usize = 64;
+
+/// One element's target; `None` is a null reference.
+type ResolvedReference = Result<Option<ReferenceTarget>, AppError>;
+
+/// Bounding box of a referenced dataset region, inclusive on both ends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatasetRegionTarget {
+    pub path: String,
+    pub start: Vec<usize>,
+    pub end: Vec<usize>,
+    /// The region has more than one block or point, so the bounds cover extra elements.
+    pub approximate: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReferenceTarget {
+    Object {
+        path: String,
+        attr_name: Option<String>,
+    },
+    DatasetRegion(DatasetRegionTarget),
+}
+
+impl fmt::Display for ReferenceTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReferenceTarget::Object {
+                path,
+                attr_name: None,
+            } => write!(f, "{path}"),
+            ReferenceTarget::Object {
+                path,
+                attr_name: Some(attr_name),
+            } => write!(f, "{path}@{attr_name}"),
+            ReferenceTarget::DatasetRegion(region) => {
+                let bounds = region
+                    .start
+                    .iter()
+                    .zip(&region.end)
+                    .map(|(start, end)| format!("{start}:{}", end + 1))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "{}[{bounds}]", region.path)?;
+                if region.approximate {
+                    write!(f, " (bounding box)")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn read_hdf5_name(
+    reader: impl Fn(*mut c_char, usize) -> isize,
+    context: &str,
+) -> Result<String, AppError> {
+    let len = reader(ptr::null_mut(), 0);
+    if len < 0 {
+        return Err(AppError::EditError(format!(
+            "Failed to query {context} length"
+        )));
+    }
+    let mut buffer = vec![0 as c_char; len as usize + 1];
+    let written = reader(buffer.as_mut_ptr(), buffer.len());
+    if written < 0 {
+        return Err(AppError::EditError(format!("Failed to read {context}")));
+    }
+    Ok(unsafe { CStr::from_ptr(buffer.as_ptr()) }
+        .to_string_lossy()
+        .into_owned())
+}
+
+fn read_region_bounds(space_id: hid_t) -> Result<(Vec<usize>, Vec<usize>, bool), AppError> {
+    let ndims = unsafe { H5Sget_simple_extent_ndims(space_id) };
+    if ndims < 0 {
+        return Err(AppError::EditError(
+            "Failed to inspect referenced region".to_string(),
+        ));
+    }
+    let rank = ndims as usize;
+    let mut start = vec![0 as hsize_t; rank];
+    let mut end = vec![0 as hsize_t; rank];
+    if unsafe { H5Sget_select_bounds(space_id, start.as_mut_ptr(), end.as_mut_ptr()) } < 0 {
+        return Err(AppError::EditError(
+            "Failed to inspect referenced region bounds".to_string(),
+        ));
+    }
+
+    let selection_type = unsafe { H5Sget_select_type(space_id) };
+    let approximate = match selection_type {
+        H5S_SEL_POINTS => (unsafe { H5Sget_select_elem_npoints(space_id) }) > 1,
+        H5S_SEL_HYPERSLABS => (unsafe { H5Sget_select_hyper_nblocks(space_id) }) > 1,
+        H5S_SEL_ALL => false,
+        unsupported => {
+            return Err(AppError::EditError(format!(
+                "Unsupported referenced region selection type: {unsupported:?}"
+            )))
+        }
+    };
+
+    Ok((
+        start.into_iter().map(|idx| idx as usize).collect(),
+        end.into_iter().map(|idx| idx as usize).collect(),
+        approximate,
+    ))
+}
+
+fn read_region_target_from_space(
+    path: String,
+    space_id: hid_t,
+) -> Result<ReferenceTarget, AppError> {
+    let result = read_region_bounds(space_id);
+    unsafe {
+        H5Sclose(space_id);
+    }
+    let (start, end, approximate) = result?;
+    Ok(ReferenceTarget::DatasetRegion(DatasetRegionTarget {
+        path,
+        start,
+        end,
+        approximate,
+    }))
+}
+
+fn resolve_std_reference(reference: &H5R_ref_t) -> Result<Option<ReferenceTarget>, AppError> {
+    let object_path = |context| {
+        read_hdf5_name(
+            |name, size| unsafe { H5Rget_obj_name(reference, H5P_DEFAULT, name, size) as isize },
+            context,
+        )
+    };
+    match unsafe { H5Rget_type(reference) } {
+        H5R_BADTYPE => Ok(None),
+        H5R_OBJECT2 => Ok(Some(ReferenceTarget::Object {
+            path: object_path("reference target path")?,
+            attr_name: None,
+        })),
+        H5R_DATASET_REGION2 => {
+            let path = object_path("reference target path")?;
+            let space_id = unsafe { H5Ropen_region(reference, H5P_DEFAULT, H5P_DEFAULT) };
+            if space_id < 0 {
+                return Err(AppError::EditError(
+                    "Failed to open referenced dataset region".to_string(),
+                ));
+            }
+            read_region_target_from_space(path, space_id).map(Some)
+        }
+        H5R_ATTR => Ok(Some(ReferenceTarget::Object {
+            path: object_path("attribute owner path")?,
+            attr_name: Some(read_hdf5_name(
+                |name, size| unsafe { H5Rget_attr_name(reference, name, size) as isize },
+                "attribute target name",
+            )?),
+        })),
+        _ => Err(AppError::EditError(
+            "Unsupported reference target type".to_string(),
+        )),
+    }
+}
+
+/// Size in memory of one reference of the given kind.
+fn reference_size(kind: &Reference) -> usize {
+    match kind {
+        Reference::Object => std::mem::size_of::<hobj_ref_t>(),
+        Reference::Region => std::mem::size_of::<hdset_reg_ref_t>(),
+        Reference::Std => std::mem::size_of::<H5R_ref_t>(),
+    }
+}
+
+/// Resolves one reference as read into memory; `None` is a null reference.
+fn resolve_reference_bytes(file_id: hid_t, kind: &Reference, bytes: &[u8]) -> ResolvedReference {
+    if bytes.len() != reference_size(kind) {
+        return Err(AppError::EditError(format!(
+            "Expected a {}-byte reference, got {} bytes",
+            reference_size(kind),
+            bytes.len()
+        )));
+    }
+    match kind {
+        Reference::Object => {
+            let mut address = [0_u8; std::mem::size_of::<hobj_ref_t>()];
+            address.copy_from_slice(bytes);
+            let address = hobj_ref_t::from_ne_bytes(address);
+            if address == 0 || address == hobj_ref_t::MAX {
+                return Ok(None);
+            }
+            let reference = ptr::from_ref(&address).cast();
+            Ok(Some(ReferenceTarget::Object {
+                path: read_hdf5_name(
+                    |name, size| unsafe {
+                        H5Rget_name(file_id, H5R_OBJECT1, reference, name, size) as isize
+                    },
+                    "reference target path",
+                )?,
+                attr_name: None,
+            }))
+        }
+        Reference::Region => {
+            if bytes.iter().all(|byte| *byte == 0) {
+                return Ok(None);
+            }
+            let reference = bytes.as_ptr().cast();
+            let path = read_hdf5_name(
+                |name, size| unsafe {
+                    H5Rget_name(file_id, H5R_DATASET_REGION1, reference, name, size) as isize
+                },
+                "reference target path",
+            )?;
+            let space_id = unsafe { H5Rget_region(file_id, H5R_DATASET_REGION1, reference) };
+            if space_id < 0 {
+                return Err(AppError::EditError(
+                    "Failed to open referenced dataset region".to_string(),
+                ));
+            }
+            read_region_target_from_space(path, space_id).map(Some)
+        }
+        Reference::Std => {
+            let mut reference = H5R_ref_t::default();
+            // The byte buffer is unaligned; copy into a properly aligned reference first.
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    bytes.as_ptr(),
+                    ptr::from_mut(&mut reference).cast::<u8>(),
+                    bytes.len(),
+                );
+            }
+            let result = resolve_std_reference(&reference);
+            unsafe {
+                H5Rdestroy(&mut reference);
+            }
+            result
+        }
+    }
+}
+
+fn reference_kind(type_desc: &TypeDescriptor) -> Result<&Reference, AppError> {
+    match type_desc {
+        TypeDescriptor::Reference(kind) => Ok(kind),
+        other => Err(AppError::DrawingError(format!(
+            "{other} is not a reference type"
+        ))),
+    }
+}
+
+/// Target of a reference attribute holding exactly one reference.
+pub fn read_reference_attr_target(attr: &Attribute) -> Result<Option<ReferenceTarget>, AppError> {
+    let type_desc = attr.dtype()?.to_descriptor()?;
+    let kind = reference_kind(&type_desc)?;
+    if attr.size() != 1 {
+        return Err(AppError::EditError(
+            "Reference navigation requires a single target".to_string(),
+        ));
+    }
+    let file = attr.file()?;
+    resolve_reference_bytes(file.id(), kind, &read_attr_memory_bytes(attr)?)
+}
+
+fn read_reference_targets(
+    dataset: &Dataset,
+    selection: Selection,
+) -> Result<(Vec<ResolvedReference>, Vec<usize>), AppError> {
+    let type_desc = dataset.dtype()?.to_descriptor()?;
+    let kind = reference_kind(&type_desc)?;
+    let item_size = dataset.dtype()?.size();
+    if item_size != reference_size(kind) {
+        return Err(AppError::DrawingError(format!(
+            "Unexpected {item_size}-byte {type_desc} in memory"
+        )));
+    }
+    let file = dataset.file()?;
+    let (bytes, out_shape) = read_selected_values_bytes(dataset, selection)?;
+    let targets = bytes
+        .chunks_exact(item_size)
+        .map(|reference| resolve_reference_bytes(file.id(), kind, reference))
+        .collect();
+    Ok((targets, out_shape))
+}
+
+/// Matrix cell text: the resolved target, `<null>`, or why it did not resolve.
+fn format_reference_cell(target: ResolvedReference) -> String {
+    match target {
+        Ok(Some(target)) => target.to_string(),
+        Ok(None) => "<null>".to_string(),
+        Err(error) => format!("<unresolved: {error}>"),
+    }
+}
+
+/// Target of one element of a reference dataset, addressed by an index per dimension.
+pub fn read_reference_element(
+    dataset: &Dataset,
+    indexes: &[usize],
+) -> Result<Option<ReferenceTarget>, AppError> {
+    let shape = dataset.shape();
+    if indexes.len() != shape.len() {
+        return Err(AppError::DrawingError(format!(
+            "Element index {indexes:?} does not match the dataset rank {}",
+            shape.len()
+        )));
+    }
+    if let Some((dim, (index, len))) = indexes
+        .iter()
+        .zip(&shape)
+        .enumerate()
+        .find(|(_, (index, len))| index >= len)
+    {
+        return Err(AppError::DrawingError(format!(
+            "Element index {index} is out of bounds for dim {dim} with length {len}"
+        )));
+    }
+    let selection = if shape.is_empty() {
+        Selection::All
+    } else {
+        Selection::Hyperslab(Hyperslab::from(
+            indexes
+                .iter()
+                .map(|index| SliceOrIndex::Index(*index))
+                .collect::<Vec<_>>(),
+        ))
+    };
+    let (targets, _) = read_reference_targets(dataset, selection)?;
+    targets
+        .into_iter()
+        .next()
+        .ok_or_else(|| AppError::DrawingError("Reference element is unavailable".to_string()))?
+}
+
+pub fn read_reference_matrix_values(
+    dataset: &Dataset,
+    selection: Selection,
+) -> Result<Array1<String>, AppError> {
+    let (targets, _) = read_reference_targets(dataset, selection)?;
+    Ok(targets.into_iter().map(format_reference_cell).collect())
+}
+
+pub fn read_reference_matrix_table(
+    dataset: &Dataset,
+    selection: Selection,
+) -> Result<Array2<String>, AppError> {
+    let (targets, out_shape) = read_reference_targets(dataset, selection)?;
+    if out_shape.len() != 2 {
+        return Err(AppError::DrawingError(format!(
+            "Expected 2D reference selection, got shape {out_shape:?}"
+        )));
+    }
+    Array2::from_shape_vec(
+        (out_shape[0], out_shape[1]),
+        targets.into_iter().map(format_reference_cell).collect(),
+    )
+    .map_err(|err| AppError::DrawingError(format!("Failed reshaping reference matrix: {err}")))
+}
+
+/// Text preview listing resolved targets, paged like string datasets when `value_count` is set.
+pub fn read_reference_dataset_preview(
+    dataset: &Dataset,
+    value_start: usize,
+    value_count: usize,
+) -> Result<String, AppError> {
+    let shape = dataset.shape();
+    let count = shape.iter().copied().fold(1usize, usize::saturating_mul);
+    if count == 0 {
+        return Ok(format!("shape {shape:?}\n\n<empty>"));
+    }
+    if dataset.is_scalar() {
+        return read_reference_element(dataset, &[])
+            .map(|target| format_reference_cell(Ok(target)));
+    }
+
+    let paged = shape.len() == 1 && value_count > 0;
+    let (start, selection, truncated) = if paged {
+        let start = value_start.min(count);
+        let end = start.saturating_add(value_count).min(count);
+        let selection = Selection::Hyperslab(Hyperslab::from(vec![SliceOrIndex::SliceTo {
+            start,
+            step: 1,
+            end,
+            block: 1,
+        }]));
+        (start, selection, end < count)
+    } else {
+        (
+            0,
+            bounded_preview_selection(&shape, REFERENCE_PREVIEW_ELEMENTS),
+            count > REFERENCE_PREVIEW_ELEMENTS,
+        )
+    };
+    let (targets, _) = read_reference_targets(dataset, selection)?;
+
+    let mut out = if paged {
+        String::new()
+    } else {
+        format!("shape {shape:?}\n\n")
+    };
+    let lines = targets
+        .into_iter()
+        .enumerate()
+        .map(|(idx, target)| format!("[{}] {}", start + idx, format_reference_cell(target)))
+        .collect::<Vec<_>>();
+    out.push_str(&lines.join("\n"));
+    if truncated {
+        if !lines.is_empty() {
+            out.push('\n');
+        }
+        out.push_str("...");
+    }
+    Ok(out.trim_end().to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use hdf5_metno::{File, ObjectReference1};
+
+    use super::{
+        read_reference_dataset_preview, read_reference_element, read_reference_matrix_values,
+        DatasetRegionTarget, ReferenceTarget,
+    };
+
+    #[test]
+    fn formats_targets_as_paths_and_exclusive_bounds() {
+        let object = ReferenceTarget::Object {
+            path: "/raw/trace".to_string(),
+            attr_name: None,
+        };
+        assert_eq!(object.to_string(), "/raw/trace");
+        let attribute = ReferenceTarget::Object {
+            path: "/raw/trace".to_string(),
+            attr_name: Some("units".to_string()),
+        };
+        assert_eq!(attribute.to_string(), "/raw/trace@units");
+        let region = ReferenceTarget::DatasetRegion(DatasetRegionTarget {
+            path: "/raw/trace".to_string(),
+            start: vec![0, 2],
+            end: vec![9, 4],
+            approximate: true,
+        });
+        assert_eq!(region.to_string(), "/raw/trace[0:10, 2:5] (bounding box)");
+    }
+
+    #[test]
+    fn resolves_object_reference_datasets() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = File::create(temp.path()).expect("failed to create hdf5 file");
+        let group = file.create_group("runs").expect("create group");
+        group
+            .new_dataset_builder()
+            .with_data(&[1.0_f64, 2.0])
+            .create("trace")
+            .expect("create trace");
+        let refs: [ObjectReference1; 2] = [
+            file.reference("runs").expect("reference group"),
+            file.reference("runs/trace").expect("reference dataset"),
+        ];
+        let index = file
+            .new_dataset_builder()
+            .with_data(&refs)
+            .create("index")
+            .expect("create index");
+
+        assert_eq!(
+            read_reference_element(&index, &[1]).expect("resolve element"),
+            Some(ReferenceTarget::Object {
+                path: "/runs/trace".to_string(),
+                attr_name: None,
+            })
+        );
+        assert_eq!(
+            read_reference_matrix_values(&index, hdf5_metno::Selection::All)
+                .expect("resolve matrix")
+                .to_vec(),
+            vec!["/runs".to_string(), "/runs/trace".to_string()]
+        );
+        assert_eq!(
+            read_reference_dataset_preview(&index, 0, 0).expect("preview"),
+            "shape [2]\n\n[0] /runs\n[1] /runs/trace"
+        );
+        assert!(read_reference_element(&index, &[2]).is_err());
+    }
+}
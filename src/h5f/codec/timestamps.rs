@@ -0,0 +1,605 @@
+use hdf5_metno::{types::TypeDescriptor, Dataset};
+use ndarray::s;
+use time::{Date, Month, OffsetDateTime};
+
+use crate::configure;
+
+use super::read_string_attr_values;
+
+const UNITS_ATTR: &str = "units";
+/// Dataset names containing one of these are probed for epoch timestamps.
+const TIME_NAME_MARKERS: [&str; 3] = ["time", "epoch", "date"];
+/// Epoch values are only guessed when they fall between 2000 and 2100.
+const GUESS_RANGE_SECONDS: (f64, f64) = (946_684_800.0, 4_102_444_800.0);
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl TimeUnit {
+    fn parse(word: &str) -> Option<Self> {
+        Some(match word.trim().to_ascii_lowercase().as_str() {
+            "d" | "day" | "days" => Self::Days,
+            "h" | "hr" | "hrs" | "hour" | "hours" => Self::Hours,
+            "min" | "mins" | "minute" | "minutes" => Self::Minutes,
+            "s" | "sec" | "secs" | "second" | "seconds" => Self::Seconds,
+            "ms" | "msec" | "millisecond" | "milliseconds" => Self::Milliseconds,
+            "us" | "µs" | "usec" | "microsecond" | "microseconds" => Self::Microseconds,
+            "ns" | "nsec" | "nanosecond" | "nanoseconds" => Self::Nanoseconds,
+            _ => return None,
+        })
+    }
+
+    pub fn seconds(self) -> f64 {
+        match self {
+            Self::Days => SECONDS_PER_DAY,
+            Self::Hours => 3_600.0,
+            Self::Minutes => 60.0,
+            Self::Seconds => 1.0,
+            Self::Milliseconds => 1e-3,
+            Self::Microseconds => 1e-6,
+            Self::Nanoseconds => 1e-9,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Days => "days",
+            Self::Hours => "hours",
+            Self::Minutes => "minutes",
+            Self::Seconds => "seconds",
+            Self::Milliseconds => "milliseconds",
+            Self::Microseconds => "microseconds",
+            Self::Nanoseconds => "nanoseconds",
+        }
+    }
+}
+
+/// Finest calendar field shown when formatting a timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Precision {
+    Day,
+    Minute,
+    Second,
+    Milli,
+    Micro,
+    Nano,
+}
+
+impl Precision {
+    fn for_step(step_seconds: f64) -> Self {
+        let step = step_seconds.abs();
+        if step >= SECONDS_PER_DAY {
+            Self::Day
+        } else if step >= 60.0 {
+            Self::Minute
+        } else if step >= 1.0 {
+            Self::Second
+        } else if step >= 1e-3 {
+            Self::Milli
+        } else if step >= 1e-6 {
+            Self::Micro
+        } else {
+            Self::Nano
+        }
+    }
+}
+
+/// Numeric values counting `unit`s from `epoch`, shown as UTC datetimes.
+/// Detected from CF `units = "<unit> since <date>"` attributes, from
+/// time-like dataset names holding epoch-sized values, or configured per path
+/// with `h5v.data.timestamps`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeEncoding {
+    pub unit: TimeUnit,
+    /// Unix seconds of the value zero.
+    pub epoch: f64,
+}
+
+impl TimeEncoding {
+    /// Parses `"ns"`, `"seconds since 2020-01-01"` or `"ms since epoch"`. A
+    /// bare unit counts from the Unix epoch.
+    pub fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+        let (unit, epoch) = match spec.to_ascii_lowercase().find(" since ") {
+            Some(at) => (
+                &spec[..at],
+                parse_reference_time(&spec[at + " since ".len()..])?,
+            ),
+            None => (spec, 0.0),
+        };
+        Some(Self {
+            unit: TimeUnit::parse(unit)?,
+            epoch,
+        })
+    }
+
+    /// The configured encoding of `dataset`, else its CF `units`, else an
+    /// epoch unit guessed from a time-like name and the first value.
+    pub fn read(dataset: &Dataset) -> Option<Self> {
+        if !is_numeric(dataset) {
+            return None;
+        }
+        if let Some(spec) = configure::current_data_settings()
+            .timestamps
+            .get(&dataset.name())
+        {
+            return spec.as_deref().and_then(Self::parse);
+        }
+        let units = dataset
+            .attr(UNITS_ATTR)
+            .ok()
+            .and_then(|attr| read_string_attr_values(&attr).ok())
+            .and_then(|values| values.into_iter().next());
+        if let Some(encoding) = units
+            .as_deref()
+            .filter(|units| units.to_ascii_lowercase().contains(" since "))
+            .and_then(Self::parse)
+        {
+            return Some(encoding);
+        }
+        let name = dataset.name().rsplit('/').next()?.to_ascii_lowercase();
+        if !TIME_NAME_MARKERS.iter().any(|marker| name.contains(marker)) {
+            return None;
+        }
+        Self::guess_epoch_unit(first_value(dataset)?)
+    }
+
+    /// Seconds, milliseconds, microseconds or nanoseconds since the Unix
+    /// epoch, whichever puts `value` between 2000 and 2100.
+    fn guess_epoch_unit(value: f64) -> Option<Self> {
+        let (low, high) = GUESS_RANGE_SECONDS;
+        [
+            TimeUnit::Seconds,
+            TimeUnit::Milliseconds,
+            TimeUnit::Microseconds,
+            TimeUnit::Nanoseconds,
+        ]
+        .into_iter()
+        .find(|unit| (low..=high).contains(&(value * unit.seconds())))
+        .map(|unit| Self { unit, epoch: 0.0 })
+    }
+
+    pub fn unix_seconds(&self, raw: f64) -> f64 {
+        self.epoch + raw * self.unit.seconds()
+    }
+
+    pub fn raw_value(&self, unix_seconds: f64) -> f64 {
+        (unix_seconds - self.epoch) / self.unit.seconds()
+    }
+
+    /// `2024-03-05 12:34:56.250`, with as many fractional digits as the value
+    /// needs. Day counts landing on midnight show just the date.
+    pub fn format(&self, raw: f64) -> String {
+        match self.datetime(raw) {
+            Some(datetime) => self.format_exact(datetime),
+            None => format!("{raw}"),
+        }
+    }
+
+    /// [`Self::format`] for integer storage, exact to the nanosecond where a
+    /// float would round.
+    pub fn format_integer(&self, raw: i128) -> String {
+        let unit_nanos = (self.unit.seconds() * 1e9).round() as i128;
+        raw.checked_mul(unit_nanos)
+            .and_then(|nanos| nanos.checked_add((self.epoch * 1e9).round() as i128))
+            .and_then(|nanos| OffsetDateTime::from_unix_timestamp_nanos(nanos).ok())
+            .map_or_else(|| raw.to_string(), |datetime| self.format_exact(datetime))
+    }
+
+    fn format_exact(&self, datetime: OffsetDateTime) -> String {
+        let nanos = datetime.nanosecond();
+        let precision = if nanos != 0 {
+            if nanos.is_multiple_of(1_000_000) {
+                Precision::Milli
+            } else if nanos.is_multiple_of(1_000) {
+                Precision::Micro
+            } else {
+                Precision::Nano
+            }
+        } else if self.unit == TimeUnit::Days && datetime.time() == time::Time::MIDNIGHT {
+            Precision::Day
+        } else {
+            Precision::Second
+        };
+        format_datetime(datetime, precision)
+    }
+
+    /// An axis label for `raw`, precise enough to tell ticks `step` raw units
+    /// apart.
+    pub fn format_tick(&self, raw: f64, step: f64) -> String {
+        match self.datetime(raw) {
+            Some(datetime) => {
+                format_datetime(datetime, Precision::for_step(step * self.unit.seconds()))
+            }
+            None => format!("{raw}"),
+        }
+    }
+
+    /// `seconds since 1970-01-01 00:00:00`
+    pub fn describe(&self) -> String {
+        let epoch = self
+            .datetime_at(self.epoch)
+            .map(|datetime| format_datetime(datetime, Precision::Second))
+            .unwrap_or_else(|| format!("{}", self.epoch));
+        format!("{} since {epoch}", self.unit.name())
+    }
+
+    fn datetime(&self, raw: f64) -> Option<OffsetDateTime> {
+        self.datetime_at(self.unix_seconds(raw))
+    }
+
+    fn datetime_at(&self, unix_seconds: f64) -> Option<OffsetDateTime> {
+        if !unix_seconds.is_finite() {
+            return None;
+        }
+        // Round to the unit's resolution so float noise does not print as
+        // stray nanoseconds.
+        let resolution = (self.unit.seconds() * 1e9).clamp(1.0, 1e9);
+        let nanos = (unix_seconds * 1e9 / resolution).round() * resolution;
+        OffsetDateTime::from_unix_timestamp_nanos(nanos as i128).ok()
+    }
+}
+
+/// Raw timestamp storage types.
+pub trait TimestampValue: Copy {
+    fn format_timestamp(self, time: &TimeEncoding) -> String;
+}
+
+impl TimestampValue for f64 {
+    fn format_timestamp(self, time: &TimeEncoding) -> String {
+        time.format(self)
+    }
+}
+
+impl TimestampValue for i64 {
+    fn format_timestamp(self, time: &TimeEncoding) -> String {
+        time.format_integer(i128::from(self))
+    }
+}
+
+impl TimestampValue for u64 {
+    fn format_timestamp(self, time: &TimeEncoding) -> String {
+        time.format_integer(i128::from(self))
+    }
+}
+
+/// Calendar-aligned tick positions, in raw units, for an axis spanning
+/// `min..=max`, aiming for about `target` ticks.
+pub fn time_ticks(encoding: &TimeEncoding, min: f64, max: f64, target: usize) -> Vec<f64> {
+    let (start, end) = (encoding.unix_seconds(min), encoding.unix_seconds(max));
+    if !start.is_finite() || !end.is_finite() || end <= start {
+        return vec![min];
+    }
+    let rough = (end - start) / target.max(1) as f64;
+    let ticks = if rough > 20.0 * SECONDS_PER_DAY {
+        month_ticks(start, end, rough)
+    } else {
+        let step = nice_time_step(rough);
+        let mut tick = (start / step).ceil() * step;
+        let mut ticks = Vec::new();
+        while tick <= end && ticks.len() <= target * 4 {
+            ticks.push(tick);
+            tick += step;
+        }
+        ticks
+    };
+    ticks
+        .into_iter()
+        .map(|tick| encoding.raw_value(tick))
+        .collect()
+}
+
+/// Tick spacing in raw units for the same axis as [`time_ticks`].
+pub fn time_tick_step(encoding: &TimeEncoding, min: f64, max: f64, target: usize) -> f64 {
+    match time_ticks(encoding, min, max, target)[..] {
+        [first, second, ..] => second - first,
+        _ => (max - min) / target.max(1) as f64,
+    }
+}
+
+fn nice_time_step(rough: f64) -> f64 {
+    const CLOCK_STEPS: [f64; 20] = [
+        1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 900.0, 1_800.0, 3_600.0,
+        7_200.0, 10_800.0, 21_600.0, 43_200.0, 86_400.0, 172_800.0, 604_800.0,
+    ];
+    if rough < 1.0 {
+        let magnitude = 10_f64.powf(rough.log10().floor());
+        return [1.0, 2.0, 5.0, 10.0]
+            .into_iter()
+            .map(|factor| factor * magnitude)
+            .find(|step| *step >= rough)
+            .unwrap_or(magnitude * 10.0);
+    }
+    CLOCK_STEPS
+        .into_iter()
+        .find(|step| *step >= rough)
+        .unwrap_or(1_209_600.0)
+}
+
+/// Ticks on the first of every 1, 2, 3 or 6 months, or every N years.
+fn month_ticks(start: f64, end: f64, rough: f64) -> Vec<f64> {
+    let rough_months = rough / (30.44 * SECONDS_PER_DAY);
+    let step_months = [1, 2, 3, 6, 12, 24, 60, 120, 240, 600, 1_200]
+        .into_iter()
+        .find(|step| *step as f64 >= rough_months)
+        .unwrap_or(1_200);
+    let Ok(first) = OffsetDateTime::from_unix_timestamp(start.floor() as i64) else {
+        return vec![start];
+    };
+    let mut month_index = i64::from(first.year()) * 12 + i64::from(u8::from(first.month()) - 1);
+    month_index = month_index.div_euclid(step_months) * step_months;
+    let mut ticks = Vec::new();
+    while let Some(tick) = month_start(month_index) {
+        if tick > end {
+            break;
+        }
+        if tick >= start {
+            ticks.push(tick);
+        }
+        month_index += step_months;
+    }
+    ticks
+}
+
+fn month_start(month_index: i64) -> Option<f64> {
+    let year = i32::try_from(month_index.div_euclid(12)).ok()?;
+    let month = Month::try_from(u8::try_from(month_index.rem_euclid(12) + 1).ok()?).ok()?;
+    let date = Date::from_calendar_date(year, month, 1).ok()?;
+    Some(date.midnight().assume_utc().unix_timestamp() as f64)
+}
+
+/// `2020-01-01 06:30:00` for whole Unix seconds, such as HDF5 object times.
+pub fn format_unix_seconds(seconds: i64) -> String {
+    OffsetDateTime::from_unix_timestamp(seconds).map_or_else(
+        |_| seconds.to_string(),
+        |datetime| format_datetime(datetime, Precision::Second),
+    )
+}
+
+fn format_datetime(datetime: OffsetDateTime, precision: Precision) -> String {
+    let date = format!(
+        "{:04}-{:02}-{:02}",
+        datetime.year(),
+        u8::from(datetime.month()),
+        datetime.day()
+    );
+    let clock = format!("{:02}:{:02}", datetime.hour(), datetime.minute());
+    let seconds = format!("{:02}", datetime.second());
+    let nanos = datetime.nanosecond();
+    match precision {
+        Precision::Day => date,
+        Precision::Minute => format!("{date} {clock}"),
+        Precision::Second => format!("{date} {clock}:{seconds}"),
+        Precision::Milli => format!("{date} {clock}:{seconds}.{:03}", nanos / 1_000_000),
+        Precision::Micro => format!("{date} {clock}:{seconds}.{:06}", nanos / 1_000),
+        Precision::Nano => format!("{date} {clock}:{seconds}.{nanos:09}"),
+    }
+}
+
+/// Unix seconds of a CF reference time such as `1970-01-01`,
+/// `2020-1-1 00:00:00.0`, `2020-01-01T06:00:00Z` or `2020-01-01 00:00 +01:00`.
+/// `epoch`, `unix` and `posix` stand for 1970-01-01.
+fn parse_reference_time(text: &str) -> Option<f64> {
+    let text = text.trim();
+    if matches!(
+        text.to_ascii_lowercase().as_str(),
+        "epoch" | "unix" | "posix" | "unix epoch" | "the epoch"
+    ) {
+        return Some(0.0);
+    }
+    let (date, rest) = match text.find([' ', 'T', 't']) {
+        Some(at) => (&text[..at], text[at + 1..].trim()),
+        None => (text, ""),
+    };
+    let mut fields = date.splitn(3, '-');
+    let year = fields.next()?.parse::<i32>().ok()?;
+    let month = Month::try_from(fields.next()?.parse::<u8>().ok()?).ok()?;
+    let day = fields.next()?.parse::<u8>().ok()?;
+    let midnight = Date::from_calendar_date(year, month, day)
+        .ok()?
+        .midnight()
+        .assume_utc()
+        .unix_timestamp() as f64;
+
+    // Zone names (`Z`, `UTC`) are taken to mean UTC.
+    let rest = rest
+        .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+        .trim();
+    let (clock, offset) = match rest.find(['+', '-']) {
+        Some(at) => (rest[..at].trim(), parse_utc_offset(&rest[at..])?),
+        None => (rest, 0.0),
+    };
+    let mut seconds = 0.0;
+    if !clock.is_empty() {
+        let mut parts = clock.split(':');
+        let hours = parts.next()?.parse::<f64>().ok()?;
+        let minutes = parts.next().map_or(Some(0.0), |part| part.parse().ok())?;
+        let secs = parts.next().map_or(Some(0.0), |part| part.parse().ok())?;
+        seconds = hours * 3_600.0 + minutes * 60.0 + secs;
+    }
+    Some(midnight + seconds - offset)
+}
+
+/// Seconds east of UTC for `+01:00`, `-0530` or `+1`.
+fn parse_utc_offset(text: &str) -> Option<f64> {
+    let sign = if text.starts_with('-') { -1.0 } else { 1.0 };
+    let digits = text[1..].trim().replace(':', "");
+    let (hours, minutes) = match digits.len() {
+        1 | 2 => (digits.parse::<f64>().ok()?, 0.0),
+        3 | 4 => {
+            let split = digits.len() - 2;
+            (
+                digits[..split].parse::<f64>().ok()?,
+                digits[split..].parse::<f64>().ok()?,
+            )
+        }
+        _ => return None,
+    };
+    Some(sign * (hours * 3_600.0 + minutes * 60.0))
+}
+
+fn is_numeric(dataset: &Dataset) -> bool {
+    matches!(
+        dataset.dtype().and_then(|dtype| dtype.to_descriptor()),
+        Ok(TypeDescriptor::Integer(_) | TypeDescriptor::Unsigned(_) | TypeDescriptor::Float(_))
+    )
+}
+
+fn first_value(dataset: &Dataset) -> Option<f64> {
+    match dataset.ndim() {
+        0 => dataset.read_scalar::<f64>().ok(),
+        1 if dataset.size() > 0 => dataset
+            .read_slice_1d::<f64, _>(s![0..1])
+            .ok()?
+            .first()
+            .copied(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::{time_ticks, TimeEncoding, TimeUnit};
+
+    #[test]
+    fn parses_cf_units_and_bare_units() {
+        let encoding = TimeEncoding::parse("seconds since 2020-01-01").unwrap();
+        assert_eq!(encoding.unit, TimeUnit::Seconds);
+        assert_eq!(encoding.epoch, 1_577_836_800.0);
+
+        let encoding = TimeEncoding::parse("hours since 2020-1-1 06:30:00.0 UTC").unwrap();
+        assert_eq!(encoding.unit, TimeUnit::Hours);
+        assert_eq!(encoding.epoch, 1_577_836_800.0 + 6.5 * 3_600.0);
+
+        let encoding = TimeEncoding::parse("days since 2020-01-01T00:00:00+01:00").unwrap();
+        assert_eq!(encoding.epoch, 1_577_836_800.0 - 3_600.0);
+
+        assert_eq!(
+            TimeEncoding::parse("ns"),
+            Some(TimeEncoding {
+                unit: TimeUnit::Nanoseconds,
+                epoch: 0.0
+            })
+        );
+        assert_eq!(
+            TimeEncoding::parse("ms since epoch").map(|encoding| encoding.unit),
+            Some(TimeUnit::Milliseconds)
+        );
+        assert_eq!(TimeEncoding::parse("mV"), None);
+        assert_eq!(TimeEncoding::parse("seconds since yesterday"), None);
+    }
+
+    #[test]
+    fn formats_values_with_the_precision_they_need() {
+        let seconds = TimeEncoding::parse("s").unwrap();
+        assert_eq!(seconds.format(1_700_000_000.0), "2023-11-14 22:13:20");
+        assert_eq!(seconds.format(1_700_000_000.25), "2023-11-14 22:13:20.250");
+        assert_eq!(seconds.format(f64::NAN), "NaN");
+
+        let millis = TimeEncoding::parse("ms").unwrap();
+        assert_eq!(
+            millis.format(1_700_000_000_123.0),
+            "2023-11-14 22:13:20.123"
+        );
+
+        let nanos = TimeEncoding::parse("ns").unwrap();
+        assert_eq!(
+            nanos.format_integer(1_700_000_000_000_000_123),
+            "2023-11-14 22:13:20.000000123"
+        );
+        assert_eq!(
+            nanos.format_tick(1_700_000_000_000_000_000.0, 3.6e12),
+            "2023-11-14 22:13"
+        );
+
+        let days = TimeEncoding::parse("days since 2020-01-01").unwrap();
+        assert_eq!(days.format(31.0), "2020-02-01");
+        assert_eq!(days.format(31.5), "2020-02-01 12:00:00");
+        assert_eq!(days.describe(), "days since 2020-01-01 00:00:00");
+    }
+
+    #[test]
+    fn guesses_epoch_units_from_magnitude() {
+        let guess = |value: f64| TimeEncoding::guess_epoch_unit(value).map(|e| e.unit);
+        assert_eq!(guess(1.7e9), Some(TimeUnit::Seconds));
+        assert_eq!(guess(1.7e12), Some(TimeUnit::Milliseconds));
+        assert_eq!(guess(1.7e15), Some(TimeUnit::Microseconds));
+        assert_eq!(guess(1.7e18), Some(TimeUnit::Nanoseconds));
+        assert_eq!(guess(12.5), None);
+    }
+
+    #[test]
+    fn ticks_land_on_calendar_boundaries() {
+        let seconds = TimeEncoding::parse("s").unwrap();
+        // 2024-01-01 00:00:00 .. 2024-01-01 01:00:00
+        let ticks = time_ticks(&seconds, 1_704_067_200.0, 1_704_070_800.0, 5);
+        assert_eq!(ticks.len(), 5);
+        assert_eq!(ticks[1] - ticks[0], 900.0);
+        assert!(ticks.iter().all(|tick| tick % 900.0 == 0.0));
+
+        // 2024-01-15 .. 2025-01-15, quarterly ticks on the first of the month
+        let ticks = time_ticks(&seconds, 1_705_276_800.0, 1_736_899_200.0, 5);
+        let labels = ticks
+            .iter()
+            .map(|tick| seconds.format_tick(*tick, 90.0 * 86_400.0))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            vec!["2024-04-01", "2024-07-01", "2024-10-01", "2025-01-01"]
+        );
+    }
+
+    #[test]
+    fn reads_cf_units_and_time_named_datasets() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let cf = file
+            .new_dataset::<f64>()
+            .shape(3)
+            .create("t")
+            .expect("create cf dataset");
+        cf.write(&[0.0, 1.0, 2.0]).expect("write cf dataset");
+        cf.new_attr::<hdf5_metno::types::VarLenUnicode>()
+            .create("units")
+            .expect("create units")
+            .write_scalar(
+                &"minutes since 2000-01-01"
+                    .parse::<hdf5_metno::types::VarLenUnicode>()
+                    .unwrap(),
+            )
+            .expect("write units");
+        let epoch = file
+            .new_dataset::<i64>()
+            .shape(2)
+            .create("timestamp_ns")
+            .expect("create epoch dataset");
+        epoch
+            .write(&[1_700_000_000_000_000_000_i64, 1_700_000_001_000_000_000])
+            .expect("write epoch dataset");
+        let plain = file
+            .new_dataset::<f64>()
+            .shape(2)
+            .create("voltage")
+            .expect("create plain dataset");
+        plain.write(&[1.7e9, 1.7e9]).expect("write plain dataset");
+
+        let cf = TimeEncoding::read(&cf).expect("cf units");
+        assert_eq!(cf.unit, TimeUnit::Minutes);
+        assert_eq!(cf.format(90.0), "2000-01-01 01:30:00");
+        let epoch = TimeEncoding::read(&epoch).expect("epoch guess");
+        assert_eq!(epoch.unit, TimeUnit::Nanoseconds);
+        assert_eq!(TimeEncoding::read(&plain), None);
+    }
+}
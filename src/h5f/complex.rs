@@ -0,0 +1,208 @@
+use hdf5_metno::types::{CompoundType, FloatSize, TypeDescriptor};
+
+use crate::error::AppError;
+
+/// Field names of the compound types numpy/h5py, MATLAB and others use for
+/// complex numbers, compared case-insensitively.
+const COMPLEX_FIELD_NAMES: [(&str, &str); 3] = [("r", "i"), ("real", "imag"), ("re", "im")];
+
+/// A complex number decoded from a `{r, i}`-style compound element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComplexValue {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl ComplexValue {
+    pub fn magnitude(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    pub fn phase(self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    /// `a+bj`, the notation numpy prints complex numbers in.
+    pub fn format(self) -> String {
+        let sign = if self.im.is_sign_negative() { '-' } else { '+' };
+        format!("{}{sign}{}j", self.re, self.im.abs())
+    }
+}
+
+/// Real-valued view of a complex dataset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComplexPart {
+    Real,
+    Imag,
+    Magnitude,
+    Phase,
+    Decibel,
+}
+
+impl ComplexPart {
+    pub const ALL: [Self; 5] = [
+        Self::Real,
+        Self::Imag,
+        Self::Magnitude,
+        Self::Phase,
+        Self::Decibel,
+    ];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Real => "real",
+            Self::Imag => "imag",
+            Self::Magnitude => "magnitude",
+            Self::Phase => "phase",
+            Self::Decibel => "dB",
+        }
+    }
+
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Real => "real part",
+            Self::Imag => "imaginary part",
+            Self::Magnitude => "|z|",
+            Self::Phase => "arg(z) in radians",
+            Self::Decibel => "20·log10(|z|)",
+        }
+    }
+
+    pub fn project(self, value: ComplexValue) -> f64 {
+        match self {
+            Self::Real => value.re,
+            Self::Imag => value.im,
+            Self::Magnitude => value.magnitude(),
+            Self::Phase => value.phase(),
+            Self::Decibel => 20.0 * value.magnitude().log10(),
+        }
+    }
+}
+
+/// Where the real and imaginary parts sit in a complex compound element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComplexLayout {
+    pub real_offset: usize,
+    pub imag_offset: usize,
+    pub float: FloatSize,
+    /// Size of the whole compound element.
+    pub size: usize,
+}
+
+impl ComplexLayout {
+    /// Recognizes a compound of two floats of the same size named like
+    /// `{r, i}` or `{real, imag}`.
+    pub fn detect(compound: &CompoundType) -> Option<Self> {
+        let [real, imag] = compound.fields.as_slice() else {
+            return None;
+        };
+        let (TypeDescriptor::Float(real_size), TypeDescriptor::Float(imag_size)) =
+            (&real.ty, &imag.ty)
+        else {
+            return None;
+        };
+        let names = (
+            real.name.to_ascii_lowercase(),
+            imag.name.to_ascii_lowercase(),
+        );
+        let named_complex = COMPLEX_FIELD_NAMES
+            .iter()
+            .any(|(re, im)| names.0 == *re && names.1 == *im);
+        (named_complex && real_size == imag_size).then_some(Self {
+            real_offset: real.offset,
+            imag_offset: imag.offset,
+            float: *real_size,
+            size: compound.size,
+        })
+    }
+
+    pub fn type_name(&self) -> String {
+        format!("complex{}", self.float as usize * 16)
+    }
+
+    pub fn decode(&self, element: &[u8]) -> Result<ComplexValue, AppError> {
+        Ok(ComplexValue {
+            re: self.decode_float(element, self.real_offset)?,
+            im: self.decode_float(element, self.imag_offset)?,
+        })
+    }
+
+    fn decode_float(&self, element: &[u8], offset: usize) -> Result<f64, AppError> {
+        let width = self.float as usize;
+        let bytes = element.get(offset..offset + width).ok_or_else(|| {
+            AppError::DrawingError("Complex part exceeded element bounds".to_string())
+        })?;
+        Ok(match width {
+            4 => f32::from_le_bytes(bytes.try_into().unwrap_or_default()) as f64,
+            _ => f64::from_le_bytes(bytes.try_into().unwrap_or_default()),
+        })
+    }
+}
+
+/// Complex interpretation of a compound projection. `part` is `None` for the
+/// complex value itself and names the real-valued view otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComplexProjection {
+    pub layout: ComplexLayout,
+    pub part: Option<ComplexPart>,
+}
+
+impl ComplexProjection {
+    pub fn detect(type_desc: &TypeDescriptor) -> Option<Self> {
+        match type_desc {
+            TypeDescriptor::Compound(compound) => {
+                ComplexLayout::detect(compound).map(|layout| Self { layout, part: None })
+            }
+            _ => None,
+        }
+    }
+
+    /// Value type of a part, which follows the precision of the stored floats.
+    pub fn part_type(&self) -> TypeDescriptor {
+        TypeDescriptor::Float(self.layout.float)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use hdf5_metno::types::{CompoundField, CompoundType, FloatSize, TypeDescriptor};
+
+    use super::{ComplexLayout, ComplexPart, ComplexValue};
+
+    fn compound(names: (&str, &str), float: FloatSize) -> CompoundType {
+        let width = float as usize;
+        CompoundType {
+            fields: vec![
+                CompoundField::new(names.0, TypeDescriptor::Float(float), 0, 0),
+                CompoundField::new(names.1, TypeDescriptor::Float(float), width, 1),
+            ],
+            size: width * 2,
+        }
+    }
+
+    #[test]
+    fn detects_complex_compounds_by_field_names() {
+        let layout = ComplexLayout::detect(&compound(("r", "i"), FloatSize::U4)).expect("r/i");
+        assert_eq!(layout.imag_offset, 4);
+        assert_eq!(layout.type_name(), "complex64");
+        assert!(ComplexLayout::detect(&compound(("Real", "Imag"), FloatSize::U8)).is_some());
+        assert!(ComplexLayout::detect(&compound(("x", "y"), FloatSize::U8)).is_none());
+
+        let mut element = 3.0_f32.to_le_bytes().to_vec();
+        element.extend_from_slice(&(-4.0_f32).to_le_bytes());
+        let value = layout.decode(&element).expect("decode element");
+        assert_eq!(value, ComplexValue { re: 3.0, im: -4.0 });
+        assert_eq!(value.format(), "3-4j");
+    }
+
+    #[test]
+    fn projects_complex_parts() {
+        let value = ComplexValue { re: 0.0, im: 10.0 };
+        assert_eq!(ComplexPart::Magnitude.project(value), 10.0);
+        assert_eq!(ComplexPart::Decibel.project(value), 20.0);
+        assert!((ComplexPart::Phase.project(value) - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert_eq!(ComplexPart::Imag.project(value), 10.0);
+    }
+}
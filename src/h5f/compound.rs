@@ -16,7 +16,10 @@ use crate::{
     error::AppError,
 };
 
-use super::meta::{CompoundFieldProjection, DatasetMeta};
+use super::{
+    complex::{ComplexPart, ComplexProjection, ComplexValue},
+    meta::{CompoundFieldProjection, DatasetMeta},
+};
 
 unsafe extern "C" {
     fn H5Dread(
@@ -51,6 +54,14 @@ fn checked_byte_len(
 
 pub fn compound_children(meta: &DatasetMeta) -> Option<Vec<CompoundFieldProjection>> {
     let projection = meta.compound_projection.as_ref()?;
+    if meta.is_complex_value() {
+        return Some(
+            ComplexPart::ALL
+                .into_iter()
+                .filter_map(|part| projection.complex_part(part))
+                .collect(),
+        );
+    }
     let compound = projection.current_compound_type()?;
     Some(
         compound
@@ -110,7 +121,7 @@ fn read_projected_bytes(
         ));
     }
 
-    let field_size = projection.field_type.size();
+    let field_size = projection.value_size();
     let start_offset = projection.absolute_offset();
     let out_capacity = checked_byte_len(field_size, total_elems, "Projected compound field")?;
     let mut out = Vec::with_capacity(out_capacity);
@@ -149,7 +160,7 @@ fn read_projected_scalar_bytes(
         ));
     }
 
-    let field_size = projection.field_type.size();
+    let field_size = projection.value_size();
     let start = projection.absolute_offset();
     let end = start + field_size;
     if end > buffer.len() {
@@ -333,6 +344,7 @@ pub fn read_projected_selection_bytes(
     let projection = meta.compound_projection.as_ref().ok_or_else(|| {
         AppError::DrawingError("Projected bytes requested for non-compound dataset".to_string())
     })?;
+    reject_complex_edit(projection)?;
     if selection.is_none() && dataset.is_scalar() {
         return read_projected_scalar_bytes(dataset, projection);
     }
@@ -364,6 +376,7 @@ pub fn write_projected_selection_bytes(
     let projection = meta.compound_projection.as_ref().ok_or_else(|| {
         AppError::EditError("Projected write requested for non-compound dataset".to_string())
     })?;
+    reject_complex_edit(projection)?;
     let field_size = projection.field_type.size();
     if field_bytes.len() != field_size {
         return Err(AppError::EditError(format!(
@@ -385,9 +398,27 @@ pub fn write_projected_selection_bytes(
     write_selected_element_bytes(dataset, selection, &element_bytes)
 }
 
+fn reject_complex_edit(projection: &CompoundFieldProjection) -> Result<(), AppError> {
+    match projection.complex {
+        Some(_) => Err(AppError::EditError(format!(
+            "{} is a complex value and is read-only",
+            projection.virtual_path
+        ))),
+        None => Ok(()),
+    }
+}
+
 pub trait ProjectionDecode: Sized {
     fn decode(field_type: &TypeDescriptor, bytes: &[u8]) -> Result<Self, AppError>;
 
+    /// Value of a complex element, or of the `part` of it a projection selects.
+    fn from_complex(value: ComplexValue, part: Option<ComplexPart>) -> Result<Self, AppError> {
+        let _ = (value, part);
+        Err(AppError::DrawingError(
+            "Complex values cannot be read as this type".to_string(),
+        ))
+    }
+
     fn decode_scalar_buffer(
         field_type: &TypeDescriptor,
         bytes: &mut [u8],
@@ -421,6 +452,11 @@ pub trait ProjectionDecode: Sized {
 }
 
 impl ProjectionDecode for f64 {
+    /// Complex values are charted by their magnitude unless a part is selected.
+    fn from_complex(value: ComplexValue, part: Option<ComplexPart>) -> Result<Self, AppError> {
+        Ok(part.unwrap_or(ComplexPart::Magnitude).project(value))
+    }
+
     fn decode(field_type: &TypeDescriptor, bytes: &[u8]) -> Result<Self, AppError> {
         match field_type {
             TypeDescriptor::Float(FloatSize::U4) => Ok(f32::from_le_bytes(to_array(bytes)?) as f64),
@@ -449,6 +485,13 @@ impl ProjectionDecode for u64 {
 }
 
 impl ProjectionDecode for String {
+    fn from_complex(value: ComplexValue, part: Option<ComplexPart>) -> Result<Self, AppError> {
+        Ok(match part {
+            Some(part) => part.project(value).to_string(),
+            None => value.format(),
+        })
+    }
+
     fn decode(field_type: &TypeDescriptor, bytes: &[u8]) -> Result<Self, AppError> {
         match field_type {
             TypeDescriptor::FixedArray(inner, size) => {
@@ -493,7 +536,33 @@ pub fn read_projected_scalar<T: ProjectionDecode>(
         AppError::DrawingError("Projected scalar requested for non-compound dataset".to_string())
     })?;
     let mut bytes = read_projected_scalar_bytes(dataset, projection)?;
-    T::decode_scalar_buffer(&projection.field_type, &mut bytes)
+    match projection.complex {
+        Some(complex) => decode_complex_values(complex, &bytes)?
+            .pop()
+            .ok_or_else(|| AppError::DrawingError("Complex scalar was empty".to_string())),
+        None => T::decode_scalar_buffer(&projection.field_type, &mut bytes),
+    }
+}
+
+fn decode_complex_values<T: ProjectionDecode>(
+    complex: ComplexProjection,
+    bytes: &[u8],
+) -> Result<Vec<T>, AppError> {
+    bytes
+        .chunks_exact(complex.layout.size)
+        .map(|element| T::from_complex(complex.layout.decode(element)?, complex.part))
+        .collect()
+}
+
+fn decode_projected_values<T: ProjectionDecode>(
+    projection: &CompoundFieldProjection,
+    out_shape: &[usize],
+    bytes: &mut [u8],
+) -> Result<Vec<T>, AppError> {
+    match projection.complex {
+        Some(complex) => decode_complex_values(complex, bytes),
+        None => T::decode_value_buffer(&projection.field_type, out_shape, bytes),
+    }
 }
 
 pub fn read_projected_values_1d<T: ProjectionDecode>(
@@ -511,7 +580,7 @@ pub fn read_projected_values_1d<T: ProjectionDecode>(
             out_shape
         )));
     }
-    let values = T::decode_value_buffer(&projection.field_type, &out_shape, &mut bytes)?;
+    let values = decode_projected_values::<T>(projection, &out_shape, &mut bytes)?;
     Ok(Array1::from(values))
 }
 
@@ -530,7 +599,7 @@ pub fn read_projected_values_2d<T: ProjectionDecode>(
             out_shape
         )));
     }
-    let values = T::decode_value_buffer(&projection.field_type, &out_shape, &mut bytes)?;
+    let values = decode_projected_values::<T>(projection, &out_shape, &mut bytes)?;
     Array2::from_shape_vec((out_shape[0], out_shape[1]), values).map_err(|e| {
         AppError::DrawingError(format!("Failed shaping projected 2D field values: {e}"))
     })
@@ -711,9 +780,11 @@ pub fn root_compound_projection(
     dataset_path: &str,
     compound_type: CompoundType,
 ) -> CompoundFieldProjection {
+    let field_type = TypeDescriptor::Compound(compound_type);
     CompoundFieldProjection {
         field_path: vec![],
-        field_type: TypeDescriptor::Compound(compound_type),
+        complex: ComplexProjection::detect(&field_type),
+        field_type,
         virtual_path: dataset_path.to_string(),
     }
 }
@@ -757,7 +828,18 @@ mod tests {
                 field_path: vec![],
                 field_type: TypeDescriptor::Float(super::FloatSize::U8),
                 virtual_path: "/values/value".to_string(),
+                complex: None,
             }),
+            storage: None,
+            virtual_sources: vec![],
+            cf_decoding: None,
+            dimension_names: None,
+            matlab: None,
+            float16: None,
+            bitfield: None,
+            time: None,
+            committed_type: None,
+            alias: None,
         }
     }
 
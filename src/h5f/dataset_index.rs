@@ -0,0 +1,97 @@
+use hdf5_metno::{types::TypeDescriptor, Dataset};
+
+use crate::ui::render::{is_image, sprint_typedescriptor};
+
+use super::{BitfieldType, ComplexProjection, Float16Type, ImageType};
+
+/// Kind of element a dataset holds, as metadata queries name it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeClass {
+    Integer,
+    Unsigned,
+    Float,
+    Complex,
+    Boolean,
+    Enum,
+    String,
+    Compound,
+    Reference,
+    Array,
+    /// Types h5v cannot describe.
+    Opaque,
+}
+
+impl TypeClass {
+    fn of(type_descriptor: &TypeDescriptor) -> Self {
+        match type_descriptor {
+            TypeDescriptor::Integer(_) => Self::Integer,
+            TypeDescriptor::Unsigned(_) => Self::Unsigned,
+            TypeDescriptor::Float(_) => Self::Float,
+            TypeDescriptor::Boolean => Self::Boolean,
+            TypeDescriptor::Enum(_) => Self::Enum,
+            TypeDescriptor::Compound(_) => Self::Compound,
+            TypeDescriptor::FixedArray(..) | TypeDescriptor::VarLenArray(_) => Self::Array,
+            TypeDescriptor::FixedAscii(_)
+            | TypeDescriptor::FixedUnicode(_)
+            | TypeDescriptor::VarLenAscii
+            | TypeDescriptor::VarLenUnicode => Self::String,
+            TypeDescriptor::Reference(_) => Self::Reference,
+        }
+    }
+}
+
+/// The metadata of a dataset read by [`index_objects`] that metadata
+/// queries filter on.
+///
+/// [`index_objects`]: super::index_objects
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedDataset {
+    pub path: String,
+    /// Dataspace shape; empty for scalars.
+    pub shape: Vec<usize>,
+    pub data_type: String,
+    pub class: TypeClass,
+    /// Size of the values once read.
+    pub bytes: u64,
+    /// Space allocated for the values in the file.
+    pub storage_bytes: u64,
+    pub chunked: bool,
+    pub image: Option<ImageType>,
+}
+
+impl IndexedDataset {
+    /// Reads what metadata queries filter on from the dataset's type,
+    /// dataspace and creation properties, naming the type as the tree does
+    /// without reading the rest of the tree's metadata.
+    pub fn read(path: String, dataset: &Dataset) -> Result<Self, hdf5_metno::Error> {
+        let dtype = dataset.dtype()?;
+        let descriptor = dtype.to_descriptor().ok();
+        let bitfield = BitfieldType::read(dataset);
+        let float16 = Float16Type::read(dataset);
+        let (data_type, class) = match (&bitfield, float16, &descriptor) {
+            (Some(bitfield), _, _) => (bitfield.name(), TypeClass::Unsigned),
+            (None, Some(float16), _) => (float16.name().to_string(), TypeClass::Float),
+            (None, None, Some(descriptor)) => match ComplexProjection::detect(descriptor) {
+                Some(complex) => (complex.layout.type_name(), TypeClass::Complex),
+                None => (sprint_typedescriptor(descriptor), TypeClass::of(descriptor)),
+            },
+            (None, None, None) => (format!("opaque[{} bytes]", dtype.size()), TypeClass::Opaque),
+        };
+        // The tree offers image views for plain numeric datasets only.
+        let image = match class {
+            TypeClass::Opaque | TypeClass::Compound | TypeClass::Complex => None,
+            _ if bitfield.is_some() => None,
+            _ => is_image(dataset),
+        };
+        Ok(Self {
+            path,
+            shape: dataset.shape(),
+            data_type,
+            class,
+            bytes: (dtype.size() * dataset.size()) as u64,
+            storage_bytes: dataset.storage_size(),
+            chunked: dataset.is_chunked(),
+            image,
+        })
+    }
+}
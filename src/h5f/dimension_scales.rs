@@ -0,0 +1,311 @@
+use hdf5_metno::{
+    types::{TypeDescriptor, VarLenArray, VarLenAscii, VarLenUnicode},
+    Dataset, ObjectReference1, ReferencedObject,
+};
+
+use crate::{
+    error::AppError,
+    h5f::{read_string_attr_values, TimeEncoding},
+};
+
+const DIMENSION_LIST_ATTR: &str = "DIMENSION_LIST";
+const SCALE_NAME_ATTR: &str = "NAME";
+/// Scales longer than this are ignored rather than read into memory for labels.
+const MAX_SCALE_VALUES: usize = 1 << 22;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DimensionScaleValues {
+    Numeric(Vec<f64>),
+    Text(Vec<String>),
+}
+
+/// A 1-D dataset attached to a dimension through `DIMENSION_LIST`, read in full
+/// so axis labels can be looked up without touching the file again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DimensionScale {
+    pub path: String,
+    pub label: String,
+    pub values: DimensionScaleValues,
+    /// Set for timestamp scales, labelled with datetimes.
+    pub time: Option<TimeEncoding>,
+}
+
+impl DimensionScale {
+    pub fn read(scale: &Dataset) -> Result<Self, AppError> {
+        let path = scale.name();
+        let shape = scale.shape();
+        if shape.len() != 1 {
+            return Err(AppError::FileError(format!(
+                "Dimension scale {path} must be 1-D, got shape {shape:?}"
+            )));
+        }
+        if shape[0] > MAX_SCALE_VALUES {
+            return Err(AppError::FileError(format!(
+                "Dimension scale {path} has {} values; at most {MAX_SCALE_VALUES} are read",
+                shape[0]
+            )));
+        }
+        let values = match scale.dtype()?.to_descriptor()? {
+            TypeDescriptor::Integer(_) | TypeDescriptor::Unsigned(_) | TypeDescriptor::Float(_) => {
+                DimensionScaleValues::Numeric(scale.read_1d::<f64>()?.to_vec())
+            }
+            TypeDescriptor::VarLenUnicode => DimensionScaleValues::Text(
+                scale
+                    .read_1d::<VarLenUnicode>()?
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect(),
+            ),
+            TypeDescriptor::VarLenAscii => DimensionScaleValues::Text(
+                scale
+                    .read_1d::<VarLenAscii>()?
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect(),
+            ),
+            other => {
+                return Err(AppError::FileError(format!(
+                    "Dimension scale {path} has unsupported type {other}"
+                )))
+            }
+        };
+        let label = scale
+            .attr(SCALE_NAME_ATTR)
+            .ok()
+            .and_then(|attr| read_string_attr_values(&attr).ok())
+            .and_then(|names| names.into_iter().next())
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| {
+                path.rsplit('/')
+                    .find(|segment| !segment.is_empty())
+                    .unwrap_or(path.as_str())
+                    .to_string()
+            });
+        Ok(Self {
+            path,
+            label,
+            values,
+            time: TimeEncoding::read(scale),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.values {
+            DimensionScaleValues::Numeric(values) => values.len(),
+            DimensionScaleValues::Text(values) => values.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Label for an exact index along the dimension.
+    pub fn label_at(&self, index: usize) -> Option<String> {
+        match &self.values {
+            DimensionScaleValues::Numeric(values) => {
+                values.get(index).map(|value| match &self.time {
+                    Some(time) => time.format(*value),
+                    None => format_scale_number(*value),
+                })
+            }
+            DimensionScaleValues::Text(values) => values.get(index).cloned(),
+        }
+    }
+
+    /// Label for a fractional index, as used by chart ticks. Numeric scales are
+    /// interpolated between neighbouring values; text scales use the nearest one.
+    pub fn label_at_position(&self, position: f64) -> Option<String> {
+        if !position.is_finite() || position < -0.5 {
+            return None;
+        }
+        match &self.values {
+            DimensionScaleValues::Numeric(values) => {
+                let last = values.len().checked_sub(1)?;
+                if position > last as f64 + 0.5 {
+                    return None;
+                }
+                let position = position.clamp(0.0, last as f64);
+                let lower = position.floor() as usize;
+                let upper = (lower + 1).min(last);
+                let fraction = position - lower as f64;
+                let value = values[lower] + (values[upper] - values[lower]) * fraction;
+                Some(match &self.time {
+                    // Interpolated times are shown to the sampling interval.
+                    Some(time) => time.format_tick(value, values[upper] - values[lower]),
+                    None => format_scale_number(value),
+                })
+            }
+            DimensionScaleValues::Text(_) => self.label_at(position.round().max(0.0) as usize),
+        }
+    }
+}
+
+fn format_scale_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{value:.0}")
+    } else if value.abs() >= 1e5 || value.abs() < 1e-3 {
+        format!("{value:.3e}")
+    } else {
+        let text = format!("{value:.4}");
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+/// First scale dataset attached to each dimension through `DIMENSION_LIST`, in
+/// dimension order. Dimensions without a resolvable scale are `None`.
+pub fn dimension_list_targets(dataset: &Dataset) -> Vec<Option<Dataset>> {
+    let rank = dataset.ndim();
+    let mut targets = vec![None; rank];
+    let Ok(attr) = dataset.attr(DIMENSION_LIST_ATTR) else {
+        return targets;
+    };
+    let Ok(lists) = attr.read_1d::<VarLenArray<ObjectReference1>>() else {
+        return targets;
+    };
+    let Ok(file) = dataset.file() else {
+        return targets;
+    };
+    for (dim, list) in lists.iter().enumerate().take(rank) {
+        let Some(reference) = list.iter().next() else {
+            continue;
+        };
+        if let Ok(ReferencedObject::Dataset(scale)) = file.dereference(reference) {
+            targets[dim] = Some(scale);
+        }
+    }
+    targets
+}
+
+/// Scales attached to each dimension of a dataset, in dimension order. Only the
+/// first scale of a dimension is used; dimensions without a readable scale, or
+/// whose scale is shorter than the dimension, are `None`.
+pub fn read_dimension_scales(dataset: &Dataset) -> Vec<Option<DimensionScale>> {
+    let shape = dataset.shape();
+    dimension_list_targets(dataset)
+        .into_iter()
+        .enumerate()
+        .map(|(dim, scale)| {
+            let scale = scale?;
+            match DimensionScale::read(&scale) {
+                Ok(scale) if !scale.is_empty() && scale.len() >= shape[dim] => Some(scale),
+                Ok(scale) => {
+                    tracing::debug!(
+                        "Ignoring dimension scale {} for dim {dim}: {} values for length {}",
+                        scale.path,
+                        scale.len(),
+                        shape[dim]
+                    );
+                    None
+                }
+                Err(error) => {
+                    tracing::debug!("Ignoring dimension scale for dim {dim}: {error}");
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::str::FromStr;
+
+    use hdf5_metno::{
+        types::{VarLenArray, VarLenUnicode},
+        ObjectReference1,
+    };
+
+    use super::{read_dimension_scales, DimensionScale, DimensionScaleValues, TimeEncoding};
+
+    #[test]
+    fn numeric_labels_interpolate_between_values() {
+        let scale = DimensionScale {
+            path: "/time".to_string(),
+            label: "time".to_string(),
+            values: DimensionScaleValues::Numeric(vec![0.0, 0.5, 1.0, 2.0]),
+            time: None,
+        };
+        assert_eq!(scale.label_at(1).as_deref(), Some("0.5"));
+        assert_eq!(scale.label_at_position(2.5).as_deref(), Some("1.5"));
+        assert_eq!(scale.label_at_position(3.0).as_deref(), Some("2"));
+        assert_eq!(scale.label_at_position(9.0), None);
+        assert_eq!(scale.label_at(4), None);
+    }
+
+    #[test]
+    fn time_scales_label_with_datetimes() {
+        let scale = DimensionScale {
+            path: "/time".to_string(),
+            label: "time".to_string(),
+            values: DimensionScaleValues::Numeric(vec![0.0, 60.0, 120.0]),
+            time: TimeEncoding::parse("seconds since 2024-01-01"),
+        };
+        assert_eq!(scale.label_at(1).as_deref(), Some("2024-01-01 00:01:00"));
+        assert_eq!(
+            scale.label_at_position(1.5).as_deref(),
+            Some("2024-01-01 00:01")
+        );
+    }
+
+    #[test]
+    fn reads_scales_referenced_by_dimension_list() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let dir = tempfile::tempdir().expect("failed to create temp dir");
+        let file = hdf5_metno::File::create(dir.path().join("scales.h5")).expect("create file");
+        file.new_dataset_builder()
+            .with_data(&[0.0_f64, 0.25, 0.5])
+            .create("time")
+            .expect("create time scale");
+        let channel = file
+            .new_dataset_builder()
+            .with_data(&[
+                VarLenUnicode::from_str("a").unwrap(),
+                VarLenUnicode::from_str("b").unwrap(),
+            ])
+            .create("channel")
+            .expect("create channel scale");
+        channel
+            .new_attr::<VarLenUnicode>()
+            .create("NAME")
+            .expect("create NAME")
+            .write_scalar(&VarLenUnicode::from_str("channel id").unwrap())
+            .expect("write NAME");
+        let data = file
+            .new_dataset_builder()
+            .with_data(&ndarray::arr2(&[[1_i32, 2], [3, 4], [5, 6]]))
+            .create("data")
+            .expect("create data");
+        let time: ObjectReference1 = file.reference("time").expect("reference time");
+        let channel: ObjectReference1 = file.reference("channel").expect("reference channel");
+        data.new_attr::<VarLenArray<ObjectReference1>>()
+            .shape(2)
+            .create("DIMENSION_LIST")
+            .expect("create DIMENSION_LIST")
+            .write(&[
+                VarLenArray::from_slice(&[time]),
+                VarLenArray::from_slice(&[channel]),
+            ])
+            .expect("write DIMENSION_LIST");
+
+        let scales = read_dimension_scales(&data);
+
+        assert_eq!(scales.len(), 2);
+        let time = scales[0].as_ref().expect("time scale");
+        assert_eq!(time.label, "time");
+        assert_eq!(time.label_at(2).as_deref(), Some("0.5"));
+        let channel = scales[1].as_ref().expect("channel scale");
+        assert_eq!(channel.label, "channel id");
+        assert_eq!(channel.label_at(1).as_deref(), Some("b"));
+
+        let plain = file
+            .new_dataset_builder()
+            .with_data(&[1_u8, 2])
+            .create("plain")
+            .expect("create plain");
+        assert_eq!(read_dimension_scales(&plain), vec![None]);
+    }
+}
@@ -0,0 +1,725 @@
+use hdf5_metno::{
+    types::{EnumType, TypeDescriptor, VarLenAscii, VarLenUnicode},
+    Dataset, Hyperslab, Selection, SliceOrIndex,
+};
+use ndarray::IxDyn;
+
+use crate::error::AppError;
+
+use super::compound::read_selected_values_bytes;
+
+/// Elements read from the file at a time.
+pub const FIND_BLOCK_ELEMENTS: usize = 1 << 20;
+/// Scans stop once this many hits were found.
+pub const MAX_FIND_HITS: usize = 10_000;
+const MAX_HIT_TEXT_CHARS: usize = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FindOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl FindOp {
+    fn holds(self, ordering: std::cmp::Ordering) -> bool {
+        match self {
+            Self::Eq => ordering.is_eq(),
+            Self::Ne => ordering.is_ne(),
+            Self::Lt => ordering.is_lt(),
+            Self::Le => ordering.is_le(),
+            Self::Gt => ordering.is_gt(),
+            Self::Ge => ordering.is_ge(),
+        }
+    }
+}
+
+/// What `find` looks for: `nan`, a comparison such as `> 3.5`, `<= -1` or
+/// `!= 0`, a bare number for equality, or text. Text matches string
+/// elements containing it and enum members whose name contains it; `= text`
+/// requires the whole string or member name to match. Text is compared
+/// case-sensitively.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FindQuery {
+    Nan,
+    Compare {
+        op: FindOp,
+        value: f64,
+        /// Exact value for integer elements, when the query is integral.
+        integer: Option<i128>,
+        text: String,
+    },
+    Text {
+        text: String,
+        exact: bool,
+    },
+}
+
+impl FindQuery {
+    pub fn parse(query: &str) -> Result<Self, AppError> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(AppError::InvalidCommand(
+                "find needs a value, for example `find nan`, `find > 3.5` or `find \"text\"`"
+                    .to_string(),
+            ));
+        }
+        if query.eq_ignore_ascii_case("nan") {
+            return Ok(Self::Nan);
+        }
+        let (op, rest) = [
+            (">=", FindOp::Ge),
+            ("<=", FindOp::Le),
+            ("!=", FindOp::Ne),
+            ("==", FindOp::Eq),
+            (">", FindOp::Gt),
+            ("<", FindOp::Lt),
+            ("=", FindOp::Eq),
+        ]
+        .into_iter()
+        .find_map(|(prefix, op)| query.strip_prefix(prefix).map(|rest| (Some(op), rest)))
+        .unwrap_or((None, query));
+        let rest = unquote(rest.trim());
+        match (op, rest.parse::<f64>()) {
+            (_, Ok(value)) if !value.is_nan() => Ok(Self::Compare {
+                op: op.unwrap_or(FindOp::Eq),
+                value,
+                integer: rest.parse::<i128>().ok(),
+                text: rest.to_string(),
+            }),
+            (None, _) => Ok(Self::Text {
+                text: rest.to_string(),
+                exact: false,
+            }),
+            (Some(FindOp::Eq), _) => Ok(Self::Text {
+                text: rest.to_string(),
+                exact: true,
+            }),
+            (Some(_), _) => Err(AppError::InvalidCommand(format!(
+                "find: '{rest}' is not a number to compare with"
+            ))),
+        }
+    }
+
+    fn matches_float(&self, element: f64) -> bool {
+        match self {
+            Self::Nan => element.is_nan(),
+            Self::Compare { op, value, .. } => element
+                .partial_cmp(value)
+                .is_some_and(|ordering| op.holds(ordering)),
+            Self::Text { .. } => false,
+        }
+    }
+
+    fn matches_integer(&self, element: i128) -> bool {
+        match self {
+            Self::Compare {
+                op,
+                integer: Some(integer),
+                ..
+            } => op.holds(element.cmp(integer)),
+            _ => self.matches_float(element as f64),
+        }
+    }
+
+    /// Strings compare as text; a bare number looks for its digits.
+    fn matches_text(&self, element: &str) -> bool {
+        match self {
+            Self::Text { text, exact: true } => element == text,
+            Self::Text { text, exact: false } => element.contains(text.as_str()),
+            Self::Compare {
+                op: FindOp::Eq,
+                text,
+                ..
+            } => element.contains(text.as_str()),
+            _ => false,
+        }
+    }
+
+    fn matches_enum(&self, name: Option<&str>, element: i128) -> bool {
+        match self {
+            Self::Text { text, exact: true } => name == Some(text.as_str()),
+            Self::Text { text, exact: false } => {
+                name.is_some_and(|name| name.contains(text.as_str()))
+            }
+            _ => self.matches_integer(element),
+        }
+    }
+
+    fn accepts(&self, kind: &ElementKind) -> Result<(), AppError> {
+        let reason = match (self, kind) {
+            (Self::Text { .. }, ElementKind::Number(_)) => {
+                "the dataset holds numbers; give a number, `nan`, or a comparison"
+            }
+            (Self::Nan, ElementKind::Text(_) | ElementKind::Enum(_)) => {
+                "`nan` only occurs in floating point datasets"
+            }
+            (Self::Compare { op, .. }, ElementKind::Text(_)) if *op != FindOp::Eq => {
+                "strings can only be searched for text"
+            }
+            _ => return Ok(()),
+        };
+        Err(AppError::InvalidCommand(format!("find: {reason}")))
+    }
+}
+
+fn unquote(text: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|quote| {
+            text.strip_prefix(*quote)
+                .and_then(|inner| inner.strip_suffix(*quote))
+        })
+        .unwrap_or(text)
+}
+
+/// An element that matched, with its value as listed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FindHit {
+    pub index: Vec<usize>,
+    pub value: String,
+}
+
+/// How a scan ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FindEnd {
+    /// Every match was listed.
+    Complete,
+    /// Matches past the first [`MAX_FIND_HITS`] were found and left out.
+    Truncated,
+    /// The scan stopped once [`MAX_FIND_HITS`] matches were listed, without
+    /// reading the rest of the dataset.
+    LimitReached,
+    /// The caller stopped the scan.
+    Stopped,
+}
+
+/// Progress of a scan, sent after every block.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FindBatch {
+    /// Elements scanned so far.
+    pub scanned: usize,
+    pub hits: Vec<FindHit>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberKind {
+    Signed,
+    Unsigned,
+    Float,
+    Boolean,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TextKind {
+    VarLenAscii,
+    VarLenUnicode,
+    Fixed(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ElementKind {
+    Number(NumberKind),
+    Text(TextKind),
+    Enum(EnumType),
+}
+
+impl ElementKind {
+    fn of(descriptor: &TypeDescriptor) -> Result<Self, AppError> {
+        Ok(match descriptor {
+            TypeDescriptor::Integer(_) => Self::Number(NumberKind::Signed),
+            TypeDescriptor::Unsigned(_) => Self::Number(NumberKind::Unsigned),
+            TypeDescriptor::Float(_) => Self::Number(NumberKind::Float),
+            TypeDescriptor::Boolean => Self::Number(NumberKind::Boolean),
+            TypeDescriptor::VarLenAscii => Self::Text(TextKind::VarLenAscii),
+            TypeDescriptor::VarLenUnicode => Self::Text(TextKind::VarLenUnicode),
+            TypeDescriptor::FixedAscii(size) | TypeDescriptor::FixedUnicode(size) => {
+                Self::Text(TextKind::Fixed(*size))
+            }
+            TypeDescriptor::Enum(enum_type) => Self::Enum(enum_type.clone()),
+            _ => {
+                return Err(AppError::InvalidCommand(
+                    "find supports numeric, boolean, enum and string datasets".to_string(),
+                ))
+            }
+        })
+    }
+}
+
+/// Checks that `query` can match elements of `descriptor`, before a scan is
+/// queued.
+pub fn check_find_query(query: &FindQuery, descriptor: &TypeDescriptor) -> Result<(), AppError> {
+    query.accepts(&ElementKind::of(descriptor)?)
+}
+
+/// Row-major blocks of at most `budget` elements covering `shape`, as start
+/// and count per dimension. Blocks span whole trailing dimensions and are cut
+/// along the first dimension that does not fit, at chunk boundaries when the
+/// dataset is chunked, so hits come out in index order.
+#[derive(Debug, Clone)]
+pub struct BlockPlan {
+    shape: Vec<usize>,
+    split_dim: usize,
+    step: usize,
+    cursor: Option<Vec<usize>>,
+}
+
+impl BlockPlan {
+    pub fn new(shape: &[usize], chunk_shape: Option<&[usize]>, budget: usize) -> Self {
+        let budget = budget.max(1);
+        let mut split_dim = 0;
+        let mut inner = shape.iter().product::<usize>();
+        for (dim, len) in shape.iter().enumerate() {
+            split_dim = dim;
+            inner /= (*len).max(1);
+            if inner <= budget {
+                break;
+            }
+        }
+        let mut step = (budget / inner.max(1))
+            .max(1)
+            .min(shape.get(split_dim).copied().unwrap_or(1));
+        if let Some(chunk) = chunk_shape.and_then(|chunk| chunk.get(split_dim)) {
+            if *chunk > 0 && step >= *chunk {
+                step -= step % chunk;
+            }
+        }
+        let empty = shape.contains(&0);
+        Self {
+            shape: shape.to_vec(),
+            split_dim,
+            step,
+            cursor: (!empty).then(|| vec![0; shape.len()]),
+        }
+    }
+}
+
+impl Iterator for BlockPlan {
+    type Item = (Vec<usize>, Vec<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.cursor.take()?;
+        if self.shape.is_empty() {
+            return Some((start, Vec::new()));
+        }
+        let count = self
+            .shape
+            .iter()
+            .enumerate()
+            .map(|(dim, len)| match dim.cmp(&self.split_dim) {
+                std::cmp::Ordering::Less => 1,
+                std::cmp::Ordering::Equal => self.step.min(len - start[dim]),
+                std::cmp::Ordering::Greater => *len,
+            })
+            .collect::<Vec<_>>();
+        let mut next = start.clone();
+        let mut dim = self.split_dim;
+        next[dim] += self.step;
+        loop {
+            if next[dim] < self.shape[dim] {
+                self.cursor = Some(next);
+                break;
+            }
+            if dim == 0 {
+                break;
+            }
+            next[dim] = 0;
+            dim -= 1;
+            next[dim] += 1;
+        }
+        Some((start, count))
+    }
+}
+
+fn block_selection(start: &[usize], count: &[usize]) -> Selection {
+    if start.is_empty() {
+        return Selection::All;
+    }
+    Selection::Hyperslab(Hyperslab::from(
+        start
+            .iter()
+            .zip(count)
+            .map(|(start, count)| SliceOrIndex::SliceTo {
+                start: *start,
+                step: 1,
+                end: start + count,
+                block: 1,
+            })
+            .collect::<Vec<_>>(),
+    ))
+}
+
+fn element_index(start: &[usize], count: &[usize], mut position: usize) -> Vec<usize> {
+    let mut index = start.to_vec();
+    for dim in (0..count.len()).rev() {
+        index[dim] += position % count[dim].max(1);
+        position /= count[dim].max(1);
+    }
+    index
+}
+
+fn hit_text(text: &str) -> String {
+    let mut shown = text.chars().take(MAX_HIT_TEXT_CHARS).collect::<String>();
+    if shown.len() < text.len() {
+        shown.push('…');
+    }
+    format!("\"{shown}\"")
+}
+
+fn read_block<T: hdf5_metno::H5Type>(
+    dataset: &Dataset,
+    start: &[usize],
+    count: &[usize],
+) -> Result<Vec<T>, AppError> {
+    Ok(dataset
+        .read_slice::<T, _, IxDyn>(block_selection(start, count))?
+        .into_iter()
+        .collect())
+}
+
+/// Hits of one block, as (position in the block, listed value).
+fn scan_block(
+    dataset: &Dataset,
+    kind: &ElementKind,
+    query: &FindQuery,
+    start: &[usize],
+    count: &[usize],
+) -> Result<Vec<(usize, String)>, AppError> {
+    fn collect<T>(
+        values: Vec<T>,
+        matches: impl Fn(&T) -> bool,
+        show: impl Fn(&T) -> String,
+    ) -> Vec<(usize, String)> {
+        values
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| matches(value))
+            .map(|(position, value)| (position, show(value)))
+            .collect()
+    }
+    Ok(match kind {
+        ElementKind::Number(NumberKind::Signed) => collect(
+            read_block::<i64>(dataset, start, count)?,
+            |value| query.matches_integer(*value as i128),
+            i64::to_string,
+        ),
+        ElementKind::Number(NumberKind::Unsigned) => collect(
+            read_block::<u64>(dataset, start, count)?,
+            |value| query.matches_integer(*value as i128),
+            u64::to_string,
+        ),
+        ElementKind::Number(NumberKind::Float) => collect(
+            read_block::<f64>(dataset, start, count)?,
+            |value| query.matches_float(*value),
+            f64::to_string,
+        ),
+        ElementKind::Number(NumberKind::Boolean) => collect(
+            read_block::<bool>(dataset, start, count)?,
+            |value| query.matches_integer(*value as i128),
+            bool::to_string,
+        ),
+        ElementKind::Text(TextKind::VarLenAscii) => collect(
+            read_block::<VarLenAscii>(dataset, start, count)?,
+            |value| query.matches_text(value.as_str()),
+            |value| hit_text(value.as_str()),
+        ),
+        ElementKind::Text(TextKind::VarLenUnicode) => collect(
+            read_block::<VarLenUnicode>(dataset, start, count)?,
+            |value| query.matches_text(value.as_str()),
+            |value| hit_text(value.as_str()),
+        ),
+        ElementKind::Text(TextKind::Fixed(size)) => {
+            let (bytes, _) = read_selected_values_bytes(dataset, block_selection(start, count))?;
+            collect(
+                bytes
+                    .chunks((*size).max(1))
+                    .map(fixed_string_text)
+                    .collect(),
+                |value| query.matches_text(value),
+                |value| hit_text(value),
+            )
+        }
+        ElementKind::Enum(enum_type) => {
+            let (bytes, _) = read_selected_values_bytes(dataset, block_selection(start, count))?;
+            collect(
+                bytes
+                    .chunks(enum_type.size as usize)
+                    .map(|bytes| enum_value(bytes, enum_type.signed))
+                    .collect(),
+                |value| query.matches_enum(enum_member(enum_type, *value), *value),
+                |value| match enum_member(enum_type, *value) {
+                    Some(name) => format!("{name} ({value})"),
+                    None => value.to_string(),
+                },
+            )
+        }
+    })
+}
+
+fn fixed_string_text(bytes: &[u8]) -> String {
+    let end = bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end])
+        .trim_end_matches(' ')
+        .to_string()
+}
+
+fn enum_value(bytes: &[u8], signed: bool) -> i128 {
+    let mut buffer = [0_u8; 8];
+    let len = bytes.len().min(8);
+    buffer[..len].copy_from_slice(&bytes[..len]);
+    let unsigned = u64::from_ne_bytes(buffer);
+    if !signed || len == 0 {
+        return unsigned as i128;
+    }
+    let shift = 64 - 8 * len as u32;
+    (((unsigned << shift) as i64) >> shift) as i128
+}
+
+fn enum_member(enum_type: &EnumType, value: i128) -> Option<&str> {
+    enum_type
+        .members
+        .iter()
+        .find(|member| {
+            if enum_type.signed {
+                member.value as i64 as i128 == value
+            } else {
+                member.value as i128 == value
+            }
+        })
+        .map(|member| member.name.as_str())
+}
+
+/// Scans `dataset` block by block in index order, calling `progress` after
+/// each block. Returning `false` from `progress` stops the scan. No block is
+/// read after the one in which the [`MAX_FIND_HITS`]th match is found.
+pub fn find_in_dataset(
+    dataset: &Dataset,
+    query: &FindQuery,
+    progress: &mut dyn FnMut(FindBatch) -> bool,
+) -> Result<FindEnd, AppError> {
+    let kind = ElementKind::of(&dataset.dtype()?.to_descriptor()?)?;
+    query.accepts(&kind)?;
+    let shape = dataset.shape();
+    let chunk_shape = dataset.chunk();
+    let mut scanned = 0;
+    let mut found = 0;
+    let mut blocks = BlockPlan::new(&shape, chunk_shape.as_deref(), FIND_BLOCK_ELEMENTS).peekable();
+    while let Some((start, count)) = blocks.next() {
+        let mut hits = scan_block(dataset, &kind, query, &start, &count)?
+            .into_iter()
+            .map(|(position, value)| FindHit {
+                index: element_index(&start, &count, position),
+                value,
+            })
+            .collect::<Vec<_>>();
+        let truncated = found + hits.len() > MAX_FIND_HITS;
+        hits.truncate(MAX_FIND_HITS - found);
+        found += hits.len();
+        scanned += count.iter().product::<usize>();
+        if !progress(FindBatch { scanned, hits }) {
+            return Ok(FindEnd::Stopped);
+        }
+        if truncated {
+            return Ok(FindEnd::Truncated);
+        }
+        if found == MAX_FIND_HITS && blocks.peek().is_some() {
+            return Ok(FindEnd::LimitReached);
+        }
+    }
+    Ok(FindEnd::Complete)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::{
+        element_index, find_in_dataset, BlockPlan, FindEnd, FindOp, FindQuery, FIND_BLOCK_ELEMENTS,
+        MAX_FIND_HITS,
+    };
+
+    #[test]
+    fn parses_find_queries() {
+        assert_eq!(FindQuery::parse("NaN").expect("nan"), FindQuery::Nan);
+        assert_eq!(
+            FindQuery::parse(">= 3.5").expect("comparison"),
+            FindQuery::Compare {
+                op: FindOp::Ge,
+                value: 3.5,
+                integer: None,
+                text: "3.5".to_string(),
+            }
+        );
+        assert_eq!(
+            FindQuery::parse("123456789012345678").expect("integer"),
+            FindQuery::Compare {
+                op: FindOp::Eq,
+                value: 123456789012345678.0,
+                integer: Some(123456789012345678),
+                text: "123456789012345678".to_string(),
+            }
+        );
+        assert_eq!(
+            FindQuery::parse("\"run 1\"").expect("text"),
+            FindQuery::Text {
+                text: "run 1".to_string(),
+                exact: false,
+            }
+        );
+        assert_eq!(
+            FindQuery::parse("= done").expect("exact text"),
+            FindQuery::Text {
+                text: "done".to_string(),
+                exact: true,
+            }
+        );
+        assert!(FindQuery::parse("> abc").is_err());
+        assert!(FindQuery::parse("  ").is_err());
+
+        let above = FindQuery::parse(">2").expect("comparison");
+        assert!(above.matches_float(2.5));
+        assert!(!above.matches_float(f64::NAN));
+        assert!(above.matches_integer(3));
+        let id = FindQuery::parse("9007199254740993").expect("integer");
+        assert!(id.matches_integer(9007199254740993));
+        assert!(!id.matches_integer(9007199254740992));
+        assert!(FindQuery::parse("inf")
+            .expect("infinity")
+            .matches_float(f64::INFINITY));
+    }
+
+    #[test]
+    fn blocks_cover_the_shape_in_index_order() {
+        let blocks = BlockPlan::new(&[3, 4, 5], None, 10).collect::<Vec<_>>();
+        assert_eq!(blocks.len(), 6);
+        assert_eq!(blocks[0], (vec![0, 0, 0], vec![1, 2, 5]));
+        assert_eq!(blocks[1], (vec![0, 2, 0], vec![1, 2, 5]));
+        assert_eq!(blocks[5], (vec![2, 2, 0], vec![1, 2, 5]));
+
+        let blocks = BlockPlan::new(&[10], Some(&[3]), 8).collect::<Vec<_>>();
+        assert_eq!(blocks, vec![(vec![0], vec![6]), (vec![6], vec![4]),]);
+        assert_eq!(
+            BlockPlan::new(&[4, 5], None, 100).collect::<Vec<_>>(),
+            vec![(vec![0, 0], vec![4, 5])]
+        );
+        assert_eq!(BlockPlan::new(&[0, 5], None, 100).count(), 0);
+        assert_eq!(
+            BlockPlan::new(&[], None, 100).collect::<Vec<_>>(),
+            vec![(vec![], vec![])]
+        );
+        assert_eq!(element_index(&[2, 4, 0], &[1, 2, 5], 7), vec![2, 5, 2]);
+    }
+
+    #[test]
+    fn finds_values_in_chunked_datasets() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let mut values = (0..64).map(f64::from).collect::<Vec<_>>();
+        values[5] = f64::NAN;
+        values[42] = f64::NAN;
+        let dataset = file
+            .new_dataset::<f64>()
+            .shape([8, 8])
+            .chunk([2, 4])
+            .create("data")
+            .expect("failed to create dataset");
+        dataset.write_raw(&values).expect("failed to write dataset");
+
+        let mut hits = Vec::new();
+        let end = find_in_dataset(&dataset, &FindQuery::Nan, &mut |batch| {
+            hits.extend(batch.hits);
+            true
+        })
+        .expect("scan dataset");
+        assert_eq!(end, FindEnd::Complete);
+        assert_eq!(
+            hits.iter().map(|hit| hit.index.clone()).collect::<Vec<_>>(),
+            vec![vec![0, 5], vec![5, 2]]
+        );
+
+        let mut scanned = 0;
+        find_in_dataset(
+            &dataset,
+            &FindQuery::parse("> 60").expect("comparison"),
+            &mut |batch| {
+                scanned = batch.scanned;
+                hits = batch.hits;
+                true
+            },
+        )
+        .expect("scan dataset");
+        assert_eq!(scanned, 64);
+        assert_eq!(hits.len(), 3);
+        assert_eq!(hits[0].value, "61");
+        assert!(find_in_dataset(
+            &dataset,
+            &FindQuery::parse("abc").expect("text"),
+            &mut |_| true
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn text_matches_strings_and_enum_names_with_the_same_case() {
+        let partial = FindQuery::parse("done").expect("text");
+        assert!(partial.matches_text("not done"));
+        assert!(!partial.matches_text("Done"));
+        assert!(partial.matches_enum(Some("done_ok"), 0));
+        assert!(!partial.matches_enum(Some("DONE"), 0));
+        assert!(!partial.matches_enum(None, 0));
+
+        let exact = FindQuery::parse("= done").expect("exact text");
+        assert!(exact.matches_text("done"));
+        assert!(!exact.matches_text("Done"));
+        assert!(exact.matches_enum(Some("done"), 0));
+        assert!(!exact.matches_enum(Some("Done"), 0));
+    }
+
+    #[test]
+    fn stops_at_the_hit_limit() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let query = FindQuery::parse("0").expect("number");
+        for (name, matches, filler, end) in [
+            ("exact", MAX_FIND_HITS, 1, FindEnd::Complete),
+            ("over", MAX_FIND_HITS + 1, 1, FindEnd::Truncated),
+            (
+                "limit",
+                MAX_FIND_HITS,
+                2 * FIND_BLOCK_ELEMENTS,
+                FindEnd::LimitReached,
+            ),
+        ] {
+            let mut values = vec![0_u8; matches];
+            values.resize(matches + filler, 1);
+            let dataset = file
+                .new_dataset::<u8>()
+                .shape([values.len()])
+                .create(name)
+                .expect("failed to create dataset");
+            dataset.write_raw(&values).expect("failed to write dataset");
+
+            let mut hits = 0;
+            let mut scanned = 0;
+            let found = find_in_dataset(&dataset, &query, &mut |batch| {
+                hits += batch.hits.len();
+                scanned = batch.scanned;
+                true
+            })
+            .expect("scan dataset");
+            assert_eq!(found, end, "{name}");
+            assert_eq!(hits, MAX_FIND_HITS, "{name}");
+            if end == FindEnd::LimitReached {
+                assert!(scanned < values.len(), "{name}");
+            }
+        }
+    }
+}
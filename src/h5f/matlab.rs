@@ -0,0 +1,295 @@
+use std::{fs, io::Read, path::Path};
+
+use hdf5_metno::{
+    types::{Reference, TypeDescriptor},
+    Dataset, Group, Location,
+};
+
+use crate::{configure, error::AppError};
+
+use super::read_string_attr_values;
+
+/// Start of the 116-byte text header MATLAB writes into the userblock of a
+/// v7.3 MAT-file, and at the start of older, non-HDF5 MAT-files.
+const MAT_HEADER_PREFIX: &str = "MATLAB ";
+const MAT_HEADER_LEN: usize = 116;
+const MAT_V73_VERSION: &str = "7.3";
+const CLASS_ATTR: &str = "MATLAB_class";
+const EMPTY_ATTR: &str = "MATLAB_empty";
+const CELL_CLASS: &str = "cell";
+const CHAR_CLASS: &str = "char";
+/// Class shown for struct-array fields, which hold references but no `MATLAB_class`.
+const STRUCT_FIELD_CLASS: &str = "struct field";
+/// Root groups holding cell and struct-array elements and object metadata.
+const HIDDEN_ROOT_GROUPS: [&str; 2] = ["/#refs#", "/#subsystem#"];
+/// `char` arrays larger than this are not decoded for the preview.
+const MAX_CHAR_ELEMENTS: usize = 1 << 20;
+
+/// How MATLAB sees a dataset or group of a v7.3 MAT-file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatlabVariable {
+    /// `MATLAB_class`, e.g. `double`, `char`, `logical`, `cell` or `struct`.
+    pub class: String,
+    /// Dimensions in MATLAB order, i.e. the HDF5 shape reversed. Empty for
+    /// groups, which have no shape of their own.
+    pub dims: Vec<usize>,
+    /// `MATLAB_empty` arrays store their dimensions as data instead of values.
+    pub empty: bool,
+    /// Elements are object references: cells and struct-array fields.
+    pub references: bool,
+}
+
+impl MatlabVariable {
+    pub fn is_char(&self) -> bool {
+        self.class == CHAR_CLASS && !self.references
+    }
+
+    pub fn is_cell(&self) -> bool {
+        self.class == CELL_CLASS
+    }
+
+    /// Cells and struct-array fields, whose elements become tree children.
+    pub fn is_container(&self) -> bool {
+        self.references && !self.empty
+    }
+
+    /// Size and class as MATLAB prints them, e.g. `3×4 double`.
+    pub fn describe(&self) -> String {
+        if self.dims.is_empty() {
+            return self.class.clone();
+        }
+        let dims = self
+            .dims
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("×");
+        format!("{dims} {}", self.class)
+    }
+}
+
+fn read_mat_header(path: &Path) -> Option<String> {
+    let mut header = [0_u8; MAT_HEADER_LEN];
+    let mut file = fs::File::open(path).ok()?;
+    let read = file.read(&mut header).ok()?;
+    let text = String::from_utf8_lossy(&header[..read]);
+    text.starts_with(MAT_HEADER_PREFIX)
+        .then(|| text.trim_end_matches(['\0', ' ']).to_string())
+}
+
+fn mat_header_version(header: &str) -> Option<&str> {
+    header
+        .strip_prefix(MAT_HEADER_PREFIX)?
+        .split_whitespace()
+        .next()
+}
+
+/// Whether `path` starts with the text header of a v7.3 MAT-file.
+pub fn is_matlab_file(path: &Path) -> bool {
+    read_mat_header(path)
+        .as_deref()
+        .and_then(mat_header_version)
+        .is_some_and(|version| version == MAT_V73_VERSION)
+}
+
+/// Version of a MAT-file that predates v7.3 and therefore is not HDF5, e.g. `5.0`.
+pub fn legacy_mat_file_version(path: &Path) -> Option<String> {
+    let header = read_mat_header(path)?;
+    let version = mat_header_version(&header)?;
+    (version != MAT_V73_VERSION).then(|| version.to_string())
+}
+
+/// Whether `group` belongs to a v7.3 MAT-file and the MATLAB-aware
+/// presentation is enabled.
+pub fn is_matlab_view(group: &Group) -> bool {
+    configure::current_data_settings().matlab && is_matlab_file(Path::new(&group.filename()))
+}
+
+/// `#refs#` and `#subsystem#`, which MATLAB uses for storage rather than variables.
+pub fn is_hidden_matlab_group(group: &Group) -> bool {
+    HIDDEN_ROOT_GROUPS.contains(&group.name().as_str())
+}
+
+fn matlab_class(location: &Location) -> Option<String> {
+    location
+        .attr(CLASS_ATTR)
+        .ok()
+        .and_then(|attr| read_string_attr_values(&attr).ok())
+        .and_then(|values| values.into_iter().next())
+        .map(|class| class.trim().to_string())
+        .filter(|class| !class.is_empty())
+}
+
+/// MATLAB view of a dataset, or `None` when it has no `MATLAB_class` and does
+/// not hold references.
+pub fn read_matlab_variable(dataset: &Dataset) -> Option<MatlabVariable> {
+    let references = dataset
+        .dtype()
+        .and_then(|dtype| dtype.to_descriptor())
+        .is_ok_and(|desc| matches!(desc, TypeDescriptor::Reference(Reference::Object)));
+    let class = match matlab_class(dataset) {
+        Some(class) => class,
+        None if references => STRUCT_FIELD_CLASS.to_string(),
+        None => return None,
+    };
+    let empty = dataset
+        .attr(EMPTY_ATTR)
+        .and_then(|attr| attr.read_scalar::<u8>())
+        .is_ok_and(|flag| flag != 0);
+    let dims = if empty {
+        dataset
+            .read_raw::<u64>()
+            .map(|dims| dims.into_iter().map(|dim| dim as usize).collect())
+            .unwrap_or_default()
+    } else {
+        let mut dims = dataset.shape();
+        dims.reverse();
+        dims
+    };
+    Some(MatlabVariable {
+        class,
+        dims,
+        empty,
+        references,
+    })
+}
+
+/// MATLAB view of a group, e.g. a scalar `struct` whose fields are its members.
+pub fn read_matlab_group(group: &Group) -> Option<MatlabVariable> {
+    matlab_class(group).map(|class| MatlabVariable {
+        class,
+        dims: vec![],
+        empty: false,
+        references: false,
+    })
+}
+
+/// 1-based MATLAB subscript of the `index`-th stored element, e.g. `{2,1}` for
+/// a cell or `(2,1)` for a struct-array field. HDF5 stores MATLAB arrays
+/// column-major, so the flat index runs down the first MATLAB dimension first.
+pub fn matlab_element_label(index: usize, dims: &[usize], cell: bool) -> String {
+    let mut rest = index;
+    let subscripts = dims
+        .iter()
+        .map(|len| {
+            let len = (*len).max(1);
+            let subscript = rest % len + 1;
+            rest /= len;
+            subscript.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    if cell {
+        format!("{{{subscripts}}}")
+    } else {
+        format!("({subscripts})")
+    }
+}
+
+/// Decodes a `char` array into one line per MATLAB row. MATLAB stores
+/// characters as UTF-16 code units in column-major order.
+pub fn read_matlab_char_text(
+    dataset: &Dataset,
+    matlab: &MatlabVariable,
+) -> Result<String, AppError> {
+    let total = dataset.size();
+    if total > MAX_CHAR_ELEMENTS {
+        return Err(AppError::FileError(format!(
+            "char array has {total} characters; at most {MAX_CHAR_ELEMENTS} are decoded"
+        )));
+    }
+    let units = dataset.read_raw::<u16>()?;
+    let rows = matlab.dims.first().copied().unwrap_or(1).max(1);
+    let lines = (0..rows)
+        .map(|row| {
+            let line = units
+                .iter()
+                .skip(row)
+                .step_by(rows)
+                .copied()
+                .collect::<Vec<_>>();
+            String::from_utf16_lossy(&line)
+        })
+        .collect::<Vec<_>>();
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::io::Write;
+
+    use super::{
+        is_matlab_file, legacy_mat_file_version, matlab_element_label, read_matlab_char_text,
+        read_matlab_variable, MatlabVariable,
+    };
+
+    #[test]
+    fn element_labels_follow_column_major_subscripts() {
+        let dims = [2, 3];
+        assert_eq!(matlab_element_label(0, &dims, true), "{1,1}");
+        assert_eq!(matlab_element_label(1, &dims, true), "{2,1}");
+        assert_eq!(matlab_element_label(2, &dims, true), "{1,2}");
+        assert_eq!(matlab_element_label(5, &dims, false), "(2,3)");
+    }
+
+    #[test]
+    fn recognizes_mat_file_headers() {
+        let dir = tempfile::tempdir().expect("failed to create temp dir");
+        let legacy = dir.path().join("legacy.mat");
+        let mut header = b"MATLAB 5.0 MAT-file, Platform: GLNXA64".to_vec();
+        header.resize(128, b' ');
+        std::fs::File::create(&legacy)
+            .and_then(|mut file| file.write_all(&header))
+            .expect("write legacy header");
+
+        assert_eq!(legacy_mat_file_version(&legacy).as_deref(), Some("5.0"));
+        assert!(!is_matlab_file(&legacy));
+    }
+
+    #[test]
+    fn reads_matlab_variables_in_matlab_order() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let dir = tempfile::tempdir().expect("failed to create temp dir");
+        let file = hdf5_metno::File::create(dir.path().join("vars.h5")).expect("create file");
+        let write_class = |dataset: &hdf5_metno::Dataset, class: &str| {
+            dataset
+                .new_attr::<hdf5_metno::types::VarLenAscii>()
+                .create("MATLAB_class")
+                .expect("create MATLAB_class")
+                .write_scalar(&hdf5_metno::types::VarLenAscii::from_ascii(class).unwrap())
+                .expect("write MATLAB_class");
+        };
+        let matrix = file
+            .new_dataset::<f64>()
+            .shape((4, 3))
+            .create("matrix")
+            .expect("create matrix");
+        write_class(&matrix, "double");
+        let text: Vec<u16> = "abcdef".encode_utf16().collect();
+        let chars = file
+            .new_dataset_builder()
+            .with_data(&ndarray::Array2::from_shape_vec((3, 2), text).unwrap())
+            .create("chars")
+            .expect("create chars");
+        write_class(&chars, "char");
+
+        let matrix = read_matlab_variable(&matrix).expect("matlab matrix");
+        assert_eq!(matrix.describe(), "3×4 double");
+        let chars = read_matlab_variable(&chars).expect("matlab chars");
+        assert_eq!(
+            chars,
+            MatlabVariable {
+                class: "char".to_string(),
+                dims: vec![2, 3],
+                empty: false,
+                references: false,
+            }
+        );
+        assert_eq!(
+            read_matlab_char_text(&file.dataset("chars").unwrap(), &chars).unwrap(),
+            "ace\nbdf"
+        );
+    }
+}
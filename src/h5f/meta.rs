@@ -6,7 +6,11 @@ use ratatui::{
 
 use crate::{configure, ui::render::MatrixRenderType};
 
-use super::RenderedAttributeRow;
+use super::{
+    BitfieldType, CfDecoding, ComplexPart, ComplexProjection, DatasetStorage, Float16Type,
+    MatlabVariable, ObjectAlias, RenderedAttributeRow, StorageLayout, TimeEncoding, VirtualSource,
+    VirtualSourceStatus,
+};
 
 #[derive(Debug, Clone)]
 pub enum Encoding {
@@ -18,13 +22,13 @@ pub enum Encoding {
     AsciiFixed,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum InterlaceMode {
     Pixel, // [height][width][pixel components] -> value
     Plane, // [pixel components][height][width] -> value
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ImageType {
     Jpeg,
     Png,
@@ -45,6 +49,9 @@ pub struct CompoundFieldProjection {
     pub field_path: Vec<CompoundFieldPathSegment>,
     pub field_type: TypeDescriptor,
     pub virtual_path: String,
+    /// Set when the projected value is a `{r, i}`-style complex compound, or
+    /// one of its real-valued parts.
+    pub complex: Option<ComplexProjection>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -66,6 +73,21 @@ pub struct GroupMeta {
     pub filename: String,
     pub display_name: String,
     pub preview_expr: Option<String>,
+    pub matlab: Option<MatlabVariable>,
+    pub alias: Option<ObjectAlias>,
+}
+
+/// A datatype committed to the file under a name of its own.
+#[derive(Debug, Clone)]
+pub struct DatatypeMeta {
+    pub is_link: bool,
+    pub filename: String,
+    pub display_name: String,
+    /// `None` for types h5v cannot describe, such as opaque or time types.
+    pub type_descriptor: Option<TypeDescriptor>,
+    pub data_type: String,
+    pub size: usize,
+    pub alias: Option<ObjectAlias>,
 }
 
 #[derive(Debug, Clone)]
@@ -89,16 +111,42 @@ pub struct DatasetMeta {
     pub is_link: bool,
     pub filename: String,
     pub compound_projection: Option<CompoundFieldProjection>,
+    pub storage: Option<DatasetStorage>,
+    /// Source mappings of a virtual dataset.
+    pub virtual_sources: Vec<VirtualSource>,
+    pub cf_decoding: Option<CfDecoding>,
+    /// netCDF dimension names, e.g. `[time, lat, lon]`, shown next to the tree name.
+    pub dimension_names: Option<Vec<String>>,
+    /// MATLAB class and size, for variables of a v7.3 MAT-file.
+    pub matlab: Option<MatlabVariable>,
+    /// Set for 16-bit float datasets, which are read as f64.
+    pub float16: Option<Float16Type>,
+    /// Set for bitfield datasets, shown as named bit flags.
+    pub bitfield: Option<BitfieldType>,
+    /// Set for timestamp datasets, whose values are shown as UTC datetimes.
+    pub time: Option<TimeEncoding>,
+    /// Path of the named datatype the dataset was created with.
+    pub committed_type: Option<String>,
+    pub alias: Option<ObjectAlias>,
 }
 
 impl CompoundFieldProjection {
     pub fn current_compound_type(&self) -> Option<&CompoundType> {
         match &self.field_type {
+            TypeDescriptor::Compound(_) if self.complex.is_some() => None,
             TypeDescriptor::Compound(compound) => Some(compound),
             _ => None,
         }
     }
 
+    /// Bytes read per element: the whole complex compound for complex values
+    /// and their parts, the field itself otherwise.
+    pub fn value_size(&self) -> usize {
+        self.complex
+            .map(|complex| complex.layout.size)
+            .unwrap_or_else(|| self.field_type.size())
+    }
+
     pub fn absolute_offset(&self) -> usize {
         self.field_path.iter().map(|segment| segment.offset).sum()
     }
@@ -113,8 +161,92 @@ impl CompoundFieldProjection {
             virtual_path: format!("{}/{}", self.virtual_path, field.name),
             field_path,
             field_type: field.ty.clone(),
+            complex: ComplexProjection::detect(&field.ty),
         }
     }
+
+    /// Real-valued view of a complex value. It reads the same bytes and is
+    /// typed like the stored floats.
+    pub fn complex_part(&self, part: ComplexPart) -> Option<Self> {
+        let complex = self.complex.filter(|complex| complex.part.is_none())?;
+        let mut field_path = self.field_path.clone();
+        field_path.push(CompoundFieldPathSegment {
+            name: part.label().to_string(),
+            offset: 0,
+        });
+        Some(Self {
+            virtual_path: format!("{}/{}", self.virtual_path, part.label()),
+            field_path,
+            field_type: complex.part_type(),
+            complex: Some(ComplexProjection {
+                layout: complex.layout,
+                part: Some(part),
+            }),
+        })
+    }
+}
+
+pub(super) fn property_value_span(value: String) -> Span<'static> {
+    Span::styled(
+        value,
+        Style::default()
+            .fg(configure::themed_color(|colors| {
+                colors.metadata.property_value
+            }))
+            .bold(),
+    )
+}
+
+fn property_name_line(name: String, min_first_panel: u16) -> Line<'static> {
+    let name_len = name.len();
+    let name_span = Span::styled(
+        name,
+        Style::default()
+            .fg(configure::themed_color(|colors| {
+                colors.metadata.property_name
+            }))
+            .bold(),
+    );
+    let extra_name_space = match (min_first_panel as usize).saturating_sub(name_len) {
+        0..=1 => 1,
+        extra => extra,
+    };
+    let name_helper_line = Span::styled(
+        configure::configured_symbol(|symbols| symbols.tree.horizontal_rule)
+            .repeat(extra_name_space - 1),
+        Style::default().fg(configure::themed_color(|colors| colors.tree.lines)),
+    );
+    let equals_sign = Span::styled(
+        "=",
+        Style::default().fg(configure::themed_color(|colors| colors.accent.equal_sign)),
+    );
+    Line::from(vec![name_span, name_helper_line, equals_sign])
+}
+
+pub(super) fn property_rows(
+    entries: Vec<(&'static str, Span<'static>)>,
+    min_first_panel: u16,
+) -> Vec<RenderedAttributeRow> {
+    let mut lines = vec![];
+    for (name, value) in entries {
+        let name_line = property_name_line(name.to_string(), min_first_panel);
+        let value_line = Line::from(vec![value]);
+        let empty_line = Line::from(vec![Span::raw("")]);
+        lines.push(RenderedAttributeRow::property(
+            name,
+            (name_line, value_line, empty_line),
+        ));
+    }
+    lines
+}
+
+pub fn human_bytes(size: u64) -> String {
+    match size {
+        0..1024 => format!("{} B", size),
+        1024..1048576 => format!("{:.2} KB", size as f64 / 1024.0),
+        1048576..1073741824 => format!("{:.2} MB", size as f64 / 1024.0 / 1024.0),
+        _ => format!("{:.2} GB", size as f64 / 1024.0 / 1024.0 / 1024.0),
+    }
 }
 
 impl GroupMeta {
@@ -137,42 +269,34 @@ impl GroupMeta {
             );
             data_set_attrs.push((name, external_value));
         }
+        if let Some(matlab) = &self.matlab {
+            data_set_attrs.push(("matlab", property_value_span(matlab.describe())));
+        }
+        if let Some(alias) = &self.alias {
+            data_set_attrs.push(("alias", property_value_span(alias.describe())));
+        }
 
-        let mut lines = vec![];
-        for (name, value) in data_set_attrs {
-            let name_len = name.len();
-            let name_span = Span::styled(
-                name,
-                Style::default()
-                    .fg(configure::themed_color(|colors| {
-                        colors.metadata.property_name
-                    }))
-                    .bold(),
-            );
-            let extra_name_space = match min_first_panel as usize - name_len {
-                0..=1 => 1,
-                _ => min_first_panel as usize - name_len,
-            };
-            let name_helper_line = Span::styled(
-                configure::configured_symbol(|symbols| symbols.tree.horizontal_rule)
-                    .repeat(extra_name_space - 1),
-                Style::default().fg(configure::themed_color(|colors| colors.tree.lines)),
-            );
-            let equals_sign = Span::styled(
-                "=",
-                Style::default().fg(configure::themed_color(|colors| colors.accent.equal_sign)),
-            );
-            let name_line = Line::from(vec![name_span, name_helper_line, equals_sign]);
+        property_rows(data_set_attrs, min_first_panel)
+    }
+}
 
-            let value_line = Line::from(vec![value]);
-            let empty_line = Line::from(vec![Span::raw("")]);
-            lines.push(RenderedAttributeRow::property(
-                name,
-                (name_line, value_line, empty_line),
-            ));
+impl DatatypeMeta {
+    pub fn render(&self, longest_name: u16) -> Vec<RenderedAttributeRow> {
+        let min_first_panel = match longest_name {
+            0..8 => 8,
+            8..=u16::MAX => longest_name,
+        };
+        let mut rows = vec![
+            ("type", property_value_span(self.data_type.clone())),
+            ("size", property_value_span(format!("{} bytes", self.size))),
+        ];
+        if self.is_link {
+            rows.push(("link", property_value_span(self.filename.clone())));
         }
-
-        lines
+        if let Some(alias) = &self.alias {
+            rows.push(("alias", property_value_span(alias.describe())));
+        }
+        property_rows(rows, min_first_panel)
     }
 }
 
@@ -180,6 +304,42 @@ pub static SYSTEM_PROPERTIES: [&str; 8] = [
     "type", "size", "shape", "chunk", "link", "path", "origin", "field",
 ];
 
+/// Names of every non-attribute row the metadata panel can show, which sets
+/// the width of the name column. Rows past the first eight render under
+/// their own section or only for the object they describe, and attribute
+/// lookups match attribute rows only, so attributes may share these names.
+pub static PROPERTY_ROW_NAMES: [&str; 29] = [
+    "type",
+    "size",
+    "shape",
+    "chunk",
+    "link",
+    "path",
+    "origin",
+    "field",
+    "committed",
+    "complex",
+    "decode",
+    "time",
+    "netcdf",
+    "dims",
+    "matlab",
+    "alias",
+    "layout",
+    "filters",
+    "ratio",
+    "fill",
+    "alloc",
+    "address",
+    "refs",
+    "header",
+    "index",
+    "btime",
+    "mtime",
+    "ctime",
+    "atime",
+];
+
 impl DatasetMeta {
     pub fn current_compound_type(&self) -> Option<&CompoundType> {
         self.compound_projection
@@ -201,6 +361,17 @@ impl DatasetMeta {
         self.current_compound_type().is_some()
     }
 
+    pub fn complex(&self) -> Option<&ComplexProjection> {
+        self.compound_projection
+            .as_ref()
+            .and_then(|projection| projection.complex.as_ref())
+    }
+
+    /// A complex value itself, as opposed to one of its parts.
+    pub fn is_complex_value(&self) -> bool {
+        self.complex().is_some_and(|complex| complex.part.is_none())
+    }
+
     pub fn is_compound_leaf(&self) -> bool {
         self.compound_projection.is_some() && !self.is_compound_container()
     }
@@ -214,6 +385,18 @@ impl DatasetMeta {
             .map(|compound| compound.fields.len())
     }
 
+    /// 16-bit floats in opaque storage, which HDF5 cannot convert and h5v decodes.
+    pub fn is_opaque_float16(&self) -> bool {
+        self.float16.is_some_and(|float16| float16.opaque)
+    }
+
+    /// CF decoding to apply to values read for previews, when enabled in the config.
+    pub fn active_cf_decoding(&self) -> Option<&CfDecoding> {
+        self.cf_decoding
+            .as_ref()
+            .filter(|_| configure::current_data_settings().cf_decoding)
+    }
+
     pub fn render(&self, longest_name: u16) -> Vec<RenderedAttributeRow> {
         let min_first_panel = match longest_name {
             0..8 => 8,
@@ -229,6 +412,9 @@ impl DatasetMeta {
                 .bold(),
         );
         data_set_attrs.push(("type", type_value));
+        if let Some(committed_type) = &self.committed_type {
+            data_set_attrs.push(("committed", property_value_span(committed_type.clone())));
+        }
 
         let size_value = Span::styled(
             self.size_string(),
@@ -293,47 +479,60 @@ impl DatasetMeta {
             );
             data_set_attrs.push(("field", field_value));
         }
-
-        let mut lines = vec![];
-        for (name, value) in data_set_attrs {
-            let name_len = name.len();
-            let name_span = Span::styled(
-                name,
-                Style::default()
-                    .fg(configure::themed_color(|colors| {
-                        colors.metadata.property_name
-                    }))
-                    .bold(),
-            );
-            let extra_name_space = match min_first_panel as usize - name_len {
-                0..=1 => 1,
-                _ => min_first_panel as usize - name_len,
+        if let Some(complex) = self.complex() {
+            let complex_value = match complex.part {
+                Some(part) => part.description().to_string(),
+                None => format!(
+                    "{}, charted as magnitude; expand for real, imag, phase and dB",
+                    complex.layout.type_name()
+                ),
             };
-            let name_helper_line = Span::styled(
-                configure::configured_symbol(|symbols| symbols.tree.horizontal_rule)
-                    .repeat(extra_name_space - 1),
-                Style::default().fg(configure::themed_color(|colors| colors.tree.lines)),
-            );
-            let equals_sign = Span::styled(
-                "=",
-                Style::default().fg(configure::themed_color(|colors| colors.accent.equal_sign)),
-            );
-            let name_line = Line::from(vec![name_span, name_helper_line, equals_sign]);
-
-            let value_line = Line::from(vec![value]);
-            let empty_line = Line::from(vec![Span::raw("")]);
-            lines.push(RenderedAttributeRow::property(
-                name,
-                (name_line, value_line, empty_line),
+            data_set_attrs.push(("complex", property_value_span(complex_value)));
+        }
+        if let Some(cf_decoding) = &self.cf_decoding {
+            let decode_value = if configure::current_data_settings().cf_decoding {
+                property_value_span(format!("{} (active)", cf_decoding.describe()))
+            } else {
+                Span::styled(
+                    format!("{} (off, :decode on)", cf_decoding.describe()),
+                    Style::default().fg(configure::themed_color(|colors| colors.help.muted)),
+                )
+            };
+            data_set_attrs.push(("decode", decode_value));
+        }
+        if let Some(time) = &self.time {
+            data_set_attrs.push((
+                "time",
+                property_value_span(format!("{} (UTC)", time.describe())),
             ));
         }
+        if let Some(matlab) = &self.matlab {
+            data_set_attrs.push(("matlab", property_value_span(matlab.describe())));
+        }
+        if let Some(alias) = &self.alias {
+            data_set_attrs.push(("alias", property_value_span(alias.describe())));
+        }
+
+        property_rows(data_set_attrs, min_first_panel)
+    }
 
-        lines
+    /// MATLAB variables of two or more dimensions show their shape, chunks and
+    /// element indexes in MATLAB order, the HDF5 order reversed.
+    pub fn uses_matlab_axis_order(&self) -> bool {
+        self.matlab.is_some() && self.shape.len() >= 2
+    }
+
+    fn display_order(&self, dims: &[usize]) -> Vec<usize> {
+        let mut dims = dims.to_vec();
+        if self.uses_matlab_axis_order() {
+            dims.reverse();
+        }
+        dims
     }
 
     pub fn shape_string(&self) -> String {
         let dims_str = self
-            .shape
+            .display_order(&self.shape)
             .iter()
             .map(|d| d.to_string())
             .collect::<Vec<String>>()
@@ -345,7 +544,8 @@ impl DatasetMeta {
     pub fn chunk_shape_string(&self) -> Option<String> {
         match &self.chunk_shape {
             Some(chunk_shape) => {
-                let chunk_str = chunk_shape
+                let chunk_str = self
+                    .display_order(chunk_shape)
                     .iter()
                     .map(|d| d.to_string())
                     .collect::<Vec<String>>()
@@ -363,24 +563,103 @@ impl DatasetMeta {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.total_elems == 0
+        self.total_elems == 0 || self.matlab.as_ref().is_some_and(|matlab| matlab.empty)
     }
 
     pub fn size_string(&self) -> String {
-        let size = self.total_bytes;
-        let total_storage = self.storage_required;
-        let size_str = match size {
-            0..1024 => format!("{} B", size),
-            1024..1048576 => format!("{:.2} KB", size as f64 / 1024.0),
-            1048576..1073741824 => format!("{:.2} MB", size as f64 / 1024.0 / 1024.0),
-            _ => format!("{:.2} GB", size as f64 / 1024.0 / 1024.0 / 1024.0),
+        format!(
+            "{} ({})",
+            human_bytes(self.total_bytes as u64),
+            human_bytes(self.storage_required)
+        )
+    }
+
+    /// Logical bytes per stored byte, or `None` while nothing has been written.
+    pub fn compression_ratio(&self) -> Option<f64> {
+        (self.storage_required > 0 && self.total_bytes > 0)
+            .then(|| self.total_bytes as f64 / self.storage_required as f64)
+    }
+
+    pub fn compression_ratio_string(&self) -> String {
+        match self.compression_ratio() {
+            Some(ratio) => format!(
+                "{ratio:.2}x ({} → {})",
+                human_bytes(self.total_bytes as u64),
+                human_bytes(self.storage_required)
+            ),
+            None => "unallocated".to_string(),
+        }
+    }
+
+    pub fn render_storage(&self, longest_name: u16) -> Vec<RenderedAttributeRow> {
+        let Some(storage) = &self.storage else {
+            return vec![];
         };
-        let total_storage_str = match total_storage {
-            0..1024 => format!("{} B", total_storage),
-            1024..1048576 => format!("{:.2} KB", total_storage as f64 / 1024.0),
-            1048576..1073741824 => format!("{:.2} MB", total_storage as f64 / 1024.0 / 1024.0),
-            _ => format!("{:.2} GB", total_storage as f64 / 1024.0 / 1024.0 / 1024.0),
+        let min_first_panel = match longest_name {
+            0..8 => 8,
+            8..=u16::MAX => longest_name,
+        };
+        let mut storage_attrs = vec![
+            ("layout", property_value_span(storage.layout_string())),
+            (
+                "filters",
+                if storage.has_unavailable_filters() {
+                    Span::styled(
+                        storage.filters_string(),
+                        Style::default()
+                            .fg(configure::themed_color(|colors| colors.text.error))
+                            .bold(),
+                    )
+                } else {
+                    property_value_span(storage.filters_string())
+                },
+            ),
+        ];
+        if storage.layout != StorageLayout::Virtual {
+            storage_attrs.push((
+                "ratio",
+                property_value_span(self.compression_ratio_string()),
+            ));
+        }
+        storage_attrs.push(("fill", property_value_span(storage.fill_string())));
+        storage_attrs.push(("alloc", property_value_span(storage.allocation_string())));
+        property_rows(storage_attrs, min_first_panel)
+    }
+
+    pub fn render_virtual_sources(&self, longest_name: u16) -> Vec<RenderedAttributeRow> {
+        let min_first_panel = match longest_name {
+            0..8 => 8,
+            8..=u16::MAX => longest_name,
         };
-        format!("{} ({})", size_str, total_storage_str)
+        self.virtual_sources
+            .iter()
+            .enumerate()
+            .map(|(index, source)| {
+                let name_line = property_name_line(format!("[{index}]"), min_first_panel);
+                let value_span = match source.status {
+                    VirtualSourceStatus::Resolved => property_value_span(source.describe()),
+                    VirtualSourceStatus::Pattern => Span::styled(
+                        source.describe(),
+                        Style::default()
+                            .fg(configure::themed_color(|colors| colors.text.type_desc))
+                            .bold(),
+                    ),
+                    _ => Span::styled(
+                        source.describe(),
+                        Style::default()
+                            .fg(configure::themed_color(|colors| colors.text.error))
+                            .bold(),
+                    ),
+                };
+                RenderedAttributeRow::virtual_source(
+                    index,
+                    (
+                        name_line,
+                        Line::from(vec![value_span]),
+                        Line::from(vec![Span::raw("")]),
+                    ),
+                )
+            })
+            .collect()
     }
 }
@@ -1,6 +1,6 @@
 use std::{cell::RefCell, rc::Rc};
 
-use hdf5_metno::{types::TypeDescriptor, Dataset, File, Group};
+use hdf5_metno::{types::TypeDescriptor, Dataset, File, Group, Location};
 use ratatui::{
     style::Style,
     text::{Line, Span},
@@ -17,7 +17,8 @@ use crate::{
 
 use super::{
     attrs::{ComputedAttributes, HasPath, RenderedAttributeRow},
-    meta::{DatasetMeta, GroupMeta},
+    meta::{DatasetMeta, DatatypeMeta, GroupMeta},
+    MatlabVariable, ObjectAlias,
 };
 
 #[derive(Debug, Clone)]
@@ -27,6 +28,9 @@ pub struct DatasetIdentity {
     pub link_name: Option<String>,
     pub path: String,
     pub is_compound_container: bool,
+    pub dimension_names: Option<Vec<String>>,
+    pub matlab: Option<MatlabVariable>,
+    pub alias: Option<ObjectAlias>,
 }
 
 #[derive(Debug, Clone)]
@@ -47,6 +51,8 @@ pub enum Node {
     File(File),
     Group(Group, GroupMeta),
     Dataset(DatasetHandle, DatasetMetaState),
+    /// A named datatype, opened as the location it was committed at.
+    Datatype(Location, DatatypeMeta),
     Broken(String),
 }
 
@@ -173,6 +179,9 @@ pub struct H5FNode {
     pub loading: bool,
     pub load_error: Option<String>,
     pub children: Vec<Rc<RefCell<H5FNode>>>,
+    /// Position of the link in its parent's creation order, when the parent
+    /// tracks it.
+    pub creation_order: Option<i64>,
     pub view_loaded: u32,
     pub selected_dim: usize,
     pub selected_x: usize,
@@ -181,6 +190,11 @@ pub struct H5FNode {
     pub line_offset: usize,
     pub col_offset: isize,
     pub selected_indexes: Vec<usize>,
+    /// Bit charted by the preview of a bitfield dataset.
+    pub selected_bit: usize,
+    /// Set once the initial axes were chosen, so metadata loaded again later
+    /// keeps the axes the user picked.
+    pub initial_axes_applied: bool,
 }
 
 impl H5FNode {
@@ -200,6 +214,7 @@ impl H5FNode {
             loading: false,
             load_error: None,
             children: vec![],
+            creation_order: None,
             view_loaded: 50,
             computed_attributes: None,
             metadata_loading: false,
@@ -213,6 +228,8 @@ impl H5FNode {
             line_offset: 0,
             col_offset: 0,
             selected_indexes,
+            selected_bit: 0,
+            initial_axes_applied: false,
         }
     }
 
@@ -251,16 +268,61 @@ impl H5FNode {
                 link_name: meta.link_name.clone(),
                 path: meta.virtual_path().unwrap_or(&meta.filename).to_string(),
                 is_compound_container: meta.is_compound_container(),
+                dimension_names: meta.dimension_names.clone(),
+                matlab: meta.matlab.clone(),
+                alias: meta.alias.clone(),
             }),
             _ => None,
         }
     }
 
+    /// MATLAB class and size of a variable, when the tree was built MATLAB-aware.
+    pub fn matlab_variable(&self) -> Option<&MatlabVariable> {
+        match &self.node {
+            Node::Group(_, meta) => meta.matlab.as_ref(),
+            Node::Dataset(_, DatasetMetaState::Pending(identity)) => identity.matlab.as_ref(),
+            Node::Dataset(_, DatasetMetaState::Loaded(meta)) => meta.matlab.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Shows the first two MATLAB dimensions as matrix rows and columns, which
+    /// are the last two HDF5 dimensions of a MAT-file variable. Only the first
+    /// call after the metadata is loaded changes the axes.
+    pub fn apply_initial_axis_order(&mut self) {
+        let Node::Dataset(_, DatasetMetaState::Loaded(meta)) = &self.node else {
+            return;
+        };
+        let rank = meta.shape.len();
+        let matlab = meta.uses_matlab_axis_order();
+        if std::mem::replace(&mut self.initial_axes_applied, true) || !matlab {
+            return;
+        }
+        self.selected_row = rank - 1;
+        self.selected_col = rank - 2;
+        self.selected_x = rank - 1;
+        self.selected_dim = 0;
+    }
+
+    /// netCDF dimension names of a dataset, when the tree was built netCDF-aware.
+    pub fn dimension_names(&self) -> Option<&[String]> {
+        match &self.node {
+            Node::Dataset(_, DatasetMetaState::Pending(identity)) => {
+                identity.dimension_names.as_deref()
+            }
+            Node::Dataset(_, DatasetMetaState::Loaded(meta)) => meta.dimension_names.as_deref(),
+            _ => None,
+        }
+    }
+
     pub fn icon(&self) -> String {
         if let Node::Broken(_) = &self.node {
             return configure::configured_symbol(|symbols| symbols.tree.broken_node_icon)
                 .to_string();
         }
+        if let Node::Datatype(_, _) = &self.node {
+            return configure::configured_symbol(|symbols| symbols.tree.datatype_icon).to_string();
+        }
         if self.is_compound_container() {
             return configure::configured_symbol(|symbols| symbols.tree.compound_container_icon)
                 .to_string();
@@ -298,11 +360,12 @@ impl H5FNode {
         let mut result = vec![];
 
         match &self.node {
-            Node::File(_) => {
+            Node::File(_) | Node::Group(_, _) => {
                 result.push(ContentShowMode::Preview);
+                result.push(ContentShowMode::Usage);
             }
             Node::Broken(_) => {}
-            Node::Group(_, _) => {
+            Node::Datatype(_, _) => {
                 result.push(ContentShowMode::Preview);
             }
             Node::Dataset(_, DatasetMetaState::Loaded(dataset_meta))
@@ -400,6 +463,22 @@ impl H5FNode {
                                 result.push(ContentShowMode::Preview);
                             }
                         }
+                        MatrixRenderType::Ragged => {
+                            if !dataset_meta.is_compound_leaf()
+                                && dataset_meta.shape.iter().any(|x| *x > 1)
+                            {
+                                result.push(ContentShowMode::Matrix);
+                            }
+                            result.push(ContentShowMode::Preview);
+                        }
+                        MatrixRenderType::References | MatrixRenderType::Bitfield => {
+                            if !dataset_meta.is_compound_leaf()
+                                && dataset_meta.shape.iter().any(|x| *x > 1)
+                            {
+                                result.push(ContentShowMode::Matrix);
+                            }
+                            result.push(ContentShowMode::Preview);
+                        }
                         MatrixRenderType::Compound => {}
                     },
                     None => result.push(ContentShowMode::Preview),
@@ -407,6 +486,17 @@ impl H5FNode {
             }
             Node::Dataset(_, DatasetMetaState::Pending(_)) => result.push(ContentShowMode::Preview),
         }
+        if let Node::Dataset(_, DatasetMetaState::Loaded(dataset_meta)) = &self.node {
+            if dataset_meta.chunk_shape.is_some()
+                && dataset_meta
+                    .compound_projection
+                    .as_ref()
+                    .is_none_or(|projection| projection.field_path.is_empty())
+                && !dataset_meta.is_empty()
+            {
+                result.push(ContentShowMode::Chunks);
+            }
+        }
         result
     }
 
@@ -428,8 +518,40 @@ impl H5FNode {
         matches!(&self.node, Node::Dataset(_, DatasetMetaState::Loaded(meta)) if meta.is_compound_leaf())
     }
 
+    /// Complex values, whose real, imaginary, magnitude, phase and dB views are
+    /// tree children.
+    pub fn is_complex_value(&self) -> bool {
+        matches!(&self.node, Node::Dataset(_, DatasetMetaState::Loaded(meta)) if meta.is_complex_value())
+    }
+
+    /// MATLAB cells and struct-array fields, whose elements are tree children.
+    pub fn is_matlab_container(&self) -> bool {
+        self.matlab_variable()
+            .is_some_and(MatlabVariable::is_container)
+    }
+
+    /// Set for nodes reached through a link other than their canonical path.
+    pub fn alias(&self) -> Option<&ObjectAlias> {
+        match &self.node {
+            Node::Group(_, meta) => meta.alias.as_ref(),
+            Node::Dataset(_, DatasetMetaState::Pending(identity)) => identity.alias.as_ref(),
+            Node::Dataset(_, DatasetMetaState::Loaded(meta)) => meta.alias.as_ref(),
+            Node::Datatype(_, meta) => meta.alias.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Group links back to an ancestor, which are not expanded.
+    pub fn is_cycle(&self) -> bool {
+        self.alias().is_some_and(|alias| alias.cycle)
+    }
+
     pub fn is_expandable(&self) -> bool {
-        matches!(self.node, Node::File(_,)) || self.is_group() || self.is_compound_container()
+        matches!(self.node, Node::File(_,))
+            || (self.is_group() && !self.is_cycle())
+            || self.is_compound_container()
+            || self.is_complex_value()
+            || self.is_matlab_container()
     }
 }
 
@@ -454,13 +576,16 @@ mod tests {
     use ndarray::arr2;
 
     #[test]
-    fn file_nodes_support_preview_mode() {
+    fn file_nodes_support_preview_and_usage_modes() {
         let _guard = crate::test_support::hdf5_test_guard();
         let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
         let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
         let node = H5FNode::new(Node::File(file));
 
-        assert_eq!(node.content_show_modes(), vec![ContentShowMode::Preview]);
+        assert_eq!(
+            node.content_show_modes(),
+            vec![ContentShowMode::Preview, ContentShowMode::Usage]
+        );
     }
 
     #[test]
@@ -507,7 +632,18 @@ mod tests {
                     field_path: vec![],
                     field_type: TypeDescriptor::FixedAscii(8),
                     virtual_path: "/values/labels".to_string(),
+                    complex: None,
                 }),
+                storage: None,
+                virtual_sources: vec![],
+                cf_decoding: None,
+                dimension_names: None,
+                matlab: None,
+                float16: None,
+                bitfield: None,
+                time: None,
+                committed_type: None,
+                alias: None,
             }),
         ));
 
@@ -546,6 +682,16 @@ mod tests {
                 is_link: false,
                 filename: file.filename(),
                 compound_projection: None,
+                storage: None,
+                virtual_sources: vec![],
+                cf_decoding: None,
+                dimension_names: None,
+                matlab: None,
+                float16: None,
+                bitfield: None,
+                time: None,
+                committed_type: None,
+                alias: None,
             }),
         ));
 
@@ -596,6 +742,16 @@ mod tests {
                         size: 16,
                     },
                 )),
+                storage: None,
+                virtual_sources: vec![],
+                cf_decoding: None,
+                dimension_names: None,
+                matlab: None,
+                float16: None,
+                bitfield: None,
+                time: None,
+                committed_type: None,
+                alias: None,
             }),
         ));
 
@@ -648,6 +804,16 @@ mod tests {
                         size: 16,
                     },
                 )),
+                storage: None,
+                virtual_sources: vec![],
+                cf_decoding: None,
+                dimension_names: None,
+                matlab: None,
+                float16: None,
+                bitfield: None,
+                time: None,
+                committed_type: None,
+                alias: None,
             }),
         ));
 
@@ -689,6 +855,16 @@ mod tests {
                 is_link: false,
                 filename: file.filename(),
                 compound_projection: None,
+                storage: None,
+                virtual_sources: vec![],
+                cf_decoding: None,
+                dimension_names: None,
+                matlab: None,
+                float16: None,
+                bitfield: None,
+                time: None,
+                committed_type: None,
+                alias: None,
             }),
         ));
 
@@ -732,6 +908,16 @@ mod tests {
                 is_link: false,
                 filename: file.filename(),
                 compound_projection: None,
+                storage: None,
+                virtual_sources: vec![],
+                cf_decoding: None,
+                dimension_names: None,
+                matlab: None,
+                float16: None,
+                bitfield: None,
+                time: None,
+                committed_type: None,
+                alias: None,
             }),
         ));
 
@@ -770,6 +956,16 @@ mod tests {
                 is_link: false,
                 filename: file.filename(),
                 compound_projection: None,
+                storage: None,
+                virtual_sources: vec![],
+                cf_decoding: None,
+                dimension_names: None,
+                matlab: None,
+                float16: None,
+                bitfield: None,
+                time: None,
+                committed_type: None,
+                alias: None,
             }),
         ));
 
@@ -783,6 +979,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn chunked_datasets_support_chunk_map_mode() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let dataset = file
+            .new_dataset_builder()
+            .chunk(2)
+            .with_data(&[1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64])
+            .create("values")
+            .expect("failed to create dataset");
+        let node = H5FNode::new(Node::Dataset(
+            DatasetHandle::Loaded(dataset),
+            DatasetMetaState::Loaded(DatasetMeta {
+                link_name: None,
+                display_name: "values".to_string(),
+                shape: vec![4],
+                data_type: "float64".to_string(),
+                unsupported_reason: None,
+                type_descriptor: TypeDescriptor::Float(hdf5_metno::types::FloatSize::U8),
+                data_bytesize: 8,
+                storage_required: 32,
+                total_bytes: 32,
+                total_elems: 4,
+                chunk_shape: Some(vec![2]),
+                hl: None,
+                matrixable: Some(MatrixRenderType::Float64),
+                encoding: Encoding::UTF8,
+                image: None,
+                enum_render_overrides: None,
+                is_link: false,
+                filename: file.filename(),
+                compound_projection: None,
+                storage: None,
+                virtual_sources: vec![],
+                cf_decoding: None,
+                dimension_names: None,
+                matlab: None,
+                float16: None,
+                bitfield: None,
+                time: None,
+                committed_type: None,
+                alias: None,
+            }),
+        ));
+
+        assert_eq!(
+            node.content_show_modes(),
+            vec![
+                ContentShowMode::Matrix,
+                ContentShowMode::Preview,
+                ContentShowMode::Chunks
+            ]
+        );
+    }
+
     #[test]
     fn rank_one_numeric_datasets_do_not_support_heatmap_mode() {
         let _guard = crate::test_support::hdf5_test_guard();
@@ -815,6 +1067,16 @@ mod tests {
                 is_link: false,
                 filename: file.filename(),
                 compound_projection: None,
+                storage: None,
+                virtual_sources: vec![],
+                cf_decoding: None,
+                dimension_names: None,
+                matlab: None,
+                float16: None,
+                bitfield: None,
+                time: None,
+                committed_type: None,
+                alias: None,
             }),
         ));
 
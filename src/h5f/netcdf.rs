@@ -0,0 +1,297 @@
+use hdf5_metno::{Attribute, Dataset, Group};
+
+use crate::configure;
+
+use super::{
+    dimension_list_targets,
+    meta::{property_rows, property_value_span},
+    read_string_attr_values, RenderedAttributeRow,
+};
+
+const NC_PROPERTIES_ATTR: &str = "_NCProperties";
+const NC3_STRICT_ATTR: &str = "_nc3_strict";
+const NC_DIMID_ATTR: &str = "_Netcdf4Dimid";
+const CLASS_ATTR: &str = "CLASS";
+const NAME_ATTR: &str = "NAME";
+const DIMENSION_SCALE_CLASS: &str = "DIMENSION_SCALE";
+/// `NAME` that netCDF-4 gives datasets which only exist to hold a dimension.
+const DIMENSION_PLACEHOLDER_PREFIX: &str = "This is a netCDF dimension but not a netCDF variable";
+/// Attributes the netCDF-4 library writes to track dimensions and provenance.
+const BOOKKEEPING_ATTRS: [&str; 6] = [
+    NC_PROPERTIES_ATTR,
+    NC3_STRICT_ATTR,
+    NC_DIMID_ATTR,
+    "_Netcdf4Coordinates",
+    "DIMENSION_LIST",
+    "REFERENCE_LIST",
+];
+
+/// A netCDF dimension defined in a group, backed by a dimension scale dataset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetcdfDimension {
+    pub name: String,
+    pub len: usize,
+    pub unlimited: bool,
+    /// `false` for dimension-only placeholders, which are hidden from the tree.
+    pub is_variable: bool,
+}
+
+impl NetcdfDimension {
+    pub fn describe(&self) -> String {
+        if self.unlimited {
+            format!("{}={} (unlimited)", self.name, self.len)
+        } else {
+            format!("{}={}", self.name, self.len)
+        }
+    }
+}
+
+fn first_string_attr(attr: Option<Attribute>) -> Option<String> {
+    attr.and_then(|attr| read_string_attr_values(&attr).ok())
+        .and_then(|values| values.into_iter().next())
+}
+
+fn last_segment(path: &str) -> String {
+    path.rsplit('/')
+        .find(|segment| !segment.is_empty())
+        .unwrap_or(path)
+        .to_string()
+}
+
+/// Whether `group` belongs to a file written by the netCDF-4 library and the
+/// netCDF-aware presentation is enabled.
+pub fn is_netcdf_view(group: &Group) -> bool {
+    if !configure::current_data_settings().netcdf {
+        return false;
+    }
+    group.file().is_ok_and(|file| {
+        file.attr_names().is_ok_and(|names| {
+            names
+                .iter()
+                .any(|name| name == NC_PROPERTIES_ATTR || name == NC3_STRICT_ATTR)
+        })
+    })
+}
+
+pub fn is_netcdf_bookkeeping_attr(name: &str) -> bool {
+    BOOKKEEPING_ATTRS.contains(&name)
+}
+
+/// Drops netCDF bookkeeping attributes, plus `CLASS` and `NAME` on dimension
+/// scales, from an attribute list.
+pub fn netcdf_visible_attributes(attributes: Vec<(String, Attribute)>) -> Vec<(String, Attribute)> {
+    let is_scale = attributes.iter().any(|(name, attr)| {
+        name == CLASS_ATTR
+            && first_string_attr(Some(attr.clone()))
+                .is_some_and(|class| class.trim() == DIMENSION_SCALE_CLASS)
+    });
+    attributes
+        .into_iter()
+        .filter(|(name, _)| {
+            !is_netcdf_bookkeeping_attr(name)
+                && !(is_scale && (name == CLASS_ATTR || name == NAME_ATTR))
+        })
+        .collect()
+}
+
+/// `_NCProperties` of the file, e.g. `version=2,netcdf=4.9.2,hdf5=1.14.3`.
+pub fn netcdf_properties(group: &Group) -> Option<String> {
+    let file = group.file().ok()?;
+    first_string_attr(file.attr(NC_PROPERTIES_ATTR).ok())
+}
+
+fn is_dimension_scale(dataset: &Dataset) -> bool {
+    first_string_attr(dataset.attr(CLASS_ATTR).ok())
+        .is_some_and(|class| class.trim() == DIMENSION_SCALE_CLASS)
+}
+
+pub fn is_dimension_placeholder(dataset: &Dataset) -> bool {
+    first_string_attr(dataset.attr(NAME_ATTR).ok())
+        .is_some_and(|name| name.starts_with(DIMENSION_PLACEHOLDER_PREFIX))
+}
+
+/// Names of the dimensions a variable is defined over, in dimension order.
+/// Coordinate variables are their own dimension. `None` when the dataset has
+/// no netCDF dimensions.
+pub fn netcdf_dimension_names(dataset: &Dataset) -> Option<Vec<String>> {
+    let targets = dimension_list_targets(dataset);
+    if targets.iter().any(Option::is_some) {
+        return Some(
+            targets
+                .into_iter()
+                .map(|scale| {
+                    scale
+                        .map(|scale| last_segment(&scale.name()))
+                        .unwrap_or_else(|| "?".to_string())
+                })
+                .collect(),
+        );
+    }
+    (dataset.ndim() == 1 && is_dimension_scale(dataset))
+        .then(|| vec![last_segment(&dataset.name())])
+}
+
+/// Dimensions defined directly in `group`, ordered by their netCDF dimension id.
+pub fn read_group_dimensions(group: &Group) -> Vec<NetcdfDimension> {
+    let Ok(datasets) = group.datasets() else {
+        return vec![];
+    };
+    let mut dimensions = datasets
+        .into_iter()
+        .filter(is_dimension_scale)
+        .map(|dataset| {
+            let dimid = dataset
+                .attr(NC_DIMID_ATTR)
+                .and_then(|attr| attr.read_scalar::<i32>())
+                .unwrap_or(i32::MAX);
+            let dimension = NetcdfDimension {
+                name: last_segment(&dataset.name()),
+                len: dataset.shape().first().copied().unwrap_or_default(),
+                unlimited: dataset
+                    .space()
+                    .is_ok_and(|space| space.maxdims().first().is_some_and(Option::is_none)),
+                is_variable: !is_dimension_placeholder(&dataset),
+            };
+            (dimid, dimension)
+        })
+        .collect::<Vec<_>>();
+    dimensions.sort_by(|(a, a_dim), (b, b_dim)| a.cmp(b).then_with(|| a_dim.name.cmp(&b_dim.name)));
+    dimensions
+        .into_iter()
+        .map(|(_, dimension)| dimension)
+        .collect()
+}
+
+/// `netcdf` and `dims` property rows of a group; `netcdf` only on the root.
+pub fn render_netcdf_properties(
+    group: &Group,
+    is_root: bool,
+    longest_name: u16,
+) -> Vec<RenderedAttributeRow> {
+    let min_first_panel = match longest_name {
+        0..8 => 8,
+        8..=u16::MAX => longest_name,
+    };
+    let mut entries = vec![];
+    if is_root {
+        if let Some(properties) = netcdf_properties(group) {
+            entries.push(("netcdf", property_value_span(properties)));
+        }
+    }
+    let dimensions = read_group_dimensions(group);
+    if !dimensions.is_empty() {
+        let dims = dimensions
+            .iter()
+            .map(NetcdfDimension::describe)
+            .collect::<Vec<_>>()
+            .join(", ");
+        entries.push(("dims", property_value_span(dims)));
+    }
+    property_rows(entries, min_first_panel)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use hdf5_metno::{
+        types::{VarLenArray, VarLenAscii},
+        ObjectReference1,
+    };
+
+    use super::{
+        is_dimension_placeholder, is_netcdf_bookkeeping_attr, netcdf_dimension_names,
+        read_group_dimensions, NetcdfDimension, DIMENSION_PLACEHOLDER_PREFIX,
+    };
+
+    fn write_string_attr(location: &hdf5_metno::Location, name: &str, value: &str) {
+        location
+            .new_attr::<VarLenAscii>()
+            .create(name)
+            .expect("create string attr")
+            .write_scalar(&VarLenAscii::from_ascii(value).unwrap())
+            .expect("write string attr");
+    }
+
+    #[test]
+    fn reads_netcdf_dimensions_and_variable_dimension_names() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let dir = tempfile::tempdir().expect("failed to create temp dir");
+        let file = hdf5_metno::File::create(dir.path().join("nc.h5")).expect("create file");
+        let time = file
+            .new_dataset::<f64>()
+            .shape(2..)
+            .chunk(2)
+            .create("time")
+            .expect("create time");
+        write_string_attr(&time, "CLASS", "DIMENSION_SCALE");
+        time.new_attr::<i32>()
+            .create("_Netcdf4Dimid")
+            .expect("create dimid")
+            .write_scalar(&0)
+            .expect("write dimid");
+        let station = file
+            .new_dataset::<f32>()
+            .shape(3)
+            .create("station")
+            .expect("create station");
+        write_string_attr(&station, "CLASS", "DIMENSION_SCALE");
+        write_string_attr(
+            &station,
+            "NAME",
+            &format!("{DIMENSION_PLACEHOLDER_PREFIX}         3"),
+        );
+        station
+            .new_attr::<i32>()
+            .create("_Netcdf4Dimid")
+            .expect("create dimid")
+            .write_scalar(&1)
+            .expect("write dimid");
+        let temp = file
+            .new_dataset::<f32>()
+            .shape((2, 3))
+            .create("temp")
+            .expect("create temp");
+        let time_ref: ObjectReference1 = file.reference("time").expect("reference time");
+        let station_ref: ObjectReference1 = file.reference("station").expect("reference station");
+        temp.new_attr::<VarLenArray<ObjectReference1>>()
+            .shape(2)
+            .create("DIMENSION_LIST")
+            .expect("create DIMENSION_LIST")
+            .write(&[
+                VarLenArray::from_slice(&[time_ref]),
+                VarLenArray::from_slice(&[station_ref]),
+            ])
+            .expect("write DIMENSION_LIST");
+
+        assert_eq!(
+            netcdf_dimension_names(&temp),
+            Some(vec!["time".to_string(), "station".to_string()])
+        );
+        assert_eq!(
+            netcdf_dimension_names(&time),
+            Some(vec!["time".to_string()])
+        );
+        assert!(is_dimension_placeholder(&station));
+        assert!(!is_dimension_placeholder(&time));
+        assert_eq!(
+            read_group_dimensions(&file),
+            vec![
+                NetcdfDimension {
+                    name: "time".to_string(),
+                    len: 2,
+                    unlimited: true,
+                    is_variable: true,
+                },
+                NetcdfDimension {
+                    name: "station".to_string(),
+                    len: 3,
+                    unlimited: false,
+                    is_variable: false,
+                },
+            ]
+        );
+        assert!(is_netcdf_bookkeeping_attr("_Netcdf4Dimid"));
+        assert!(!is_netcdf_bookkeeping_attr("units"));
+    }
+}
@@ -0,0 +1,355 @@
+use hdf5_metno::{Group, LinkType, Location, LocationType};
+
+use super::{read_indexed_attributes, IndexedAttribute, IndexedDataset, VisitedGroups};
+
+/// What [`index_objects`] reads of every object.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IndexScope {
+    pub paths: bool,
+    pub attributes: bool,
+    pub datasets: bool,
+}
+
+impl IndexScope {
+    pub fn is_empty(self) -> bool {
+        !(self.paths || self.attributes || self.datasets)
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            paths: self.paths || other.paths,
+            attributes: self.attributes || other.attributes,
+            datasets: self.datasets || other.datasets,
+        }
+    }
+
+    pub fn intersects(self, other: Self) -> bool {
+        (self.paths && other.paths)
+            || (self.attributes && other.attributes)
+            || (self.datasets && other.datasets)
+    }
+
+    /// Whether everything `other` reads is read by `self` too.
+    pub fn covers(self, other: Self) -> bool {
+        self.union(other) == self
+    }
+}
+
+/// What [`index_objects`] read of one object.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndexedObject {
+    /// Path search lists every object except the root.
+    pub path: Option<String>,
+    pub attributes: Vec<IndexedAttribute>,
+    pub dataset: Option<IndexedDataset>,
+}
+
+impl IndexedObject {
+    fn is_empty(&self) -> bool {
+        self.path.is_none() && self.attributes.is_empty() && self.dataset.is_none()
+    }
+}
+
+/// Reads what `scope` asks for of `root` and of every object below it in one
+/// walk, calling `visit` with one object at a time; objects with nothing to
+/// report are skipped. Soft and hard links are followed and groups reached
+/// again through another link are listed but not entered twice. External
+/// links are listed without being followed, and datasets whose metadata
+/// cannot be read have no `dataset`. Returning `false` from `visit` stops the
+/// walk, in which case `false` is returned.
+pub fn index_objects(
+    root: &Group,
+    scope: IndexScope,
+    visit: &mut dyn FnMut(IndexedObject) -> bool,
+) -> bool {
+    let mut walk = ObjectWalk {
+        scope,
+        visited: VisitedGroups::default(),
+        visit,
+    };
+    walk.visited.enter(root);
+    let object = IndexedObject {
+        attributes: walk.attributes("/", root),
+        ..IndexedObject::default()
+    };
+    walk.emit(object) && walk.members("/", root)
+}
+
+struct ObjectWalk<'a> {
+    scope: IndexScope,
+    visited: VisitedGroups,
+    visit: &'a mut dyn FnMut(IndexedObject) -> bool,
+}
+
+impl ObjectWalk<'_> {
+    fn emit(&mut self, object: IndexedObject) -> bool {
+        object.is_empty() || (self.visit)(object)
+    }
+
+    fn listed(&self, path: &str) -> IndexedObject {
+        IndexedObject {
+            path: self.scope.paths.then(|| path.to_string()),
+            ..IndexedObject::default()
+        }
+    }
+
+    fn attributes(&self, path: &str, location: &Location) -> Vec<IndexedAttribute> {
+        if !self.scope.attributes {
+            return Vec::new();
+        }
+        read_indexed_attributes(path, location)
+    }
+
+    fn members(&mut self, path: &str, group: &Group) -> bool {
+        let members = group
+            .iter_visit_default(vec![], |group, name, link, members| {
+                // External links and links that do not resolve are listed
+                // only.
+                let kind = match link.link_type {
+                    LinkType::External => None,
+                    _ => group.loc_type_by_name(name).ok(),
+                };
+                members.push((name.to_string(), kind));
+                true
+            })
+            .unwrap_or_default();
+        let base = if path == "/" { "" } else { path };
+        for (name, kind) in members {
+            let member_path = format!("{base}/{name}");
+            let keep_going = match kind {
+                Some(LocationType::Group) => match group.group(&name) {
+                    Ok(member) => {
+                        let object = IndexedObject {
+                            attributes: self.attributes(&member_path, &member),
+                            ..self.listed(&member_path)
+                        };
+                        self.emit(object)
+                            && (!self.visited.enter(&member) || self.members(&member_path, &member))
+                    }
+                    Err(_) => self.emit(self.listed(&member_path)),
+                },
+                Some(kind) => {
+                    let object = self.member(group, &name, &member_path, kind);
+                    self.emit(object)
+                }
+                None => self.emit(self.listed(&member_path)),
+            };
+            if !keep_going {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn member(&self, group: &Group, name: &str, path: &str, kind: LocationType) -> IndexedObject {
+        let mut object = self.listed(path);
+        if self.scope.datasets && kind == LocationType::Dataset {
+            // One handle serves both the attributes and the metadata.
+            if let Ok(dataset) = group.dataset(name) {
+                if self.scope.attributes {
+                    object.attributes = read_indexed_attributes(path, &dataset);
+                }
+                object.dataset = IndexedDataset::read(path.to_string(), &dataset).ok();
+            }
+        } else if self.scope.attributes {
+            if let Ok(member) = group
+                .loc_info_by_name(name)
+                .and_then(|info| group.open_by_token(info.token))
+            {
+                object.attributes = read_indexed_attributes(path, &member);
+            }
+        }
+        object
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use hdf5_metno::types::VarLenUnicode;
+
+    use super::{index_objects, IndexScope, IndexedObject};
+    use crate::h5f::{AttributeValue, IndexedAttribute, TypeClass};
+
+    const EVERYTHING: IndexScope = IndexScope {
+        paths: true,
+        attributes: true,
+        datasets: true,
+    };
+
+    fn write_sample(file: &hdf5_metno::File) {
+        file.new_attr::<i32>()
+            .create("version")
+            .expect("failed to create attribute")
+            .write_scalar(&3)
+            .expect("failed to write attribute");
+        let group = file.create_group("run").expect("failed to create group");
+        let dataset = group
+            .new_dataset::<f32>()
+            .shape([4, 8, 2])
+            .chunk([2, 8, 2])
+            .create("raw")
+            .expect("failed to create dataset");
+        let units: VarLenUnicode = "mV".parse().expect("failed to build string");
+        dataset
+            .new_attr::<VarLenUnicode>()
+            .create("units")
+            .expect("failed to create attribute")
+            .write_scalar(&units)
+            .expect("failed to write attribute");
+        dataset
+            .new_attr::<f64>()
+            .shape([2])
+            .create("range")
+            .expect("failed to create attribute")
+            .write_raw(&[-1.5, 2.0])
+            .expect("failed to write attribute");
+        group
+            .new_dataset::<i16>()
+            .shape([10])
+            .create("counts")
+            .expect("failed to create dataset");
+        file.new_dataset::<u8>()
+            .create("flag")
+            .expect("failed to create dataset");
+        group
+            .link_hard("/run", "loop")
+            .expect("failed to link group");
+        group
+            .link_external("other.h5", "/data", "elsewhere")
+            .expect("failed to link file");
+    }
+
+    fn index(file: &hdf5_metno::File, scope: IndexScope) -> Vec<IndexedObject> {
+        let mut found = Vec::new();
+        assert!(index_objects(
+            &file.as_group().expect("root group"),
+            scope,
+            &mut |object| {
+                found.push(object);
+                true
+            }
+        ));
+        found
+    }
+
+    #[test]
+    fn indexes_paths_attributes_and_datasets_in_one_walk() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        write_sample(&file);
+
+        let found = index(&file, EVERYTHING);
+        let mut paths = found
+            .iter()
+            .filter_map(|object| object.path.clone())
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "/flag",
+                "/run",
+                "/run/counts",
+                "/run/elsewhere",
+                "/run/loop",
+                "/run/raw"
+            ]
+        );
+
+        let mut attributes = found
+            .iter()
+            .flat_map(|object| object.attributes.clone())
+            .collect::<Vec<_>>();
+        attributes.sort_by(|a, b| (&a.path, &a.name).cmp(&(&b.path, &b.name)));
+        let summary = attributes
+            .iter()
+            .map(|IndexedAttribute { path, name, .. }| format!("{path}@{name}"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec!["/@version", "/run/raw@range", "/run/raw@units"]
+        );
+        assert_eq!(attributes[0].values, vec![AttributeValue::Number(3.0)]);
+        assert_eq!(
+            attributes[1].values,
+            vec![AttributeValue::Number(-1.5), AttributeValue::Number(2.0)]
+        );
+        assert_eq!(
+            attributes[2].values,
+            vec![AttributeValue::Text("mV".into())]
+        );
+
+        let mut datasets = found
+            .iter()
+            .filter_map(|object| object.dataset.clone())
+            .collect::<Vec<_>>();
+        datasets.sort_by(|a, b| a.path.cmp(&b.path));
+        let summary = datasets
+            .iter()
+            .map(|dataset| {
+                (
+                    dataset.path.as_str(),
+                    dataset.shape.clone(),
+                    dataset.class,
+                    dataset.bytes,
+                    dataset.chunked,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("/flag", vec![], TypeClass::Unsigned, 1, false),
+                ("/run/counts", vec![10], TypeClass::Integer, 20, false),
+                ("/run/raw", vec![4, 8, 2], TypeClass::Float, 256, true),
+            ]
+        );
+        assert_eq!(datasets[2].data_type, "f32");
+    }
+
+    #[test]
+    fn reads_only_what_the_scope_asks_for() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        write_sample(&file);
+
+        let attributes_only = IndexScope {
+            attributes: true,
+            ..IndexScope::default()
+        };
+        let found = index(&file, attributes_only);
+        assert!(found
+            .iter()
+            .all(|object| object.path.is_none() && object.dataset.is_none()));
+        assert_eq!(found.len(), 2);
+
+        let mut visited = 0;
+        assert!(!index_objects(
+            &file.as_group().expect("root group"),
+            EVERYTHING,
+            &mut |_| {
+                visited += 1;
+                false
+            }
+        ));
+        assert_eq!(visited, 1);
+    }
+
+    #[test]
+    fn scopes_combine() {
+        let paths = IndexScope {
+            paths: true,
+            ..IndexScope::default()
+        };
+        assert!(IndexScope::default().is_empty());
+        assert!(EVERYTHING.covers(paths));
+        assert!(!paths.covers(EVERYTHING));
+        assert_eq!(paths.union(EVERYTHING), EVERYTHING);
+        assert!(paths.intersects(EVERYTHING));
+        assert!(!paths.intersects(IndexScope::default()));
+    }
+}
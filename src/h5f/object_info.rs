@@ -0,0 +1,477 @@
+use std::{
+    ffi::CStr,
+    mem::MaybeUninit,
+    os::raw::{c_char, c_uint, c_void},
+};
+
+use hdf5_metno::{file::FileDriver, Dataset, File, Location};
+use hdf5_metno_sys::{
+    h5::{herr_t, H5_ih_info_t, H5_index_t, H5_iter_order_t, H5free_memory, H5get_libversion},
+    h5f::{H5F_info2_t, H5F_libver_t, H5Fget_filesize, H5Fget_freespace, H5Fget_info2},
+    h5i::hid_t,
+    h5o::{
+        H5O_hdr_info_t, H5O_info2_t, H5O_token_t, H5O_type_t, H5Oget_info3, H5Oget_native_info,
+        H5Otoken_to_str, H5Ovisit3, H5O_INFO_BASIC, H5O_INFO_TIME, H5O_NATIVE_INFO_ALL,
+    },
+    h5p::H5Pget_libver_bounds,
+    h5t::H5Tcommitted,
+};
+use ratatui::{style::Style, text::Span};
+
+use crate::configure;
+
+use super::{
+    format_unix_seconds, human_bytes,
+    meta::{property_rows, property_value_span},
+    RenderedAttributeRow,
+};
+
+/// Layout of `H5O_native_info_t`, whose fields the bindings keep private.
+#[repr(C)]
+struct NativeObjectInfo {
+    hdr: H5O_hdr_info_t,
+    obj_index: H5_ih_info_t,
+    attr_index: H5_ih_info_t,
+}
+
+/// Object header details of a group, dataset or named datatype, as
+/// `h5dump -H -p` and `h5stat` report them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectHeader {
+    /// File address of the object header; `None` outside the native file format.
+    pub address: Option<u64>,
+    /// Hard links to the object.
+    pub ref_count: u32,
+    pub version: u32,
+    pub total_size: u64,
+    pub free_size: u64,
+    pub messages: u32,
+    pub chunks: u32,
+    /// B-tree and heap bytes indexing a group's links.
+    pub link_index_size: u64,
+    /// B-tree and heap bytes of dense attribute storage.
+    pub attr_index_size: u64,
+    /// Access, modification, change and birth times in Unix seconds; zero
+    /// when the file does not track them.
+    pub atime: i64,
+    pub mtime: i64,
+    pub ctime: i64,
+    pub btime: i64,
+}
+
+impl ObjectHeader {
+    pub fn read(location: &Location) -> Option<Self> {
+        let mut info = MaybeUninit::<H5O_info2_t>::uninit();
+        if unsafe {
+            H5Oget_info3(
+                location.id(),
+                info.as_mut_ptr(),
+                H5O_INFO_BASIC | H5O_INFO_TIME,
+            )
+        } < 0
+        {
+            return None;
+        }
+        let info = unsafe { info.assume_init() };
+        let mut native = MaybeUninit::<NativeObjectInfo>::uninit();
+        if unsafe {
+            H5Oget_native_info(
+                location.id(),
+                native.as_mut_ptr().cast(),
+                H5O_NATIVE_INFO_ALL,
+            )
+        } < 0
+        {
+            return None;
+        }
+        let native = unsafe { native.assume_init() };
+        Some(Self {
+            address: token_address(location, &info),
+            ref_count: info.rc,
+            version: native.hdr.version,
+            total_size: native.hdr.space.total,
+            free_size: native.hdr.space.free,
+            messages: native.hdr.nmesgs,
+            chunks: native.hdr.nchunks,
+            link_index_size: native.obj_index.index_size + native.obj_index.heap_size,
+            attr_index_size: native.attr_index.index_size + native.attr_index.heap_size,
+            atime: info.atime as _,
+            mtime: info.mtime as _,
+            ctime: info.ctime as _,
+            btime: info.btime as _,
+        })
+    }
+
+    /// `800 (0x320)`, the decimal address `h5dump` prints, then hex.
+    pub fn address_string(&self) -> String {
+        match self.address {
+            Some(address) => format!("{address} (0x{address:x})"),
+            None => "not addressable".to_string(),
+        }
+    }
+
+    pub fn refs_string(&self) -> String {
+        match self.ref_count {
+            1 => "1 hard link".to_string(),
+            count => format!("{count} hard links"),
+        }
+    }
+
+    /// `v2, 272 B in 1 chunk, 9 messages, 12 B free`.
+    pub fn header_string(&self) -> String {
+        format!(
+            "v{}, {} in {} chunk{}, {} message{}, {} free",
+            self.version,
+            human_bytes(self.total_size),
+            self.chunks,
+            if self.chunks == 1 { "" } else { "s" },
+            self.messages,
+            if self.messages == 1 { "" } else { "s" },
+            human_bytes(self.free_size)
+        )
+    }
+
+    /// Link and attribute index sizes, or `None` when neither is stored.
+    pub fn index_string(&self) -> Option<String> {
+        (self.link_index_size > 0 || self.attr_index_size > 0).then(|| {
+            format!(
+                "links {}, attributes {}",
+                human_bytes(self.link_index_size),
+                human_bytes(self.attr_index_size)
+            )
+        })
+    }
+
+    pub fn tracks_times(&self) -> bool {
+        [self.atime, self.mtime, self.ctime, self.btime]
+            .iter()
+            .any(|time| *time != 0)
+    }
+
+    pub fn render(&self, longest_name: u16) -> Vec<RenderedAttributeRow> {
+        let min_first_panel = match longest_name {
+            0..8 => 8,
+            8..=u16::MAX => longest_name,
+        };
+        let mut object_attrs = vec![
+            ("address", property_value_span(self.address_string())),
+            ("refs", property_value_span(self.refs_string())),
+            ("header", property_value_span(self.header_string())),
+        ];
+        if let Some(index) = self.index_string() {
+            object_attrs.push(("index", property_value_span(index)));
+        }
+        if self.tracks_times() {
+            for (name, time) in [
+                ("btime", self.btime),
+                ("mtime", self.mtime),
+                ("ctime", self.ctime),
+                ("atime", self.atime),
+            ] {
+                if time != 0 {
+                    object_attrs.push((name, property_value_span(format_unix_seconds(time))));
+                }
+            }
+        } else {
+            object_attrs.push((
+                "mtime",
+                Span::styled(
+                    "not tracked",
+                    Style::default().fg(configure::themed_color(|colors| colors.help.muted)),
+                ),
+            ));
+        }
+        property_rows(object_attrs, min_first_panel)
+    }
+}
+
+fn token_address(location: &Location, info: &H5O_info2_t) -> Option<u64> {
+    let mut text: *mut c_char = std::ptr::null_mut();
+    if unsafe { H5Otoken_to_str(location.id(), &info.token, &mut text) } < 0 || text.is_null() {
+        return None;
+    }
+    let address = unsafe { CStr::from_ptr(text) }
+        .to_string_lossy()
+        .trim()
+        .parse()
+        .ok();
+    unsafe {
+        H5free_memory(text.cast());
+    }
+    address
+}
+
+/// Path of the named datatype a dataset was created with, or `None` when its
+/// type is not committed. Committed types without a link to them are shown by
+/// header address.
+pub fn committed_type_path(dataset: &Dataset) -> Option<String> {
+    let dtype = dataset.dtype().ok()?;
+    if unsafe { H5Tcommitted(dtype.id()) } <= 0 {
+        return None;
+    }
+    let mut info = MaybeUninit::<H5O_info2_t>::uninit();
+    if unsafe { H5Oget_info3(dtype.id(), info.as_mut_ptr(), H5O_INFO_BASIC) } < 0 {
+        return None;
+    }
+    let info = unsafe { info.assume_init() };
+    let file = dataset.file().ok()?;
+    let mut search = NamedTypeSearch {
+        token: info.token,
+        path: None,
+    };
+    unsafe {
+        H5Ovisit3(
+            file.id(),
+            H5_index_t::H5_INDEX_NAME,
+            H5_iter_order_t::H5_ITER_INC,
+            Some(find_named_type),
+            (&mut search as *mut NamedTypeSearch).cast(),
+            H5O_INFO_BASIC,
+        )
+    };
+    search.path.or_else(|| {
+        let address = token_address(&file, &info)?;
+        Some(format!("anonymous type at {address}"))
+    })
+}
+
+struct NamedTypeSearch {
+    token: H5O_token_t,
+    path: Option<String>,
+}
+
+extern "C" fn find_named_type(
+    _object: hid_t,
+    name: *const c_char,
+    info: *const H5O_info2_t,
+    op_data: *mut c_void,
+) -> herr_t {
+    let search = unsafe { &mut *op_data.cast::<NamedTypeSearch>() };
+    let info = unsafe { &*info };
+    if info.type_ != H5O_type_t::H5O_TYPE_NAMED_DATATYPE || info.token != search.token {
+        return 0;
+    }
+    let name = unsafe { CStr::from_ptr(name) }.to_string_lossy();
+    search.path = Some(format!("/{name}"));
+    // A positive return stops the visit.
+    1
+}
+
+/// File-level internals, as `h5stat` and `h5dump -B` report them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileInternals {
+    pub superblock_version: u32,
+    pub superblock_size: u64,
+    pub superblock_ext_size: u64,
+    pub userblock_size: u64,
+    /// Size HDF5 reports for the file, which can differ from the filesystem
+    /// size while a writer has it open.
+    pub hdf5_size: u64,
+    pub free_space: u64,
+    pub free_space_version: u32,
+    pub free_space_meta_size: u64,
+    pub sohm_version: u32,
+    pub sohm_header_size: u64,
+    /// Library version bounds of the file access property list h5v opened with.
+    pub libver_bounds: Option<(String, String)>,
+    pub driver: String,
+}
+
+impl FileInternals {
+    pub fn read(file: &File) -> Option<Self> {
+        let mut info = MaybeUninit::<H5F_info2_t>::uninit();
+        if unsafe { H5Fget_info2(file.id(), info.as_mut_ptr()) } < 0 {
+            return None;
+        }
+        let info = unsafe { info.assume_init() };
+        let mut hdf5_size = 0;
+        if unsafe { H5Fget_filesize(file.id(), &mut hdf5_size) } < 0 {
+            return None;
+        }
+        let fapl = file.fapl().ok();
+        Some(Self {
+            superblock_version: info.super_.version,
+            superblock_size: info.super_.super_size,
+            superblock_ext_size: info.super_.super_ext_size,
+            userblock_size: file.userblock(),
+            hdf5_size,
+            free_space: unsafe { H5Fget_freespace(file.id()) }.max(0) as u64,
+            free_space_version: info.free.version,
+            free_space_meta_size: info.free.meta_size,
+            sohm_version: info.sohm.version,
+            sohm_header_size: info.sohm.hdr_size,
+            libver_bounds: fapl.as_ref().and_then(|fapl| {
+                let mut low = H5F_libver_t::H5F_LIBVER_EARLIEST;
+                let mut high = H5F_libver_t::H5F_LIBVER_EARLIEST;
+                (unsafe { H5Pget_libver_bounds(fapl.id(), &mut low, &mut high) } >= 0)
+                    .then(|| (libver_name(low).to_string(), libver_name(high).to_string()))
+            }),
+            driver: fapl
+                .and_then(|fapl| fapl.get_driver().ok())
+                .map_or("unknown", |driver| driver_name(&driver))
+                .to_string(),
+        })
+    }
+
+    /// Label and value rows for the file preview.
+    pub fn rows(&self) -> Vec<(&'static str, String)> {
+        let mut superblock = format!(
+            "version {}, {}",
+            self.superblock_version,
+            human_bytes(self.superblock_size)
+        );
+        if self.superblock_ext_size > 0 {
+            superblock.push_str(&format!(
+                " + {} extension",
+                human_bytes(self.superblock_ext_size)
+            ));
+        }
+        let mut rows = vec![
+            ("superblock", superblock),
+            ("userblock", human_bytes(self.userblock_size)),
+            ("hdf5 file size", human_bytes(self.hdf5_size)),
+            (
+                "free space",
+                format!(
+                    "{} (manager version {}, {} metadata)",
+                    human_bytes(self.free_space),
+                    self.free_space_version,
+                    human_bytes(self.free_space_meta_size)
+                ),
+            ),
+            (
+                "shared messages",
+                if self.sohm_header_size > 0 {
+                    format!(
+                        "version {}, {} header",
+                        self.sohm_version,
+                        human_bytes(self.sohm_header_size)
+                    )
+                } else {
+                    "none".to_string()
+                },
+            ),
+        ];
+        if let Some((low, high)) = &self.libver_bounds {
+            rows.push(("libver bounds", format!("{low} to {high} (as opened)")));
+        }
+        rows.push(("driver", self.driver.clone()));
+        rows.push(("hdf5 library", hdf5_library_version()));
+        rows
+    }
+}
+
+fn libver_name(version: H5F_libver_t) -> &'static str {
+    match version {
+        H5F_libver_t::H5F_LIBVER_EARLIEST => "earliest",
+        H5F_libver_t::H5F_LIBVER_V18 => "v1.8",
+        H5F_libver_t::H5F_LIBVER_V110 => "v1.10",
+        H5F_libver_t::H5F_LIBVER_V112 => "v1.12",
+        H5F_libver_t::H5F_LIBVER_V114 => "v1.14",
+        H5F_libver_t::H5F_LIBVER_V200 => "v2.0",
+        _ => "unknown",
+    }
+}
+
+fn driver_name(driver: &FileDriver) -> &'static str {
+    match driver {
+        FileDriver::Sec2 => "sec2",
+        FileDriver::Stdio => "stdio",
+        FileDriver::Log => "log",
+        FileDriver::Core(_) => "core (in memory)",
+        FileDriver::Family(_) => "family",
+        FileDriver::Multi(_) => "multi",
+        FileDriver::Split(_) => "split",
+    }
+}
+
+/// Version of the HDF5 library h5v is linked against.
+fn hdf5_library_version() -> String {
+    let (mut major, mut minor, mut release): (c_uint, c_uint, c_uint) = (0, 0, 0);
+    unsafe {
+        H5get_libversion(&mut major, &mut minor, &mut release);
+    }
+    format!("{major}.{minor}.{release}")
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::{FileInternals, ObjectHeader};
+
+    fn header() -> ObjectHeader {
+        ObjectHeader {
+            address: Some(800),
+            ref_count: 2,
+            version: 2,
+            total_size: 272,
+            free_size: 12,
+            messages: 1,
+            chunks: 1,
+            link_index_size: 0,
+            attr_index_size: 0,
+            atime: 0,
+            mtime: 0,
+            ctime: 0,
+            btime: 0,
+        }
+    }
+
+    #[test]
+    fn describes_object_headers() {
+        let header = header();
+        assert_eq!(header.address_string(), "800 (0x320)");
+        assert_eq!(header.refs_string(), "2 hard links");
+        assert_eq!(
+            header.header_string(),
+            "v2, 272 B in 1 chunk, 1 message, 12 B free"
+        );
+        assert_eq!(header.index_string(), None);
+        assert!(!header.tracks_times());
+    }
+
+    #[test]
+    fn reads_object_headers_and_file_internals() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::with_options()
+            .with_fapl(|fapl| fapl.libver_v110())
+            .create(temp.path())
+            .expect("failed to create hdf5 file");
+        let group = file.create_group("run").expect("create group");
+        let dataset = group
+            .new_dataset_builder()
+            .obj_track_times(true)
+            .with_data(&[1_u32, 2, 3])
+            .create("values")
+            .expect("create dataset");
+        group
+            .link_hard("values", "alias")
+            .expect("create hard link");
+
+        let object = ObjectHeader::read(&dataset).expect("dataset header");
+        assert!(object.address.is_some_and(|address| address > 0));
+        assert_eq!(object.ref_count, 2);
+        assert!(object.total_size > 0);
+        assert!(object.tracks_times());
+        assert_ne!(
+            ObjectHeader::read(&group).expect("group header").address,
+            object.address
+        );
+
+        let internals = FileInternals::read(&file).expect("file internals");
+        assert!(internals.superblock_version >= 2);
+        assert_eq!(internals.userblock_size, 0);
+        assert_eq!(internals.driver, "sec2");
+        assert_eq!(
+            internals
+                .libver_bounds
+                .as_ref()
+                .map(|(low, _)| low.as_str()),
+            Some("v1.10")
+        );
+        let rows = internals.rows();
+        assert!(rows.iter().any(|(label, _)| *label == "superblock"));
+    }
+}
@@ -0,0 +1,357 @@
+use std::{
+    ffi::CStr,
+    os::raw::{c_char, c_uint},
+};
+
+use hdf5_metno::{
+    dataset::{AllocTime, FillTime, FillValue, Layout},
+    plist::DatasetCreate,
+    Dataset,
+};
+use hdf5_metno_sys::{
+    h5p::{H5Pget_filter2, H5Pget_nfilters},
+    h5z::{H5Z_filter_t, H5Zfilter_avail, H5Z_FLAG_OPTIONAL},
+};
+
+const MAX_FILTER_PARAMS: usize = 32;
+const MAX_FILTER_NAME: usize = 257;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageLayout {
+    Compact,
+    Contiguous,
+    Chunked,
+    Virtual,
+}
+
+impl StorageLayout {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Compact => "compact",
+            Self::Contiguous => "contiguous",
+            Self::Chunked => "chunked",
+            Self::Virtual => "virtual",
+        }
+    }
+}
+
+impl From<Layout> for StorageLayout {
+    fn from(layout: Layout) -> Self {
+        match layout {
+            Layout::Compact => Self::Compact,
+            Layout::Contiguous => Self::Contiguous,
+            Layout::Chunked => Self::Chunked,
+            Layout::Virtual => Self::Virtual,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterSummary {
+    pub id: H5Z_filter_t,
+    pub name: String,
+    pub params: Vec<u32>,
+    pub optional: bool,
+    pub available: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct DatasetStorage {
+    pub layout: StorageLayout,
+    pub filters: Vec<FilterSummary>,
+    pub fill_value: FillValue,
+    pub fill_value_text: Option<String>,
+    pub fill_time: FillTime,
+    pub alloc_time: AllocTime,
+    pub external_files: Vec<String>,
+}
+
+fn blosc_compressor_name(code: u32) -> Option<&'static str> {
+    match code {
+        0 => Some("blosclz"),
+        1 => Some("lz4"),
+        2 => Some("lz4hc"),
+        3 => Some("snappy"),
+        4 => Some("zlib"),
+        5 => Some("zstd"),
+        _ => None,
+    }
+}
+
+fn blosc_shuffle_name(code: u32) -> &'static str {
+    match code {
+        0 => "noshuffle",
+        1 => "shuffle",
+        2 => "bitshuffle",
+        _ => "shuffle?",
+    }
+}
+
+fn raw_params(params: &[u32]) -> String {
+    params
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl FilterSummary {
+    /// Canonical short name for well-known filter ids, falling back to the name
+    /// stored in the pipeline for registered third-party filters.
+    pub fn display_name(&self) -> String {
+        let known = match self.id {
+            1 => Some("deflate"),
+            2 => Some("shuffle"),
+            3 => Some("fletcher32"),
+            4 => Some("szip"),
+            5 => Some("nbit"),
+            6 => Some("scaleoffset"),
+            307 => Some("bzip2"),
+            32000 => Some("lzf"),
+            32001 => Some("blosc"),
+            32004 => Some("lz4"),
+            32008 => Some("bitshuffle"),
+            32013 => Some("zfp"),
+            32015 => Some("zstd"),
+            32026 => Some("blosc2"),
+            _ => None,
+        };
+        match known {
+            Some(name) => name.to_string(),
+            None if !self.name.trim().is_empty() => self.name.trim().to_string(),
+            None => format!("filter {}", self.id),
+        }
+    }
+
+    pub fn parameter_string(&self) -> Option<String> {
+        let params = self.params.as_slice();
+        let described = match self.id {
+            1 => params.first().map(|level| format!("level={level}")),
+            2 => params.first().map(|size| format!("element={size}B")),
+            3 | 5 | 32000 => None,
+            4 => match params {
+                [mask, pixels, ..] => Some(format!(
+                    "{}, {pixels} px/block",
+                    if mask & 32 != 0 { "nn" } else { "ec" }
+                )),
+                _ => None,
+            },
+            6 => match params {
+                [0, factor, ..] => Some(format!("float dscale={factor}")),
+                [1, factor, ..] => Some(format!("float escale={factor}")),
+                [2, minbits, ..] => Some(format!("int minbits={minbits}")),
+                _ => None,
+            },
+            32001 | 32026 if params.len() >= 7 => Some(format!(
+                "{}, level={}, {}",
+                blosc_compressor_name(params[6]).unwrap_or("unknown"),
+                params[4],
+                blosc_shuffle_name(params[5])
+            )),
+            32008 if params.len() >= 5 => Some(format!(
+                "block={}, {}",
+                params[3],
+                match params[4] {
+                    2 => "lz4",
+                    3 => "zstd",
+                    _ => "uncompressed",
+                }
+            )),
+            32015 | 307 => params.first().map(|level| format!("level={level}")),
+            _ if params.is_empty() => None,
+            _ => Some(raw_params(params)),
+        };
+        described.filter(|text| !text.is_empty())
+    }
+
+    pub fn describe(&self) -> String {
+        let mut text = self.display_name();
+        if let Some(params) = self.parameter_string() {
+            text.push_str(&format!("({params})"));
+        }
+        if self.optional {
+            text.push_str(" [optional]");
+        }
+        if !self.available {
+            text.push_str(" [unavailable]");
+        }
+        text
+    }
+}
+
+impl DatasetStorage {
+    pub fn filters_string(&self) -> String {
+        if self.filters.is_empty() {
+            return "none".to_string();
+        }
+        self.filters
+            .iter()
+            .map(FilterSummary::describe)
+            .collect::<Vec<_>>()
+            .join(" → ")
+    }
+
+    pub fn layout_string(&self) -> String {
+        if self.external_files.is_empty() {
+            self.layout.label().to_string()
+        } else {
+            format!(
+                "{} (external: {})",
+                self.layout.label(),
+                self.external_files.join(", ")
+            )
+        }
+    }
+
+    pub fn fill_string(&self) -> String {
+        match (self.fill_value, self.fill_value_text.as_deref()) {
+            (FillValue::Undefined, _) => "undefined".to_string(),
+            (FillValue::Default, Some(value)) => format!("{value} (default)"),
+            (FillValue::Default, None) => "default".to_string(),
+            (FillValue::UserDefined, Some(value)) => value.to_string(),
+            (FillValue::UserDefined, None) => "user-defined".to_string(),
+        }
+    }
+
+    pub fn allocation_string(&self) -> String {
+        let alloc = match self.alloc_time {
+            AllocTime::Early => "early",
+            AllocTime::Incr => "incremental",
+            AllocTime::Late => "late",
+        };
+        let fill = match self.fill_time {
+            FillTime::IfSet => "fill if set",
+            FillTime::Alloc => "fill on alloc",
+            FillTime::Never => "never fill",
+        };
+        format!("{alloc}, {fill}")
+    }
+
+    pub fn has_unavailable_filters(&self) -> bool {
+        self.filters.iter().any(|filter| !filter.available)
+    }
+}
+
+fn read_filter_pipeline(dcpl: &DatasetCreate) -> Vec<FilterSummary> {
+    let count = unsafe { H5Pget_nfilters(dcpl.id()) };
+    if count <= 0 {
+        return vec![];
+    }
+    let mut filters = Vec::with_capacity(count as usize);
+    for index in 0..count as c_uint {
+        let mut flags: c_uint = 0;
+        let mut param_count = MAX_FILTER_PARAMS;
+        let mut params = vec![0 as c_uint; MAX_FILTER_PARAMS];
+        let mut name = vec![0 as c_char; MAX_FILTER_NAME];
+        let id = unsafe {
+            H5Pget_filter2(
+                dcpl.id(),
+                index,
+                &mut flags,
+                &mut param_count,
+                params.as_mut_ptr(),
+                name.len(),
+                name.as_mut_ptr(),
+                std::ptr::null_mut(),
+            )
+        };
+        if id < 0 {
+            continue;
+        }
+        params.truncate(param_count.min(MAX_FILTER_PARAMS));
+        let name = unsafe { CStr::from_ptr(name.as_ptr()) }
+            .to_string_lossy()
+            .into_owned();
+        filters.push(FilterSummary {
+            id,
+            name,
+            params,
+            optional: flags & H5Z_FLAG_OPTIONAL != 0,
+            available: unsafe { H5Zfilter_avail(id) } > 0,
+        });
+    }
+    filters
+}
+
+pub fn read_dataset_storage(dataset: &Dataset) -> Option<DatasetStorage> {
+    let dcpl = dataset.dcpl().ok()?;
+    let fill_value_text = dataset
+        .fill_value()
+        .ok()
+        .flatten()
+        .map(|value| value.to_string());
+    Some(DatasetStorage {
+        layout: StorageLayout::from(dcpl.layout()),
+        filters: read_filter_pipeline(&dcpl),
+        fill_value: dcpl.fill_value_defined(),
+        fill_value_text,
+        fill_time: dcpl.fill_time(),
+        alloc_time: dcpl.alloc_time(),
+        external_files: dcpl
+            .external()
+            .into_iter()
+            .map(|external| external.name)
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::{read_dataset_storage, FilterSummary, StorageLayout};
+
+    fn filter(id: i32, params: Vec<u32>) -> FilterSummary {
+        FilterSummary {
+            id,
+            name: String::new(),
+            params,
+            optional: false,
+            available: true,
+        }
+    }
+
+    #[test]
+    fn well_known_filters_describe_their_parameters() {
+        assert_eq!(filter(1, vec![6]).describe(), "deflate(level=6)");
+        assert_eq!(filter(2, vec![4]).describe(), "shuffle(element=4B)");
+        assert_eq!(filter(3, vec![]).describe(), "fletcher32");
+        assert_eq!(filter(32015, vec![3]).describe(), "zstd(level=3)");
+        assert_eq!(
+            filter(32001, vec![2, 2, 4, 4000, 5, 1, 5]).describe(),
+            "blosc(zstd, level=5, shuffle)"
+        );
+    }
+
+    #[test]
+    fn unknown_filters_fall_back_to_pipeline_name_and_raw_params() {
+        let mut summary = filter(40000, vec![1, 2]);
+        summary.name = "custom".to_string();
+        summary.available = false;
+        assert_eq!(summary.describe(), "custom(1, 2) [unavailable]");
+    }
+
+    #[test]
+    fn reads_layout_and_filter_pipeline_from_datasets() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let dataset = file
+            .new_dataset_builder()
+            .chunk(4)
+            .shuffle()
+            .deflate(4)
+            .with_data(&[1_u32; 16])
+            .create("values")
+            .expect("failed to create dataset");
+
+        let storage = read_dataset_storage(&dataset).expect("read storage");
+
+        assert_eq!(storage.layout, StorageLayout::Chunked);
+        assert_eq!(
+            storage.filters_string(),
+            "shuffle(element=4B) → deflate(level=4)"
+        );
+        assert_eq!(storage.fill_string(), "0 (default)");
+    }
+}
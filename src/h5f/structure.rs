@@ -0,0 +1,572 @@
+use hdf5_metno::{
+    types::{FloatSize, IntSize, TypeDescriptor},
+    Group,
+};
+
+use crate::error::AppError;
+
+/// Filter ID of the Zstandard plugin h5v registers at startup.
+const ZSTD_FILTER_ID: i32 = 32015;
+
+/// Element types new datasets can have, by the names the tree shows.
+const DATASET_TYPES: [(&str, &[&str]); 11] = [
+    ("i8", &["int8"]),
+    ("i16", &["int16"]),
+    ("i32", &["int32", "int"]),
+    ("i64", &["int64"]),
+    ("u8", &["uint8"]),
+    ("u16", &["uint16"]),
+    ("u32", &["uint32", "uint"]),
+    ("u64", &["uint64"]),
+    ("f32", &["float32", "single", "float"]),
+    ("f64", &["float64", "double"]),
+    ("bool", &["boolean"]),
+];
+
+fn type_descriptor(dtype: &str) -> TypeDescriptor {
+    match dtype {
+        "i8" => TypeDescriptor::Integer(IntSize::U1),
+        "i16" => TypeDescriptor::Integer(IntSize::U2),
+        "i32" => TypeDescriptor::Integer(IntSize::U4),
+        "i64" => TypeDescriptor::Integer(IntSize::U8),
+        "u8" => TypeDescriptor::Unsigned(IntSize::U1),
+        "u16" => TypeDescriptor::Unsigned(IntSize::U2),
+        "u32" => TypeDescriptor::Unsigned(IntSize::U4),
+        "u64" => TypeDescriptor::Unsigned(IntSize::U8),
+        "f32" => TypeDescriptor::Float(FloatSize::U4),
+        "f64" => TypeDescriptor::Float(FloatSize::U8),
+        "bool" => TypeDescriptor::Boolean,
+        _ => TypeDescriptor::VarLenUnicode,
+    }
+}
+
+fn parse_dataset_type(text: &str) -> Result<&'static str, AppError> {
+    let text = text.trim().to_ascii_lowercase();
+    if matches!(text.as_str(), "string" | "str" | "text" | "unicode") {
+        return Ok("string");
+    }
+    DATASET_TYPES
+        .iter()
+        .find(|(name, aliases)| *name == text || aliases.contains(&text.as_str()))
+        .map(|(name, _)| *name)
+        .ok_or_else(|| {
+            AppError::InvalidCommand(format!(
+                "Unsupported dataset type '{}'. Expected one of: i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, bool, string",
+                text
+            ))
+        })
+}
+
+/// `1000x3`, `1000,3` or `scalar`.
+pub(crate) fn parse_extents(text: &str, what: &str) -> Result<Vec<usize>, AppError> {
+    let text = text.trim();
+    if text.eq_ignore_ascii_case("scalar") || text == "()" {
+        return Ok(Vec::new());
+    }
+    text.split(['x', 'X', ',', '×'])
+        .map(|dim| dim.trim().parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| {
+            AppError::InvalidCommand(format!(
+                "Invalid {what} '{text}'. Expected sizes separated by x, such as 1000x3"
+            ))
+        })
+}
+
+pub(crate) fn format_extents(extents: &[usize]) -> String {
+    if extents.is_empty() {
+        return "scalar".to_string();
+    }
+    extents
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" x ")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatasetCompression {
+    None,
+    Gzip(u8),
+    Lzf,
+    /// Blosc with the LZ4 compressor and byte shuffle.
+    BloscLz4(u8),
+    Zstd(u8),
+}
+
+impl DatasetCompression {
+    /// `gzip`, `gzip=6`, `lzf`, `blosc-lz4=5`, `zstd=3` or `none`; `None` when the
+    /// word names no filter.
+    fn parse(text: &str) -> Option<Result<Self, AppError>> {
+        let (name, level) = match text.split_once('=') {
+            Some((name, level)) => (name, Some(level)),
+            None => (text, None),
+        };
+        let (default, max, make): (u8, u8, fn(u8) -> Self) =
+            match name.to_ascii_lowercase().as_str() {
+                "none" if level.is_none() => return Some(Ok(Self::None)),
+                "lzf" if level.is_none() => return Some(Ok(Self::Lzf)),
+                "gzip" | "deflate" => (4, 9, Self::Gzip),
+                "blosc-lz4" => (5, 9, Self::BloscLz4),
+                "zstd" => (3, 22, Self::Zstd),
+                _ => return None,
+            };
+        let Some(level) = level else {
+            return Some(Ok(make(default)));
+        };
+        Some(match level.parse::<u8>() {
+            Ok(level) if level <= max => Ok(make(level)),
+            _ => Err(AppError::InvalidCommand(format!(
+                "Invalid {name} level '{level}'. Expected 0 to {max}"
+            ))),
+        })
+    }
+
+    fn label(self) -> Option<String> {
+        match self {
+            Self::None => None,
+            Self::Gzip(level) => Some(format!("gzip level {level}")),
+            Self::Lzf => Some("lzf".to_string()),
+            Self::BloscLz4(level) => Some(format!("blosc-lz4 level {level} with shuffle")),
+            Self::Zstd(level) => Some(format!("zstd level {level}")),
+        }
+    }
+}
+
+/// A dataset `object dataset` creates, without values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatasetSpec {
+    pub path: String,
+    pub dtype: &'static str,
+    pub shape: Vec<usize>,
+    pub chunks: Option<Vec<usize>>,
+    pub compression: DatasetCompression,
+}
+
+impl DatasetSpec {
+    /// `options` holds `chunks=100x3` and a compression filter, in any order.
+    /// Compressed datasets without chunks get the chunks HDF5 picks.
+    pub fn parse(
+        path: String,
+        dtype: &str,
+        shape: &str,
+        options: &[&str],
+    ) -> Result<Self, AppError> {
+        let mut spec = Self {
+            path,
+            dtype: parse_dataset_type(dtype)?,
+            shape: parse_extents(shape, "shape")?,
+            chunks: None,
+            compression: DatasetCompression::None,
+        };
+        for option in options {
+            if let Some(chunks) = option
+                .strip_prefix("chunks=")
+                .or_else(|| option.strip_prefix("chunk="))
+            {
+                spec.chunks = Some(parse_extents(chunks, "chunk shape")?);
+            } else if let Some(compression) = DatasetCompression::parse(option) {
+                spec.compression = compression?;
+            } else {
+                return Err(AppError::InvalidCommand(format!(
+                    "Unknown dataset option '{option}'. Expected chunks=<shape> or gzip, lzf, blosc-lz4, zstd, none"
+                )));
+            }
+        }
+        if let Some(chunks) = &spec.chunks {
+            if chunks.len() != spec.shape.len() {
+                return Err(AppError::InvalidCommand(format!(
+                    "Chunk shape {} does not match the {} dimensions of shape {}",
+                    format_extents(chunks),
+                    spec.shape.len(),
+                    format_extents(&spec.shape)
+                )));
+            }
+            if chunks
+                .iter()
+                .zip(&spec.shape)
+                .any(|(chunk, dim)| *chunk == 0 || chunk > dim)
+            {
+                return Err(AppError::InvalidCommand(format!(
+                    "Chunk sizes must be between 1 and the size of their dimension, got {}",
+                    format_extents(chunks)
+                )));
+            }
+        }
+        if spec.shape.is_empty()
+            && (spec.chunks.is_some() || spec.compression != DatasetCompression::None)
+        {
+            return Err(AppError::InvalidCommand(
+                "Scalar datasets cannot be chunked or compressed".to_string(),
+            ));
+        }
+        Ok(spec)
+    }
+
+    fn describe(&self) -> String {
+        let mut text = format!(
+            "dataset '{}' of {} with shape {}",
+            self.path,
+            self.dtype,
+            format_extents(&self.shape)
+        );
+        if let Some(chunks) = &self.chunks {
+            text.push_str(&format!(", chunks {}", format_extents(chunks)));
+        }
+        if let Some(compression) = self.compression.label() {
+            text.push_str(&format!(", {compression}"));
+        }
+        text
+    }
+
+    fn create(&self, root: &Group) -> Result<(), AppError> {
+        let mut builder = root
+            .new_dataset_builder()
+            .empty_as(&type_descriptor(self.dtype));
+        if let Some(chunks) = &self.chunks {
+            builder = builder.chunk(chunks.clone());
+        }
+        builder = match self.compression {
+            DatasetCompression::None => builder,
+            DatasetCompression::Gzip(level) => builder.deflate(level),
+            DatasetCompression::Lzf => builder.lzf(),
+            DatasetCompression::BloscLz4(level) => builder.blosc_lz4(level, true),
+            DatasetCompression::Zstd(level) => {
+                builder.add_filter(ZSTD_FILTER_ID, &[std::ffi::c_uint::from(level)])
+            }
+        };
+        builder
+            .shape(self.shape.clone())
+            .create(self.path.as_str())?;
+        Ok(())
+    }
+}
+
+/// A change to the links of a file. Paths are absolute, except for soft and
+/// external link targets, which HDF5 stores as given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StructureEdit {
+    CreateGroup {
+        path: String,
+    },
+    CreateDataset(DatasetSpec),
+    Move {
+        from: String,
+        to: String,
+    },
+    SoftLink {
+        target: String,
+        path: String,
+    },
+    ExternalLink {
+        file: String,
+        target: String,
+        path: String,
+    },
+    Delete {
+        path: String,
+    },
+}
+
+/// Joins `path` to the group `base` unless it is absolute, and resolves `.`
+/// and `..`.
+pub fn resolve_object_path(base: &str, path: &str) -> String {
+    let mut segments = Vec::new();
+    let joined = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("{base}/{path}")
+    };
+    for segment in joined.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    format!("/{}", segments.join("/"))
+}
+
+/// The group holding the link at `path`.
+pub fn parent_object_path(path: &str) -> &str {
+    match path.rfind('/') {
+        Some(0) | None => "/",
+        Some(index) => &path[..index],
+    }
+}
+
+fn ensure_free(root: &Group, path: &str) -> Result<(), AppError> {
+    if path == "/" {
+        return Err(AppError::EditError(
+            "The root group cannot be replaced".to_string(),
+        ));
+    }
+    if root.link_exists(path) {
+        return Err(AppError::EditError(format!("'{path}' already exists")));
+    }
+    Ok(())
+}
+
+fn ensure_parent_group(root: &Group, path: &str) -> Result<(), AppError> {
+    let parent = parent_object_path(path);
+    root.group(parent)
+        .map(|_| ())
+        .map_err(|_| AppError::EditError(format!("'{parent}' is not a group in this file")))
+}
+
+fn ensure_link(root: &Group, path: &str) -> Result<(), AppError> {
+    if path == "/" {
+        return Err(AppError::EditError(
+            "The root group cannot be moved or deleted".to_string(),
+        ));
+    }
+    if !root.link_exists(path) {
+        return Err(AppError::EditError(format!("'{path}' does not exist")));
+    }
+    Ok(())
+}
+
+impl StructureEdit {
+    /// The question the confirmation dialog asks.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::CreateGroup { path } => format!("Create group '{path}'?"),
+            Self::CreateDataset(spec) => format!("Create {}?", spec.describe()),
+            Self::Move { from, to } => format!("Move '{from}' to '{to}'?"),
+            Self::SoftLink { target, path } => {
+                format!("Create soft link '{path}' pointing to '{target}'?")
+            }
+            Self::ExternalLink { file, target, path } => {
+                format!("Create external link '{path}' pointing to '{target}' in '{file}'?")
+            }
+            Self::Delete { path } => format!(
+                "Delete '{path}'? Objects only reachable through it are lost, and the file keeps its size until it is repacked."
+            ),
+        }
+    }
+
+    /// What the toast reports once the edit was written.
+    pub fn summary(&self) -> String {
+        match self {
+            Self::CreateGroup { path } => format!("Created group '{path}'"),
+            Self::CreateDataset(spec) => format!("Created dataset '{}'", spec.path),
+            Self::Move { from, to } => format!("Moved '{from}' to '{to}'"),
+            Self::SoftLink { path, .. } => format!("Created soft link '{path}'"),
+            Self::ExternalLink { path, .. } => format!("Created external link '{path}'"),
+            Self::Delete { path } => format!("Deleted '{path}'"),
+        }
+    }
+
+    /// The path the tree selects once the edit was written.
+    pub fn selection_after(&self) -> &str {
+        match self {
+            Self::CreateGroup { path }
+            | Self::SoftLink { path, .. }
+            | Self::ExternalLink { path, .. } => path,
+            Self::CreateDataset(spec) => &spec.path,
+            Self::Move { to, .. } => to,
+            Self::Delete { path } => parent_object_path(path),
+        }
+    }
+
+    /// Where the node at `path` is once the edit was written, or `None` when
+    /// it was deleted.
+    pub fn relocate(&self, path: &str) -> Option<String> {
+        let (edited, to) = match self {
+            Self::Move { from, to } => (from, Some(to)),
+            Self::Delete { path } => (path, None),
+            _ => return Some(path.to_string()),
+        };
+        match path.strip_prefix(edited.as_str()) {
+            Some(rest) if rest.is_empty() || rest.starts_with('/') => {
+                to.map(|to| format!("{to}{rest}"))
+            }
+            _ => Some(path.to_string()),
+        }
+    }
+
+    /// Rejects edits HDF5 would refuse, before asking for confirmation.
+    pub fn check(&self, root: &Group) -> Result<(), AppError> {
+        match self {
+            // Missing parents are created along with the group.
+            Self::CreateGroup { path } => ensure_free(root, path),
+            Self::CreateDataset(DatasetSpec { path, .. })
+            | Self::SoftLink { path, .. }
+            | Self::ExternalLink { path, .. } => {
+                ensure_free(root, path)?;
+                ensure_parent_group(root, path)
+            }
+            Self::Move { from, to } => {
+                ensure_link(root, from)?;
+                if to.starts_with(&format!("{from}/")) {
+                    return Err(AppError::EditError(format!(
+                        "'{from}' cannot be moved into itself"
+                    )));
+                }
+                ensure_free(root, to)?;
+                ensure_parent_group(root, to)
+            }
+            Self::Delete { path } => ensure_link(root, path),
+        }
+    }
+
+    pub fn apply(&self, root: &Group) -> Result<(), AppError> {
+        self.check(root)?;
+        match self {
+            Self::CreateGroup { path } => {
+                root.create_group(path)?;
+            }
+            Self::CreateDataset(spec) => spec.create(root)?,
+            Self::Move { from, to } => root.relink(from, to)?,
+            Self::SoftLink { target, path } => root.link_soft(target, path)?,
+            Self::ExternalLink { file, target, path } => root.link_external(file, target, path)?,
+            Self::Delete { path } => root.unlink(path)?,
+        }
+        root.file()?.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::{resolve_object_path, DatasetCompression, DatasetSpec, StructureEdit};
+
+    #[test]
+    fn parses_dataset_specs() {
+        let spec = DatasetSpec::parse(
+            "/runs/raw".to_string(),
+            "float32",
+            "1000x3",
+            &["gzip=6", "chunks=100x3"],
+        )
+        .expect("dataset spec");
+        assert_eq!(spec.dtype, "f32");
+        assert_eq!(spec.shape, vec![1000, 3]);
+        assert_eq!(spec.chunks, Some(vec![100, 3]));
+        assert_eq!(spec.compression, DatasetCompression::Gzip(6));
+        assert_eq!(
+            StructureEdit::CreateDataset(spec).describe(),
+            "Create dataset '/runs/raw' of f32 with shape 1000 x 3, chunks 100 x 3, gzip level 6?"
+        );
+
+        let scalar = DatasetSpec::parse("/n".to_string(), "string", "scalar", &[]).expect("scalar");
+        assert!(scalar.shape.is_empty());
+        assert!(DatasetSpec::parse("/n".to_string(), "f16", "4", &[]).is_err());
+        assert!(DatasetSpec::parse("/n".to_string(), "f32", "4x", &[]).is_err());
+        assert!(DatasetSpec::parse("/n".to_string(), "f32", "4", &["chunks=2x2"]).is_err());
+        assert!(DatasetSpec::parse("/n".to_string(), "f32", "4", &["gzip=12"]).is_err());
+        assert!(DatasetSpec::parse("/n".to_string(), "f32", "scalar", &["lzf"]).is_err());
+        assert!(DatasetSpec::parse("/n".to_string(), "f32", "4", &["fast"]).is_err());
+        assert!(DatasetSpec::parse("/n".to_string(), "f32", "4", &["blosc"]).is_err());
+
+        let blosc =
+            DatasetSpec::parse("/b".to_string(), "f32", "4", &["blosc-lz4"]).expect("blosc");
+        assert_eq!(blosc.compression, DatasetCompression::BloscLz4(5));
+        assert_eq!(
+            StructureEdit::CreateDataset(blosc).describe(),
+            "Create dataset '/b' of f32 with shape 4, blosc-lz4 level 5 with shuffle?"
+        );
+    }
+
+    #[test]
+    fn resolves_object_paths() {
+        assert_eq!(resolve_object_path("/runs", "raw"), "/runs/raw");
+        assert_eq!(resolve_object_path("/runs", "/raw"), "/raw");
+        assert_eq!(resolve_object_path("/", "a//b/"), "/a/b");
+        assert_eq!(resolve_object_path("/runs/a", "../b/./c"), "/runs/b/c");
+        assert_eq!(resolve_object_path("/", ".."), "/");
+    }
+
+    #[test]
+    fn applies_structure_edits() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+
+        StructureEdit::CreateGroup {
+            path: "/runs/a".to_string(),
+        }
+        .apply(&file)
+        .expect("create group");
+        let spec = DatasetSpec::parse(
+            "/runs/a/raw".to_string(),
+            "u16",
+            "64x8",
+            &["chunks=16x8", "gzip"],
+        )
+        .expect("dataset spec");
+        StructureEdit::CreateDataset(spec)
+            .apply(&file)
+            .expect("create dataset");
+        let dataset = file.dataset("/runs/a/raw").expect("created dataset");
+        assert_eq!(dataset.shape(), vec![64, 8]);
+        assert_eq!(dataset.chunk(), Some(vec![16, 8]));
+        assert!(dataset.dtype().expect("dtype").is::<u16>());
+
+        let duplicate = StructureEdit::CreateGroup {
+            path: "/runs/a".to_string(),
+        };
+        assert!(duplicate.check(&file).is_err());
+
+        StructureEdit::Move {
+            from: "/runs/a".to_string(),
+            to: "/runs/b".to_string(),
+        }
+        .apply(&file)
+        .expect("move group");
+        assert!(!file.link_exists("/runs/a"));
+        assert!(file.dataset("/runs/b/raw").is_ok());
+        let moved = StructureEdit::Move {
+            from: "/runs/a".to_string(),
+            to: "/runs/b".to_string(),
+        };
+        assert_eq!(
+            moved.relocate("/runs/a/raw").as_deref(),
+            Some("/runs/b/raw")
+        );
+        assert_eq!(moved.relocate("/runs/ab").as_deref(), Some("/runs/ab"));
+        assert!(StructureEdit::Move {
+            from: "/runs".to_string(),
+            to: "/runs/b/runs".to_string(),
+        }
+        .check(&file)
+        .is_err());
+
+        StructureEdit::SoftLink {
+            target: "/runs/b/raw".to_string(),
+            path: "/latest".to_string(),
+        }
+        .apply(&file)
+        .expect("soft link");
+        assert_eq!(
+            file.dataset("/latest").expect("linked dataset").shape(),
+            vec![64, 8]
+        );
+        StructureEdit::ExternalLink {
+            file: "other.h5".to_string(),
+            target: "/data".to_string(),
+            path: "/runs/external".to_string(),
+        }
+        .apply(&file)
+        .expect("external link");
+        assert!(file.link_exists("/runs/external"));
+
+        let delete = StructureEdit::Delete {
+            path: "/runs/b".to_string(),
+        };
+        assert_eq!(delete.selection_after(), "/runs");
+        assert_eq!(delete.relocate("/runs/b/raw"), None);
+        assert_eq!(delete.relocate("/runs").as_deref(), Some("/runs"));
+        delete.apply(&file).expect("delete group");
+        assert!(!file.link_exists("/runs/b"));
+        assert!(StructureEdit::Delete {
+            path: "/".to_string(),
+        }
+        .check(&file)
+        .is_err());
+    }
+}
@@ -1,9 +1,10 @@
-use std::{cell::RefCell, fs, path::Path, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, fs, path::Path, rc::Rc};
 
 use hdf5_metno::{
     plist::file_access::FileCloseDegree,
-    types::{TypeDescriptor, VarLenUnicode},
-    Dataset, File, Group, LinkType, LocationType, OpenMode,
+    types::{FloatSize, IntSize, TypeDescriptor, VarLenUnicode},
+    Dataset, File, Group, LinkType, Location, LocationType, ObjectReference1, OpenMode,
+    ReferencedObject,
 };
 use hdf5_metno_sys::h5t::{H5T_class_t::H5T_COMPOUND, H5Tget_class};
 use ratatui::style::Color;
@@ -17,11 +18,19 @@ use crate::{
 };
 
 use super::{
+    aliases::{is_own_ancestor, object_links, CanonicalPaths, ObjectAlias, ObjectIdentity},
     attrs::HasName,
     compound::root_compound_projection,
-    meta::{CompoundFieldProjection, DatasetMeta, EnumRenderOverrides, GroupMeta},
+    is_dimension_placeholder, is_hidden_matlab_group, is_matlab_view, is_netcdf_view,
+    matlab_element_label,
+    meta::{CompoundFieldProjection, DatasetMeta, DatatypeMeta, EnumRenderOverrides, GroupMeta},
     model::{DatasetHandle, DatasetIdentity, DatasetMetaState, H5FNode, Node, H5F},
-    ReadOpenMode, RequestedOpenMode, ResolvedOpenMode,
+    netcdf_dimension_names,
+    object_info::committed_type_path,
+    read_matlab_group, read_matlab_variable, read_virtual_sources,
+    storage::read_dataset_storage,
+    BitfieldType, CfDecoding, Float16Type, ReadOpenMode, RequestedOpenMode, ResolvedOpenMode,
+    TimeEncoding,
 };
 
 fn highlight_hint_from_name(name: &str) -> Option<String> {
@@ -183,6 +192,7 @@ impl H5FNode {
                     DatasetHandle::Loaded(dataset),
                     DatasetMetaState::Loaded(meta),
                 );
+                self.apply_initial_axis_order();
                 self.load_error = None;
             }
             Err(error) => {
@@ -220,6 +230,12 @@ impl H5FNode {
                 .unwrap_or("")
                 .to_string(),
             Node::Dataset(DatasetHandle::Pending { name, .. }, _) => name.clone(),
+            Node::Datatype(datatype, _) => datatype
+                .filename()
+                .split('/')
+                .next_back()
+                .unwrap_or("")
+                .to_string(),
             Node::Broken(path) => path.clone(),
         }
     }
@@ -265,18 +281,25 @@ impl H5FNode {
 
     #[cfg(test)]
     fn read_children(&mut self) -> Result<(), hdf5_metno::Error> {
-        let result = self.read_children_once();
+        let canonical = file_canonical_paths(&self.node);
+        let result = self.read_children_once(canonical.as_ref()).map(|_| ());
         self.load_error = result.as_ref().err().map(ToString::to_string);
         result
     }
 
-    fn read_children_once(&mut self) -> Result<(), hdf5_metno::Error> {
+    /// Reads the children, marking aliases among them when `canonical` is
+    /// given. Returns whether some children may be aliases that were left
+    /// unmarked for want of canonical paths.
+    fn read_children_once(
+        &mut self,
+        canonical: Option<&CanonicalPaths>,
+    ) -> Result<bool, hdf5_metno::Error> {
         if self.read {
-            return Ok(());
+            return Ok(false);
         }
-        if matches!(self.node, Node::Broken(_)) {
+        if matches!(self.node, Node::Broken(_) | Node::Datatype(_, _)) || self.is_cycle() {
             self.read = true;
-            return Ok(());
+            return Ok(false);
         }
 
         if matches!(self.node, Node::Dataset(_, _)) {
@@ -284,20 +307,27 @@ impl H5FNode {
             let Node::Dataset(DatasetHandle::Loaded(dataset), _) = &self.node else {
                 unreachable!()
             };
-            if !meta.is_compound_container() {
+            if let Some(matlab) = meta.matlab.as_ref().filter(|matlab| matlab.is_container()) {
+                self.children = matlab_element_children(dataset, matlab.is_cell(), &matlab.dims)?;
+                self.read = true;
+                return Ok(false);
+            }
+            if !meta.is_compound_container() && !meta.is_complex_value() {
                 self.read = true;
-                return Ok(());
+                return Ok(false);
             }
             let children = synthetic_compound_children(dataset, &meta)?;
             self.children = children;
             self.read = true;
-            return Ok(());
+            return Ok(false);
         }
 
         let has_children = match &self.node {
             Node::File(file) => file,
             Node::Group(group, _) => group,
-            Node::Broken(_) => unreachable!("It should be guarded by the previous match"),
+            Node::Broken(_) | Node::Datatype(_, _) => {
+                unreachable!("It should be guarded by the previous match")
+            }
             Node::Dataset(_, _) => unreachable!("It should be guarded by the previous match"),
         };
 
@@ -309,8 +339,18 @@ impl H5FNode {
             external_datasets,
             soft_datasets,
             broken_externals,
+            datatypes,
         ) = has_children.iter_visit_default(
-            (vec![], vec![], vec![], vec![], vec![], vec![], vec![]),
+            (
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+            ),
             |group, name, link, nodes| {
                 let is_link = link.link_type != LinkType::Hard;
                 match group.loc_type_by_name(name) {
@@ -340,6 +380,16 @@ impl H5FNode {
                             nodes.3.push((group.clone(), name.to_string()));
                         }
                     }
+                    // Tokens only identify objects within this file, so
+                    // datatypes behind external links are left out.
+                    Ok(LocationType::NamedDatatype) if link.link_type != LinkType::External => {
+                        if let Ok(datatype) = group
+                            .loc_info_by_name(name)
+                            .and_then(|info| group.open_by_token(info.token))
+                        {
+                            nodes.7.push((datatype, name.to_string(), is_link));
+                        }
+                    }
                     Ok(_) if link.link_type == LinkType::External => nodes
                         .6
                         .push((name.to_string(), group.filename().to_string())),
@@ -379,55 +429,145 @@ impl H5FNode {
                 .map(|(group, name)| DSType::Linked(group, name)),
         );
 
+        let netcdf = is_netcdf_view(has_children);
+        let matlab = is_matlab_view(has_children);
         let mut children = Vec::new();
         for wrapped_g in groups {
-            let (g_maybe, is_link) = match wrapped_g {
-                GrpType::Hard(g) => (Some(g), false),
-                GrpType::External(g) => (Some(g), true),
-                GrpType::Soft(g) => (Some(g), true),
+            let (g, is_link) = match wrapped_g {
+                GrpType::Hard(g) => (g, false),
+                GrpType::External(g) => (g, true),
+                GrpType::Soft(g) => (g, true),
             };
-            let Some(g) = g_maybe else {
+            if matlab && is_hidden_matlab_group(&g) {
                 continue;
-            };
+            }
             let display_name = g
                 .name()
                 .split('/')
                 .next_back()
                 .unwrap_or("Hidden")
                 .to_string();
-
-            let meta = GroupMeta {
-                is_link,
-                has_children: !g.is_empty(),
-                display_name,
-                filename: g.filename().to_string(),
-                preview_expr: g
-                    .attr("H5V_PREVIEW_EXPR")
-                    .ok()
-                    .and_then(|a| a.read_scalar::<VarLenUnicode>().ok().map(|v| v.to_string())),
-            };
-            children.push(Rc::new(RefCell::new(H5FNode::new(Node::Group(g, meta)))));
+            children.push(build_group_node(g, is_link, display_name, matlab));
         }
         for wrapped_ds in datasets {
-            let Some(node) = build_dataset_node(wrapped_ds)? else {
+            let Some(node) = build_dataset_node(wrapped_ds, netcdf, matlab)? else {
                 continue;
             };
-            children.push(Rc::new(RefCell::new(H5FNode::new(node))));
+            children.push(node);
         }
-        self.children = children;
+        for (datatype, display_name, is_link) in datatypes {
+            children.push(build_datatype_node(datatype, is_link, display_name));
+        }
+        let unmarked = mark_aliases(has_children, children.iter_mut(), canonical);
+        self.children = children
+            .into_iter()
+            .map(|node| Rc::new(RefCell::new(H5FNode::new(node))))
+            .collect();
+        self.apply_creation_orders(&link_creation_orders(&self.node));
         self.read = true;
-        Ok(())
+        Ok(unmarked)
+    }
+
+    /// Marks aliases among the children read so far, and theirs, once the
+    /// canonical paths of the file are known.
+    pub fn mark_aliases(&mut self, canonical: &CanonicalPaths) {
+        let parent = match &self.node {
+            Node::File(file) => file.as_group().ok(),
+            Node::Group(group, _) => Some(group.clone()),
+            _ => None,
+        };
+        let Some(parent) = parent.filter(|_| self.read) else {
+            return;
+        };
+        let mut children = self
+            .children
+            .iter()
+            .map(|child| child.borrow_mut())
+            .collect::<Vec<_>>();
+        mark_aliases(
+            &parent,
+            children.iter_mut().map(|child| &mut child.node),
+            Some(canonical),
+        );
+        for child in &mut children {
+            child.mark_aliases(canonical);
+        }
+    }
+
+    fn apply_creation_orders(&mut self, orders: &HashMap<String, i64>) {
+        if orders.is_empty() {
+            return;
+        }
+        for child in &self.children {
+            let mut child = child.borrow_mut();
+            child.creation_order =
+                enumerated_link_name(&child.node).and_then(|name| orders.get(&name).copied());
+        }
     }
 }
 
-pub fn enumerate_group_children(node: &Node) -> Result<Vec<Node>, hdf5_metno::Error> {
+/// Creation order of the links of a group by link name; empty unless the
+/// group was created with creation order tracked.
+pub fn link_creation_orders(node: &Node) -> HashMap<String, i64> {
+    let group: &Group = match node {
+        Node::File(file) => file,
+        Node::Group(group, _) => group,
+        _ => return HashMap::new(),
+    };
+    group
+        .iter_visit_default(HashMap::new(), |_, name, link, orders| {
+            if let Some(order) = link.creation_order {
+                orders.insert(name.to_string(), order);
+            }
+            true
+        })
+        .unwrap_or_default()
+}
+
+/// Name of the link a child was enumerated through.
+fn enumerated_link_name(node: &Node) -> Option<String> {
+    match node {
+        Node::Group(group, _) => group.name().rsplit('/').next().map(str::to_string),
+        Node::Dataset(DatasetHandle::Pending { name, .. }, _) => Some(name.clone()),
+        Node::Datatype(_, meta) => Some(meta.display_name.clone()),
+        Node::Broken(name) => Some(name.clone()),
+        Node::File(_) | Node::Dataset(_, _) => None,
+    }
+}
+
+/// Children of a group as read in the background for the tree.
+pub struct EnumeratedChildren {
+    pub children: Vec<Node>,
+    /// Set when some children may be aliases, which could not be marked
+    /// because no canonical paths were given.
+    pub unmarked_aliases: bool,
+}
+
+pub fn enumerate_group_children(
+    node: &Node,
+    canonical: Option<&CanonicalPaths>,
+) -> Result<EnumeratedChildren, hdf5_metno::Error> {
     let mut enumerated = H5FNode::new(node.clone());
-    enumerated.read_children_once()?;
-    Ok(enumerated
-        .children
-        .iter()
-        .map(|child| child.borrow().node.clone())
-        .collect())
+    let unmarked_aliases = enumerated.read_children_once(canonical)?;
+    Ok(EnumeratedChildren {
+        children: enumerated
+            .children
+            .iter()
+            .map(|child| child.borrow().node.clone())
+            .collect(),
+        unmarked_aliases,
+    })
+}
+
+/// Canonical paths of the file `node` belongs to, read on the spot.
+#[cfg(test)]
+fn file_canonical_paths(node: &Node) -> Option<CanonicalPaths> {
+    let file = match node {
+        Node::File(file) => file.clone(),
+        Node::Group(group, _) => group.file().ok()?,
+        _ => return None,
+    };
+    super::read_canonical_paths(&file, &mut || true)
 }
 
 impl H5FNode {
@@ -435,16 +575,27 @@ impl H5FNode {
         match &self.node {
             Node::File(file) => Some(Node::File(file.clone())),
             Node::Group(group, meta) => Some(Node::Group(group.clone(), meta.clone())),
-            Node::Dataset(_, _) if self.is_compound_container() => Some(self.node.clone()),
+            Node::Dataset(_, _)
+                if self.is_compound_container()
+                    || self.is_complex_value()
+                    || self.is_matlab_container() =>
+            {
+                Some(self.node.clone())
+            }
             _ => None,
         }
     }
 
-    pub fn apply_enumerated_children(&mut self, children: Vec<Node>) {
+    pub fn apply_enumerated_children(
+        &mut self,
+        children: Vec<Node>,
+        creation_orders: &HashMap<String, i64>,
+    ) {
         self.children = children
             .into_iter()
             .map(|node| Rc::new(RefCell::new(H5FNode::new(node))))
             .collect();
+        self.apply_creation_orders(creation_orders);
         self.read = true;
         self.loading = false;
         self.load_error = None;
@@ -456,7 +607,119 @@ impl H5FNode {
     }
 }
 
-fn build_dataset_node(wrapped_ds: DSType) -> Result<Option<Node>, hdf5_metno::Error> {
+fn build_group_node(group: Group, is_link: bool, display_name: String, matlab: bool) -> Node {
+    let meta = GroupMeta {
+        is_link,
+        has_children: !group.is_empty(),
+        display_name,
+        filename: group.filename().to_string(),
+        preview_expr: group
+            .attr("H5V_PREVIEW_EXPR")
+            .ok()
+            .and_then(|a| a.read_scalar::<VarLenUnicode>().ok().map(|v| v.to_string())),
+        matlab: if matlab {
+            read_matlab_group(&group)
+        } else {
+            None
+        },
+        alias: None,
+    };
+    Node::Group(group, meta)
+}
+
+/// Marks the children reached through a link other than their canonical
+/// path, and the group links that lead back to an ancestor. Without
+/// `canonical`, returns whether some children may be aliases.
+fn mark_aliases<'n>(
+    parent: &Group,
+    children: impl Iterator<Item = &'n mut Node>,
+    canonical: Option<&CanonicalPaths>,
+) -> bool {
+    let Some(parent_identity) = ObjectIdentity::of(parent) else {
+        return false;
+    };
+    let mut candidates = children
+        .filter_map(|node| alias_candidate(node, parent_identity).map(|found| (node, found)))
+        .peekable();
+    let Some(canonical) = canonical else {
+        return candidates.peek().is_some();
+    };
+    let Ok(file) = parent.file() else {
+        return false;
+    };
+    for (node, (path, identity)) in candidates {
+        let Some(canonical_path) = canonical
+            .get(&identity)
+            .filter(|canonical| **canonical != path)
+        else {
+            continue;
+        };
+        let alias = ObjectAlias {
+            canonical_path: canonical_path.clone(),
+            cycle: matches!(node, Node::Group(_, _)) && is_own_ancestor(&file, &path, identity),
+        };
+        match node {
+            Node::Group(_, meta) => meta.alias = Some(alias),
+            Node::Dataset(_, DatasetMetaState::Pending(identity)) => identity.alias = Some(alias),
+            Node::Dataset(_, DatasetMetaState::Loaded(meta)) => meta.alias = Some(alias),
+            Node::Datatype(_, meta) => meta.alias = Some(alias),
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Path and identity of a child that other links may reach too: one with
+/// several hard links, or a soft link. Objects in other files are skipped.
+fn alias_candidate(node: &Node, parent: ObjectIdentity) -> Option<(String, ObjectIdentity)> {
+    let (path, (identity, hard_links), is_link) = match node {
+        Node::Group(group, meta) => (group.name(), object_links(group, None)?, meta.is_link),
+        Node::Dataset(
+            DatasetHandle::Pending { parent, name },
+            DatasetMetaState::Pending(dataset),
+        ) => (
+            dataset.path.clone(),
+            object_links(parent, Some(name))?,
+            dataset.is_link,
+        ),
+        Node::Dataset(DatasetHandle::Loaded(dataset), DatasetMetaState::Loaded(meta)) => {
+            (dataset.name(), object_links(dataset, None)?, meta.is_link)
+        }
+        Node::Datatype(datatype, meta) => {
+            (datatype.name(), object_links(datatype, None)?, meta.is_link)
+        }
+        _ => return None,
+    };
+    (identity.same_file(&parent) && (hard_links > 1 || is_link)).then_some((path, identity))
+}
+
+fn build_datatype_node(datatype: Location, is_link: bool, display_name: String) -> Node {
+    let (type_descriptor, size) = match datatype.as_datatype() {
+        Ok(dtype) => (dtype.to_descriptor().ok(), dtype.size()),
+        Err(_) => (None, 0),
+    };
+    let meta = DatatypeMeta {
+        is_link,
+        filename: datatype.filename(),
+        display_name,
+        data_type: match &type_descriptor {
+            Some(type_descriptor) => sprint_typedescriptor(type_descriptor),
+            None => format!("opaque[{size} bytes]"),
+        },
+        type_descriptor,
+        size,
+        alias: None,
+    };
+    Node::Datatype(datatype, meta)
+}
+
+/// Builds the tree node for a dataset link. In netCDF-aware mode,
+/// dimension-only placeholder datasets get no node.
+fn build_dataset_node(
+    wrapped_ds: DSType,
+    netcdf: bool,
+    matlab: bool,
+) -> Result<Option<Node>, hdf5_metno::Error> {
     let (parent, name, is_link, link_name, display_name, broken_node) = match wrapped_ds {
         DSType::Hard(parent, name) => (Some(parent), Some(name.clone()), false, None, name, None),
         DSType::Linked(parent, link_name) => (
@@ -484,17 +747,33 @@ fn build_dataset_node(wrapped_ds: DSType) -> Result<Option<Node>, hdf5_metno::Er
     let (Some(parent), Some(name)) = (parent, name) else {
         return Ok(None);
     };
-    let is_compound_container = parent
-        .dataset(&name)
-        .ok()
+    let dataset = parent.dataset(&name).ok();
+    if netcdf && dataset.as_ref().is_some_and(is_dimension_placeholder) {
+        return Ok(None);
+    }
+    let is_compound_container = dataset
+        .as_ref()
         .and_then(|dataset| dataset.dtype().ok())
         .is_some_and(|dtype| unsafe { H5Tget_class(dtype.id()) == H5T_COMPOUND });
+    let dimension_names = if netcdf {
+        dataset.as_ref().and_then(netcdf_dimension_names)
+    } else {
+        None
+    };
+    let matlab = if matlab {
+        dataset.as_ref().and_then(read_matlab_variable)
+    } else {
+        None
+    };
     let identity = DatasetIdentity {
         display_name,
         is_link,
         link_name,
         path: format!("{}/{}", parent.name().trim_end_matches('/'), name),
         is_compound_container,
+        dimension_names,
+        matlab,
+        alias: None,
     };
     Ok(Some(Node::Dataset(
         DatasetHandle::Pending { parent, name },
@@ -508,9 +787,19 @@ fn materialize_dataset_meta(
 ) -> Result<DatasetMeta, hdf5_metno::Error> {
     let dtype = dataset.dtype()?;
     let data_bytesize = dtype.size();
-    let (dtype_desc, unsupported_reason) = match dtype.to_descriptor() {
-        Ok(dtype_desc) => (dtype_desc, None),
-        Err(err) => (TypeDescriptor::VarLenAscii, Some(err.to_string())),
+    let float16 = Float16Type::read(dataset);
+    let bitfield = BitfieldType::read(dataset);
+    let (dtype_desc, unsupported_reason) = match (dtype.to_descriptor(), float16, &bitfield) {
+        (Ok(dtype_desc), _, _) => (dtype_desc, None),
+        // 16-bit floats are widened: HDF5 converts float storage on read and
+        // opaque storage is decoded by h5v.
+        (Err(_), Some(_), _) => (TypeDescriptor::Float(FloatSize::U4), None),
+        // Bitfields are read as raw words and decoded into flags by h5v.
+        (Err(_), None, Some(bitfield)) => (
+            TypeDescriptor::Unsigned(IntSize::from_int(bitfield.size).unwrap_or(IntSize::U8)),
+            None,
+        ),
+        (Err(err), None, None) => (TypeDescriptor::VarLenAscii, Some(err.to_string())),
     };
 
     let mut shape = dataset.shape();
@@ -526,7 +815,7 @@ fn materialize_dataset_meta(
         }
         _ => None,
     };
-    let meta = build_dataset_meta(
+    let mut meta = build_dataset_meta(
         &dataset,
         identity.display_name,
         identity.is_link,
@@ -538,9 +827,75 @@ fn materialize_dataset_meta(
         total_elems,
         unsupported_reason,
     )?;
+    meta.dimension_names = identity.dimension_names;
+    if let Some(float16) = float16 {
+        meta.data_type = float16.name().to_string();
+        meta.float16 = Some(float16);
+    }
+    meta.time = TimeEncoding::read(dataset);
+    meta.committed_type = committed_type_path(dataset);
+    if let Some(bitfield) = bitfield {
+        meta.data_type = bitfield.name();
+        meta.matrixable = Some(MatrixRenderType::Bitfield);
+        meta.image = None;
+        meta.cf_decoding = None;
+        meta.bitfield = Some(bitfield);
+    }
+    if let Some(matlab) = &identity.matlab {
+        // Characters, empty arrays and references have no numeric view; the
+        // preview decodes them instead.
+        if matlab.is_char() || matlab.empty || matlab.references {
+            meta.matrixable = None;
+            meta.image = None;
+            meta.cf_decoding = None;
+        }
+    }
+    meta.matlab = identity.matlab;
+    meta.alias = identity.alias;
     Ok(meta)
 }
 
+/// Children of a MATLAB cell or struct-array field: the objects its references
+/// point to, named by their MATLAB subscript.
+fn matlab_element_children(
+    dataset: &Dataset,
+    cell: bool,
+    dims: &[usize],
+) -> Result<Vec<Rc<RefCell<H5FNode>>>, hdf5_metno::Error> {
+    let file = dataset.file()?;
+    let references = dataset.read_raw::<ObjectReference1>()?;
+    let mut out = Vec::with_capacity(references.len());
+    for (index, reference) in references.iter().enumerate() {
+        let label = matlab_element_label(index, dims, cell);
+        let node = match file.dereference(reference) {
+            Ok(ReferencedObject::Group(group)) => Some(build_group_node(group, false, label, true)),
+            Ok(ReferencedObject::Dataset(target)) => {
+                let path = target.name();
+                let (parent_path, name) = path.rsplit_once('/').unwrap_or(("", path.as_str()));
+                let parent = file.group(if parent_path.is_empty() {
+                    "/"
+                } else {
+                    parent_path
+                })?;
+                build_dataset_node(DSType::Hard(parent, name.to_string()), false, true)?.map(
+                    |mut node| {
+                        if let Node::Dataset(_, DatasetMetaState::Pending(identity)) = &mut node {
+                            identity.display_name = label.clone();
+                        }
+                        node
+                    },
+                )
+            }
+            Ok(ReferencedObject::Datatype(_)) => None,
+            Err(_) => Some(Node::Broken(label)),
+        };
+        if let Some(node) = node {
+            out.push(Rc::new(RefCell::new(H5FNode::new(node))));
+        }
+    }
+    Ok(out)
+}
+
 fn synthetic_compound_children(
     dataset: &Dataset,
     meta: &DatasetMeta,
@@ -601,6 +956,10 @@ fn build_dataset_meta(
         .as_ref()
         .and_then(|projection| projection.current_compound_type())
         .is_some();
+    let complex = compound_projection
+        .as_ref()
+        .and_then(|projection| projection.complex)
+        .filter(|complex| complex.part.is_none());
     let is_unsupported = unsupported_reason.is_some();
     let total_bytes = data_bytesize * total_elems;
     let storage_required = dataset.storage_size();
@@ -622,6 +981,16 @@ fn build_dataset_meta(
             &display_name,
         )
     };
+    let cf_decoding = match type_descriptor {
+        hdf5_metno::types::TypeDescriptor::Integer(_)
+        | hdf5_metno::types::TypeDescriptor::Unsigned(_)
+        | hdf5_metno::types::TypeDescriptor::Float(_)
+            if !is_unsupported && compound_projection.is_none() =>
+        {
+            CfDecoding::read(dataset)
+        }
+        _ => None,
+    };
     let enum_render_overrides = if is_unsupported {
         None
     } else {
@@ -631,10 +1000,11 @@ fn build_dataset_meta(
     Ok(DatasetMeta {
         hl,
         shape,
-        data_type: unsupported_reason
-            .as_ref()
-            .map(|_| format!("opaque[{data_bytesize} bytes]"))
-            .unwrap_or_else(|| sprint_typedescriptor(&type_descriptor)),
+        data_type: match (&unsupported_reason, complex) {
+            (Some(_), _) => format!("opaque[{data_bytesize} bytes]"),
+            (None, Some(complex)) => complex.layout.type_name(),
+            (None, None) => sprint_typedescriptor(&type_descriptor),
+        },
         unsupported_reason,
         type_descriptor: type_descriptor.clone(),
         display_name,
@@ -648,6 +1018,8 @@ fn build_dataset_meta(
             Some(MatrixRenderType::Opaque)
         } else if is_compound_container {
             None
+        } else if complex.is_some() {
+            Some(MatrixRenderType::Float64)
         } else if compound_projection.is_some() {
             projected_matrixable(&type_descriptor)
         } else {
@@ -663,6 +1035,16 @@ fn build_dataset_meta(
         is_link,
         filename,
         compound_projection,
+        storage: read_dataset_storage(dataset),
+        virtual_sources: read_virtual_sources(dataset),
+        cf_decoding,
+        dimension_names: None,
+        matlab: None,
+        float16: None,
+        bitfield: None,
+        time: None,
+        committed_type: None,
+        alias: None,
     })
 }
 
@@ -801,18 +1183,27 @@ mod tests {
     use std::str::FromStr;
 
     use hdf5_metno::types::{
-        CompoundField, CompoundType, EnumMember, EnumType, IntSize, TypeDescriptor, VarLenUnicode,
+        CompoundField, CompoundType, EnumMember, EnumType, IntSize, TypeDescriptor, VarLenAscii,
+        VarLenUnicode,
     };
     use ratatui::style::Color;
 
     use super::{
         build_dataset_meta, build_dataset_node, enum_render_attr_names, enumerate_group_children,
-        highlight_hint_from_name, parse_enum_color, resolve_enum_render_overrides,
-        resolve_highlight_hint, DSType, ReadOpenMode, RequestedOpenMode, ResolvedOpenMode, H5F,
+        file_canonical_paths, highlight_hint_from_name, parse_enum_color,
+        resolve_enum_render_overrides, resolve_highlight_hint, DSType, ReadOpenMode,
+        RequestedOpenMode, ResolvedOpenMode, H5F,
     };
-    use crate::h5f::{DatasetMetaState, H5FNode, HasName, Node};
+    use crate::h5f::{DatasetHandle, DatasetMetaState, H5FNode, HasName, HasPath, Node};
     use crate::ui::render::MatrixRenderType;
 
+    fn children_of(node: &Node) -> Vec<Node> {
+        let canonical = file_canonical_paths(node);
+        enumerate_group_children(node, canonical.as_ref())
+            .expect("enumerate children")
+            .children
+    }
+
     fn sample_enum() -> EnumType {
         EnumType {
             size: IntSize::U1,
@@ -946,6 +1337,544 @@ mod tests {
         assert_eq!(nested.borrow().children.len(), 1);
     }
 
+    #[test]
+    fn netcdf_files_hide_dimension_placeholders_and_name_dimensions() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        file.new_attr::<VarLenAscii>()
+            .create("_NCProperties")
+            .expect("create _NCProperties")
+            .write_scalar(&VarLenAscii::from_ascii("version=2,netcdf=4.9.2").unwrap())
+            .expect("write _NCProperties");
+        let x = file
+            .new_dataset::<f32>()
+            .shape(2)
+            .create("x")
+            .expect("create placeholder");
+        for (name, value) in [
+            ("CLASS", "DIMENSION_SCALE"),
+            (
+                "NAME",
+                "This is a netCDF dimension but not a netCDF variable         2",
+            ),
+        ] {
+            x.new_attr::<VarLenAscii>()
+                .create(name)
+                .expect("create scale attr")
+                .write_scalar(&VarLenAscii::from_ascii(value).unwrap())
+                .expect("write scale attr");
+        }
+        let signal = file
+            .new_dataset_builder()
+            .with_data(&[1.0_f32, 2.0])
+            .create("signal")
+            .expect("create variable");
+        let x_ref: hdf5_metno::ObjectReference1 = file.reference("x").expect("reference x");
+        signal
+            .new_attr::<hdf5_metno::types::VarLenArray<hdf5_metno::ObjectReference1>>()
+            .shape(1)
+            .create("DIMENSION_LIST")
+            .expect("create DIMENSION_LIST")
+            .write(&[hdf5_metno::types::VarLenArray::from_slice(&[x_ref])])
+            .expect("write DIMENSION_LIST");
+
+        let mut root = H5FNode::new(Node::File(file));
+        root.ensure_expanded().expect("expand root");
+
+        assert_eq!(root.children.len(), 1);
+        let signal = root.children[0].borrow();
+        assert_eq!(signal.name(), "signal");
+        assert_eq!(signal.dimension_names(), Some(&["x".to_string()][..]));
+    }
+
+    #[test]
+    fn matlab_files_hide_refs_and_expand_cells() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let dir = tempfile::tempdir().expect("failed to create temp dir");
+        let path = dir.path().join("vars.mat");
+        let file = hdf5_metno::File::with_options()
+            .with_fcpl(|fcpl| fcpl.userblock(512))
+            .create(&path)
+            .expect("failed to create mat file");
+        let write_class = |location: &hdf5_metno::Location, class: &str| {
+            location
+                .new_attr::<VarLenAscii>()
+                .create("MATLAB_class")
+                .expect("create MATLAB_class")
+                .write_scalar(&VarLenAscii::from_ascii(class).unwrap())
+                .expect("write MATLAB_class");
+        };
+        let refs = file.create_group("#refs#").expect("create #refs#");
+        let element = refs
+            .new_dataset::<f64>()
+            .shape((5, 1))
+            .create("a")
+            .expect("create element");
+        write_class(&element, "double");
+        let settings = file.create_group("settings").expect("create struct");
+        write_class(&settings, "struct");
+        let element_ref: hdf5_metno::ObjectReference1 =
+            file.reference("/#refs#/a").expect("reference element");
+        let settings_ref: hdf5_metno::ObjectReference1 =
+            file.reference("/settings").expect("reference struct");
+        let cell = file
+            .new_dataset_builder()
+            .with_data(&ndarray::arr2(&[[element_ref], [settings_ref]]))
+            .create("c")
+            .expect("create cell");
+        write_class(&cell, "cell");
+        drop((cell, settings, element, refs, file));
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .and_then(|mut mat| {
+                std::io::Write::write_all(&mut mat, b"MATLAB 7.3 MAT-file, Platform: GLNXA64")
+            })
+            .expect("write mat header");
+
+        let file = hdf5_metno::File::open(&path).expect("open mat file");
+        let mut root = H5FNode::new(Node::File(file));
+        root.ensure_expanded().expect("expand root");
+
+        let names = root
+            .children
+            .iter()
+            .map(|child| child.borrow().name())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["settings".to_string(), "c".to_string()]);
+        let cell = root.children[1].clone();
+        assert!(cell.borrow().is_matlab_container());
+        assert_eq!(
+            cell.borrow()
+                .matlab_variable()
+                .map(|matlab| matlab.describe()),
+            Some("1×2 cell".to_string())
+        );
+        cell.borrow_mut().ensure_expanded().expect("expand cell");
+        let cell = cell.borrow();
+        assert_eq!(cell.children.len(), 2);
+        let mut first = cell.children[0].borrow_mut();
+        assert_eq!(first.name(), "{1,1}");
+        let shape = first
+            .ensure_dataset_meta()
+            .expect("element meta")
+            .shape
+            .clone();
+        assert_eq!(shape, vec![5, 1]);
+        assert_eq!((first.selected_row, first.selected_col), (1, 0));
+        first.selected_row = 0;
+        first.selected_col = 1;
+        first.apply_initial_axis_order();
+        assert_eq!((first.selected_row, first.selected_col), (0, 1));
+        assert_eq!(
+            first
+                .ensure_dataset_meta()
+                .expect("element meta")
+                .shape_string(),
+            "1 x 5 = 5"
+        );
+        assert_eq!(cell.children[1].borrow().name(), "{1,2}");
+        assert!(cell.children[1].borrow().is_group());
+    }
+
+    #[test]
+    fn complex_compounds_expand_into_part_views() {
+        #[derive(hdf5_metno::H5Type, Clone, Copy)]
+        #[repr(C)]
+        struct Iq {
+            r: f64,
+            i: f64,
+        }
+
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        file.new_dataset_builder()
+            .with_data(&[Iq { r: 3.0, i: -4.0 }, Iq { r: 0.0, i: 2.0 }])
+            .create("iq")
+            .expect("failed to create complex dataset");
+
+        let mut node = H5FNode::new(
+            children_of(&Node::File(file))
+                .into_iter()
+                .find(|node| node.name() == "iq")
+                .expect("complex dataset"),
+        );
+        let meta = node.ensure_dataset_meta().expect("complex meta").clone();
+        assert!(meta.is_complex_value());
+        assert_eq!(meta.data_type, "complex128");
+        assert!(matches!(meta.matrixable, Some(MatrixRenderType::Float64)));
+        let Node::Dataset(DatasetHandle::Loaded(dataset), _) = &node.node else {
+            panic!("dataset handle should be loaded");
+        };
+        let dataset = dataset.clone();
+        assert_eq!(
+            crate::h5f::read_projected_values_1d::<String>(
+                &dataset,
+                &meta,
+                hdf5_metno::Selection::All
+            )
+            .expect("read complex values")
+            .to_vec(),
+            vec!["3-4j".to_string(), "0+2j".to_string()]
+        );
+        assert_eq!(
+            crate::h5f::read_projected_values_1d::<f64>(
+                &dataset,
+                &meta,
+                hdf5_metno::Selection::All
+            )
+            .expect("read magnitudes")
+            .to_vec(),
+            vec![5.0, 2.0]
+        );
+
+        node.expand().expect("expand complex dataset");
+        let names = node
+            .children
+            .iter()
+            .map(|child| child.borrow().name())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["real", "imag", "magnitude", "phase", "dB"]);
+        let imag = node.children[1]
+            .borrow_mut()
+            .ensure_dataset_meta()
+            .expect("imag meta")
+            .clone();
+        assert!(imag.is_compound_leaf());
+        assert_eq!(
+            crate::h5f::read_projected_values_1d::<f64>(
+                &dataset,
+                &imag,
+                hdf5_metno::Selection::All
+            )
+            .expect("read imaginary parts")
+            .to_vec(),
+            vec![-4.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn half_float_datasets_are_widened_to_f64() {
+        use hdf5_metno_sys::{
+            h5d::{H5Dclose, H5Dcreate2, H5Dwrite},
+            h5p::H5P_DEFAULT,
+            h5s::{H5Sclose, H5Screate_simple, H5S_ALL},
+            h5t::{
+                H5Tclose, H5Tcopy, H5Tset_ebias, H5Tset_fields, H5Tset_size, H5T_IEEE_F32LE,
+                H5T_NATIVE_DOUBLE,
+            },
+        };
+
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let values = [1.0_f64, -2.5, 65504.0];
+        let name = std::ffi::CString::new("half").unwrap();
+        unsafe {
+            let dtype = H5Tcopy(*H5T_IEEE_F32LE);
+            assert!(H5Tset_fields(dtype, 15, 10, 5, 0, 10) >= 0);
+            assert!(H5Tset_size(dtype, 2) >= 0);
+            assert!(H5Tset_ebias(dtype, 15) >= 0);
+            let dims = [values.len() as u64];
+            let space = H5Screate_simple(1, dims.as_ptr(), std::ptr::null());
+            let dataset = H5Dcreate2(
+                file.id(),
+                name.as_ptr(),
+                dtype,
+                space,
+                H5P_DEFAULT,
+                H5P_DEFAULT,
+                H5P_DEFAULT,
+            );
+            assert!(dataset >= 0);
+            assert!(
+                H5Dwrite(
+                    dataset,
+                    *H5T_NATIVE_DOUBLE,
+                    H5S_ALL,
+                    H5S_ALL,
+                    H5P_DEFAULT,
+                    values.as_ptr().cast(),
+                ) >= 0
+            );
+            H5Dclose(dataset);
+            H5Sclose(space);
+            H5Tclose(dtype);
+        }
+
+        let mut node = H5FNode::new(
+            children_of(&Node::File(file))
+                .into_iter()
+                .find(|node| node.name() == "half")
+                .expect("half dataset"),
+        );
+        let meta = node.ensure_dataset_meta().expect("half meta").clone();
+        assert_eq!(meta.data_type, "f16");
+        assert!(meta.unsupported_reason.is_none());
+        assert!(matches!(meta.matrixable, Some(MatrixRenderType::Float64)));
+        let Node::Dataset(DatasetHandle::Loaded(dataset), _) = &node.node else {
+            panic!("dataset handle should be loaded");
+        };
+        assert_eq!(
+            crate::data::read_f64_values::<ndarray::Ix1>(dataset, hdf5_metno::Selection::All)
+                .expect("read half values")
+                .to_vec(),
+            values.to_vec()
+        );
+    }
+
+    #[test]
+    fn committed_datatypes_are_tree_nodes_named_by_their_datasets() {
+        use hdf5_metno_sys::{
+            h5d::{H5Dclose, H5Dcreate2},
+            h5p::H5P_DEFAULT,
+            h5s::{H5Sclose, H5Screate_simple},
+            h5t::{H5Tclose, H5Tcommit2, H5Tcopy, H5T_STD_I32LE},
+        };
+
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let types = file.create_group("types").expect("failed to create group");
+        let type_name = std::ffi::CString::new("counter_t").unwrap();
+        let dataset_name = std::ffi::CString::new("counts").unwrap();
+        unsafe {
+            let dtype = H5Tcopy(*H5T_STD_I32LE);
+            assert!(
+                H5Tcommit2(
+                    types.id(),
+                    type_name.as_ptr(),
+                    dtype,
+                    H5P_DEFAULT,
+                    H5P_DEFAULT,
+                    H5P_DEFAULT,
+                ) >= 0
+            );
+            let dims = [3_u64];
+            let space = H5Screate_simple(1, dims.as_ptr(), std::ptr::null());
+            let dataset = H5Dcreate2(
+                file.id(),
+                dataset_name.as_ptr(),
+                dtype,
+                space,
+                H5P_DEFAULT,
+                H5P_DEFAULT,
+                H5P_DEFAULT,
+            );
+            assert!(dataset >= 0);
+            H5Dclose(dataset);
+            H5Sclose(space);
+            H5Tclose(dtype);
+        }
+
+        let root_children = children_of(&Node::File(file));
+        let types = root_children
+            .iter()
+            .find(|node| node.name() == "types")
+            .expect("types group");
+        let type_children = children_of(types);
+        assert_eq!(type_children.len(), 1);
+        let Node::Datatype(_, meta) = &type_children[0] else {
+            panic!("committed type should be a datatype node");
+        };
+        assert_eq!(meta.display_name, "counter_t");
+        assert_eq!(meta.data_type, "i32");
+        assert_eq!(meta.size, 4);
+        assert!(!meta.is_link);
+        assert_eq!(type_children[0].path(), "/types/counter_t");
+
+        let mut counts = H5FNode::new(
+            root_children
+                .into_iter()
+                .find(|node| node.name() == "counts")
+                .expect("counts dataset"),
+        );
+        let meta = counts.ensure_dataset_meta().expect("counts meta");
+        assert_eq!(meta.committed_type.as_deref(), Some("/types/counter_t"));
+        assert_eq!(meta.data_type, "i32");
+    }
+
+    #[test]
+    fn children_record_link_creation_order_when_the_group_tracks_it() {
+        use hdf5_metno_sys::{
+            h5g::{H5Gclose, H5Gcreate2},
+            h5p::{
+                H5Pclose, H5Pcreate, H5Pset_link_creation_order, H5P_CLS_GROUP_CREATE,
+                H5P_CRT_ORDER_INDEXED, H5P_CRT_ORDER_TRACKED, H5P_DEFAULT,
+            },
+        };
+
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let group_name = std::ffi::CString::new("ordered").unwrap();
+        unsafe {
+            let gcpl = H5Pcreate(*H5P_CLS_GROUP_CREATE);
+            assert!(
+                H5Pset_link_creation_order(gcpl, H5P_CRT_ORDER_TRACKED | H5P_CRT_ORDER_INDEXED)
+                    >= 0
+            );
+            let group = H5Gcreate2(
+                file.id(),
+                group_name.as_ptr(),
+                H5P_DEFAULT,
+                gcpl,
+                H5P_DEFAULT,
+            );
+            assert!(group >= 0);
+            H5Gclose(group);
+            H5Pclose(gcpl);
+        }
+        let ordered = file.group("ordered").expect("ordered group");
+        for name in ["zeta", "alpha", "mid"] {
+            ordered
+                .new_dataset::<i32>()
+                .shape([1])
+                .create(name)
+                .expect("failed to create dataset");
+        }
+        ordered
+            .create_group("nested")
+            .expect("failed to create group");
+        file.new_dataset::<i32>()
+            .shape([1])
+            .create("untracked")
+            .expect("failed to create dataset");
+
+        let mut root = H5FNode::new(Node::File(file));
+        root.read_children().expect("read root");
+        assert!(root
+            .children
+            .iter()
+            .all(|child| child.borrow().creation_order.is_none()));
+        let ordered = root
+            .children
+            .iter()
+            .find(|child| child.borrow().name() == "ordered")
+            .expect("ordered group")
+            .clone();
+        let mut ordered = ordered.borrow_mut();
+        ordered.read_children().expect("read ordered group");
+        let orders = ordered
+            .children
+            .iter()
+            .map(|child| (child.borrow().name(), child.borrow().creation_order))
+            .collect::<std::collections::HashMap<_, _>>();
+        assert_eq!(orders["zeta"], Some(0));
+        assert_eq!(orders["alpha"], Some(1));
+        assert_eq!(orders["mid"], Some(2));
+        assert_eq!(orders["nested"], Some(3));
+    }
+
+    #[test]
+    fn hard_link_aliases_name_their_canonical_path_and_cycles_stay_closed() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let group = file.create_group("group").expect("failed to create group");
+        group
+            .new_dataset::<i32>()
+            .shape([2])
+            .create("data")
+            .expect("failed to create dataset");
+        file.link_hard("group/data", "shortcut")
+            .expect("failed to link dataset");
+        group
+            .link_hard("/group", "loop")
+            .expect("failed to link group");
+
+        let root_children = children_of(&Node::File(file));
+        let group = root_children
+            .iter()
+            .find(|node| node.name() == "group")
+            .expect("group");
+        let Node::Group(_, group_meta) = group else {
+            panic!("group should be a group node");
+        };
+        assert!(group_meta.alias.is_none());
+        let mut shortcut = H5FNode::new(
+            root_children
+                .iter()
+                .find(|node| node.name() == "shortcut")
+                .cloned()
+                .expect("shortcut"),
+        );
+        let alias = shortcut.alias().expect("shortcut is an alias");
+        assert_eq!(alias.canonical_path, "/group/data");
+        assert!(!alias.cycle);
+
+        let group_children = children_of(group);
+        let data = H5FNode::new(
+            group_children
+                .iter()
+                .find(|node| node.name() == "data")
+                .cloned()
+                .expect("data"),
+        );
+        assert!(data.alias().is_none(), "first path reached depth-first");
+
+        let mut cycle = H5FNode::new(
+            group_children
+                .into_iter()
+                .find(|node| node.name() == "loop")
+                .expect("loop"),
+        );
+        let alias = cycle.alias().expect("loop is an alias");
+        assert_eq!(alias.canonical_path, "/group");
+        assert!(alias.cycle);
+        assert!(!cycle.is_expandable());
+        cycle.read_children().expect("read cycle");
+        assert!(cycle.children.is_empty());
+
+        let meta = shortcut.ensure_dataset_meta().expect("shortcut meta");
+        assert_eq!(
+            meta.alias
+                .as_ref()
+                .map(|alias| alias.canonical_path.as_str()),
+            Some("/group/data")
+        );
+    }
+
+    #[test]
+    fn aliases_read_before_the_canonical_paths_are_marked_once_they_arrive() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        file.create_group("group")
+            .expect("failed to create group")
+            .new_dataset::<i32>()
+            .shape([2])
+            .create("data")
+            .expect("failed to create dataset");
+        file.link_hard("group/data", "shortcut")
+            .expect("failed to link dataset");
+
+        let enumerated =
+            enumerate_group_children(&Node::File(file.clone()), None).expect("enumerate root");
+        assert!(enumerated.unmarked_aliases);
+        let mut root = H5FNode::new(Node::File(file.clone()));
+        root.apply_enumerated_children(enumerated.children, &Default::default());
+        let shortcut = root
+            .children
+            .iter()
+            .find(|child| child.borrow().name() == "shortcut")
+            .expect("shortcut")
+            .clone();
+        assert!(shortcut.borrow().alias().is_none());
+
+        let canonical = file_canonical_paths(&root.node).expect("canonical paths");
+        root.mark_aliases(&canonical);
+        assert_eq!(
+            shortcut
+                .borrow()
+                .alias()
+                .map(|alias| alias.canonical_path.clone()),
+            Some("/group/data".to_string())
+        );
+    }
+
     #[test]
     fn unread_groups_keep_a_direct_child_count() {
         let _guard = crate::test_support::hdf5_test_guard();
@@ -1114,6 +2043,7 @@ mod tests {
                 ],
                 field_type: TypeDescriptor::Enum(sample_enum()),
                 virtual_path: "/scan/status/evaluation-level".to_string(),
+                complex: None,
             }),
         );
         assert_eq!(
@@ -1234,10 +2164,14 @@ mod tests {
         file.link_soft("/target", "alias")
             .expect("failed to create alias");
 
-        let node = build_dataset_node(DSType::Linked(
-            file.as_group().expect("open root group"),
-            "alias".to_string(),
-        ))
+        let node = build_dataset_node(
+            DSType::Linked(
+                file.as_group().expect("open root group"),
+                "alias".to_string(),
+            ),
+            false,
+            false,
+        )
         .expect("failed to build linked dataset node")
         .expect("expected linked dataset node");
 
@@ -1307,7 +2241,7 @@ mod tests {
             .create("values")
             .expect("failed to create ordinary dataset");
 
-        let children = enumerate_group_children(&Node::File(file)).expect("enumerate root");
+        let children = children_of(&Node::File(file));
         let compound = H5FNode::new(
             children
                 .iter()
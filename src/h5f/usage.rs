@@ -0,0 +1,228 @@
+use std::collections::HashSet;
+
+use hdf5_metno::{Dataset, Group, LinkType, LocationType};
+
+use super::ObjectIdentity;
+
+/// Bytes taken by the datasets of a subtree.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UsageTotals {
+    /// Bytes allocated in the file, after filters.
+    pub storage: u64,
+    /// Bytes the values take once read, elements times element size.
+    pub logical: u64,
+    pub datasets: usize,
+}
+
+impl UsageTotals {
+    fn add(&mut self, other: &Self) {
+        self.storage += other.storage;
+        self.logical += other.logical;
+        self.datasets += other.datasets;
+    }
+
+    /// Logical over allocated bytes; above 1 the subtree is compressed.
+    pub fn ratio(&self) -> Option<f64> {
+        (self.storage > 0 && self.logical > 0).then(|| self.logical as f64 / self.storage as f64)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageEntry {
+    pub name: String,
+    pub is_group: bool,
+    pub totals: UsageTotals,
+}
+
+/// Space used below a group, broken down by direct member, largest allocation
+/// first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageReport {
+    pub path: String,
+    pub totals: UsageTotals,
+    pub children: Vec<UsageEntry>,
+}
+
+/// Sums the datasets below `group`. Only hard links are followed and an object
+/// reached through several of them is counted once, under the first member
+/// that reaches it in name order. `progress` is called with the running totals
+/// after each dataset; returning `false` stops the walk and yields `None`.
+pub fn read_usage(
+    group: &Group,
+    progress: &mut dyn FnMut(&UsageTotals) -> bool,
+) -> Result<Option<UsageReport>, hdf5_metno::Error> {
+    let mut walk = UsageWalk {
+        seen: HashSet::new(),
+        running: UsageTotals::default(),
+        progress,
+        cancelled: false,
+    };
+    if let Some(identity) = ObjectIdentity::of(group) {
+        walk.seen.insert(identity);
+    }
+    let mut children = Vec::new();
+    for (name, kind) in hard_members(group)? {
+        if let Some(entry) = walk.member(group, &name, kind) {
+            children.push(entry);
+        }
+    }
+    if walk.cancelled {
+        return Ok(None);
+    }
+    children.sort_by(|a, b| {
+        b.totals
+            .storage
+            .cmp(&a.totals.storage)
+            .then_with(|| b.totals.logical.cmp(&a.totals.logical))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    let mut totals = UsageTotals::default();
+    for child in &children {
+        totals.add(&child.totals);
+    }
+    Ok(Some(UsageReport {
+        path: group.name(),
+        totals,
+        children,
+    }))
+}
+
+fn hard_members(group: &Group) -> Result<Vec<(String, LocationType)>, hdf5_metno::Error> {
+    group.iter_visit_default(vec![], |group, name, link, members| {
+        if link.link_type == LinkType::Hard {
+            if let Ok(kind) = group.loc_type_by_name(name) {
+                members.push((name.to_string(), kind));
+            }
+        }
+        true
+    })
+}
+
+struct UsageWalk<'a> {
+    seen: HashSet<ObjectIdentity>,
+    running: UsageTotals,
+    progress: &'a mut dyn FnMut(&UsageTotals) -> bool,
+    cancelled: bool,
+}
+
+impl UsageWalk<'_> {
+    fn member(&mut self, group: &Group, name: &str, kind: LocationType) -> Option<UsageEntry> {
+        if self.cancelled {
+            return None;
+        }
+        let is_group = match kind {
+            LocationType::Group => true,
+            LocationType::Dataset => false,
+            _ => return None,
+        };
+        let first_visit =
+            ObjectIdentity::by_name(group, name).is_none_or(|identity| self.seen.insert(identity));
+        if !first_visit {
+            return None;
+        }
+        let totals = if is_group {
+            self.group_totals(&group.group(name).ok()?)
+        } else {
+            self.dataset_totals(&group.dataset(name).ok()?)
+        };
+        Some(UsageEntry {
+            name: name.to_string(),
+            is_group,
+            totals,
+        })
+    }
+
+    fn group_totals(&mut self, group: &Group) -> UsageTotals {
+        let mut totals = UsageTotals::default();
+        for (name, kind) in hard_members(group).unwrap_or_default() {
+            if let Some(entry) = self.member(group, &name, kind) {
+                totals.add(&entry.totals);
+            }
+        }
+        totals
+    }
+
+    fn dataset_totals(&mut self, dataset: &Dataset) -> UsageTotals {
+        let element_size = dataset.dtype().map_or(0, |dtype| dtype.size() as u64);
+        let totals = UsageTotals {
+            storage: dataset.storage_size(),
+            logical: element_size * dataset.size() as u64,
+            datasets: 1,
+        };
+        self.running.add(&totals);
+        if !(self.progress)(&self.running) {
+            self.cancelled = true;
+        }
+        totals
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::{read_usage, UsageTotals};
+
+    #[test]
+    fn usage_sums_subtrees_once_and_sorts_by_allocation() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let raw = file.create_group("raw").expect("failed to create group");
+        raw.new_dataset_builder()
+            .with_data(&[1.5_f64; 1000])
+            .create("samples")
+            .expect("failed to create dataset");
+        let nested = raw.create_group("nested").expect("failed to create group");
+        nested
+            .new_dataset_builder()
+            .with_data(&[7_i32; 100])
+            .create("counts")
+            .expect("failed to create dataset");
+        file.new_dataset_builder()
+            .chunk(1000)
+            .deflate(9)
+            .with_data(&[0_u8; 4000])
+            .create("zeros")
+            .expect("failed to create dataset");
+        file.link_hard("raw/samples", "samples_again")
+            .expect("failed to link dataset");
+        nested
+            .link_hard("/raw", "up")
+            .expect("failed to link group");
+
+        let mut calls = 0;
+        let report = read_usage(&file, &mut |_| {
+            calls += 1;
+            true
+        })
+        .expect("usage read")
+        .expect("usage not cancelled");
+
+        assert_eq!(calls, 3);
+        assert_eq!(report.path, "/");
+        let names = report
+            .children
+            .iter()
+            .map(|child| child.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["raw", "zeros"]);
+        assert_eq!(
+            report.children[0].totals,
+            UsageTotals {
+                storage: 8400,
+                logical: 8400,
+                datasets: 2,
+            }
+        );
+        let zeros = report.children[1].totals;
+        assert_eq!(zeros.logical, 4000);
+        assert!(zeros.storage < zeros.logical);
+        assert!(zeros.ratio().expect("compressed ratio") > 1.0);
+        assert_eq!(report.totals.datasets, 3);
+        assert_eq!(report.totals.logical, 12400);
+
+        let cancelled = read_usage(&file, &mut |_| false).expect("usage read");
+        assert!(cancelled.is_none());
+    }
+}
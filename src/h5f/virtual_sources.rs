@@ -0,0 +1,255 @@
+use std::{
+    collections::HashMap,
+    env,
+    path::{Path, PathBuf},
+};
+
+use hdf5_metno::{
+    dataset::{Layout, VirtualMapping},
+    Dataset, File,
+};
+
+const SAME_FILE: &str = ".";
+const VDS_PREFIX_ENV: &str = "HDF5_VDS_PREFIX";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VirtualSourceStatus {
+    Resolved,
+    /// Filename uses a `%b` block pattern and expands to many files at read time.
+    Pattern,
+    MissingFile,
+    MissingDataset,
+    Unreadable(String),
+}
+
+impl VirtualSourceStatus {
+    pub fn is_unresolved(&self) -> bool {
+        matches!(
+            self,
+            Self::MissingFile | Self::MissingDataset | Self::Unreadable(_)
+        )
+    }
+
+    pub fn label(&self) -> Option<String> {
+        match self {
+            Self::Resolved => None,
+            Self::Pattern => Some("pattern".to_string()),
+            Self::MissingFile => Some("missing file".to_string()),
+            Self::MissingDataset => Some("missing dataset".to_string()),
+            Self::Unreadable(error) => Some(format!("unreadable: {error}")),
+        }
+    }
+}
+
+/// One mapping of a virtual dataset, with its source file resolved the way the
+/// HDF5 library would look it up when reading.
+#[derive(Debug, Clone)]
+pub struct VirtualSource {
+    pub src_filename: String,
+    pub src_dataset: String,
+    pub src_selection: String,
+    pub vds_selection: String,
+    /// `None` when the source lives in the same file as the virtual dataset.
+    pub resolved_file: Option<PathBuf>,
+    pub searched: Vec<PathBuf>,
+    pub status: VirtualSourceStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VirtualSourceTarget {
+    SameFile(String),
+    OtherFile { file: PathBuf, dataset: String },
+}
+
+impl VirtualSource {
+    pub fn is_same_file(&self) -> bool {
+        self.src_filename == SAME_FILE
+    }
+
+    pub fn describe(&self) -> String {
+        let mut text = format!(
+            "{}:{} {} → {}",
+            self.src_filename, self.src_dataset, self.src_selection, self.vds_selection
+        );
+        if let Some(label) = self.status.label() {
+            text.push_str(&format!(" [{label}]"));
+        }
+        text
+    }
+
+    pub fn navigation_target(&self) -> Option<VirtualSourceTarget> {
+        if self.status.is_unresolved() || self.status == VirtualSourceStatus::Pattern {
+            return None;
+        }
+        match &self.resolved_file {
+            None => Some(VirtualSourceTarget::SameFile(self.src_dataset.clone())),
+            Some(file) => Some(VirtualSourceTarget::OtherFile {
+                file: file.clone(),
+                dataset: self.src_dataset.clone(),
+            }),
+        }
+    }
+}
+
+/// Candidate locations for a source file, in the order HDF5 searches them:
+/// the `HDF5_VDS_PREFIX` directory, then the directory of the virtual dataset's
+/// file, then the working directory.
+pub fn virtual_source_candidates(src_filename: &str, vds_file: &Path) -> Vec<PathBuf> {
+    let src = Path::new(src_filename);
+    if src.is_absolute() {
+        return vec![src.to_path_buf()];
+    }
+    let mut candidates = vec![];
+    if let Some(prefix) = env::var_os(VDS_PREFIX_ENV).filter(|prefix| !prefix.is_empty()) {
+        candidates.push(PathBuf::from(prefix).join(src));
+    }
+    if let Some(dir) = vds_file.parent() {
+        candidates.push(dir.join(src));
+    }
+    candidates.push(src.to_path_buf());
+    candidates.dedup();
+    candidates
+}
+
+fn check_source_dataset(file: &File, dataset: &str) -> VirtualSourceStatus {
+    if !file.link_exists(dataset) {
+        return VirtualSourceStatus::MissingDataset;
+    }
+    match file.dataset(dataset) {
+        Ok(_) => VirtualSourceStatus::Resolved,
+        Err(error) => VirtualSourceStatus::Unreadable(error.to_string()),
+    }
+}
+
+fn resolve_mapping(
+    mapping: VirtualMapping,
+    dataset: &Dataset,
+    vds_file: &Path,
+    opened: &mut HashMap<PathBuf, Result<File, String>>,
+) -> VirtualSource {
+    let mut source = VirtualSource {
+        src_selection: mapping.src_selection.to_string(),
+        vds_selection: mapping.vds_selection.to_string(),
+        src_filename: mapping.src_filename,
+        src_dataset: mapping.src_dataset,
+        resolved_file: None,
+        searched: vec![],
+        status: VirtualSourceStatus::Resolved,
+    };
+    if source.is_same_file() {
+        source.status = match dataset.file() {
+            Ok(file) => check_source_dataset(&file, &source.src_dataset),
+            Err(error) => VirtualSourceStatus::Unreadable(error.to_string()),
+        };
+        return source;
+    }
+    if source.src_filename.contains("%b") {
+        source.status = VirtualSourceStatus::Pattern;
+        return source;
+    }
+
+    source.searched = virtual_source_candidates(&source.src_filename, vds_file);
+    let Some(path) = source.searched.iter().find(|path| path.is_file()).cloned() else {
+        source.status = VirtualSourceStatus::MissingFile;
+        return source;
+    };
+    let path = path.canonicalize().unwrap_or(path);
+    source.status = match opened
+        .entry(path.clone())
+        .or_insert_with(|| File::open(&path).map_err(|error| error.to_string()))
+    {
+        Ok(file) => check_source_dataset(file, &source.src_dataset),
+        Err(error) => VirtualSourceStatus::Unreadable(error.clone()),
+    };
+    source.resolved_file = Some(path);
+    source
+}
+
+/// Source mappings of `dataset`; empty unless it has the virtual layout.
+pub fn read_virtual_sources(dataset: &Dataset) -> Vec<VirtualSource> {
+    let Ok(dcpl) = dataset.dcpl() else {
+        return vec![];
+    };
+    if dcpl.layout() != Layout::Virtual {
+        return vec![];
+    }
+    let vds_file = PathBuf::from(dataset.filename());
+    let mut opened = HashMap::new();
+    dcpl.virtual_map()
+        .into_iter()
+        .map(|mapping| resolve_mapping(mapping, dataset, &vds_file, &mut opened))
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{
+        read_virtual_sources, virtual_source_candidates, VirtualSourceStatus, VirtualSourceTarget,
+    };
+
+    #[test]
+    fn relative_sources_are_searched_next_to_the_virtual_file() {
+        let candidates = virtual_source_candidates("det_01.h5", Path::new("/data/run/master.h5"));
+        assert!(candidates.contains(&PathBuf::from("/data/run/det_01.h5")));
+        assert_eq!(candidates.last(), Some(&PathBuf::from("det_01.h5")));
+        assert_eq!(
+            virtual_source_candidates("/abs/det.h5", Path::new("/data/master.h5")),
+            vec![PathBuf::from("/abs/det.h5")]
+        );
+    }
+
+    #[test]
+    fn flags_missing_sources_and_resolves_present_ones() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let dir = tempfile::tempdir().expect("failed to create temp dir");
+        let source_path = dir.path().join("det_00.h5");
+        {
+            let source = hdf5_metno::File::create(&source_path).expect("create source file");
+            source
+                .new_dataset_builder()
+                .with_data(&[1_u16; 4])
+                .create("data")
+                .expect("create source dataset");
+        }
+
+        let master =
+            hdf5_metno::File::create(dir.path().join("master.h5")).expect("create master file");
+        master
+            .new_dataset_builder()
+            .with_data(&[0_u16; 4])
+            .create("local")
+            .expect("create local dataset");
+        let vds = master
+            .new_dataset::<u16>()
+            .shape(12)
+            .virtual_map("det_00.h5", "/data", 4, .., 12, 0..4)
+            .virtual_map("det_01.h5", "/data", 4, .., 12, 4..8)
+            .virtual_map(".", "/local", 4, .., 12, 8..12)
+            .create("stitched")
+            .expect("create virtual dataset");
+
+        let sources = read_virtual_sources(&vds);
+
+        assert_eq!(sources.len(), 3);
+        assert_eq!(sources[0].status, VirtualSourceStatus::Resolved);
+        assert_eq!(
+            sources[0].navigation_target(),
+            Some(VirtualSourceTarget::OtherFile {
+                file: source_path.canonicalize().unwrap(),
+                dataset: "/data".to_string(),
+            })
+        );
+        assert_eq!(sources[1].status, VirtualSourceStatus::MissingFile);
+        assert!(sources[1].navigation_target().is_none());
+        assert!(sources[1].describe().ends_with("[missing file]"));
+        assert_eq!(sources[2].status, VirtualSourceStatus::Resolved);
+        assert_eq!(
+            sources[2].navigation_target(),
+            Some(VirtualSourceTarget::SameFile("/local".to_string()))
+        );
+    }
+}
@@ -127,6 +127,13 @@ fn resolve_cli_input(path: &Path) -> Result<ResolvedHdf5Input, AppError> {
         });
     }
 
+    if let Some(version) = crate::h5f::legacy_mat_file_version(path) {
+        return Err(AppError::FileError(format!(
+            "'{}' is a MATLAB {version} MAT-file, which is not HDF5. Re-save it with save(..., '-v7.3') to open it",
+            path.display()
+        )));
+    }
+
     match detect_non_hdf5_format(path) {
         Some(format) => {
             let imported_path = import_tabular_file(path, format)?;
@@ -259,6 +266,33 @@ mod tests {
         assert_eq!(resolved[0].link_name, "native.h5");
     }
 
+    #[test]
+    fn resolves_matlab_files_natively_and_rejects_legacy_versions() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempdir().expect("tempdir");
+        let mat_path = temp.path().join("v73.mat");
+        hdf5_metno::File::with_options()
+            .with_fcpl(|fcpl| fcpl.userblock(512))
+            .create(&mat_path)
+            .expect("create mat file");
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&mat_path)
+            .and_then(|mut file| file.write_all(b"MATLAB 7.3 MAT-file, Platform: GLNXA64"))
+            .expect("write mat header");
+        let legacy_path = temp.path().join("v5.mat");
+        std::fs::write(&legacy_path, b"MATLAB 5.0 MAT-file, Platform: GLNXA64")
+            .expect("write legacy mat file");
+
+        let resolved =
+            resolve_cli_inputs(&[mat_path.to_string_lossy().into_owned()]).expect("resolve mat");
+        assert!(!resolved[0].imported);
+        assert!(crate::h5f::is_matlab_file(&mat_path));
+        let error = resolve_cli_inputs(&[legacy_path.to_string_lossy().into_owned()])
+            .expect_err("legacy mat files are not HDF5");
+        assert!(error.to_string().contains("-v7.3"));
+    }
+
     #[test]
     fn resolves_xlsx_input_into_sheet_groups() {
         let _guard = crate::test_support::hdf5_test_guard();
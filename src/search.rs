@@ -5,8 +5,33 @@ use hdf5_metno::Group;
 use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 
+use crate::h5f::{
+    human_bytes, AttributeValue, IndexScope, IndexedAttribute, IndexedDataset, VisitedGroups,
+};
+
+mod metadata_query;
+mod path_cache;
+pub use metadata_query::MetadataQuery;
+pub use path_cache::{load_cached_paths, store_cached_paths, PathCacheKey};
+
+/// Queries starting with this prefix search attributes instead of paths.
+pub const ATTRIBUTE_SCOPE_PREFIX: &str = "attr:";
+
+const MAX_ATTRIBUTE_RESULT_VALUE_CHARS: usize = 80;
+
 pub struct Searcher {
     paths: Vec<String>,
+    /// Cleared while paths are still being streamed in by the background
+    /// walk.
+    pub paths_complete: bool,
+    attributes: Vec<IndexedAttribute>,
+    /// Set once the background walk has read every attribute.
+    pub attributes_complete: bool,
+    /// Objects whose attributes have been read so far.
+    pub attribute_objects: usize,
+    datasets: Vec<IndexedDataset>,
+    /// Set once the background walk has read the metadata of every dataset.
+    pub datasets_complete: bool,
     pub query: String,
     pub line_cursor: usize,
     pub select_cursor: usize,
@@ -18,27 +43,152 @@ impl Debug for Searcher {
     }
 }
 
+/// Paths of every object below `g`. A group reached again through another
+/// link is listed, but its members are listed only once.
 pub fn full_traversal(g: &Group) -> Vec<String> {
-    let traversal_result =
-        g.iter_visit_default(vec![], |group, name, _, acc| match group.group(name) {
-            Ok(g) => {
-                acc.push(g.name());
-                let grand_children = full_traversal(&g);
-                acc.extend(grand_children);
-
-                true
+    let mut paths = Vec::new();
+    visit_paths(g, &mut |path| {
+        paths.push(path);
+        true
+    });
+    paths
+}
+
+/// Calls `visit` with the path of every object below `g`, in the order
+/// [`full_traversal`] lists them. Returning `false` from `visit` stops the
+/// walk, in which case `false` is returned.
+pub fn visit_paths(g: &Group, visit: &mut dyn FnMut(String) -> bool) -> bool {
+    let visited = VisitedGroups::default();
+    visited.enter(g);
+    traverse(g, &visited, visit)
+}
+
+fn traverse(g: &Group, visited: &VisitedGroups, visit: &mut dyn FnMut(String) -> bool) -> bool {
+    let names = g
+        .iter_visit_default(vec![], |_, name, _, names| {
+            names.push(name.to_string());
+            true
+        })
+        .unwrap_or_default();
+    let base_name = if g.name() == "/" {
+        "/".to_string()
+    } else {
+        g.name() + "/"
+    };
+    for name in names {
+        let keep_going = match g.group(&name) {
+            Ok(member) => {
+                visit(member.name())
+                    && (!visited.enter(&member) || traverse(&member, visited, visit))
+            }
+            Err(_) => visit(format!("{base_name}{name}")),
+        };
+        if !keep_going {
+            return false;
+        }
+    }
+    true
+}
+
+/// What a search result opens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchTarget<'a> {
+    Path(&'a str),
+    Attribute(&'a IndexedAttribute),
+    Dataset(&'a IndexedDataset),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ValueQuery {
+    /// `=`: a string, enum member or boolean equal to the text ignoring case,
+    /// or a number equal to it.
+    Equals { text: String, number: Option<f64> },
+    /// `~`: the rendered value contains the text ignoring case.
+    Contains(String),
+}
+
+/// `attr:NAME`, `attr:NAME=VALUE` or `attr:NAME~TEXT`. The name matches any
+/// attribute name containing it, ignoring case, and may be left out to match
+/// on the value alone. Values may be quoted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeQuery {
+    name: String,
+    value: Option<ValueQuery>,
+}
+
+impl AttributeQuery {
+    /// Parses an attribute-scope query; other queries give `None`.
+    pub fn parse(query: &str) -> Option<Self> {
+        let prefix = query.get(..ATTRIBUTE_SCOPE_PREFIX.len())?;
+        if !prefix.eq_ignore_ascii_case(ATTRIBUTE_SCOPE_PREFIX) {
+            return None;
+        }
+        let rest = &query[ATTRIBUTE_SCOPE_PREFIX.len()..];
+        let Some(split) = rest.find(['=', '~']) else {
+            return Some(Self {
+                name: rest.trim().to_lowercase(),
+                value: None,
+            });
+        };
+        let expected = unquote(rest[split + 1..].trim());
+        let value = if rest[split..].starts_with('=') {
+            ValueQuery::Equals {
+                number: expected.parse::<f64>().ok(),
+                text: expected.to_lowercase(),
             }
-            Err(_) => {
-                let base_name = if group.name() == "/" {
-                    "/".to_string()
-                } else {
-                    group.name() + "/"
-                };
-                acc.push(format!("{}{}", base_name, name));
-                true
+        } else {
+            ValueQuery::Contains(expected.to_lowercase())
+        };
+        Some(Self {
+            name: rest[..split].trim().to_lowercase(),
+            value: Some(value),
+        })
+    }
+
+    pub fn matches(&self, attribute: &IndexedAttribute) -> bool {
+        if !attribute.name.to_lowercase().contains(&self.name) {
+            return false;
+        }
+        match &self.value {
+            None => true,
+            Some(ValueQuery::Contains(text)) => attribute.display.to_lowercase().contains(text),
+            // Arrays match when any element does.
+            Some(ValueQuery::Equals { text, number }) => {
+                attribute
+                    .values
+                    .iter()
+                    .any(|value| value_equals(value, text, *number))
+                    || (attribute.values.is_empty()
+                        && attribute.display.trim().to_lowercase() == *text)
             }
-        });
-    traversal_result.unwrap_or_default()
+        }
+    }
+
+    fn is_exact_name(&self, attribute: &IndexedAttribute) -> bool {
+        attribute.name.to_lowercase() == self.name
+    }
+}
+
+fn unquote(text: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|quote| {
+            text.strip_prefix(*quote)
+                .and_then(|inner| inner.strip_suffix(*quote))
+        })
+        .unwrap_or(text)
+}
+
+fn value_equals(value: &AttributeValue, text: &str, number: Option<f64>) -> bool {
+    match value {
+        AttributeValue::Number(value) => number == Some(*value),
+        AttributeValue::Text(value) => value.to_lowercase() == text,
+        AttributeValue::Enum { name, value } => {
+            name.as_ref()
+                .is_some_and(|name| name.to_lowercase() == text)
+                || number == Some(*value as f64)
+        }
+    }
 }
 
 pub(crate) fn fuzzy_match_score(candidate: &str, query: &str) -> Option<i64> {
@@ -139,29 +289,249 @@ fn render_line_with_highlight<'a>(path: &'a str, query: &str) -> Line<'a> {
     Line::from(spans)
 }
 
+fn render_dataset_line<'a>(dataset: &'a IndexedDataset, query: &str) -> Line<'a> {
+    let shape = if dataset.shape.is_empty() {
+        "scalar".to_string()
+    } else {
+        dataset
+            .shape
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" x ")
+    };
+    let mut line = render_line_with_highlight(&dataset.path, query);
+    line.push_span(Span::styled(
+        format!(
+            "  {shape} · {} · {}",
+            dataset.data_type,
+            human_bytes(dataset.bytes)
+        ),
+        Style::default().fg(crate::configure::themed_color(|colors| colors.help.muted)),
+    ));
+    line
+}
+
+fn render_attribute_line(attribute: &IndexedAttribute) -> Line<'_> {
+    let mut value = attribute
+        .display
+        .chars()
+        .take(MAX_ATTRIBUTE_RESULT_VALUE_CHARS)
+        .collect::<String>();
+    if value.len() < attribute.display.len() {
+        value.push('…');
+    }
+    Line::from(vec![
+        Span::styled(
+            attribute.path.as_str(),
+            Style::default().fg(crate::configure::themed_color(|colors| colors.text.primary)),
+        ),
+        Span::styled(
+            format!(" @{}", attribute.name),
+            Style::default().fg(crate::configure::themed_color(|colors| {
+                colors.accent.search_highlight
+            })),
+        ),
+        Span::styled(
+            format!(" = {value}"),
+            Style::default().fg(crate::configure::themed_color(|colors| colors.help.muted)),
+        ),
+    ])
+}
+
 impl Searcher {
     pub fn new(paths: Vec<String>) -> Self {
         Searcher {
             paths,
+            paths_complete: true,
+            attributes: Vec::new(),
+            attributes_complete: false,
+            attribute_objects: 0,
+            datasets: Vec::new(),
+            datasets_complete: false,
             query: String::new(),
             line_cursor: 0,
             select_cursor: 0,
         }
     }
 
+    /// A searcher whose paths are added with [`Searcher::add_paths`] as the
+    /// background walk reads them.
+    pub fn indexing() -> Self {
+        Searcher {
+            paths_complete: false,
+            ..Searcher::new(Vec::new())
+        }
+    }
+
+    pub fn add_paths(&mut self, paths: Vec<String>) {
+        self.paths.extend(paths);
+    }
+
+    /// Paths listed so far.
+    pub fn indexed_paths(&self) -> usize {
+        self.paths.len()
+    }
+
     pub fn count_results(&self) -> usize {
-        self.result_paths(&self.query).len()
+        self.results(&self.query).len()
     }
 
     pub fn result_paths(&self, query: &str) -> Vec<&str> {
         fuzzy_search(&self.paths, query)
     }
 
+    /// Attributes matching an attribute-scope query, exact name matches
+    /// first and otherwise in traversal order.
+    pub fn result_attributes(&self, query: &AttributeQuery) -> Vec<&IndexedAttribute> {
+        let mut results = self
+            .attributes
+            .iter()
+            .filter(|attribute| query.matches(attribute))
+            .collect::<Vec<_>>();
+        results.sort_by_key(|attribute| !query.is_exact_name(attribute));
+        results
+    }
+
+    /// Objects matching a metadata query. Datasets are listed in traversal
+    /// order, or best path matches first when the query has words besides
+    /// its filters. Queries filtering on the path alone list any object.
+    pub fn result_filtered(&self, query: &MetadataQuery) -> Vec<SearchTarget<'_>> {
+        let score = |path: &str| match query.text.as_str() {
+            "" => Some(0),
+            text => fuzzy_match_score(path, text),
+        };
+        let mut results = if query.needs_metadata() {
+            self.datasets
+                .iter()
+                .filter(|dataset| query.matches(dataset))
+                .filter_map(|dataset| Some((score(&dataset.path)?, SearchTarget::Dataset(dataset))))
+                .collect::<Vec<_>>()
+        } else {
+            self.paths
+                .iter()
+                .filter(|path| query.matches_path(path))
+                .filter_map(|path| Some((score(path)?, SearchTarget::Path(path.as_str()))))
+                .collect()
+        };
+        results.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    pub fn results(&self, query: &str) -> Vec<SearchTarget<'_>> {
+        if let Some(query) = AttributeQuery::parse(query) {
+            return self
+                .result_attributes(&query)
+                .into_iter()
+                .map(SearchTarget::Attribute)
+                .collect();
+        }
+        match MetadataQuery::parse(query) {
+            Some(Ok(query)) => self.result_filtered(&query),
+            Some(Err(_)) => Vec::new(),
+            None => self
+                .result_paths(query)
+                .into_iter()
+                .map(SearchTarget::Path)
+                .collect(),
+        }
+    }
+
+    /// Why `query` cannot be run, for metadata queries with a filter that
+    /// does not parse.
+    pub fn query_error(&self, query: &str) -> Option<String> {
+        if AttributeQuery::parse(query).is_some() {
+            return None;
+        }
+        MetadataQuery::parse(query)?.err()
+    }
+
+    /// What `query` needs read of the file besides the paths.
+    pub fn index_scope(query: &str) -> IndexScope {
+        let attributes = AttributeQuery::parse(query).is_some();
+        let datasets = !attributes
+            && MetadataQuery::parse(query)
+                .and_then(Result::ok)
+                .is_some_and(|query| query.needs_metadata());
+        IndexScope {
+            paths: true,
+            attributes,
+            datasets,
+        }
+    }
+
+    /// The parts of `wanted` that have not been read completely.
+    pub fn missing(&self, wanted: IndexScope) -> IndexScope {
+        IndexScope {
+            paths: wanted.paths && !self.paths_complete,
+            attributes: wanted.attributes && !self.attributes_complete,
+            datasets: wanted.datasets && !self.datasets_complete,
+        }
+    }
+
+    /// Whether `query` searches attributes that are still being read.
+    pub fn is_indexing_attributes(&self, query: &str) -> bool {
+        self.missing(Self::index_scope(query)).attributes
+    }
+
+    /// Whether `query` filters on dataset metadata that is still being read.
+    pub fn is_indexing_datasets(&self, query: &str) -> bool {
+        self.missing(Self::index_scope(query)).datasets
+    }
+
+    /// Datasets whose metadata has been read so far.
+    pub fn indexed_datasets(&self) -> usize {
+        self.datasets.len()
+    }
+
+    pub fn add_attributes(&mut self, attributes: Vec<IndexedAttribute>, objects: usize) {
+        self.attributes.extend(attributes);
+        self.attribute_objects += objects;
+    }
+
+    pub fn add_datasets(&mut self, datasets: Vec<IndexedDataset>) {
+        self.datasets.extend(datasets);
+    }
+
+    /// Marks the parts a finished walk read as complete.
+    pub fn complete(&mut self, scope: IndexScope) {
+        self.paths_complete |= scope.paths;
+        self.attributes_complete |= scope.attributes;
+        self.datasets_complete |= scope.datasets;
+    }
+
+    /// Drops what was read of `scope` so the next query needing it reads it
+    /// again.
+    pub fn clear(&mut self, scope: IndexScope) {
+        if scope.paths {
+            self.paths.clear();
+            self.paths_complete = false;
+        }
+        if scope.attributes {
+            self.attributes.clear();
+            self.attributes_complete = false;
+            self.attribute_objects = 0;
+        }
+        if scope.datasets {
+            self.datasets.clear();
+            self.datasets_complete = false;
+        }
+    }
+
     pub fn search(&self, query: &str) -> Vec<Line<'_>> {
+        // Filters of a metadata query take no part in highlighting.
+        let text = match MetadataQuery::parse(query) {
+            Some(Ok(query)) => query.text,
+            _ => query.to_string(),
+        };
         let rendered_lines = self
-            .result_paths(query)
+            .results(query)
             .into_iter()
-            .map(|p| render_line_with_highlight(p, query))
+            .map(|result| match result {
+                SearchTarget::Path(path) => render_line_with_highlight(path, &text),
+                SearchTarget::Attribute(attribute) => render_attribute_line(attribute),
+                SearchTarget::Dataset(dataset) => render_dataset_line(dataset, &text),
+            })
             .collect();
         rendered_lines
     }
@@ -171,7 +541,25 @@ impl Searcher {
 #[allow(clippy::unwrap_used)]
 #[allow(clippy::expect_used)]
 mod tests {
-    use super::{fuzzy_highlight_spans, Searcher};
+    use super::{
+        full_traversal, fuzzy_highlight_spans, visit_paths, AttributeQuery, SearchTarget, Searcher,
+    };
+    use crate::h5f::{
+        AttributeValue, ImageType, IndexedAttribute, IndexedDataset, InterlaceMode, TypeClass,
+    };
+
+    fn attribute(path: &str, name: &str, values: Vec<AttributeValue>) -> IndexedAttribute {
+        IndexedAttribute {
+            path: path.to_string(),
+            name: name.to_string(),
+            display: values
+                .iter()
+                .map(|value| format!("{value:?}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+            values,
+        }
+    }
 
     #[test]
     fn returns_raw_result_paths() {
@@ -185,6 +573,142 @@ mod tests {
         assert_eq!(searcher.search("alph")[0].to_string(), "/alpha");
     }
 
+    #[test]
+    fn attribute_queries_match_names_and_values() {
+        let mut searcher = Searcher::new(vec!["/run".to_string()]);
+        searcher.add_attributes(
+            vec![
+                attribute(
+                    "/run/voltage",
+                    "units",
+                    vec![AttributeValue::Text("mV".into())],
+                ),
+                attribute(
+                    "/run/current",
+                    "units",
+                    vec![AttributeValue::Text("mA".into())],
+                ),
+                attribute(
+                    "/run/voltage",
+                    "valid_range",
+                    vec![AttributeValue::Number(-5.0), AttributeValue::Number(5.0)],
+                ),
+                attribute(
+                    "/run",
+                    "mode",
+                    vec![AttributeValue::Enum {
+                        name: Some("FAST".into()),
+                        value: 2,
+                    }],
+                ),
+                attribute("/run/image", "SUBCLASS", vec![]),
+                attribute("/run/image", "CLASS", vec![]),
+            ],
+            5,
+        );
+        let matched = |query: &str| {
+            searcher
+                .results(query)
+                .into_iter()
+                .map(|result| match result {
+                    SearchTarget::Attribute(attribute) => {
+                        format!("{}@{}", attribute.path, attribute.name)
+                    }
+                    SearchTarget::Path(path) => path.to_string(),
+                    SearchTarget::Dataset(dataset) => dataset.path.clone(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(matched("attr:units=mv"), vec!["/run/voltage@units"]);
+        assert_eq!(matched("attr:units = \"mA\""), vec!["/run/current@units"]);
+        assert_eq!(matched("attr:=5"), vec!["/run/voltage@valid_range"]);
+        assert_eq!(matched("attr:range=-5.0"), vec!["/run/voltage@valid_range"]);
+        assert_eq!(matched("attr:mode=fast"), vec!["/run@mode"]);
+        assert_eq!(matched("attr:mode=2"), vec!["/run@mode"]);
+        assert_eq!(
+            matched("attr:units~m"),
+            vec!["/run/voltage@units", "/run/current@units"]
+        );
+        assert_eq!(
+            matched("ATTR:class"),
+            vec!["/run/image@CLASS", "/run/image@SUBCLASS"]
+        );
+        assert_eq!(matched("run"), vec!["/run"]);
+        assert!(AttributeQuery::parse("units").is_none());
+        assert!(searcher.is_indexing_attributes("attr:units"));
+        assert!(!searcher.is_indexing_attributes("units"));
+    }
+
+    fn dataset(path: &str, shape: Vec<usize>, data_type: &str, class: TypeClass) -> IndexedDataset {
+        let elements = shape.iter().product::<usize>() as u64;
+        IndexedDataset {
+            path: path.to_string(),
+            shape,
+            data_type: data_type.to_string(),
+            class,
+            bytes: elements * 4,
+            storage_bytes: elements * 4,
+            chunked: false,
+            image: None,
+        }
+    }
+
+    #[test]
+    fn metadata_queries_filter_indexed_datasets() {
+        let mut searcher = Searcher::new(vec![
+            "/runs".to_string(),
+            "/runs/a".to_string(),
+            "/runs/a/raw".to_string(),
+            "/runs/a/events".to_string(),
+            "/runs/b".to_string(),
+            "/runs/b/raw".to_string(),
+            "/preview".to_string(),
+        ]);
+        let mut chunked = dataset("/runs/a/raw", vec![512, 512, 1024], "f32", TypeClass::Float);
+        chunked.chunked = true;
+        let mut events = dataset("/runs/a/events", vec![100], "{x, y}", TypeClass::Compound);
+        events.bytes = 1600;
+        let mut preview = dataset("/preview", vec![64, 64, 3], "u8", TypeClass::Unsigned);
+        preview.image = Some(ImageType::Truecolor(InterlaceMode::Pixel));
+        let mut wide = dataset("/runs/b/raw", vec![512, 512, 1024], "f64", TypeClass::Float);
+        wide.bytes *= 2;
+        searcher.add_datasets(vec![chunked, events, wide, preview]);
+        assert!(searcher.is_indexing_datasets("dtype:f32"));
+        searcher.datasets_complete = true;
+        let matched = |query: &str| {
+            searcher
+                .results(query)
+                .into_iter()
+                .map(|result| match result {
+                    SearchTarget::Dataset(dataset) => dataset.path.clone(),
+                    SearchTarget::Path(path) => format!("path {path}"),
+                    SearchTarget::Attribute(attribute) => attribute.name.clone(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(matched("dtype:float32"), vec!["/runs/a/raw"]);
+        assert_eq!(
+            matched("dtype:float rank:>=3"),
+            vec!["/runs/a/raw", "/runs/b/raw"]
+        );
+        assert_eq!(matched("size:>1GiB chunked:no"), vec!["/runs/b/raw"]);
+        assert_eq!(matched("compound:true"), vec!["/runs/a/events"]);
+        assert_eq!(matched("image:truecolor"), vec!["/preview"]);
+        assert_eq!(matched("dtype:f32,f64 b"), vec!["/runs/b/raw"]);
+        assert_eq!(
+            matched("path:/runs/*/raw"),
+            vec!["path /runs/a/raw", "path /runs/b/raw"]
+        );
+        assert!(matched("rank:three").is_empty());
+        assert_eq!(
+            searcher.query_error("rank:three").as_deref(),
+            Some("invalid filter rank:three")
+        );
+        assert!(!searcher.is_indexing_datasets("dtype:f32"));
+    }
+
     #[test]
     fn unicode_highlight_spans_use_byte_boundaries() {
         let spans = fuzzy_highlight_spans("/måling", "ml");
@@ -196,4 +720,38 @@ mod tests {
         let searcher = Searcher::new(vec!["/måling".to_string()]);
         assert_eq!(searcher.search("ml")[0].to_string(), "/måling");
     }
+
+    #[test]
+    fn traversal_stops_at_hard_link_cycles() {
+        let _guard = crate::test_support::hdf5_test_guard();
+        let temp = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        let file = hdf5_metno::File::create(temp.path()).expect("failed to create hdf5 file");
+        let group = file.create_group("group").expect("failed to create group");
+        group
+            .new_dataset::<i32>()
+            .shape([1])
+            .create("data")
+            .expect("failed to create dataset");
+        group
+            .link_hard("/group", "loop")
+            .expect("failed to link group");
+        group
+            .link_soft("/group", "up")
+            .expect("failed to link group");
+
+        let root = file.as_group().expect("root group");
+        let mut paths = full_traversal(&root);
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec!["/group", "/group/data", "/group/loop", "/group/up"]
+        );
+
+        let mut visited = Vec::new();
+        assert!(!visit_paths(&root, &mut |path| {
+            visited.push(path);
+            visited.len() < 2
+        }));
+        assert_eq!(visited.len(), 2);
+    }
 }
@@ -0,0 +1,453 @@
+use crate::h5f::{ImageType, IndexedDataset, TypeClass};
+
+/// Keys of the `key:value` filters a metadata query is made of.
+const FILTER_KEYS: [&str; 8] = [
+    "dtype", "rank", "size", "storage", "chunked", "compound", "image", "path",
+];
+
+/// numpy spellings of the type names h5v shows.
+const DTYPE_ALIASES: [(&str, &str); 14] = [
+    ("int8", "i8"),
+    ("int16", "i16"),
+    ("int32", "i32"),
+    ("int64", "i64"),
+    ("uint8", "u8"),
+    ("uint16", "u16"),
+    ("uint32", "u32"),
+    ("uint64", "u64"),
+    ("float16", "f16"),
+    ("half", "f16"),
+    ("float32", "f32"),
+    ("single", "f32"),
+    ("float64", "f64"),
+    ("double", "f64"),
+];
+
+const IMAGE_KINDS: [&str; 6] = ["truecolor", "indexed", "grayscale", "bitmap", "jpeg", "png"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Equal,
+    GreaterOrEqual,
+    Greater,
+}
+
+/// `>=3`, `<1GiB` or a bare value, which must be equal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Bound {
+    comparison: Comparison,
+    value: u64,
+}
+
+impl Bound {
+    fn parse(text: &str, value: impl Fn(&str) -> Option<u64>) -> Option<Self> {
+        let (comparison, rest) = [
+            ("<=", Comparison::LessOrEqual),
+            (">=", Comparison::GreaterOrEqual),
+            ("<", Comparison::Less),
+            (">", Comparison::Greater),
+            ("=", Comparison::Equal),
+        ]
+        .into_iter()
+        .find_map(|(prefix, comparison)| Some((comparison, text.strip_prefix(prefix)?)))
+        .unwrap_or((Comparison::Equal, text));
+        Some(Self {
+            comparison,
+            value: value(rest.trim())?,
+        })
+    }
+
+    fn admits(&self, value: u64) -> bool {
+        match self.comparison {
+            Comparison::Less => value < self.value,
+            Comparison::LessOrEqual => value <= self.value,
+            Comparison::Equal => value == self.value,
+            Comparison::GreaterOrEqual => value >= self.value,
+            Comparison::Greater => value > self.value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ImageFilter {
+    Any,
+    Absent,
+    Kind(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Filter {
+    /// Any of the listed type names or classes.
+    Dtype(Vec<String>),
+    Rank(Bound),
+    Size(Bound),
+    Storage(Bound),
+    Chunked(bool),
+    Compound(bool),
+    Image(ImageFilter),
+    Path(Vec<char>),
+}
+
+/// Filters over dataset metadata such as `dtype:f32 rank:>=3 size:>1GiB
+/// chunked:no`, optionally mixed with words fuzzy matched against the path.
+/// Every filter must match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetadataQuery {
+    filters: Vec<Filter>,
+    /// Words that are not filters, fuzzy matched against the path.
+    pub text: String,
+}
+
+impl MetadataQuery {
+    /// Parses a query holding at least one filter; other queries give
+    /// `None`. A filter with a value it does not understand gives an error
+    /// naming it.
+    pub fn parse(query: &str) -> Option<Result<Self, String>> {
+        let mut filters = Vec::new();
+        let mut words = Vec::new();
+        for token in query.split_whitespace() {
+            let Some((key, value)) = token
+                .split_once(':')
+                .filter(|(key, _)| FILTER_KEYS.contains(&key.to_lowercase().as_str()))
+            else {
+                words.push(token);
+                continue;
+            };
+            match parse_filter(&key.to_lowercase(), value) {
+                Some(filter) => filters.push(filter),
+                None => return Some(Err(format!("invalid filter {token}"))),
+            }
+        }
+        if filters.is_empty() {
+            return None;
+        }
+        Some(Ok(Self {
+            filters,
+            text: words.join(" "),
+        }))
+    }
+
+    /// Whether any filter needs dataset metadata; queries filtering on the
+    /// path alone match every object.
+    pub fn needs_metadata(&self) -> bool {
+        self.filters
+            .iter()
+            .any(|filter| !matches!(filter, Filter::Path(_)))
+    }
+
+    pub fn matches_path(&self, path: &str) -> bool {
+        let path = path.chars().collect::<Vec<_>>();
+        self.filters.iter().all(|filter| match filter {
+            Filter::Path(pattern) => glob_matches(pattern, &path),
+            _ => true,
+        })
+    }
+
+    pub fn matches(&self, dataset: &IndexedDataset) -> bool {
+        self.matches_path(&dataset.path)
+            && self.filters.iter().all(|filter| match filter {
+                Filter::Dtype(names) => names.iter().any(|name| dtype_matches(name, dataset)),
+                Filter::Rank(bound) => bound.admits(dataset.shape.len() as u64),
+                Filter::Size(bound) => bound.admits(dataset.bytes),
+                Filter::Storage(bound) => bound.admits(dataset.storage_bytes),
+                Filter::Chunked(chunked) => dataset.chunked == *chunked,
+                Filter::Compound(compound) => (dataset.class == TypeClass::Compound) == *compound,
+                Filter::Image(ImageFilter::Any) => dataset.image.is_some(),
+                Filter::Image(ImageFilter::Absent) => dataset.image.is_none(),
+                Filter::Image(ImageFilter::Kind(kind)) => {
+                    dataset.image.as_ref().map(image_kind) == Some(*kind)
+                }
+                Filter::Path(_) => true,
+            })
+    }
+}
+
+fn parse_filter(key: &str, value: &str) -> Option<Filter> {
+    let filter = match key {
+        "dtype" => {
+            let names = value
+                .split(',')
+                .filter(|name| !name.is_empty())
+                .map(|name| {
+                    let name = name.to_lowercase();
+                    DTYPE_ALIASES
+                        .iter()
+                        .find(|(alias, _)| *alias == name)
+                        .map(|(_, canonical)| canonical.to_string())
+                        .unwrap_or(name)
+                })
+                .collect::<Vec<_>>();
+            if names.is_empty() {
+                return None;
+            }
+            Filter::Dtype(names)
+        }
+        "rank" => Filter::Rank(Bound::parse(value, |text| text.parse().ok())?),
+        "size" => Filter::Size(Bound::parse(value, parse_bytes)?),
+        "storage" => Filter::Storage(Bound::parse(value, parse_bytes)?),
+        "chunked" => Filter::Chunked(parse_flag(value)?),
+        "compound" => Filter::Compound(parse_flag(value)?),
+        "image" => Filter::Image(match parse_flag(value) {
+            Some(true) => ImageFilter::Any,
+            Some(false) => ImageFilter::Absent,
+            None => {
+                let value = value.to_lowercase();
+                ImageFilter::Kind(IMAGE_KINDS.into_iter().find(|kind| *kind == value)?)
+            }
+        }),
+        "path" if !value.is_empty() => {
+            // Patterns not anchored at the root match at any depth.
+            let anchored = if value.starts_with('/') {
+                value.to_string()
+            } else {
+                format!("**/{value}")
+            };
+            Filter::Path(anchored.chars().collect())
+        }
+        _ => return None,
+    };
+    Some(filter)
+}
+
+fn parse_flag(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "yes" | "true" | "y" | "1" | "any" => Some(true),
+        "no" | "false" | "n" | "0" | "none" => Some(false),
+        _ => None,
+    }
+}
+
+/// A byte count such as `512`, `1.5MB` or `1GiB`. Units are powers of 1024,
+/// as the sizes h5v shows are.
+fn parse_bytes(text: &str) -> Option<u64> {
+    let split = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let number = text[..split].parse::<f64>().ok()?;
+    let exponent = match text[split..].trim().to_lowercase().as_str() {
+        "" | "b" => 0,
+        "k" | "kb" | "kib" => 1,
+        "m" | "mb" | "mib" => 2,
+        "g" | "gb" | "gib" => 3,
+        "t" | "tb" | "tib" => 4,
+        _ => return None,
+    };
+    Some((number * 1024f64.powi(exponent)).round() as u64)
+}
+
+fn dtype_matches(name: &str, dataset: &IndexedDataset) -> bool {
+    if dataset.data_type.eq_ignore_ascii_case(name) {
+        return true;
+    }
+    let class_names: &[&str] = match dataset.class {
+        TypeClass::Integer => &["int", "integer", "signed"],
+        TypeClass::Unsigned => &["int", "integer", "uint", "unsigned"],
+        TypeClass::Float => &["float"],
+        TypeClass::Complex => &["complex"],
+        TypeClass::Boolean => &["bool", "boolean"],
+        TypeClass::Enum => &["enum"],
+        TypeClass::String => &["string", "str"],
+        TypeClass::Compound => &["compound"],
+        TypeClass::Reference => &["reference", "ref"],
+        TypeClass::Array => &["array"],
+        TypeClass::Opaque => &["opaque"],
+    };
+    class_names.contains(&name)
+}
+
+fn image_kind(image: &ImageType) -> &'static str {
+    match image {
+        ImageType::Jpeg => "jpeg",
+        ImageType::Png => "png",
+        ImageType::Grayscale => "grayscale",
+        ImageType::Bitmap => "bitmap",
+        ImageType::Truecolor(_) => "truecolor",
+        ImageType::Indexed(_) => "indexed",
+    }
+}
+
+/// Matches a whole path against a glob: `?` and `*` match within one path
+/// segment, `**` across segments, and `/**/` also matches a single `/`.
+///
+/// Works like the two-pointer wildcard matcher: after a mismatch only the
+/// last `*`, the last `/**/` and the last other `**` are grown, instead of
+/// backtracking through every earlier wildcard.
+fn glob_matches(pattern: &[char], path: &[char]) -> bool {
+    let (mut p, mut s) = (0, 0);
+    // Pattern position after each wildcard and the path position it has
+    // matched up to. `**/` only grows to the end of the next segment.
+    let mut star: Option<(usize, usize)> = None;
+    let mut segments: Option<(usize, usize)> = None;
+    let mut globstar: Option<(usize, usize)> = None;
+    loop {
+        if s == path.len() && matches_empty(pattern, p) {
+            return true;
+        }
+        match (&pattern[p..], path.get(s)) {
+            (_, None) => {}
+            (['*', '*', '/', ..], _) if p == 0 || pattern[p - 1] == '/' => {
+                segments = Some((p + 3, s));
+                p += 3;
+                continue;
+            }
+            (['*', '*', ..], _) => {
+                globstar = Some((p + 2, s));
+                (star, segments) = (None, None);
+                p += 2;
+                continue;
+            }
+            (['*', ..], _) => {
+                star = Some((p + 1, s));
+                p += 1;
+                continue;
+            }
+            (['?', ..], Some(c)) if *c != '/' => {
+                p += 1;
+                s += 1;
+                continue;
+            }
+            ([c, ..], Some(d)) if *c != '?' && c == d => {
+                p += 1;
+                s += 1;
+                continue;
+            }
+            _ => {}
+        }
+        // The later of `*` and `**/` is retried first; retrying the earlier
+        // one drops the later, which is reached again.
+        let star_first = match (star, segments) {
+            (Some((star_p, _)), Some((segments_p, _))) => star_p > segments_p,
+            _ => true,
+        };
+        let retried = if star_first {
+            retry_star(path, &mut star, &mut segments)
+                .or_else(|| retry_segments(path, &mut segments, &mut star))
+        } else {
+            retry_segments(path, &mut segments, &mut star)
+                .or_else(|| retry_star(path, &mut star, &mut segments))
+        };
+        if let Some(resume) = retried {
+            (p, s) = resume;
+            continue;
+        }
+        let Some((globstar_p, globstar_s)) = globstar.filter(|(_, s)| *s < path.len()) else {
+            return false;
+        };
+        globstar = Some((globstar_p, globstar_s + 1));
+        (p, s) = (globstar_p, globstar_s + 1);
+    }
+}
+
+/// Whether the pattern from `p` matches nothing, being only `*`, `**` and
+/// `**/` at the start of a segment.
+fn matches_empty(pattern: &[char], mut p: usize) -> bool {
+    loop {
+        match &pattern[p..] {
+            ['*', '*', '/', ..] if p == 0 || pattern[p - 1] == '/' => p += 3,
+            ['*', ..] => p += 1,
+            rest => return rest.is_empty(),
+        }
+    }
+}
+
+/// Grows the last `*` by one character within its segment, dropping `later`.
+fn retry_star(
+    path: &[char],
+    star: &mut Option<(usize, usize)>,
+    later: &mut Option<(usize, usize)>,
+) -> Option<(usize, usize)> {
+    let (star_p, star_s) = (*star)?;
+    if path.get(star_s).is_none_or(|c| *c == '/') {
+        *star = None;
+        return None;
+    }
+    *star = Some((star_p, star_s + 1));
+    *later = later.filter(|(later_p, _)| *later_p < star_p);
+    *star
+}
+
+/// Grows the last `**/` to the end of the next segment, dropping `later`.
+fn retry_segments(
+    path: &[char],
+    segments: &mut Option<(usize, usize)>,
+    later: &mut Option<(usize, usize)>,
+) -> Option<(usize, usize)> {
+    let (segments_p, segments_s) = (*segments)?;
+    let Some(end) = path[segments_s..].iter().position(|c| *c == '/') else {
+        *segments = None;
+        return None;
+    };
+    *segments = Some((segments_p, segments_s + end + 1));
+    *later = later.filter(|(later_p, _)| *later_p < segments_p);
+    *segments
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::{parse_bytes, MetadataQuery};
+
+    fn matches_path(query: &str, path: &str) -> bool {
+        MetadataQuery::parse(query)
+            .expect("a metadata query")
+            .expect("a valid query")
+            .matches_path(path)
+    }
+
+    #[test]
+    fn parses_filters_and_leaves_other_words_as_text() {
+        let query = MetadataQuery::parse("raw dtype:f32 RANK:>=3 size:>1GiB chunked:no")
+            .expect("a metadata query")
+            .expect("a valid query");
+        assert_eq!(query.text, "raw");
+        assert!(query.needs_metadata());
+        assert!(MetadataQuery::parse("raw data").is_none());
+        assert!(MetadataQuery::parse("time:12").is_none());
+        assert!(MetadataQuery::parse("rank:many").unwrap().is_err());
+        assert!(MetadataQuery::parse("image:sepia").unwrap().is_err());
+        assert!(!MetadataQuery::parse("path:/runs/*")
+            .unwrap()
+            .unwrap()
+            .needs_metadata());
+    }
+
+    #[test]
+    fn parses_byte_sizes_in_powers_of_1024() {
+        assert_eq!(parse_bytes("512"), Some(512));
+        assert_eq!(parse_bytes("2KB"), Some(2048));
+        assert_eq!(parse_bytes("1.5MiB"), Some(1_572_864));
+        assert_eq!(parse_bytes("1gib"), Some(1 << 30));
+        assert_eq!(parse_bytes("3 parsecs"), None);
+    }
+
+    #[test]
+    fn path_globs_match_within_and_across_segments() {
+        assert!(matches_path("path:/runs/*/raw", "/runs/007/raw"));
+        assert!(!matches_path("path:/runs/*/raw", "/runs/007/cal/raw"));
+        assert!(matches_path("path:/runs/**/raw", "/runs/007/cal/raw"));
+        assert!(matches_path("path:/runs/**/raw", "/runs/raw"));
+        assert!(matches_path("path:raw", "/runs/007/raw"));
+        assert!(!matches_path("path:raw", "/runs/007/raw_v2"));
+        assert!(matches_path("path:/run?/*", "/runs/x"));
+        assert!(!matches_path("path:/run?/*", "/run/x"));
+        assert!(matches_path("path:/runs/**", "/runs/007/raw"));
+        assert!(matches_path("path:/**/cal/*", "/runs/007/cal/raw"));
+        assert!(!matches_path("path:/**/cal/*", "/runs/007/cal/raw/x"));
+        assert!(matches_path("path:/runs/*a*b", "/runs/aab"));
+        assert!(!matches_path("path:/runs/*a*b", "/runs/aa/b"));
+    }
+
+    #[test]
+    fn path_globs_do_not_backtrack_exponentially() {
+        let path = format!("/{}", "a".repeat(200));
+        assert!(!matches_path(&format!("path:/{}b", "*a".repeat(30)), &path));
+        assert!(!matches_path(
+            &format!("path:/{}b", "**a".repeat(30)),
+            &path
+        ));
+        assert!(matches_path(&format!("path:/{}", "*a".repeat(30)), &path));
+    }
+}
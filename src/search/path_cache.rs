@@ -0,0 +1,209 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+const PATH_CACHE_VERSION: u32 = 1;
+/// Entries not used for this long are removed.
+const MAX_ENTRY_AGE: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+/// Least recently used entries are removed beyond this total size.
+const MAX_CACHE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Identifies the file contents a cached path list was read from. A file
+/// that was written since has another modification time or size.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PathCacheKey {
+    path: String,
+    len: u64,
+    modified: Option<(u64, u32)>,
+}
+
+impl PathCacheKey {
+    pub fn of(file: &Path) -> Option<Self> {
+        let path = fs::canonicalize(file).ok()?;
+        let metadata = fs::metadata(&path).ok()?;
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|value| value.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| (duration.as_secs(), duration.subsec_nanos()));
+        Some(Self {
+            path: path.to_string_lossy().into_owned(),
+            len: metadata.len(),
+            modified,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PathCacheEntry {
+    version: u32,
+    key: PathCacheKey,
+    paths: Vec<String>,
+}
+
+fn cache_root() -> Option<PathBuf> {
+    dirs::cache_dir().map(|path| path.join("h5v").join("search"))
+}
+
+/// 64-bit FNV-1a, which unlike the std hashers gives the same value in
+/// every build.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Named after the file and its size and modification time, so entries of
+/// files that changed since are left to [`prune`].
+fn entry_path(root: &Path, key: &PathCacheKey) -> PathBuf {
+    let mut bytes = key.path.as_bytes().to_vec();
+    bytes.push(0);
+    bytes.extend(key.len.to_le_bytes());
+    if let Some((secs, nanos)) = key.modified {
+        bytes.extend(secs.to_le_bytes());
+        bytes.extend(nanos.to_le_bytes());
+    }
+    root.join(format!("{:016x}.json", fnv1a(&bytes)))
+}
+
+/// Removes entries unused for [`MAX_ENTRY_AGE`], then the least recently
+/// used ones until the rest fit in [`MAX_CACHE_BYTES`].
+fn prune(root: &Path, now: SystemTime) {
+    let Ok(entries) = fs::read_dir(root) else {
+        return;
+    };
+    let mut kept = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().is_none_or(|extension| extension != "json") {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let used = metadata.modified().unwrap_or(UNIX_EPOCH);
+        if now.duration_since(used).unwrap_or_default() > MAX_ENTRY_AGE {
+            let _ = fs::remove_file(&path);
+        } else {
+            kept.push((used, metadata.len(), path));
+        }
+    }
+    kept.sort_by_key(|(used, _, _)| std::cmp::Reverse(*used));
+    let mut total = 0;
+    for (_, len, path) in kept {
+        total += len;
+        if total > MAX_CACHE_BYTES {
+            let _ = fs::remove_file(&path);
+        }
+    }
+}
+
+fn load_from(root: &Path, key: &PathCacheKey) -> Option<Vec<String>> {
+    let path = entry_path(root, key);
+    let contents = fs::read_to_string(&path).ok()?;
+    let entry: PathCacheEntry = serde_json::from_str(&contents).ok()?;
+    if entry.version != PATH_CACHE_VERSION || entry.key != *key {
+        return None;
+    }
+    // The modification time tells `prune` when the entry was last used.
+    if let Ok(file) = fs::File::options().write(true).open(&path) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+    Some(entry.paths)
+}
+
+fn store_in(root: &Path, key: &PathCacheKey, paths: Vec<String>) {
+    let entry = PathCacheEntry {
+        version: PATH_CACHE_VERSION,
+        key: key.clone(),
+        paths,
+    };
+    let Ok(contents) = serde_json::to_string(&entry) else {
+        return;
+    };
+    let _ = fs::create_dir_all(root);
+    let _ = fs::write(entry_path(root, key), contents);
+    prune(root, SystemTime::now());
+}
+
+/// Paths listed for the file by an earlier search, unless it changed since.
+pub fn load_cached_paths(key: &PathCacheKey) -> Option<Vec<String>> {
+    load_from(&cache_root()?, key)
+}
+
+pub fn store_cached_paths(key: &PathCacheKey, paths: Vec<String>) {
+    if let Some(root) = cache_root() {
+        store_in(&root, key, paths);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::{entry_path, load_from, prune, store_in, PathCacheKey, MAX_ENTRY_AGE};
+
+    #[test]
+    fn cached_paths_are_dropped_once_the_file_changes() {
+        let cache = tempfile::tempdir().expect("failed to create cache dir");
+        let source = tempfile::NamedTempFile::new().expect("failed to create source file");
+        std::fs::write(source.path(), b"first").expect("failed to write source file");
+        let key = PathCacheKey::of(source.path()).expect("cache key");
+        let paths = vec!["/a".to_string(), "/a/b".to_string()];
+
+        assert_eq!(load_from(cache.path(), &key), None);
+        store_in(cache.path(), &key, paths.clone());
+        assert_eq!(load_from(cache.path(), &key), Some(paths));
+
+        std::fs::write(source.path(), b"second write").expect("failed to write source file");
+        let changed = PathCacheKey::of(source.path()).expect("cache key");
+        assert_ne!(changed, key);
+        assert_eq!(load_from(cache.path(), &changed), None);
+    }
+
+    #[test]
+    fn entry_names_are_stable() {
+        let key = PathCacheKey {
+            path: "/data/run.h5".to_string(),
+            len: 1024,
+            modified: Some((1_700_000_000, 5)),
+        };
+        let root = std::path::Path::new("/cache");
+        // Entries written by earlier builds must still be found.
+        assert_eq!(entry_path(root, &key), root.join("fbd6386ec5374f69.json"));
+        let resized = PathCacheKey {
+            len: 2048,
+            ..key.clone()
+        };
+        assert_ne!(entry_path(root, &resized), entry_path(root, &key));
+    }
+
+    #[test]
+    fn pruning_removes_entries_unused_for_too_long() {
+        let cache = tempfile::tempdir().expect("failed to create cache dir");
+        let old = cache.path().join("old.json");
+        let fresh = cache.path().join("fresh.json");
+        let other = cache.path().join("notes.txt");
+        for path in [&old, &fresh, &other] {
+            std::fs::write(path, b"{}").expect("failed to write cache entry");
+        }
+        let now = SystemTime::now();
+        std::fs::File::options()
+            .write(true)
+            .open(&old)
+            .expect("failed to open cache entry")
+            .set_modified(now - MAX_ENTRY_AGE - Duration::from_secs(60))
+            .expect("failed to age cache entry");
+
+        prune(cache.path(), now);
+        assert!(!old.exists());
+        assert!(fresh.exists());
+        assert!(other.exists());
+    }
+}
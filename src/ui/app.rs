@@ -9,7 +9,10 @@ use crate::{
     compat::RuntimeConfig,
     data::DatasetPlotingData,
     error::AppError,
-    h5f::{ComputedAttributes, Node, RequestedOpenMode},
+    h5f::{
+        CanonicalPaths, ChunkMap, ComputedAttributes, FindEnd, FindHit, IndexedAttribute,
+        IndexedDataset, Node, RequestedOpenMode, UsageReport, UsageTotals,
+    },
     ui::{
         command::StartupCommand,
         mchart::{MultiChartExpressionRefreshResult, MultiChartLoadResult},
@@ -170,6 +173,12 @@ pub enum AppEvent {
     ResizeDebounceExpired(u64),
     TreeLoad(TreeLoadResult),
     NavigationLoad(NavigationLoadResult),
+    ChunkMap(ChunkMapLoadResult),
+    Usage(UsageLoadResult),
+    GroupOverview(GroupOverviewLoadResult),
+    CanonicalPaths(CanonicalPathsLoadResult),
+    SearchIndex(SearchIndexLoadResult),
+    Find(FindLoadResult),
     Toast(AppToast),
     FileChanged,
 }
@@ -196,6 +205,89 @@ pub enum ContentPreviewLoadedResult {
     },
 }
 
+pub enum ChunkMapLoadResult {
+    Success {
+        key: state::ChunkMapKey,
+        map: ChunkMap,
+    },
+    Failure {
+        key: state::ChunkMapKey,
+        message: String,
+    },
+}
+
+pub enum UsageLoadResult {
+    Progress {
+        key: state::UsageKey,
+        totals: UsageTotals,
+    },
+    Success {
+        key: state::UsageKey,
+        report: UsageReport,
+    },
+    Failure {
+        key: state::UsageKey,
+        message: String,
+    },
+}
+
+pub struct CanonicalPathsLoadResult {
+    pub key: u64,
+    pub paths: CanonicalPaths,
+}
+
+/// Members of a group read since the previous batch; `done` is set on the
+/// last batch.
+pub struct GroupOverviewLoadResult {
+    pub key: state::GroupOverviewKey,
+    pub members: Vec<state::GroupOverviewMember>,
+    pub done: bool,
+}
+
+/// What a search index walk read since its previous batch; `done` is set on
+/// the last batch.
+pub struct SearchIndexLoadResult {
+    pub key: u64,
+    pub paths: Vec<String>,
+    pub attributes: Vec<IndexedAttribute>,
+    /// Objects whose attributes this batch covers.
+    pub objects: usize,
+    pub datasets: Vec<IndexedDataset>,
+    pub done: bool,
+}
+
+impl SearchIndexLoadResult {
+    pub fn empty(key: u64) -> Self {
+        Self {
+            key,
+            paths: Vec::new(),
+            attributes: Vec::new(),
+            objects: 0,
+            datasets: Vec::new(),
+            done: false,
+        }
+    }
+}
+
+/// Hits are those found since the previous event of the same scan.
+pub enum FindLoadResult {
+    Progress {
+        key: state::FindKey,
+        scanned: usize,
+        hits: Vec<FindHit>,
+    },
+    Success {
+        key: state::FindKey,
+        scanned: usize,
+        hits: Vec<FindHit>,
+        end: FindEnd,
+    },
+    Failure {
+        key: state::FindKey,
+        message: String,
+    },
+}
+
 pub struct NavigationLoadRequest {
     pub generation: u64,
     pub request_id: u64,
@@ -230,6 +322,9 @@ pub struct TreeLoadRequest {
     pub generation: u64,
     pub request_id: u64,
     pub node: Node,
+    /// Canonical paths of the file when known, so aliases among the
+    /// children are marked as they are read.
+    pub canonical_paths: Option<CanonicalPaths>,
 }
 
 pub enum TreeLoadWork {
@@ -242,6 +337,11 @@ pub enum TreeLoadResult {
         generation: u64,
         request_id: u64,
         children: Vec<Node>,
+        /// Link creation order of the children by link name, when tracked.
+        creation_orders: std::collections::HashMap<String, i64>,
+        /// Some children may be aliases, left unmarked until the canonical
+        /// paths are read.
+        unmarked_aliases: bool,
     },
     Failure {
         generation: u64,
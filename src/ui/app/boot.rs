@@ -16,6 +16,10 @@ use crate::{
     h5f::{self, RequestedOpenMode},
     ui::command::CommandState,
     ui::{
+        canonical_paths::handle_canonical_paths_load,
+        chunk_map::handle_chunk_map_load,
+        find::handle_find_load,
+        group_overview::handle_group_overview_load,
         heatmap::handle_heatmap_load,
         matrix::handle_matrix_viewport_load,
         mchart::{
@@ -27,11 +31,13 @@ use crate::{
             handle_image_resize, handle_imagefs_load, handle_imagefsvlen_load,
         },
         preview::{content::handle_content_preview_load, pipeline::handle_preview_expression_eval},
+        search_index::handle_search_index_load,
         state::{
             self, AppState, AppToast, ChartPreviwState, ContentPreviewState, ContentShowMode,
             FileWatchState, Focus, ImgState, LastFocused, MatrixViewState, MatrixViewportState,
             Mode, PreviewExpressionState,
         },
+        usage::handle_usage_load,
     },
 };
 
@@ -166,6 +172,7 @@ pub(super) fn prepare_app<'a>(
         rendered_mode: None,
         rendered_viewport: None,
         rendered_roi: None,
+        rendered_bit_plane: None,
         rendered_size: None,
         pending_key: None,
         tx_resize_chartpreview: tx_chart_preview_resize,
@@ -246,6 +253,7 @@ pub(super) fn prepare_app<'a>(
         pending_tree_selection: None,
         pending_tree_selection_state: None,
         pending_tree_attribute_selection: None,
+        applied_structure_edit: None,
         editing: false,
         file: Some(h5f.file),
         requested_open_mode: h5f.requested_open_mode,
@@ -272,6 +280,7 @@ pub(super) fn prepare_app<'a>(
         attribute_create_dialog: None,
         attribute_delete_dialog: None,
         fixed_string_overflow_dialog: None,
+        structure_edit_dialog: None,
         treeview: vec![],
         tree_view_cursor: 0,
         focus: Focus::Tree(LastFocused::Attributes),
@@ -283,6 +292,8 @@ pub(super) fn prepare_app<'a>(
         logs_return_mode: Mode::Normal,
         copying: false,
         searcher: None,
+        search_index: state::SearchIndexState::new(handle_search_index_load(tx_events.clone())),
+        find: state::FindState::new(handle_find_load(tx_events.clone())),
         help: state::HelpViewState::default(),
         logs: state::LogsViewState::default(),
         pending_chord: None,
@@ -321,6 +332,15 @@ pub(super) fn prepare_app<'a>(
             selected_setting: 0,
             session_range_modes: Vec::new(),
         },
+        chunk_map: state::ChunkMapState::new(handle_chunk_map_load(tx_events.clone())),
+        usage: state::UsageState::new(handle_usage_load(tx_events.clone())),
+        group_overview: state::GroupOverviewState::new(handle_group_overview_load(
+            tx_events.clone(),
+        )),
+        canonical_paths: state::CanonicalPathsState::new(handle_canonical_paths_load(
+            tx_events.clone(),
+        )),
+        dimension_scales: state::DimensionScaleState::default(),
         chart_preview_state,
         preview_expression_state,
         content_preview_state,
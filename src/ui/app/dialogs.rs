@@ -78,8 +78,10 @@ pub(super) fn render_attribute_create_dialog(
     .split(inner);
 
     frame.render_widget(
-        Paragraph::new("Tab/Shift-Tab switch fields, Left/Right changes type, Enter creates")
-            .style(Style::default().fg(configure::themed_color(|colors| colors.text.type_desc))),
+        Paragraph::new(
+            "Tab switches fields, Left/Right changes type, Space toggles [], Enter creates",
+        )
+        .style(Style::default().fg(configure::themed_color(|colors| colors.text.type_desc))),
         rows[0],
     );
 
@@ -121,11 +123,19 @@ pub(super) fn render_attribute_create_dialog(
                 Style::default().fg(configure::themed_color(|colors| colors.text.type_desc)),
             ),
             Span::styled(
-                format!(
-                    "< {} >  ({})",
-                    dialog.attr_type.label(),
-                    dialog.attr_type.description()
-                ),
+                if dialog.array {
+                    format!(
+                        "< {}[] >  ({} array)",
+                        dialog.attr_type.label(),
+                        dialog.attr_type.description()
+                    )
+                } else {
+                    format!(
+                        "< {} >  ({})",
+                        dialog.attr_type.label(),
+                        dialog.attr_type.description()
+                    )
+                },
                 type_style,
             ),
         ])),
@@ -143,8 +153,12 @@ pub(super) fn render_attribute_create_dialog(
         rows[3],
     );
     frame.render_widget(
-        Paragraph::new("Types: bool, i64, u64, f64, string, ascii")
-            .style(Style::default().fg(configure::themed_color(|colors| colors.text.type_desc))),
+        Paragraph::new(if dialog.array {
+            "Separate values with commas; leave empty to enter them in $EDITOR"
+        } else {
+            "Types: bool, i8 to i64, u8 to u64, f32, f64, string, ascii"
+        })
+        .style(Style::default().fg(configure::themed_color(|colors| colors.text.type_desc))),
         rows[5],
     );
 
@@ -193,6 +207,37 @@ pub(super) fn render_attribute_delete_dialog(
     );
 }
 
+pub(super) fn render_structure_edit_dialog(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    state: &AppState<'_>,
+) {
+    let Some(dialog) = state.structure_edit_dialog.as_ref() else {
+        return;
+    };
+
+    let popup = render_dialog_popup(
+        frame,
+        area,
+        72,
+        10,
+        configure::configured_symbol(|symbols| symbols.title.edit_structure),
+    );
+    let inner = popup.inner(Margin {
+        horizontal: 2,
+        vertical: 1,
+    });
+    frame.render_widget(
+        Paragraph::new(format!(
+            "{}\nPress Enter to confirm or Esc to cancel.",
+            dialog.edit.describe()
+        ))
+        .style(primary_text_style())
+        .wrap(Wrap { trim: true }),
+        inner,
+    );
+}
+
 pub(super) fn render_fixed_string_overflow_dialog(
     frame: &mut Frame<'_>,
     area: Rect,
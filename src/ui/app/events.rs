@@ -13,7 +13,7 @@ use ratatui::crossterm::event;
 
 use crate::{
     error::log_error,
-    h5f::{enumerate_group_children, H5FNode},
+    h5f::{enumerate_group_children, link_creation_orders, H5FNode},
 };
 
 use super::{AppEvent, NavigationLoadResult, NavigationLoadWork, TreeLoadResult, TreeLoadWork};
@@ -93,18 +93,21 @@ pub(super) fn handle_tree_load(tx_events: Sender<AppEvent>) -> Sender<TreeLoadWo
                 let _ = done.send(());
                 continue;
             };
-            let result = match enumerate_group_children(&request.node) {
-                Ok(children) => TreeLoadResult::Success {
-                    generation: request.generation,
-                    request_id: request.request_id,
-                    children,
-                },
-                Err(error) => TreeLoadResult::Failure {
-                    generation: request.generation,
-                    request_id: request.request_id,
-                    message: error.to_string(),
-                },
-            };
+            let result =
+                match enumerate_group_children(&request.node, request.canonical_paths.as_ref()) {
+                    Ok(enumerated) => TreeLoadResult::Success {
+                        generation: request.generation,
+                        request_id: request.request_id,
+                        children: enumerated.children,
+                        creation_orders: link_creation_orders(&request.node),
+                        unmarked_aliases: enumerated.unmarked_aliases,
+                    },
+                    Err(error) => TreeLoadResult::Failure {
+                        generation: request.generation,
+                        request_id: request.request_id,
+                        message: error.to_string(),
+                    },
+                };
             if tx_events.send(AppEvent::TreeLoad(result)).is_err() {
                 return;
             }
@@ -131,14 +134,16 @@ pub(super) fn handle_file_watch_events(
     tx_events: Sender<AppEvent>,
     path: String,
     running: Arc<AtomicBool>,
+    worker_running: Arc<AtomicBool>,
 ) {
     thread::spawn(move || {
+        let active = || running.load(Ordering::Relaxed) && worker_running.load(Ordering::Relaxed);
         let mut last_modified = fs::metadata(&path)
             .ok()
             .and_then(|metadata| metadata.modified().ok());
-        while running.load(Ordering::Relaxed) {
+        while active() {
             thread::sleep(Duration::from_millis(500));
-            if !running.load(Ordering::Relaxed) {
+            if !active() {
                 return;
             }
             let current_modified = fs::metadata(&path)
@@ -99,6 +99,19 @@ fn snapshot_reload_state(state: &AppState<'_>) -> ReloadSnapshot {
     }
 }
 
+/// Follows the nodes a structure edit moved or deleted, and selects what it
+/// created or moved.
+fn apply_structure_edit_to_snapshot(snapshot: &mut ReloadSnapshot, edit: &h5f::StructureEdit) {
+    snapshot.expanded_paths = snapshot
+        .expanded_paths
+        .iter()
+        .filter_map(|path| edit.relocate(path))
+        .collect();
+    let selection = normalized_node_path(edit.selection_after());
+    snapshot.selected_path = (!selection.is_empty()).then(|| selection.to_string());
+    snapshot.selected_node = None;
+}
+
 fn clear_preview_state(state: &mut AppState<'_>, snapshot: &ReloadSnapshot) {
     state.content_generation = state.content_generation.wrapping_add(1);
     state.content_preview_state.pending_key = None;
@@ -107,6 +120,11 @@ fn clear_preview_state(state: &mut AppState<'_>, snapshot: &ReloadSnapshot) {
     state.matrix_viewport_state.pending_key = None;
     state.matrix_viewport_state.error = None;
     state.matrix_viewport_state.cached.clear();
+    state.chunk_map.invalidate();
+    state.usage.invalidate();
+    state.group_overview.invalidate();
+    state.canonical_paths.invalidate();
+    state.dimension_scales.invalidate();
     state.clear_preview_debounce();
     state.page_state = snapshot.page_state.clone();
     state.matrix_view_state = snapshot.matrix_view_state.clone();
@@ -164,7 +182,17 @@ pub(super) fn reload_current_file(state: &mut AppState<'_>, write: bool) -> Resu
     state.drain_navigation_loads();
     state.drain_content_previews();
     state.drain_matrix_viewports();
-    let snapshot = snapshot_reload_state(state);
+    state.drain_chunk_map();
+    state.drain_usage();
+    state.drain_group_overview();
+    state.drain_canonical_paths();
+    state.drain_search_index();
+    state.drain_find();
+    let mut snapshot = snapshot_reload_state(state);
+    let structure_edit = state.applied_structure_edit.take();
+    if let Some(edit) = structure_edit.as_ref() {
+        apply_structure_edit_to_snapshot(&mut snapshot, edit);
+    }
     let file_path = state.file_watch.path.clone();
     let linked = state.file_watch.linked;
     let previous_requested_open_mode = state.requested_open_mode;
@@ -173,6 +201,8 @@ pub(super) fn reload_current_file(state: &mut AppState<'_>, write: bool) -> Resu
     clear_preview_state(state, &snapshot);
     state.treeview.clear();
     state.searcher = None;
+    state.search_index.invalidate();
+    state.find.invalidate();
     let old_root = std::mem::replace(&mut state.root, placeholder_root(&file_path));
     state.tree_view_cursor = 0;
     state.compute_tree_view();
@@ -253,6 +283,9 @@ pub(super) fn reload_current_file(state: &mut AppState<'_>, write: bool) -> Resu
         Ok(event)
     })?;
 
+    if let Some(edit) = structure_edit {
+        return Ok(edit.summary());
+    }
     Ok(match state.resolved_open_mode {
         h5f::ResolvedOpenMode::Write => "Reloaded file in write mode".to_string(),
         h5f::ResolvedOpenMode::ReadSwmr => "Reloaded file in SWMR read-only mode".to_string(),
@@ -260,3 +293,85 @@ pub(super) fn reload_current_file(state: &mut AppState<'_>, write: bool) -> Resu
         h5f::ResolvedOpenMode::ReadOnly => "Reloaded file".to_string(),
     })
 }
+
+/// Replaces the open file with another one, e.g. the source file of a virtual
+/// dataset mapping, and selects `target` once its parents have loaded. The new
+/// file is opened before the current one is closed so a failure leaves the
+/// session untouched.
+pub(super) fn open_file(
+    state: &mut AppState<'_>,
+    path: String,
+    target: Option<String>,
+) -> Result<String> {
+    let opened =
+        h5f::H5F::open(path.clone(), false, state.requested_open_mode).map_err(|error| {
+            AppError::Hdf5(hdf5_metno::Error::from(format!(
+                "Failed to open HDF5 file '{}': {}",
+                path, error
+            )))
+        })?;
+
+    state.tree_load_generation = state.tree_load_generation.wrapping_add(1);
+    state.navigation_generation = state.navigation_generation.wrapping_add(1);
+    state.drain_tree_loads();
+    state.drain_navigation_loads();
+    state.drain_content_previews();
+    state.drain_matrix_viewports();
+    state.drain_chunk_map();
+    state.drain_usage();
+    state.drain_group_overview();
+    state.drain_canonical_paths();
+    state.drain_search_index();
+    state.drain_find();
+    let snapshot = snapshot_reload_state(state);
+    let previous_path = state.file_watch.path.clone();
+
+    clear_preview_state(state, &snapshot);
+    state.treeview.clear();
+    state.searcher = None;
+    state.search_index.invalidate();
+    state.find.invalidate();
+    state.cancel_pending_tree_selection();
+    state.pending_tree_expansions.clear();
+    let old_root = std::mem::replace(&mut state.root, opened.root);
+    state.tree_view_cursor = 0;
+    let old_file = state.file.replace(opened.file);
+    let old_snapshot_file = std::mem::replace(&mut state.snapshot_file, opened.snapshot_file);
+    drop(old_root);
+    drop(old_snapshot_file);
+    if let Some(old_file) = old_file {
+        if let Err(error) = old_file.close() {
+            tracing::warn!(
+                kind = "file",
+                file_path = previous_path,
+                error = %error,
+                message = "failed to close previous HDF5 file"
+            );
+        }
+    }
+
+    state.readonly = opened.resolved_open_mode.readonly();
+    state.requested_open_mode = opened.requested_open_mode;
+    state.resolved_open_mode = opened.resolved_open_mode;
+    state.file_watch.path = path.clone();
+    state.file_watch.linked = false;
+    state.sync_file_watch();
+    state.compute_tree_view();
+    if let Some(target) = target.as_deref() {
+        state.navigate_to_attribute_target(target, None)?;
+    }
+    state.request_tree_children(state.root.clone());
+
+    let readonly = state.readonly;
+    crate::configure::dispatch_lua_event(state, "builtin.event.file_opened", |lua| {
+        let event = lua.create_table()?;
+        event.set("path", path.clone())?;
+        event.set("readonly", readonly)?;
+        Ok(event)
+    })?;
+
+    Ok(match target {
+        Some(target) => format!("Opened {target} in {path}"),
+        None => format!("Opened {path}"),
+    })
+}
@@ -14,6 +14,7 @@ use crate::{
     ui::{
         chrome::truncate_to_width,
         command::render_command_dialog,
+        find::render_find_panel,
         help::render_help,
         logs::render_logs,
         main_display::render_main_display,
@@ -26,10 +27,12 @@ use crate::{
 use super::dialogs::{
     render_attribute_create_dialog, render_attribute_delete_dialog,
     render_fixed_string_overflow_dialog, render_fixed_string_resize_dialog,
+    render_structure_edit_dialog,
 };
 
 const HEADER_HEIGHT: u16 = 1;
 const COMMAND_BAR_HEIGHT: u16 = 6;
+const FIND_PANEL_HEIGHT: u16 = 12;
 
 pub(crate) fn primary_text_style() -> Style {
     let mut style = Style::default().fg(configure::themed_color(|colors| colors.text.primary));
@@ -94,14 +97,27 @@ pub(super) fn draw_app_frame(
         false => content_area,
     };
 
+    let (main_display_area, find_area) = match state.mode {
+        Mode::Find => {
+            let areas = Layout::default()
+                .direction(ratatui::layout::Direction::Vertical)
+                .constraints([Constraint::Fill(1), Constraint::Length(FIND_PANEL_HEIGHT)])
+                .split(main_display_area);
+            (areas[0], Some(areas[1]))
+        }
+        _ => (main_display_area, None),
+    };
+
     match state.mode {
         Mode::Search => {}
         Mode::Command
         | Mode::Normal
+        | Mode::Find
         | Mode::AttributeCreateDialog
         | Mode::AttributeDeleteDialog
         | Mode::FixedStringOverflowDialog
-        | Mode::FixedStringResizeDialog => {
+        | Mode::FixedStringResizeDialog
+        | Mode::StructureEditDialog => {
             let Some(selected_node) = state
                 .treeview
                 .get(state.tree_view_cursor)
@@ -120,6 +136,9 @@ pub(super) fn draw_app_frame(
         Mode::MultiChart => {}
     }
 
+    if let Some(find_area) = find_area {
+        render_find_panel(frame, find_area, state);
+    }
     match state.mode {
         Mode::Command => render_command_dialog(frame, command_area, state),
         Mode::AttributeCreateDialog => render_attribute_create_dialog(frame, content_area, state),
@@ -130,6 +149,7 @@ pub(super) fn draw_app_frame(
         Mode::FixedStringResizeDialog => {
             render_fixed_string_resize_dialog(frame, content_area, state)
         }
+        Mode::StructureEditDialog => render_structure_edit_dialog(frame, content_area, state),
         _ => {}
     }
     render_toast_overlay(frame, state, command_area);
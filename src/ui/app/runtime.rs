@@ -41,7 +41,7 @@ use super::{
     },
     lifecycle::AppTerminal,
     picker_with_cell_size,
-    reload::reload_current_file,
+    reload::{open_file, reload_current_file},
     render::{draw_app_frame, render_error},
     terminal_cell_size,
     update::spawn_update_check,
@@ -220,6 +220,7 @@ fn apply_navigation_load_result(
                 _ => 0,
             };
             node.sync_selection_rank(rank);
+            node.apply_initial_axis_order();
             state.restore_pending_tree_selection_metadata(&mut node);
         }
         NavigationLoadResult::Attributes { attributes, .. } => {
@@ -303,11 +304,7 @@ pub(super) fn main_recover_loop(
         state.edit_pause.clone(),
         worker_running.clone(),
     );
-    handle_file_watch_events(
-        tx_events.clone(),
-        state.file_watch.path.clone(),
-        worker_running,
-    );
+    let mut _file_watch_shutdown = spawn_file_watch(&tx_events, &state, &worker_running);
     let mut resize_debounce_generation: u64 = 0;
 
     loop {
@@ -605,6 +602,7 @@ pub(super) fn main_recover_loop(
                                     redraw(terminal, &mut state, new_version.as_deref())?;
                                 }
                                 EventResult::ReloadFile { .. }
+                                | EventResult::OpenFile { .. }
                                 | EventResult::Configure { .. }
                                 | EventResult::Error(_)
                                 | EventResult::Toast(_, _)
@@ -637,6 +635,21 @@ pub(super) fn main_recover_loop(
                         }
                         redraw(terminal, &mut state, new_version.as_deref())?;
                     }
+                    EventResult::OpenFile { path, target } => {
+                        match open_file(&mut state, path, target) {
+                            Ok(message) => {
+                                _file_watch_shutdown =
+                                    spawn_file_watch(&tx_events, &state, &worker_running);
+                                terminal.clear()?;
+                                terminal.flush()?;
+                                apply_app_toast(&mut state, AppToast::Info(message));
+                            }
+                            Err(error) => {
+                                apply_app_toast(&mut state, AppToast::Error(error.to_string()));
+                            }
+                        }
+                        redraw(terminal, &mut state, new_version.as_deref())?;
+                    }
                     EventResult::Configure { reset } => {
                         match open_configuration_and_reload(&mut state, tx_events.clone(), reset) {
                             Ok(toast) => {
@@ -766,6 +779,116 @@ pub(super) fn main_recover_loop(
                 }
                 redraw(terminal, &mut state, new_version.as_deref())?;
             }
+            AppEvent::ChunkMap(result) => {
+                match result {
+                    super::ChunkMapLoadResult::Success { key, map } => {
+                        if state.chunk_map.pending_key.as_ref() != Some(&key) {
+                            continue;
+                        }
+                        state.chunk_map.pending_key = None;
+                        state.chunk_map.store(key, map);
+                    }
+                    super::ChunkMapLoadResult::Failure { key, message } => {
+                        if state.chunk_map.pending_key.as_ref() != Some(&key) {
+                            continue;
+                        }
+                        state.chunk_map.pending_key = None;
+                        state.chunk_map.error = Some((key, message));
+                    }
+                }
+                redraw(terminal, &mut state, new_version.as_deref())?;
+            }
+            AppEvent::Usage(result) => {
+                match result {
+                    super::UsageLoadResult::Progress { key, totals } => {
+                        if state.usage.pending_key.as_ref() != Some(&key) {
+                            continue;
+                        }
+                        state.usage.progress = Some(totals);
+                    }
+                    super::UsageLoadResult::Success { key, report } => {
+                        if state.usage.pending_key.as_ref() != Some(&key) {
+                            continue;
+                        }
+                        state.usage.pending_key = None;
+                        state.usage.progress = None;
+                        state.usage.store(key, report);
+                    }
+                    super::UsageLoadResult::Failure { key, message } => {
+                        if state.usage.pending_key.as_ref() != Some(&key) {
+                            continue;
+                        }
+                        state.usage.pending_key = None;
+                        state.usage.progress = None;
+                        state.usage.error = Some((key, message));
+                    }
+                }
+                redraw(terminal, &mut state, new_version.as_deref())?;
+            }
+            AppEvent::GroupOverview(result) => {
+                if state.group_overview.pending_key.as_ref() != Some(&result.key) {
+                    continue;
+                }
+                state.group_overview.apply(result.members);
+                if result.done {
+                    state.group_overview.finish();
+                }
+                // Tree columns and the size order show what was read.
+                state.compute_tree_view();
+                redraw(terminal, &mut state, new_version.as_deref())?;
+            }
+            AppEvent::SearchIndex(result) => {
+                if state.search_index.pending_key != Some(result.key) {
+                    continue;
+                }
+                let scope = state.search_index.scope;
+                if result.done {
+                    state.search_index.finish();
+                }
+                let Some(searcher) = state.searcher.as_mut() else {
+                    continue;
+                };
+                searcher.add_paths(result.paths);
+                searcher.add_attributes(result.attributes, result.objects);
+                searcher.add_datasets(result.datasets);
+                if result.done {
+                    searcher.complete(scope);
+                }
+                redraw(terminal, &mut state, new_version.as_deref())?;
+            }
+            AppEvent::Find(result) => {
+                match result {
+                    super::FindLoadResult::Progress { key, scanned, hits } => {
+                        if state.find.pending_key.as_ref() != Some(&key) {
+                            continue;
+                        }
+                        state.find.scanned = scanned;
+                        state.find.hits.extend(hits);
+                    }
+                    super::FindLoadResult::Success {
+                        key,
+                        scanned,
+                        hits,
+                        end,
+                    } => {
+                        if state.find.pending_key.as_ref() != Some(&key) {
+                            continue;
+                        }
+                        state.find.pending_key = None;
+                        state.find.scanned = scanned;
+                        state.find.hits.extend(hits);
+                        state.find.end = end;
+                    }
+                    super::FindLoadResult::Failure { key, message } => {
+                        if state.find.pending_key.as_ref() != Some(&key) {
+                            continue;
+                        }
+                        state.find.pending_key = None;
+                        state.find.error = Some(message);
+                    }
+                }
+                redraw(terminal, &mut state, new_version.as_deref())?;
+            }
             AppEvent::MatrixViewport(result) => {
                 match result {
                     super::MatrixViewportLoadedResult::Success { key, data } => {
@@ -825,6 +948,7 @@ pub(super) fn main_recover_loop(
                     state.chart_preview_state.rendered_mode = Some(key.mode);
                     state.chart_preview_state.rendered_viewport = key.viewport;
                     state.chart_preview_state.rendered_roi = key.roi;
+                    state.chart_preview_state.rendered_bit_plane = key.bit_plane;
                     state.chart_preview_state.rendered_size = Some((key.width, key.height));
                     state
                         .chart_preview_state
@@ -992,8 +1116,17 @@ pub(super) fn main_recover_loop(
                 };
                 let (_, node) = state.pending_tree_loads.remove(index);
                 match result {
-                    TreeLoadResult::Success { children, .. } => {
-                        node.borrow_mut().apply_enumerated_children(children)
+                    TreeLoadResult::Success {
+                        children,
+                        creation_orders,
+                        unmarked_aliases,
+                        ..
+                    } => {
+                        node.borrow_mut()
+                            .apply_enumerated_children(children, &creation_orders);
+                        if unmarked_aliases {
+                            state.request_canonical_paths();
+                        }
                     }
                     TreeLoadResult::Failure { message, .. } => {
                         node.borrow_mut().apply_enumeration_error(message)
@@ -1017,6 +1150,10 @@ pub(super) fn main_recover_loop(
                 state.compute_tree_view();
                 redraw(terminal, &mut state, new_version.as_deref())?;
             }
+            AppEvent::CanonicalPaths(result) => {
+                state.apply_canonical_paths(result);
+                redraw(terminal, &mut state, new_version.as_deref())?;
+            }
             AppEvent::NavigationLoad(result) => {
                 let metadata_loaded = matches!(result, NavigationLoadResult::Metadata { .. });
                 let applied = match apply_navigation_load_result(&mut state, result) {
@@ -1058,6 +1195,12 @@ pub(super) fn main_recover_loop(
     state.drain_navigation_loads();
     state.drain_content_previews();
     state.drain_matrix_viewports();
+    state.drain_chunk_map();
+    state.drain_usage();
+    state.drain_group_overview();
+    state.drain_canonical_paths();
+    state.drain_search_index();
+    state.drain_find();
     if let Some(file) = state.file.take() {
         file.close()?;
     }
@@ -1224,6 +1367,7 @@ fn mode_label(mode: &state::Mode) -> &'static str {
     match mode {
         state::Mode::Normal => "normal",
         state::Mode::Search => "search",
+        state::Mode::Find => "find",
         state::Mode::Help => "help",
         state::Mode::Logs => "logs",
         state::Mode::Command => "command",
@@ -1232,6 +1376,7 @@ fn mode_label(mode: &state::Mode) -> &'static str {
         state::Mode::AttributeDeleteDialog => "attribute-delete-dialog",
         state::Mode::FixedStringOverflowDialog => "fixed-string-overflow-dialog",
         state::Mode::FixedStringResizeDialog => "fixed-string-resize-dialog",
+        state::Mode::StructureEditDialog => "structure-edit-dialog",
     }
 }
 
@@ -1250,6 +1395,7 @@ fn selected_item_kind(state: &AppState<'_>) -> Option<&'static str> {
         Node::File(_) => "file",
         Node::Group(_, _) => "group",
         Node::Dataset(_, _) => "dataset",
+        Node::Datatype(_, _) => "datatype",
         Node::Broken(_) => "broken",
     })
 }
@@ -1280,6 +1426,15 @@ fn apply_startup_event_result(state: &mut AppState<'_>, event_result: EventResul
             );
             Ok(false)
         }
+        EventResult::OpenFile { .. } => {
+            apply_app_toast(
+                state,
+                AppToast::Info(
+                    "Opening another file is only available after startup completes".to_string(),
+                ),
+            );
+            Ok(false)
+        }
         EventResult::Error(error) => {
             apply_app_toast(state, AppToast::Error(error));
             Ok(false)
@@ -1315,10 +1470,17 @@ fn run_startup_commands(
                 AppError::ChannelError(format!("Failed to receive tree load: {error}"))
             })?;
             let AppEvent::TreeLoad(result) = event else {
-                if let AppEvent::NavigationLoad(result) = event {
-                    apply_navigation_load_result(state, result).map_err(|error| {
-                        AppError::InvalidCommand(format!("{}: {}", startup_command.origin, error))
-                    })?;
+                match event {
+                    AppEvent::NavigationLoad(result) => {
+                        apply_navigation_load_result(state, result).map_err(|error| {
+                            AppError::InvalidCommand(format!(
+                                "{}: {}",
+                                startup_command.origin, error
+                            ))
+                        })?;
+                    }
+                    AppEvent::CanonicalPaths(result) => state.apply_canonical_paths(result),
+                    _ => {}
                 }
                 continue;
             };
@@ -1347,8 +1509,17 @@ fn run_startup_commands(
             };
             let (_, node) = state.pending_tree_loads.remove(index);
             match result {
-                TreeLoadResult::Success { children, .. } => {
-                    node.borrow_mut().apply_enumerated_children(children)
+                TreeLoadResult::Success {
+                    children,
+                    creation_orders,
+                    unmarked_aliases,
+                    ..
+                } => {
+                    node.borrow_mut()
+                        .apply_enumerated_children(children, &creation_orders);
+                    if unmarked_aliases {
+                        state.request_canonical_paths();
+                    }
                 }
                 TreeLoadResult::Failure { message, .. } => {
                     node.borrow_mut().apply_enumeration_error(message)
@@ -1369,6 +1540,23 @@ struct WorkerShutdownGuard {
     running: Arc<AtomicBool>,
 }
 
+/// Watches the currently open file. The returned guard stops the watcher when it
+/// is dropped, so replacing it after switching files retires the old watcher.
+fn spawn_file_watch(
+    tx_events: &std::sync::mpsc::Sender<AppEvent>,
+    state: &AppState<'_>,
+    worker_running: &Arc<AtomicBool>,
+) -> WorkerShutdownGuard {
+    let running = Arc::new(AtomicBool::new(true));
+    handle_file_watch_events(
+        tx_events.clone(),
+        state.file_watch.path.clone(),
+        running.clone(),
+        worker_running.clone(),
+    );
+    WorkerShutdownGuard { running }
+}
+
 impl Drop for WorkerShutdownGuard {
     fn drop(&mut self) {
         self.running.store(false, Ordering::Relaxed);
@@ -96,7 +96,7 @@ fn build_display_rows(
                 display_rows.push(MetadataDisplayRow::SectionHeader(row.name_line.to_string()));
             }
             MetadataRowKind::Property => property_buffer.push(row_index),
-            MetadataRowKind::Attribute => {
+            MetadataRowKind::Attribute | MetadataRowKind::VirtualSource(_) => {
                 flush_properties(&mut display_rows, &mut property_buffer);
                 display_rows.push(MetadataDisplayRow::Cells(vec![row_index]));
             }
@@ -439,7 +439,8 @@ pub fn render_info_attributes(
             | Mode::AttributeCreateDialog
             | Mode::AttributeDeleteDialog
             | Mode::FixedStringOverflowDialog
-            | Mode::FixedStringResizeDialog,
+            | Mode::FixedStringResizeDialog
+            | Mode::StructureEditDialog,
         ) => configure::themed_color(|colors| colors.surface.focus_bg),
         _ => configure::themed_color(|colors| colors.surface.bg),
     };
@@ -0,0 +1,24 @@
+use std::sync::mpsc::Sender;
+
+use crate::{
+    h5f::read_canonical_paths,
+    ui::{
+        app::{AppEvent, CanonicalPathsLoadResult},
+        state::{CanonicalPathsRequest, CanonicalPathsWork},
+        walk_worker::spawn_walk_worker,
+    },
+};
+
+/// Walks the open file for the canonical path of each object.
+pub(crate) fn handle_canonical_paths_load(
+    tx_events: Sender<AppEvent>,
+) -> Sender<CanonicalPathsWork> {
+    spawn_walk_worker(tx_events, |request: CanonicalPathsRequest, walk| {
+        if let Some(paths) = read_canonical_paths(&request.file, &mut || walk.keep_going()) {
+            walk.send(AppEvent::CanonicalPaths(CanonicalPathsLoadResult {
+                key: request.generation,
+                paths,
+            }));
+        }
+    })
+}
@@ -1,6 +1,13 @@
+use plotters::coord::{
+    ranged1d::{DefaultFormatting, KeyPointHint, Ranged},
+    types::RangedCoordf64,
+};
 use ratatui::layout::Rect;
 
-use crate::configure::{self, AxisNumberFormat};
+use crate::{
+    configure::{self, AxisNumberFormat},
+    h5f::{time_ticks, TimeEncoding},
+};
 
 pub(crate) fn format_axis_number(value: f64) -> String {
     if value.is_nan() {
@@ -45,6 +52,52 @@ pub(crate) fn axis_label_area_size(values: &[f64], padding: u32) -> u32 {
         .unwrap_or(padding)
 }
 
+/// A linear axis that ticks on calendar boundaries when its values are
+/// timestamps, and like a plain `min..max` range otherwise.
+pub(crate) struct TimeTickAxis {
+    inner: RangedCoordf64,
+    ticks: Vec<f64>,
+}
+
+impl TimeTickAxis {
+    pub(crate) fn new(min: f64, max: f64, time: Option<&TimeEncoding>, target: usize) -> Self {
+        Self {
+            inner: (min..max).into(),
+            ticks: time
+                .map(|time| time_ticks(time, min, max, target))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Ranged for TimeTickAxis {
+    type FormatOption = DefaultFormatting;
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        self.inner.map(value, limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<f64> {
+        if self.ticks.is_empty() {
+            self.inner.key_points(hint)
+        } else if hint.weight().allow_light_points() {
+            Vec::new()
+        } else {
+            self.ticks.clone()
+        }
+    }
+
+    fn range(&self) -> std::ops::Range<f64> {
+        self.inner.range()
+    }
+}
+
+/// Space for datetime tick labels, sized for whole seconds.
+pub(crate) fn time_axis_label_area_size(padding: u32) -> u32 {
+    "2000-01-01 00:00:00".len() as u32 * 3 + padding
+}
+
 /// Space for horizontal tick labels, an axis title, and a small gap between them.
 pub(crate) fn axis_title_label_area_size(font_size: u32) -> u32 {
     font_size.saturating_mul(2).saturating_add(8)
@@ -0,0 +1,319 @@
+use std::sync::mpsc::Sender;
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::{
+    configure,
+    error::AppError,
+    h5f::{human_bytes, read_chunk_map, ChunkMap, DatasetHandle, DatasetMetaState, H5FNode, Node},
+    ui::{
+        app::{AppEvent, ChunkMapLoadResult},
+        chrome::rounded_panel,
+        dims::{render_dim_selector, RenderDimSelectorOptions},
+        heatmap::heatmap_colormap_rgb,
+        matrix::render_not_yet_implemented,
+        state::{
+            chunk_plane_dims, AppState, ChunkMapKey, ChunkMapRequest, ChunkMapWork, HeatmapColormap,
+        },
+        std_comp_render::render_empty_dataset,
+        walk_worker::spawn_walk_worker,
+    },
+};
+
+const CELL_WIDTH: u16 = 2;
+const DETAIL_HEIGHT: u16 = 7;
+
+/// Reads the chunk index of one dataset at a time.
+pub(crate) fn handle_chunk_map_load(tx_events: Sender<AppEvent>) -> Sender<ChunkMapWork> {
+    spawn_walk_worker(tx_events, |request: ChunkMapRequest, walk| {
+        let key = request.key;
+        let event = match read_chunk_map(&request.dataset, &mut || walk.keep_going()) {
+            Ok(Some(map)) => ChunkMapLoadResult::Success { key, map },
+            Ok(None) if walk.superseded() => return,
+            Ok(None) => ChunkMapLoadResult::Failure {
+                key,
+                message: "Dataset does not use chunked storage".to_string(),
+            },
+            Err(error) => ChunkMapLoadResult::Failure {
+                key,
+                message: error.to_string(),
+            },
+        };
+        walk.send(AppEvent::ChunkMap(event));
+    })
+}
+
+pub fn render_chunk_map(
+    f: &mut Frame,
+    area: &Rect,
+    node: &mut H5FNode,
+    state: &mut AppState,
+) -> Result<(), AppError> {
+    let (ds, attr) = match node.node.clone() {
+        Node::Dataset(DatasetHandle::Loaded(ds), DatasetMetaState::Loaded(attr)) => (ds, attr),
+        _ => {
+            render_not_yet_implemented(f, area, "Chunk map is only available for datasets");
+            return Ok(());
+        }
+    };
+    if attr.is_empty() {
+        render_empty_dataset(f, area);
+        return Ok(());
+    }
+
+    let key = ChunkMapKey {
+        file_generation: state.content_generation,
+        ds_path: attr.virtual_path().unwrap_or(&ds.name()).to_string(),
+    };
+    state.chunk_map.show(&key);
+    let Some(map) = state.chunk_map.map.clone() else {
+        let message = match &state.chunk_map.error {
+            Some((error_key, message)) if error_key == &key => message.clone(),
+            _ => {
+                state.chunk_map.request(key, &ds);
+                "Reading chunk index...".to_string()
+            }
+        };
+        render_not_yet_implemented(f, area, &message);
+        return Ok(());
+    };
+
+    let area_inner = area.inner(ratatui::layout::Margin {
+        horizontal: 1,
+        vertical: 1,
+    });
+    if area_inner.width < 8 || area_inner.height < DETAIL_HEIGHT + 3 {
+        return Ok(());
+    }
+
+    let rank = map.grid.len();
+    node.sync_selection_rank(rank);
+    let (row_dim, col_dim) = chunk_plane_dims(node, rank);
+    let header_height = if rank > 2 { 4 } else { 0 };
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(header_height),
+            Constraint::Min(3),
+            Constraint::Length(DETAIL_HEIGHT),
+        ])
+        .split(area_inner);
+
+    if rank > 2 {
+        render_dim_selector(
+            f,
+            &layout[0],
+            node,
+            &attr.shape,
+            RenderDimSelectorOptions {
+                row_columns: true,
+                page_info: None,
+                panel_title: "Chunk plane",
+                detail_lines: None,
+            },
+        )?;
+    }
+
+    state.chunk_map.plane_rows = row_dim.map_or(1, |dim| map.grid[dim]);
+    state.chunk_map.plane_cols = map.grid[col_dim];
+    let grid_block = rounded_panel(format!(
+        "Chunks {} · {}/{} allocated · {} stored",
+        map.grid
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join("×"),
+        map.allocated_chunks(),
+        map.total_chunks(),
+        human_bytes(map.total_stored)
+    ));
+    let grid_inner = grid_block.inner(layout[1]);
+    f.render_widget(grid_block, layout[1]);
+    let visible_rows = grid_inner.height as usize;
+    let visible_cols = (grid_inner.width / CELL_WIDTH) as usize;
+    state.chunk_map.scroll_to_cursor(visible_rows, visible_cols);
+
+    let mut coords = cursor_coords(&map, node, state);
+    let mut lines = Vec::with_capacity(visible_rows);
+    for row in state.chunk_map.row_offset
+        ..(state.chunk_map.row_offset + visible_rows).min(state.chunk_map.plane_rows)
+    {
+        let mut spans = Vec::with_capacity(visible_cols);
+        for col in state.chunk_map.col_offset
+            ..(state.chunk_map.col_offset + visible_cols).min(state.chunk_map.plane_cols)
+        {
+            if let Some(row_dim) = row_dim {
+                coords[row_dim] = row;
+            }
+            coords[col_dim] = col;
+            let selected = row == state.chunk_map.cursor_row && col == state.chunk_map.cursor_col;
+            spans.push(chunk_cell(&map, &coords, selected));
+        }
+        lines.push(Line::from(spans));
+    }
+    f.render_widget(Paragraph::new(lines), grid_inner);
+
+    let coords = cursor_coords(&map, node, state);
+    let detail_block = rounded_panel("Selected chunk");
+    let detail_inner = detail_block.inner(layout[2]);
+    f.render_widget(detail_block, layout[2]);
+    f.render_widget(
+        Paragraph::new(chunk_detail_lines(&map, &coords)),
+        detail_inner,
+    );
+    Ok(())
+}
+
+/// Grid coordinates of the chunk under the cursor. Dimensions outside the drawn
+/// plane follow the chunk holding the node's selected index.
+fn cursor_coords(map: &ChunkMap, node: &H5FNode, state: &AppState) -> Vec<usize> {
+    let rank = map.grid.len();
+    let (row_dim, col_dim) = chunk_plane_dims(node, rank);
+    let mut coords = (0..rank)
+        .map(|dim| {
+            let index = node.selected_indexes.get(dim).copied().unwrap_or_default();
+            (index / map.chunk_shape[dim].max(1)).min(map.grid[dim].saturating_sub(1))
+        })
+        .collect::<Vec<_>>();
+    if let Some(row_dim) = row_dim {
+        coords[row_dim] = state.chunk_map.cursor_row;
+    }
+    coords[col_dim] = state.chunk_map.cursor_col;
+    coords
+}
+
+fn chunk_color(fraction: f64) -> Color {
+    let (r, g, b) = heatmap_colormap_rgb(0.2 + 0.8 * fraction, HeatmapColormap::Inferno);
+    Color::Rgb(r, g, b)
+}
+
+fn chunk_cell(map: &ChunkMap, coords: &[usize], selected: bool) -> Span<'static> {
+    match (map.chunk_at(coords), selected) {
+        (Some(chunk), false) => Span::styled(
+            "██",
+            Style::default().fg(chunk_color(map.fill_fraction(chunk))),
+        ),
+        (Some(chunk), true) => Span::styled(
+            "[]",
+            Style::default()
+                .fg(configure::themed_color(|colors| colors.accent.selection_fg))
+                .bg(chunk_color(map.fill_fraction(chunk)))
+                .bold(),
+        ),
+        (None, false) => Span::styled(
+            "··",
+            Style::default().fg(configure::themed_color(|colors| colors.help.muted)),
+        ),
+        (None, true) => Span::styled(
+            "[]",
+            Style::default()
+                .fg(configure::themed_color(|colors| colors.accent.selection_fg))
+                .bg(configure::themed_color(|colors| colors.accent.selection_bg))
+                .bold(),
+        ),
+    }
+}
+
+fn detail_line(label: &'static str, value: String) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(
+            format!("{label:<8}"),
+            Style::default()
+                .fg(configure::themed_color(|colors| colors.file.label))
+                .bold(),
+        ),
+        Span::styled(
+            value,
+            Style::default().fg(configure::themed_color(|colors| colors.text.primary)),
+        ),
+    ])
+}
+
+fn format_coords(values: &[usize]) -> String {
+    format!(
+        "[{}]",
+        values
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn chunk_detail_lines(map: &ChunkMap, coords: &[usize]) -> Vec<Line<'static>> {
+    let origin = map.element_origin(coords);
+    let extent = origin
+        .iter()
+        .zip(&map.chunk_shape)
+        .zip(&map.shape)
+        .map(|((start, chunk), len)| format!("{start}..{}", (start + chunk).min(*len)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut lines = vec![
+        detail_line(
+            "chunk",
+            format!("{} of {}", format_coords(coords), format_coords(&map.grid)),
+        ),
+        detail_line("offset", format!("{} ([{extent}])", format_coords(&origin))),
+    ];
+    match map.chunk_at(coords) {
+        Some(chunk) => {
+            let ratio = if chunk.size > 0 {
+                format!(" · {:.2}x", map.chunk_bytes as f64 / chunk.size as f64)
+            } else {
+                String::new()
+            };
+            lines.push(detail_line(
+                "stored",
+                format!(
+                    "{} of {}{ratio}",
+                    human_bytes(chunk.size),
+                    human_bytes(map.chunk_bytes)
+                ),
+            ));
+            let skipped = map.skipped_filters(chunk.filter_mask);
+            lines.push(detail_line(
+                "filters",
+                if skipped.is_empty() {
+                    format!("mask {:#010x} (all applied)", chunk.filter_mask)
+                } else {
+                    format!(
+                        "mask {:#010x} (skipped: {})",
+                        chunk.filter_mask,
+                        skipped.join(", ")
+                    )
+                },
+            ));
+            lines.push(detail_line("address", format!("{:#x}", chunk.addr)));
+        }
+        None => {
+            lines.push(detail_line(
+                "stored",
+                "unallocated (reads return the fill value)".to_string(),
+            ));
+        }
+    }
+    lines
+}
+
+pub fn selected_chunk_copy_text(node: &H5FNode, state: &AppState) -> Option<String> {
+    let map = state.chunk_map.map.as_ref()?;
+    let coords = cursor_coords(map, node, state);
+    let text = chunk_detail_lines(map, &coords)
+        .iter()
+        .map(|line| {
+            line.spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    Some(text)
+}
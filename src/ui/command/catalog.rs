@@ -1,10 +1,11 @@
 use super::{
     handlers::{
-        handle_attr, handle_col, handle_configure, handle_dim, handle_down, handle_focus,
-        handle_goto, handle_heatmap, handle_help, handle_index, handle_left, handle_logs,
-        handle_mchart, handle_mode, handle_page_down, handle_page_up, handle_press, handle_quit,
-        handle_reload, handle_repeat, handle_right, handle_row, handle_seek, handle_seek_col,
-        handle_seek_page, handle_seek_row, handle_toggle_tree, handle_up, handle_x,
+        handle_attr, handle_col, handle_configure, handle_decode, handle_dim, handle_down,
+        handle_find, handle_focus, handle_goto, handle_heatmap, handle_help, handle_index,
+        handle_left, handle_logs, handle_mchart, handle_mode, handle_object, handle_page_down,
+        handle_page_up, handle_press, handle_quit, handle_reload, handle_repeat, handle_right,
+        handle_row, handle_seek, handle_seek_col, handle_seek_page, handle_seek_row,
+        handle_toggle_tree, handle_tree_columns, handle_tree_sort, handle_up, handle_x,
     },
     CommandArgKind, CommandArgSpec, CommandCategory, CommandDescriptor, CommandId,
 };
@@ -80,7 +81,7 @@ const MODE_ARG: CommandArgSpec = CommandArgSpec {
     kind: CommandArgKind::Word,
     required: true,
     help: "Content mode to activate.",
-    values: &["preview", "matrix", "heatmap"],
+    values: &["preview", "matrix", "heatmap", "chunks", "usage"],
 };
 
 const PATH_ARG: CommandArgSpec = CommandArgSpec {
@@ -91,6 +92,23 @@ const PATH_ARG: CommandArgSpec = CommandArgSpec {
     values: &["/group/dataset"],
 };
 
+const FIND_QUERY_ARG: CommandArgSpec = CommandArgSpec {
+    name: "query",
+    kind: CommandArgKind::Word,
+    required: false,
+    help:
+        "nan, a number, a comparison such as >=3.5, or text; reopens the last results when omitted.",
+    values: &["nan", ">", ">=", "<", "<=", "=", "!="],
+};
+
+const FIND_VALUE_ARG: CommandArgSpec = CommandArgSpec {
+    name: "value",
+    kind: CommandArgKind::Word,
+    required: false,
+    help: "Value compared against when the query is a comparison operator on its own.",
+    values: &[],
+};
+
 const OPTIONAL_COMMAND_ARG: CommandArgSpec = CommandArgSpec {
     name: "command",
     kind: CommandArgKind::Word,
@@ -104,7 +122,7 @@ const ATTR_ACTION_ARG: CommandArgSpec = CommandArgSpec {
     kind: CommandArgKind::Word,
     required: true,
     help: "Attribute action to run.",
-    values: &["create", "delete"],
+    values: &["create", "retype", "delete"],
 };
 
 const CONFIGURE_ACTION_ARG: CommandArgSpec = CommandArgSpec {
@@ -127,18 +145,69 @@ const ATTR_TYPE_ARG: CommandArgSpec = CommandArgSpec {
     name: "type",
     kind: CommandArgKind::Word,
     required: false,
-    help: "Attribute type when creating.",
-    values: &["bool", "i64", "u64", "f64", "string", "ascii"],
+    help: "Attribute type when creating or retyping; add [] or [2x3] for an array.",
+    values: &[
+        "bool", "i32", "i64", "u64", "f32", "f64", "string", "ascii", "f64[]", "string[]",
+    ],
 };
 
 const ATTR_VALUE_ARG: CommandArgSpec = CommandArgSpec {
     name: "value",
     kind: CommandArgKind::Word,
     required: false,
-    help: "Optional initial value when creating.",
+    help: "Optional value; array values are separated by commas.",
+    values: &[],
+};
+
+const OBJECT_ACTION_ARG: CommandArgSpec = CommandArgSpec {
+    name: "action",
+    kind: CommandArgKind::Word,
+    required: true,
+    help: "Structure edit to confirm and write.",
+    values: &[
+        "group",
+        "dataset",
+        "move",
+        "soft-link",
+        "external-link",
+        "delete",
+    ],
+};
+
+const OBJECT_PATH_ARG: CommandArgSpec = CommandArgSpec {
+    name: "path",
+    kind: CommandArgKind::Word,
+    required: false,
+    help: "Object path, absolute or relative to the selected group; the link target for soft-link and the file for external-link.",
+    values: &[],
+};
+
+const OBJECT_ARG_2: CommandArgSpec = CommandArgSpec {
+    name: "arg2",
+    kind: CommandArgKind::Word,
+    required: false,
+    help: "Dataset type, move destination, soft link path, or external link target.",
+    values: &[
+        "i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64", "f32", "f64", "bool", "string",
+    ],
+};
+
+const OBJECT_ARG_3: CommandArgSpec = CommandArgSpec {
+    name: "arg3",
+    kind: CommandArgKind::Word,
+    required: false,
+    help: "Dataset shape such as 1000x3 or scalar, or the external link path.",
     values: &[],
 };
 
+const OBJECT_OPTION_ARG: CommandArgSpec = CommandArgSpec {
+    name: "option",
+    kind: CommandArgKind::Word,
+    required: false,
+    help: "Dataset option: chunks=<shape>, or gzip, lzf, blosc-lz4, zstd with an optional =level.",
+    values: &["chunks=", "gzip", "lzf", "blosc-lz4", "zstd"],
+};
+
 const MCHART_ACTION_ARG: CommandArgSpec = CommandArgSpec {
     name: "action",
     kind: CommandArgKind::Word,
@@ -214,6 +283,38 @@ const HEATMAP_ARG_4: CommandArgSpec = CommandArgSpec {
     values: &[],
 };
 
+const DECODE_ACTION_ARG: CommandArgSpec = CommandArgSpec {
+    name: "action",
+    kind: CommandArgKind::Word,
+    required: false,
+    help: "Turn CF decoding on or off; toggles when omitted.",
+    values: &["on", "off", "toggle"],
+};
+
+const TREE_SORT_ARG: CommandArgSpec = CommandArgSpec {
+    name: "order",
+    kind: CommandArgKind::Word,
+    required: true,
+    help: "Order of group members in the tree.",
+    values: &["file", "name", "creation", "size", "type"],
+};
+
+const TREE_COLUMNS_ARG: CommandArgSpec = CommandArgSpec {
+    name: "columns",
+    kind: CommandArgKind::Word,
+    required: false,
+    help: "Comma-separated columns to show, or on, off, or toggle; toggles when omitted.",
+    values: &[
+        "on",
+        "off",
+        "toggle",
+        "shape",
+        "dtype",
+        "size",
+        "shape,dtype,size",
+    ],
+};
+
 const KEY_ARG_1: CommandArgSpec = CommandArgSpec {
     name: "key1",
     kind: CommandArgKind::Word,
@@ -302,6 +403,17 @@ const COMMAND_CATALOG: &[CommandDescriptor] = &[
         example: "goto /runs/run_04/signal",
         handler: handle_goto,
     },
+    CommandDescriptor {
+        id: CommandId::Find,
+        name: "find",
+        aliases: &[],
+        description: "Scan the selected dataset for a value and list the indices of the hits",
+        category: CommandCategory::Navigation,
+        keybindings: &[],
+        args: &[FIND_QUERY_ARG, FIND_VALUE_ARG],
+        example: "find > 3.5",
+        handler: handle_find,
+    },
     CommandDescriptor {
         id: CommandId::Up,
         name: "up",
@@ -515,13 +627,31 @@ const COMMAND_CATALOG: &[CommandDescriptor] = &[
         id: CommandId::Attr,
         name: "attr",
         aliases: &["attribute"],
-        description: "Create or delete scalar attributes on the selected node",
+        description: "Create, retype or delete scalar and array attributes on the selected node",
         category: CommandCategory::Attributes,
         keybindings: &["a", "d", "Delete"],
         args: &[ATTR_ACTION_ARG, ATTR_NAME_ARG, ATTR_TYPE_ARG, ATTR_VALUE_ARG],
-        example: "attr create title string \"Run 42\"",
+        example: "attr create wavelengths f64[] \"400, 410, 420\"",
         handler: handle_attr,
     },
+    CommandDescriptor {
+        id: CommandId::Object,
+        name: "object",
+        aliases: &["obj"],
+        description: "Create groups, datasets and links, or move and delete objects, after confirmation",
+        category: CommandCategory::Structure,
+        keybindings: &["a", "A", "n", "S", "d", "Delete"],
+        args: &[
+            OBJECT_ACTION_ARG,
+            OBJECT_PATH_ARG,
+            OBJECT_ARG_2,
+            OBJECT_ARG_3,
+            OBJECT_OPTION_ARG,
+            OBJECT_OPTION_ARG,
+        ],
+        example: "object dataset raw f32 1000x3 chunks=100x3 gzip",
+        handler: handle_object,
+    },
     CommandDescriptor {
         id: CommandId::Repeat,
         name: "repeat",
@@ -572,6 +702,39 @@ const COMMAND_CATALOG: &[CommandDescriptor] = &[
         example: "heatmap range use \"Clip 1-99%\"",
         handler: handle_heatmap,
     },
+    CommandDescriptor {
+        id: CommandId::Decode,
+        name: "decode",
+        aliases: &[],
+        description: "Toggle CF scale_factor/add_offset decoding and fill-value masking of numeric data",
+        category: CommandCategory::View,
+        keybindings: &[],
+        args: &[DECODE_ACTION_ARG],
+        example: "decode on",
+        handler: handle_decode,
+    },
+    CommandDescriptor {
+        id: CommandId::TreeSort,
+        name: "tree-sort",
+        aliases: &["sort"],
+        description: "Order group members in the tree by file order, natural name, creation order, size, or type",
+        category: CommandCategory::View,
+        keybindings: &[],
+        args: &[TREE_SORT_ARG],
+        example: "tree-sort name",
+        handler: handle_tree_sort,
+    },
+    CommandDescriptor {
+        id: CommandId::TreeColumns,
+        name: "tree-columns",
+        aliases: &[],
+        description: "Show dataset shape, dtype, and storage size after member names in the tree",
+        category: CommandCategory::View,
+        keybindings: &[],
+        args: &[TREE_COLUMNS_ARG],
+        example: "tree-columns shape,dtype",
+        handler: handle_tree_columns,
+    },
 ];
 
 pub fn command_catalog() -> &'static [CommandDescriptor] {
@@ -1,14 +1,15 @@
 use crate::{
+    configure::{self, DataSettings, TreeColumns, TreeSettings, TreeSortOrder},
     error::AppError,
-    h5f::AttributeCreateType,
+    h5f::{parent_object_path, resolve_object_path, AttributeSpec, DatasetSpec, StructureEdit},
     ui::mchart::ChartZoomMode,
     ui::state::{HeatmapRangeBound, HeatmapRangeMode},
 };
 use ratatui::crossterm::event::Event;
 
 use super::super::{
-    input::{handle_input_event, EventResult},
-    state::{AppState, AppToast, ContentShowMode, Focus, Mode},
+    input::{attributes::edit_new_attribute_values, handle_input_event, EventResult},
+    state::{AppState, AppToast, ContentShowMode, Focus, Mode, StructureEditDialogState},
 };
 use super::{
     command_metadata, execute_command, parsing::describe_command_metadata, CommandArgValue,
@@ -53,6 +54,30 @@ pub(super) fn handle_goto(
     Ok(EventResult::Redraw)
 }
 
+pub(super) fn handle_find(
+    state: &mut AppState<'_>,
+    command: &CommandInvocation,
+) -> Result<EventResult, AppError> {
+    if matches!(state.command_return_mode, Mode::MultiChart) {
+        return Err(AppError::InvalidCommand(
+            "find is not available in the multi-chart view".to_string(),
+        ));
+    }
+    let query = [command.word_arg_optional(0)?, command.word_arg_optional(1)?]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if !query.is_empty() {
+        state.start_find(&query)?;
+    } else if state.find.key.is_none() {
+        state.find.input.clear();
+        state.find.input_cursor = 0;
+    }
+    state.mode = Mode::Find;
+    Ok(EventResult::Redraw)
+}
+
 pub(super) fn handle_up(
     state: &mut AppState<'_>,
     command: &CommandInvocation,
@@ -132,9 +157,11 @@ pub(super) fn handle_mode(
         "preview" => ContentShowMode::Preview,
         "matrix" => ContentShowMode::Matrix,
         "heatmap" => ContentShowMode::Heatmap,
+        "chunks" => ContentShowMode::Chunks,
+        "usage" => ContentShowMode::Usage,
         mode => {
             return Err(AppError::InvalidCommand(format!(
-                "Unknown content mode '{}'. Expected preview, matrix, or heatmap",
+                "Unknown content mode '{}'. Expected preview, matrix, heatmap, chunks, or usage",
                 mode
             )))
         }
@@ -173,6 +200,86 @@ pub(super) fn handle_reload(
     })
 }
 
+pub(super) fn handle_decode(
+    state: &mut AppState<'_>,
+    command: &CommandInvocation,
+) -> Result<EventResult, AppError> {
+    let settings = configure::current_data_settings();
+    let cf_decoding = match command
+        .word_arg_optional(0)?
+        .map(str::to_ascii_lowercase)
+        .as_deref()
+    {
+        None | Some("toggle") => !settings.cf_decoding,
+        Some("on") => true,
+        Some("off") => false,
+        Some(action) => {
+            return Err(AppError::InvalidCommand(format!(
+                "Unknown decode action '{action}'. Expected on, off, or toggle"
+            )));
+        }
+    };
+    configure::set_data_settings(&DataSettings {
+        cf_decoding,
+        ..settings
+    });
+    Ok(EventResult::ReloadFile {
+        write: !state.readonly,
+    })
+}
+
+pub(super) fn handle_tree_sort(
+    state: &mut AppState<'_>,
+    command: &CommandInvocation,
+) -> Result<EventResult, AppError> {
+    let order = command.word_arg(0)?;
+    let sort = TreeSortOrder::parse(order).ok_or_else(|| {
+        AppError::InvalidCommand(format!(
+            "Unknown tree sort order '{order}'. Expected file, name, creation, size, or type"
+        ))
+    })?;
+    configure::set_tree_settings(&TreeSettings {
+        sort,
+        ..configure::current_tree_settings()
+    });
+    state.compute_tree_view();
+    Ok(EventResult::Redraw)
+}
+
+pub(super) fn handle_tree_columns(
+    state: &mut AppState<'_>,
+    command: &CommandInvocation,
+) -> Result<EventResult, AppError> {
+    let settings = configure::current_tree_settings();
+    let columns = match command
+        .word_arg_optional(0)?
+        .map(str::to_ascii_lowercase)
+        .as_deref()
+    {
+        None | Some("toggle") if settings.columns.any() => TreeColumns::default(),
+        None | Some("toggle" | "on") => TreeColumns::ALL,
+        Some("off") => TreeColumns::default(),
+        Some(names) => {
+            let mut columns = TreeColumns::default();
+            for name in names.split(',').filter(|name| !name.trim().is_empty()) {
+                if !columns.enable(name) {
+                    return Err(AppError::InvalidCommand(format!(
+                        "Unknown tree column '{}'. Expected shape, dtype, size, on, off, or toggle",
+                        name.trim()
+                    )));
+                }
+            }
+            columns
+        }
+    };
+    configure::set_tree_settings(&TreeSettings {
+        columns,
+        ..settings
+    });
+    state.compute_tree_view();
+    Ok(EventResult::Redraw)
+}
+
 pub(super) fn handle_configure(
     _state: &mut AppState<'_>,
     command: &CommandInvocation,
@@ -514,10 +621,14 @@ pub(super) fn handle_attr(
     match action.as_str() {
         "create" | "add" | "new" => {
             let attr_name = command.word_arg(1)?;
-            let attr_type = AttributeCreateType::parse(command.word_arg(2)?)?;
-            let value = command.word_arg_optional(3)?.unwrap_or("");
+            let spec = AttributeSpec::parse(command.word_arg(2)?)?;
+            let value = match command.word_arg_optional(3)? {
+                Some(value) => value.to_string(),
+                None if spec.is_array() => edit_new_attribute_values(state, attr_name)?,
+                None => String::new(),
+            };
             let mut selected_node = state.treeview[state.tree_view_cursor].node.borrow_mut();
-            let created_type = selected_node.create_attribute(attr_name, attr_type, value)?;
+            let created_type = selected_node.create_attribute(attr_name, &spec, &value)?;
             drop(selected_node);
             state.invalidate_selected_navigation_data();
             state.acknowledge_file_write();
@@ -529,6 +640,23 @@ pub(super) fn handle_attr(
                 true,
             ))
         }
+        "retype" | "convert" => {
+            let attr_name = command.word_arg(1)?;
+            let spec = AttributeSpec::parse(command.word_arg(2)?)?;
+            let value = command.word_arg_optional(3)?;
+            let mut selected_node = state.treeview[state.tree_view_cursor].node.borrow_mut();
+            let new_type = selected_node.retype_attribute(attr_name, &spec, value)?;
+            drop(selected_node);
+            state.invalidate_selected_navigation_data();
+            state.acknowledge_file_write();
+            Ok(EventResult::Toast(
+                AppToast::Info(format!(
+                    "Recreated attribute '{}' as {}",
+                    attr_name, new_type
+                )),
+                true,
+            ))
+        }
         "delete" | "remove" | "rm" => {
             let attr_name = command.word_arg(1)?;
             let mut selected_node = state.treeview[state.tree_view_cursor].node.borrow_mut();
@@ -542,12 +670,80 @@ pub(super) fn handle_attr(
             ))
         }
         other => Err(AppError::InvalidCommand(format!(
-            "Unknown attr action '{}'. Expected create or delete",
+            "Unknown attr action '{}'. Expected create, retype or delete",
             other
         ))),
     }
 }
 
+/// Checks the edit against the file and asks for confirmation before writing.
+pub(super) fn handle_object(
+    state: &mut AppState<'_>,
+    command: &CommandInvocation,
+) -> Result<EventResult, AppError> {
+    if state.readonly {
+        return Err(AppError::EditError(
+            "Cannot edit in read-only mode; reopen with -w to modify the file".to_string(),
+        ));
+    }
+
+    let base = state.selected_group_path();
+    let resolve = |path: &str| resolve_object_path(&base, path);
+    let action = command.word_arg(0)?.to_ascii_lowercase();
+    let edit = match action.as_str() {
+        "group" | "mkdir" => StructureEdit::CreateGroup {
+            path: resolve(command.word_arg(1)?),
+        },
+        "dataset" | "ds" => {
+            let options = (4..command.args.len())
+                .map(|index| command.word_arg(index))
+                .collect::<Result<Vec<_>, _>>()?;
+            StructureEdit::CreateDataset(DatasetSpec::parse(
+                resolve(command.word_arg(1)?),
+                command.word_arg(2)?,
+                command.word_arg(3)?,
+                &options,
+            )?)
+        }
+        "move" | "mv" | "rename" => {
+            let from = resolve(command.word_arg(1)?);
+            let to = command.word_arg(2)?;
+            // A bare name renames the object within its group.
+            let to = if to.contains('/') {
+                resolve(to)
+            } else {
+                resolve_object_path(parent_object_path(&from), to)
+            };
+            StructureEdit::Move { from, to }
+        }
+        "soft-link" | "link" | "ln" => StructureEdit::SoftLink {
+            target: command.word_arg(1)?.to_string(),
+            path: resolve(command.word_arg(2)?),
+        },
+        "external-link" | "extlink" => StructureEdit::ExternalLink {
+            file: command.word_arg(1)?.to_string(),
+            target: resolve_object_path("/", command.word_arg(2)?),
+            path: resolve(command.word_arg(3)?),
+        },
+        "delete" | "remove" | "rm" => StructureEdit::Delete {
+            path: resolve(command.word_arg(1)?),
+        },
+        other => {
+            return Err(AppError::InvalidCommand(format!(
+                "Unknown object action '{}'. Expected group, dataset, move, soft-link, external-link or delete",
+                other
+            )))
+        }
+    };
+    let Some(file) = state.file.as_ref() else {
+        return Err(AppError::EditError("No HDF5 file is open".to_string()));
+    };
+    edit.check(file)?;
+    state.structure_edit_dialog = Some(StructureEditDialogState { edit });
+    state.mode = Mode::StructureEditDialog;
+    Ok(EventResult::Redraw)
+}
+
 pub(super) fn handle_repeat(
     state: &mut AppState<'_>,
     _command: &CommandInvocation,
@@ -578,6 +774,7 @@ pub(super) fn handle_press(
             EventResult::Quit
             | EventResult::Configure { .. }
             | EventResult::ReloadFile { .. }
+            | EventResult::OpenFile { .. }
             | EventResult::Error(_)
             | EventResult::Toast(_, _)
             | EventResult::Copying => return Ok(result),
@@ -32,7 +32,7 @@ pub use parsing::{
     command_keybindings_metadata, command_matches, command_metadata, command_metadata_by_handle,
     command_usage_metadata, current_command_metadata, describe_command_invocation,
     describe_command_metadata, format_command_invocation, parse_command_text,
-    parse_startup_commands, selected_command_metadata,
+    parse_startup_commands, quote_command_word, selected_command_metadata,
 };
 pub use view::render_command_dialog;
 
@@ -42,6 +42,7 @@ pub enum CommandCategory {
     View,
     Selection,
     Attributes,
+    Structure,
     App,
     MultiChart,
     Input,
@@ -54,6 +55,7 @@ pub enum CommandId {
     SeekCol,
     SeekPage,
     Goto,
+    Find,
     Up,
     Down,
     Left,
@@ -74,10 +76,14 @@ pub enum CommandId {
     Index,
     Help,
     Attr,
+    Object,
     Repeat,
     MultiChart,
     Press,
     Heatmap,
+    Decode,
+    TreeSort,
+    TreeColumns,
     Custom,
     Noop,
 }
@@ -267,6 +273,13 @@ impl CommandState {
         self.history_draft = None;
     }
 
+    /// Starts an entry with `text` already typed and the cursor at `cursor`.
+    pub fn begin_prefilled_entry(&mut self, text: String, cursor: usize) {
+        self.begin_new_entry();
+        self.cursor = cursor.min(text.len());
+        self.command_buffer = text;
+    }
+
     pub fn note_buffer_edited(&mut self) {
         self.reset_suggestion_selection();
         self.history_cursor = None;
@@ -496,6 +509,7 @@ fn execute_registered_command_callback(
                         crate::h5f::Node::File(_) => "file",
                         crate::h5f::Node::Group(_, _) => "group",
                         crate::h5f::Node::Dataset(_, _) => "dataset",
+                        crate::h5f::Node::Datatype(_, _) => "datatype",
                         crate::h5f::Node::Broken(_) => "broken",
                     };
                     let attribute_names = node.node.attribute_names().unwrap_or_default();
@@ -434,20 +434,23 @@ fn first_token(buffer: &str) -> Option<&str> {
     buffer.split_whitespace().next()
 }
 
+/// Quotes `value` when the command tokenizer would otherwise split it.
+pub fn quote_command_word(value: &str) -> String {
+    if value.is_empty()
+        || value
+            .chars()
+            .any(|ch| ch.is_whitespace() || matches!(ch, '"' | '\\' | ';'))
+    {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        value.to_string()
+    }
+}
+
 fn format_command_arg(arg: &CommandArgValue) -> String {
     match arg {
         CommandArgValue::UnsignedInt(value) => value.to_string(),
-        CommandArgValue::Word(value) => {
-            if value.is_empty()
-                || value
-                    .chars()
-                    .any(|ch| ch.is_whitespace() || matches!(ch, '"' | '\\' | ';'))
-            {
-                format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
-            } else {
-                value.clone()
-            }
-        }
+        CommandArgValue::Word(value) => quote_command_word(value),
     }
 }
 
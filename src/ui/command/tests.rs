@@ -294,6 +294,56 @@ fn parses_attr_delete_command() {
     );
 }
 
+#[test]
+fn parses_attr_array_commands() {
+    let command = parse_command_text(r#"attr create wavelengths f64[] "400, 410, 420""#)
+        .expect("attr create array");
+    assert_eq!(
+        command.args,
+        vec![
+            CommandArgValue::Word("create".to_string()),
+            CommandArgValue::Word("wavelengths".to_string()),
+            CommandArgValue::Word("f64[]".to_string()),
+            CommandArgValue::Word("400, 410, 420".to_string()),
+        ]
+    );
+
+    let command =
+        parse_command_text(r#"attr retype states "enum(idle, done)[2x1]""#).expect("attr retype");
+    assert_eq!(
+        command.args,
+        vec![
+            CommandArgValue::Word("retype".to_string()),
+            CommandArgValue::Word("states".to_string()),
+            CommandArgValue::Word("enum(idle, done)[2x1]".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn parses_object_commands() {
+    let command = parse_command_text("object dataset raw f32 1000 chunks=100 gzip=6")
+        .expect("object dataset");
+    assert_eq!(command.id, CommandId::Object);
+    assert_eq!(
+        command.args,
+        ["dataset", "raw", "f32", "1000", "chunks=100", "gzip=6"]
+            .into_iter()
+            .map(|word| CommandArgValue::Word(word.to_string()))
+            .collect::<Vec<_>>()
+    );
+
+    let command = parse_command_text(r#"obj rm "/runs/run 1""#).expect("object delete");
+    assert_eq!(command.id, CommandId::Object);
+    assert_eq!(
+        command.args,
+        vec![
+            CommandArgValue::Word("rm".to_string()),
+            CommandArgValue::Word("/runs/run 1".to_string()),
+        ]
+    );
+}
+
 #[test]
 fn describes_command_descriptor_with_aliases_and_keys() {
     let descriptor = find_command_descriptor("reload").expect("reload descriptor");
@@ -310,6 +360,52 @@ fn parses_configure_command() {
     assert!(command.args.is_empty());
 }
 
+#[test]
+fn parses_decode_command() {
+    let command = parse_command_text("decode off").expect("decode command");
+    assert_eq!(command.id, CommandId::Decode);
+    assert_eq!(command.args, vec![CommandArgValue::Word("off".to_string())]);
+    let command = parse_command_text("decode").expect("bare decode command");
+    assert!(command.args.is_empty());
+}
+
+#[test]
+fn parses_tree_sort_and_columns_commands() {
+    let command = parse_command_text("tree-sort creation").expect("tree-sort command");
+    assert_eq!(command.id, CommandId::TreeSort);
+    assert_eq!(
+        command.args,
+        vec![CommandArgValue::Word("creation".to_string())]
+    );
+    assert!(parse_command_text("tree-sort").is_err());
+    let command = parse_command_text("tree-columns shape,size").expect("tree-columns command");
+    assert_eq!(command.id, CommandId::TreeColumns);
+    assert_eq!(
+        command.args,
+        vec![CommandArgValue::Word("shape,size".to_string())]
+    );
+}
+
+#[test]
+fn parses_find_command() {
+    let command = parse_command_text("find >= 3.5").expect("find command");
+    assert_eq!(command.id, CommandId::Find);
+    assert_eq!(
+        command.args,
+        vec![
+            CommandArgValue::Word(">=".to_string()),
+            CommandArgValue::Word("3.5".to_string()),
+        ]
+    );
+    let command = parse_command_text("find \"run 1\"").expect("quoted find command");
+    assert_eq!(
+        command.args,
+        vec![CommandArgValue::Word("run 1".to_string())]
+    );
+    let command = parse_command_text("find").expect("bare find command");
+    assert!(command.args.is_empty());
+}
+
 #[test]
 fn parses_configure_reset_command() {
     let command = parse_command_text("configure reset").expect("configure reset command");
@@ -282,6 +282,9 @@ pub trait HasMatrixSelection {
         select: MatrixSelection,
         total_dims: &[usize],
     ) -> Selection;
+
+    /// Dataset index of the matrix cursor, one entry per dimension.
+    fn matrix_cursor_indexes(&self, node: &H5FNode, shape: &[usize]) -> Vec<usize>;
 }
 
 impl HasMatrixSelection for AppState<'_> {
@@ -334,4 +337,52 @@ impl HasMatrixSelection for AppState<'_> {
         }
         Selection::Hyperslab(Hyperslab::from(slice))
     }
+
+    fn matrix_cursor_indexes(&self, node: &H5FNode, shape: &[usize]) -> Vec<usize> {
+        let rank = shape.len();
+        let mut indexes = node.selected_indexes.clone();
+        indexes.resize(rank, 0);
+        if rank == 0 {
+            return indexes;
+        }
+        let (row_dim, col_dim) = if rank == 1 {
+            (0, None)
+        } else {
+            (
+                node.selected_row.min(rank - 1),
+                Some(node.selected_col.min(rank - 1)),
+            )
+        };
+        let visible_rows = self
+            .matrix_view_state
+            .rows_currently_available
+            .max(1)
+            .min(shape[row_dim]);
+        let base_row = self
+            .matrix_view_state
+            .row_offset
+            .min(shape[row_dim].saturating_sub(visible_rows));
+        indexes[row_dim] = base_row
+            + self
+                .matrix_view_state
+                .cursor_row
+                .min(visible_rows.saturating_sub(1));
+        if let Some(col_dim) = col_dim {
+            let visible_cols = self
+                .matrix_view_state
+                .cols_currently_available
+                .max(1)
+                .min(shape[col_dim]);
+            let base_col = self
+                .matrix_view_state
+                .col_offset
+                .min(shape[col_dim].saturating_sub(visible_cols));
+            indexes[col_dim] = base_col
+                + self
+                    .matrix_view_state
+                    .cursor_col
+                    .min(visible_cols.saturating_sub(1));
+        }
+        indexes
+    }
 }
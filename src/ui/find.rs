@@ -0,0 +1,167 @@
+use std::sync::mpsc::Sender;
+
+use ratatui::{
+    layout::{Position, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::{
+    configure,
+    h5f::{find_in_dataset, FindEnd, FindHit, MAX_FIND_HITS},
+    ui::{
+        app::{AppEvent, FindLoadResult},
+        chrome::{rounded_panel, truncate_to_width},
+        cursor::set_input_cursor,
+        state::{AppState, FindRequest, FindWork},
+        walk_worker::spawn_walk_worker,
+    },
+};
+
+/// Scans one dataset at a time, sending the hits found since the previous
+/// progress event.
+pub(crate) fn handle_find_load(tx_events: Sender<AppEvent>) -> Sender<FindWork> {
+    spawn_walk_worker(tx_events, |request: FindRequest, walk| {
+        let key = request.key;
+        let mut hits = Vec::new();
+        let mut scanned = 0;
+        let result = find_in_dataset(&request.dataset, &request.query, &mut |batch| {
+            if !walk.keep_going() {
+                return false;
+            }
+            hits.extend(batch.hits);
+            scanned = batch.scanned;
+            if !walk.batch_due() {
+                return true;
+            }
+            walk.send(AppEvent::Find(FindLoadResult::Progress {
+                key: key.clone(),
+                scanned,
+                hits: std::mem::take(&mut hits),
+            }))
+        });
+        let event = match result {
+            Ok(_) if walk.superseded() => return,
+            Ok(end) => FindLoadResult::Success {
+                key,
+                scanned,
+                hits,
+                end,
+            },
+            Err(error) => FindLoadResult::Failure {
+                key,
+                message: error.to_string(),
+            },
+        };
+        walk.send(AppEvent::Find(event));
+    })
+}
+
+fn status_text(state: &AppState) -> String {
+    let find = &state.find;
+    if let Some(error) = &find.error {
+        return format!("Error: {error}");
+    }
+    if find.key.is_none() {
+        return "Enter searches the selected dataset".to_string();
+    }
+    let hits = match find.hits.len() {
+        1 => "1 hit".to_string(),
+        count => format!("{count} hits"),
+    };
+    if find.is_scanning() {
+        let percent = find.scanned as f64 * 100.0 / find.total.max(1) as f64;
+        format!(
+            "{hits} · scanned {} of {} ({percent:.0}%)",
+            find.scanned, find.total
+        )
+    } else {
+        match find.end {
+            FindEnd::Truncated => format!("first {MAX_FIND_HITS} hits · more not listed"),
+            FindEnd::LimitReached => format!("{hits} · limit reached, scan stopped"),
+            FindEnd::Complete | FindEnd::Stopped => hits,
+        }
+    }
+}
+
+/// Hit index as shown in the list; MATLAB variables list subscripts in
+/// MATLAB order, the reverse of the HDF5 index.
+fn hit_line(hit: &FindHit, matlab_order: bool, selected: bool, width: usize) -> Line<'static> {
+    let mut index = hit.index.iter().map(usize::to_string).collect::<Vec<_>>();
+    if matlab_order {
+        index.reverse();
+    }
+    let index = index.join(", ");
+    let text = truncate_to_width(&format!("[{index}]  {}", hit.value), width);
+    let style = if selected {
+        Style::default()
+            .fg(configure::themed_color(|colors| colors.accent.selection_fg))
+            .bg(configure::themed_color(|colors| colors.accent.selection_bg))
+            .bold()
+    } else {
+        Style::default().fg(configure::themed_color(|colors| colors.text.primary))
+    };
+    Line::from(Span::styled(text, style))
+}
+
+/// Query line and hit list shown below the content while finding values.
+pub fn render_find_panel(f: &mut Frame, area: Rect, state: &mut AppState) {
+    let path = state
+        .find
+        .key
+        .as_ref()
+        .map(|key| format!(" · {}", key.path))
+        .unwrap_or_default();
+    let block = rounded_panel(format!("Find{path} · Enter opens a hit, Esc closes"));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    if inner.height == 0 {
+        return;
+    }
+
+    let input = Line::from(vec![
+        Span::styled(
+            " ",
+            Style::default().fg(configure::themed_color(|colors| colors.accent.search_icon)),
+        ),
+        Span::styled(
+            format!(" {}", state.find.input),
+            Style::default().fg(configure::themed_color(|colors| colors.text.search_text)),
+        ),
+        Span::styled(
+            format!("  {}", status_text(state)),
+            Style::default().fg(configure::themed_color(|colors| colors.text.search_count)),
+        ),
+    ]);
+    f.render_widget(Paragraph::new(input), inner);
+    let cursor_column = state.find.input[..state.find.input_cursor].chars().count() as u16;
+    set_input_cursor(f, Position::new(inner.x + 3 + cursor_column, inner.y));
+
+    let visible_rows = inner.height.saturating_sub(1) as usize;
+    state.find.list.scroll_to_cursor(visible_rows);
+    let lines = state
+        .find
+        .hits
+        .iter()
+        .enumerate()
+        .skip(state.find.list.row_offset)
+        .take(visible_rows)
+        .map(|(index, hit)| {
+            hit_line(
+                hit,
+                state.find.matlab_order,
+                index == state.find.list.cursor,
+                inner.width as usize,
+            )
+        })
+        .collect::<Vec<_>>();
+    let list_area = Rect::new(
+        inner.x,
+        inner.y + 1,
+        inner.width,
+        inner.height.saturating_sub(1),
+    );
+    f.render_widget(Paragraph::new(lines), list_area);
+}
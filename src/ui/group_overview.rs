@@ -0,0 +1,488 @@
+use std::{cmp::Ordering, sync::mpsc::Sender};
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::{
+    configure,
+    h5f::{human_bytes, DatasetHandle, DatasetMetaState, H5FNode, HasAttributes, HasPath, Node},
+    ui::{
+        app::{AppEvent, GroupOverviewLoadResult},
+        chrome::rounded_panel,
+        state::{
+            AppState, GroupOverviewMember, GroupOverviewRequest, GroupOverviewSort,
+            GroupOverviewState, GroupOverviewWork,
+        },
+        std_comp_render::render_error,
+        walk_worker::spawn_walk_worker,
+    },
+};
+
+const KIND_WIDTH: usize = 8;
+const SHAPE_WIDTH: usize = 16;
+const DTYPE_WIDTH: usize = 14;
+const SIZE_WIDTH: usize = 10;
+const RATIO_WIDTH: usize = 7;
+const COUNT_WIDTH: usize = 5;
+
+/// Reads the metadata and attribute count of one member at a time, sending
+/// what was read in batches.
+pub(crate) fn handle_group_overview_load(tx_events: Sender<AppEvent>) -> Sender<GroupOverviewWork> {
+    spawn_walk_worker(tx_events, |request: GroupOverviewRequest, walk| {
+        let mut members = Vec::new();
+        for node in request.members {
+            if !walk.keep_going() {
+                return;
+            }
+            members.push(read_member(node));
+            if walk.batch_due()
+                && !walk.send(AppEvent::GroupOverview(GroupOverviewLoadResult {
+                    key: request.key.clone(),
+                    members: std::mem::take(&mut members),
+                    done: false,
+                }))
+            {
+                return;
+            }
+        }
+        walk.send(AppEvent::GroupOverview(GroupOverviewLoadResult {
+            key: request.key,
+            members,
+            done: true,
+        }));
+    })
+}
+
+fn read_member(node: Node) -> GroupOverviewMember {
+    let path = node.path();
+    let mut member = H5FNode::new(node);
+    let mut error = None;
+    let mut loaded = None;
+    if matches!(member.node, Node::Dataset(_, DatasetMetaState::Pending(_))) {
+        match member.ensure_dataset_meta() {
+            Ok(_) => loaded = Some(member.node.clone()),
+            Err(load_error) => error = Some(load_error.to_string()),
+        }
+    }
+    let names = match &member.node {
+        Node::Dataset(DatasetHandle::Pending { parent, name }, _) => parent
+            .dataset(name)
+            .and_then(|dataset| dataset.attr_names()),
+        node => node.attribute_names(),
+    };
+    let attributes = match names {
+        Ok(names) => Some(names.len()),
+        Err(names_error) => {
+            error.get_or_insert_with(|| names_error.to_string());
+            None
+        }
+    };
+    GroupOverviewMember {
+        path,
+        node: loaded,
+        attributes,
+        error,
+    }
+}
+
+/// Whether the preview of `node` is the member table.
+pub fn group_overview_active(node: &H5FNode) -> bool {
+    matches!(&node.node, Node::Group(_, meta) if meta.preview_expr.is_none()) && !node.is_cycle()
+}
+
+/// One member of a group as the overview shows it; `None` values are not
+/// known yet or do not apply to the member.
+#[derive(Debug, Clone, PartialEq)]
+struct OverviewRow {
+    path: String,
+    name: String,
+    kind: &'static str,
+    shape: Option<Vec<usize>>,
+    dtype: Option<String>,
+    storage: Option<u64>,
+    ratio: Option<f64>,
+    attributes: Option<usize>,
+    /// Dataset metadata not read yet.
+    pending: bool,
+    error: Option<String>,
+}
+
+impl OverviewRow {
+    fn new(child: &H5FNode, overview: &GroupOverviewState) -> Self {
+        let path = child.node.path();
+        let mut row = Self {
+            name: child.name(),
+            kind: "",
+            shape: None,
+            dtype: None,
+            storage: None,
+            ratio: None,
+            attributes: child
+                .computed_attributes
+                .as_ref()
+                .map(|computed| computed.attributes.len())
+                .or_else(|| overview.attribute_counts.get(&path).copied()),
+            pending: false,
+            error: overview.errors.get(&path).cloned(),
+            path,
+        };
+        match &child.node {
+            Node::File(_) | Node::Group(_, _) => row.kind = "group",
+            Node::Dataset(_, DatasetMetaState::Pending(_)) => {
+                row.kind = "dataset";
+                row.pending = row.error.is_none();
+            }
+            Node::Dataset(_, DatasetMetaState::Loaded(meta)) => {
+                row.kind = "dataset";
+                row.shape = Some(meta.shape.clone());
+                row.dtype = Some(meta.data_type.clone());
+                row.storage = Some(meta.storage_required);
+                row.ratio = meta.compression_ratio();
+            }
+            Node::Datatype(_, meta) => {
+                row.kind = "datatype";
+                row.dtype = Some(meta.data_type.clone());
+            }
+            Node::Broken(_) => row.kind = "broken",
+        }
+        row
+    }
+
+    fn elements(&self) -> Option<usize> {
+        self.shape.as_ref().map(|shape| shape.iter().product())
+    }
+
+    fn shape_text(&self) -> String {
+        match &self.shape {
+            Some(shape) if shape.is_empty() => "scalar".to_string(),
+            Some(shape) => shape
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" x "),
+            None => self.unknown_text(),
+        }
+    }
+
+    /// Filler for a value the member lacks, or has not been read yet.
+    fn unknown_text(&self) -> String {
+        if self.pending {
+            "…".to_string()
+        } else {
+            "-".to_string()
+        }
+    }
+}
+
+/// Known values come first in either direction; ties keep name order.
+fn sort_rows(rows: &mut [OverviewRow], sort: GroupOverviewSort, descending: bool) {
+    fn known_first<T>(
+        a: Option<T>,
+        b: Option<T>,
+        descending: bool,
+        compare: impl Fn(&T, &T) -> Ordering,
+    ) -> Ordering {
+        match (a, b) {
+            (Some(a), Some(b)) if descending => compare(&b, &a),
+            (Some(a), Some(b)) => compare(&a, &b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+    rows.sort_by(|a, b| {
+        let ordering = match sort {
+            GroupOverviewSort::Name => Ordering::Equal,
+            GroupOverviewSort::Kind => {
+                known_first(Some(a.kind), Some(b.kind), descending, Ord::cmp)
+            }
+            GroupOverviewSort::Shape => known_first(
+                a.elements().zip(a.shape.as_ref().map(Vec::len)),
+                b.elements().zip(b.shape.as_ref().map(Vec::len)),
+                descending,
+                Ord::cmp,
+            ),
+            GroupOverviewSort::Dtype => {
+                known_first(a.dtype.as_ref(), b.dtype.as_ref(), descending, Ord::cmp)
+            }
+            GroupOverviewSort::Storage => known_first(a.storage, b.storage, descending, Ord::cmp),
+            GroupOverviewSort::Ratio => known_first(a.ratio, b.ratio, descending, f64::total_cmp),
+            GroupOverviewSort::Attributes => {
+                known_first(a.attributes, b.attributes, descending, Ord::cmp)
+            }
+        };
+        let by_name = if sort == GroupOverviewSort::Name && descending {
+            b.name.cmp(&a.name)
+        } else {
+            a.name.cmp(&b.name)
+        };
+        ordering.then(by_name)
+    });
+}
+
+fn overview_rows(node: &H5FNode, overview: &GroupOverviewState) -> Vec<OverviewRow> {
+    let mut rows = node
+        .children
+        .iter()
+        .map(|child| OverviewRow::new(&child.borrow(), overview))
+        .collect::<Vec<_>>();
+    sort_rows(&mut rows, overview.sort, overview.descending);
+    rows
+}
+
+pub fn render_group_overview(f: &mut Frame, area: &Rect, node: &H5FNode, state: &mut AppState) {
+    if let Some(alias) = node.alias().filter(|alias| alias.cycle) {
+        render_error(
+            f,
+            area,
+            format!(
+                "This group links back to {}; its members are listed there",
+                alias.canonical_path
+            ),
+        );
+        return;
+    }
+    if let Some(error) = &node.load_error {
+        render_error(f, area, format!("Error reading group members: {error}"));
+        return;
+    }
+    if !node.read {
+        render_error(f, area, "Reading group members...");
+        return;
+    }
+    let group_path = node.node.path();
+    let rows = overview_rows(node, &state.group_overview);
+    state.group_overview.show(
+        &group_path,
+        rows.iter().map(|row| row.path.clone()).collect(),
+    );
+    if area.height < 4 {
+        return;
+    }
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(3)])
+        .split(*area);
+    let pending = rows.iter().filter(|row| row.pending).count();
+    let block = rounded_panel(format!(
+        "{} · {} members{} · sorted by {} {}",
+        group_path,
+        rows.len(),
+        if pending > 0 {
+            format!(" · reading {pending}")
+        } else {
+            String::new()
+        },
+        state.group_overview.sort.label(),
+        sort_arrow(state.group_overview.descending)
+    ));
+    let inner = block.inner(layout[0]);
+    f.render_widget(block, layout[0]);
+
+    let visible_rows = inner.height.saturating_sub(1) as usize;
+    state.group_overview.list.scroll_to_cursor(visible_rows);
+    let name_width = (inner.width as usize)
+        .saturating_sub(
+            KIND_WIDTH + SHAPE_WIDTH + DTYPE_WIDTH + SIZE_WIDTH + RATIO_WIDTH + COUNT_WIDTH + 6,
+        )
+        .max(8);
+    let muted = Style::default().fg(configure::themed_color(|colors| colors.help.muted));
+    let mut lines = vec![Line::from(Span::styled(
+        header(
+            name_width,
+            state.group_overview.sort,
+            state.group_overview.descending,
+        ),
+        muted.bold(),
+    ))];
+    for (index, row) in rows
+        .iter()
+        .enumerate()
+        .skip(state.group_overview.list.row_offset)
+        .take(visible_rows)
+    {
+        lines.push(overview_line(
+            row,
+            name_width,
+            index == state.group_overview.list.cursor,
+        ));
+    }
+    f.render_widget(Paragraph::new(lines), inner);
+
+    let detail_block = rounded_panel("Selected member · Enter opens it");
+    let detail_inner = detail_block.inner(layout[1]);
+    f.render_widget(detail_block, layout[1]);
+    if let Some(row) = rows.get(state.group_overview.list.cursor) {
+        f.render_widget(
+            Paragraph::new(row_detail(row))
+                .style(Style::default().fg(configure::themed_color(|colors| colors.text.primary))),
+            detail_inner,
+        );
+    }
+}
+
+fn sort_arrow(descending: bool) -> &'static str {
+    if descending {
+        "▼"
+    } else {
+        "▲"
+    }
+}
+
+fn header(name_width: usize, sort: GroupOverviewSort, descending: bool) -> String {
+    let title = |column: GroupOverviewSort| {
+        if column == sort {
+            format!("{} {}", column.label(), sort_arrow(descending))
+        } else {
+            column.label().to_string()
+        }
+    };
+    format!(
+        "{:<name_width$} {:<KIND_WIDTH$} {:<SHAPE_WIDTH$} {:<DTYPE_WIDTH$} {:>SIZE_WIDTH$} {:>RATIO_WIDTH$} {:>COUNT_WIDTH$}",
+        title(GroupOverviewSort::Name),
+        title(GroupOverviewSort::Kind),
+        title(GroupOverviewSort::Shape),
+        title(GroupOverviewSort::Dtype),
+        title(GroupOverviewSort::Storage),
+        title(GroupOverviewSort::Ratio),
+        title(GroupOverviewSort::Attributes),
+    )
+}
+
+fn overview_line(row: &OverviewRow, name_width: usize, selected: bool) -> Line<'static> {
+    let icon = match row.kind {
+        "group" => configure::configured_symbol(|symbols| symbols.tree.folder_closed_branch),
+        "dataset" => configure::configured_symbol(|symbols| symbols.tree.dataset_icon),
+        "datatype" => configure::configured_symbol(|symbols| symbols.tree.datatype_icon),
+        _ => configure::configured_symbol(|symbols| symbols.tree.broken_node_icon),
+    };
+    let style = if selected {
+        Style::default()
+            .fg(configure::themed_color(|colors| colors.accent.selection_fg))
+            .bg(configure::themed_color(|colors| colors.accent.selection_bg))
+            .bold()
+    } else if row.error.is_some() {
+        Style::default().fg(configure::themed_color(|colors| colors.content.empty_state))
+    } else {
+        Style::default().fg(configure::themed_color(|colors| colors.text.primary))
+    };
+    let unknown = row.unknown_text();
+    Line::from(Span::styled(
+        format!(
+            "{:<name_width$} {:<KIND_WIDTH$} {:<SHAPE_WIDTH$} {:<DTYPE_WIDTH$} {:>SIZE_WIDTH$} {:>RATIO_WIDTH$} {:>COUNT_WIDTH$}",
+            truncate(&format!("{icon}{}", row.name), name_width),
+            row.kind,
+            truncate(&row.shape_text(), SHAPE_WIDTH),
+            truncate(row.dtype.as_deref().unwrap_or(&unknown), DTYPE_WIDTH),
+            row.storage.map(human_bytes).unwrap_or_else(|| unknown.clone()),
+            row.ratio
+                .map(|ratio| format!("{ratio:.2}x"))
+                .unwrap_or_else(|| unknown.clone()),
+            row.attributes
+                .map(|count| count.to_string())
+                .unwrap_or_else(|| if row.error.is_some() { "-".to_string() } else { "…".to_string() }),
+        ),
+        style,
+    ))
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let kept = text
+        .chars()
+        .take(width.saturating_sub(1))
+        .collect::<String>();
+    format!("{kept}…")
+}
+
+fn row_detail(row: &OverviewRow) -> String {
+    if let Some(error) = &row.error {
+        return format!("{}: {error}", row.path);
+    }
+    let mut parts = vec![row.kind.to_string()];
+    if row.shape.is_some() {
+        parts.push(row.shape_text());
+    }
+    if let Some(dtype) = &row.dtype {
+        parts.push(dtype.clone());
+    }
+    if let Some(storage) = row.storage {
+        parts.push(format!("{} stored", human_bytes(storage)));
+    }
+    if let Some(ratio) = row.ratio {
+        parts.push(format!("{ratio:.2}x compression"));
+    }
+    if let Some(count) = row.attributes {
+        parts.push(format!("{count} attributes"));
+    }
+    format!("{}: {}", row.path, parts.join(", "))
+}
+
+pub fn selected_group_overview_copy_text(node: &H5FNode, state: &AppState) -> Option<String> {
+    let path = state.group_overview.selected_path()?;
+    let child = node
+        .children
+        .iter()
+        .find(|child| child.borrow().node.path() == path)?;
+    let row = OverviewRow::new(&child.borrow(), &state.group_overview);
+    Some(row_detail(&row))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::ui::state::GroupOverviewSort;
+
+    use super::{sort_rows, OverviewRow};
+
+    fn row(name: &str, storage: Option<u64>, shape: Option<Vec<usize>>) -> OverviewRow {
+        OverviewRow {
+            path: format!("/{name}"),
+            name: name.to_string(),
+            kind: if shape.is_some() { "dataset" } else { "group" },
+            shape,
+            dtype: None,
+            storage,
+            ratio: None,
+            attributes: None,
+            pending: false,
+            error: None,
+        }
+    }
+
+    fn names(rows: &[OverviewRow]) -> Vec<&str> {
+        rows.iter().map(|row| row.name.as_str()).collect()
+    }
+
+    #[test]
+    fn overview_sorts_known_values_first_in_either_direction() {
+        let mut rows = vec![
+            row("b", Some(10), Some(vec![5])),
+            row("grp", None, None),
+            row("a", Some(300), Some(vec![2, 3])),
+            row("c", Some(10), Some(vec![])),
+        ];
+
+        sort_rows(&mut rows, GroupOverviewSort::Storage, false);
+        assert_eq!(names(&rows), ["b", "c", "a", "grp"]);
+        sort_rows(&mut rows, GroupOverviewSort::Storage, true);
+        assert_eq!(names(&rows), ["a", "b", "c", "grp"]);
+
+        sort_rows(&mut rows, GroupOverviewSort::Shape, false);
+        assert_eq!(names(&rows), ["c", "b", "a", "grp"]);
+
+        sort_rows(&mut rows, GroupOverviewSort::Name, true);
+        assert_eq!(names(&rows), ["grp", "c", "b", "a"]);
+        sort_rows(&mut rows, GroupOverviewSort::Kind, false);
+        assert_eq!(names(&rows), ["a", "b", "c", "grp"]);
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use ratatui::{layout::Rect, style::Style, text::Line, Frame};
+
+use crate::{configure, h5f::DimensionScale};
+
+const MIN_GUTTER_WIDTH: u16 = 3;
+const MAX_GUTTER_WIDTH: u16 = 10;
+const ROW_TICK_SPACING: u16 = 3;
+const COL_TICK_GAP: u16 = 2;
+
+/// Dimension scales of the plotted axes. Row ticks go in a gutter left of the
+/// heatmap frame; column ticks are drawn over the frame's bottom border.
+pub(super) struct HeatmapAxes {
+    pub row_scale: Option<Arc<DimensionScale>>,
+    pub col_scale: Option<Arc<DimensionScale>>,
+    gutter: Option<Rect>,
+}
+
+impl HeatmapAxes {
+    /// Reserves the row tick gutter out of `area` and returns the remaining
+    /// space for the heatmap frame.
+    pub fn split(
+        area: Rect,
+        row_scale: Option<Arc<DimensionScale>>,
+        col_scale: Option<Arc<DimensionScale>>,
+    ) -> (Rect, Self) {
+        let gutter_width = row_scale
+            .as_deref()
+            .map(row_gutter_width)
+            .filter(|width| area.width >= width + 8);
+        let Some(gutter_width) = gutter_width else {
+            return (
+                area,
+                Self {
+                    row_scale,
+                    col_scale,
+                    gutter: None,
+                },
+            );
+        };
+        let gutter = Rect {
+            width: gutter_width,
+            ..area
+        };
+        let body = Rect {
+            x: area.x + gutter_width,
+            width: area.width - gutter_width,
+            ..area
+        };
+        (
+            body,
+            Self {
+                row_scale,
+                col_scale,
+                gutter: Some(gutter),
+            },
+        )
+    }
+}
+
+fn row_gutter_width(scale: &DimensionScale) -> u16 {
+    let widest = [0, scale.len() / 2, scale.len().saturating_sub(1)]
+        .into_iter()
+        .filter_map(|index| scale.label_at(index))
+        .map(|label| label.chars().count())
+        .chain(std::iter::once(scale.label.chars().count()))
+        .max()
+        .unwrap_or_default();
+    (widest as u16 + 1).clamp(MIN_GUTTER_WIDTH, MAX_GUTTER_WIDTH)
+}
+
+/// Terminal offsets along an axis of `extent` cells that get a tick label,
+/// keeping `spacing` cells between the starts of neighbouring labels.
+pub(super) fn axis_tick_offsets(extent: u16, spacing: u16) -> Vec<u16> {
+    if extent == 0 {
+        return vec![];
+    }
+    (0..extent).step_by(usize::from(spacing.max(1))).collect()
+}
+
+/// Source index shown at a terminal offset, matching how the heatmap image
+/// spreads `start..end` over `extent` cells.
+pub(super) fn axis_tick_index(offset: u16, extent: u16, start: usize, end: usize) -> usize {
+    let visible = end.saturating_sub(start).max(1);
+    start + usize::from(offset) * visible / usize::from(extent.max(1))
+}
+
+fn truncate_label(label: &str, width: u16) -> String {
+    label.chars().take(usize::from(width)).collect()
+}
+
+fn tick_style() -> Style {
+    let style = Style::default().fg(configure::themed_color(|colors| colors.text.type_desc));
+    if configure::prefers_strong_text() {
+        style.bold()
+    } else {
+        style
+    }
+}
+
+pub(super) fn render_heatmap_axis_ticks(
+    f: &mut Frame,
+    frame: Rect,
+    heatmap_inner: Rect,
+    axes: &HeatmapAxes,
+    rows: (usize, usize),
+    cols: (usize, usize),
+) {
+    let name_style = Style::default().fg(configure::themed_color(|colors| colors.help.muted));
+    if let (Some(scale), Some(gutter)) = (axes.row_scale.as_deref(), axes.gutter) {
+        let label_width = gutter.width.saturating_sub(1);
+        f.render_widget(
+            Line::from(truncate_label(&scale.label, label_width)).style(name_style),
+            Rect {
+                y: frame.y,
+                height: 1,
+                ..gutter
+            },
+        );
+        for offset in axis_tick_offsets(heatmap_inner.height, ROW_TICK_SPACING) {
+            let index = axis_tick_index(offset, heatmap_inner.height, rows.0, rows.1);
+            let Some(label) = scale.label_at(index) else {
+                continue;
+            };
+            f.render_widget(
+                Line::from(truncate_label(&label, label_width))
+                    .style(tick_style())
+                    .right_aligned(),
+                Rect {
+                    x: gutter.x,
+                    y: heatmap_inner.y + offset,
+                    width: label_width,
+                    height: 1,
+                },
+            );
+        }
+    }
+
+    let Some(scale) = axes.col_scale.as_deref() else {
+        return;
+    };
+    let name = format!(" {} ", scale.label);
+    let name_width = (name.chars().count() as u16).min(heatmap_inner.width);
+    f.render_widget(
+        Line::from(truncate_label(&name, name_width)).style(name_style),
+        Rect {
+            x: heatmap_inner.right().saturating_sub(name_width),
+            y: frame.y,
+            width: name_width,
+            height: 1,
+        },
+    );
+    let bottom = frame.bottom().saturating_sub(1);
+    let widest = [cols.0, cols.1.saturating_sub(1)]
+        .into_iter()
+        .filter_map(|index| scale.label_at(index))
+        .map(|label| label.chars().count() as u16)
+        .max()
+        .unwrap_or(1);
+    for offset in axis_tick_offsets(heatmap_inner.width, widest + COL_TICK_GAP) {
+        let index = axis_tick_index(offset, heatmap_inner.width, cols.0, cols.1);
+        let Some(label) = scale.label_at(index) else {
+            continue;
+        };
+        let width = (label.chars().count() as u16).min(heatmap_inner.width - offset);
+        f.render_widget(
+            Line::from(truncate_label(&label, width)).style(tick_style()),
+            Rect {
+                x: heatmap_inner.x + offset,
+                y: bottom,
+                width,
+                height: 1,
+            },
+        );
+    }
+}
@@ -1,8 +1,8 @@
 use hdf5_metno::Dataset;
-use ndarray::Array2;
+use ndarray::{Array2, Ix2};
 
 use crate::{
-    data::MatrixTable,
+    data::{read_f64_values, MatrixTable},
     error::AppError,
     h5f::{read_projected_values_2d, DatasetMeta},
     ui::render::MatrixRenderType,
@@ -57,6 +57,21 @@ pub(super) fn build_heatmap_page(
         (col_start, col_end),
     );
     let transpose = key.selected_row > key.selected_col;
+    let decoding = attr.active_cf_decoding().filter(|_| {
+        matches!(
+            attr.matrixable,
+            Some(MatrixRenderType::Float64 | MatrixRenderType::Uint64 | MatrixRenderType::Int64)
+        )
+    });
+    if decoding.is_some() || attr.is_opaque_float16() {
+        let mut values = read_f64_values::<Ix2>(ds, selection)?;
+        if let Some(decoding) = decoding {
+            values.mapv_inplace(|value| decoding.decode(value));
+        }
+        return build_heatmap_page_from_data(
+            values, attr, key, row_start, row_end, col_start, col_end, transpose,
+        );
+    }
     match attr.matrixable {
         Some(MatrixRenderType::Float64) => {
             if attr.is_compound_leaf() {
@@ -25,14 +25,18 @@ use crate::{
     },
 };
 
+use axes::HeatmapAxes;
+
 use super::{
     dims::render_dim_selector, matrix::render_not_yet_implemented,
     std_comp_render::render_empty_dataset,
 };
 
+mod axes;
 mod load;
 mod panels;
 mod render;
+pub(crate) use render::heatmap_colormap_rgb;
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 #[allow(clippy::expect_used)]
@@ -96,6 +100,9 @@ pub fn render_heatmap(
         Some(MatrixRenderType::Enum)
         | Some(MatrixRenderType::Strings)
         | Some(MatrixRenderType::ByteArray)
+        | Some(MatrixRenderType::Ragged)
+        | Some(MatrixRenderType::References)
+        | Some(MatrixRenderType::Bitfield)
         | Some(MatrixRenderType::Opaque)
         | Some(MatrixRenderType::Compound)
         | None => render_not_yet_implemented(
@@ -152,6 +159,15 @@ fn render_heatmap_with_dataset(
     let base_viewport = heatmap_base_viewport(state, source_rows, source_cols);
     let layout = HeatmapLayout::new(area_inner);
     let (heatmap_body, profile_area) = split_heatmap_body(layout.body);
+    let (heatmap_body, axes) = HeatmapAxes::split(
+        heatmap_body,
+        state
+            .dimension_scales
+            .scale_for(ds_path, ds, node.selected_row),
+        state
+            .dimension_scales
+            .scale_for(ds_path, ds, node.selected_col),
+    );
     let heatmap_body_inner = panels::heatmap_frame_inner(&heatmap_body);
     if !one_axis_heatmap_paging_supported(base_viewport, heatmap_body_inner, state.image_cell_size)
     {
@@ -208,6 +224,7 @@ fn render_heatmap_with_dataset(
         ds,
         ds_path,
         header_page_window,
+        &axes,
     )?;
     panels::render_heatmap_region_panel(f, &layout.region, attr, node, state);
     panels::render_heatmap_profile_slot(f, &profile_area, state)?;
@@ -421,6 +438,7 @@ fn render_heatmap_body(
     ds: &Dataset,
     ds_path: &str,
     page_window: Option<HeatmapPageWindow>,
+    axes: &HeatmapAxes,
 ) -> Result<(), AppError> {
     let previous_key = state.heatmap_render.current_key.clone();
     let heatmap_inner = panels::heatmap_frame_inner(area);
@@ -485,6 +503,14 @@ fn render_heatmap_body(
     state.heatmap_render.current_key = Some(render_key.clone());
     let is_loading = state.heatmap_render.pending_keys.contains(&render_key);
     panels::render_heatmap_frame(f, area, is_loading);
+    axes::render_heatmap_axis_ticks(
+        f,
+        *area,
+        heatmap_inner,
+        axes,
+        (row_start, row_end),
+        (col_start, col_end),
+    );
     render::populate_viewport_hitboxes(state, heatmap_inner, viewport_rows, viewport_cols);
 
     let cached_pos = state
@@ -692,7 +692,7 @@ pub(super) fn normalize_heatmap_value(
     }
 }
 
-pub(super) fn heatmap_colormap_rgb(value: f64, colormap: HeatmapColormap) -> (u8, u8, u8) {
+pub(crate) fn heatmap_colormap_rgb(value: f64, colormap: HeatmapColormap) -> (u8, u8, u8) {
     match colormap {
         HeatmapColormap::Turbo => turbo_rgb(value),
         HeatmapColormap::Grayscale => {
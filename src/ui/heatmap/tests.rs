@@ -2,6 +2,7 @@ use ndarray::arr2;
 use ratatui::layout::Rect;
 
 use super::{
+    axes::{axis_tick_index, axis_tick_offsets},
     compute_heatmap_page_window,
     load::{bounded_heatmap_page_ranges, compute_line_profile},
     render::{compute_heatmap_color_scale, compute_region_selection, viewport_partition},
@@ -28,6 +29,15 @@ fn viewport_partition_keeps_single_source_pixel_visible() {
     assert_eq!(viewport_partition(3, 3, 2), (2, 3));
 }
 
+#[test]
+fn axis_ticks_follow_the_visible_source_range() {
+    assert_eq!(axis_tick_offsets(10, 4), vec![0, 4, 8]);
+    assert!(axis_tick_offsets(0, 4).is_empty());
+    assert_eq!(axis_tick_index(0, 80, 100, 260), 100);
+    assert_eq!(axis_tick_index(40, 80, 100, 260), 180);
+    assert_eq!(axis_tick_index(9, 10, 0, 3), 2);
+}
+
 #[test]
 fn heatmap_page_window_last_page_clamps_to_tail() {
     let window = HeatmapPageWindow {
@@ -200,6 +210,16 @@ fn heatmap_minmax_uses_numeric_type_bounds() {
         is_link: false,
         filename: String::new(),
         compound_projection: None,
+        storage: None,
+        virtual_sources: vec![],
+        cf_decoding: None,
+        dimension_names: None,
+        matlab: None,
+        float16: None,
+        bitfield: None,
+        time: None,
+        committed_type: None,
+        alias: None,
     };
     let scale = compute_heatmap_color_scale(&data, &attr, false, 2, 2, &HeatmapRangeMode::MinMax);
     assert_eq!(scale.min, 0.0);
@@ -229,6 +249,16 @@ fn heatmap_ignores_non_finite_values_in_stats_and_scale() {
         is_link: false,
         filename: String::new(),
         compound_projection: None,
+        storage: None,
+        virtual_sources: vec![],
+        cf_decoding: None,
+        dimension_names: None,
+        matlab: None,
+        float16: None,
+        bitfield: None,
+        time: None,
+        committed_type: None,
+        alias: None,
     };
     let scale = compute_heatmap_color_scale(&data, &attr, false, 2, 2, &HeatmapRangeMode::Auto);
     let region = compute_region_selection(&data, false, 2, 2, None, 0, 0);
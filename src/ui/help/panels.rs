@@ -103,6 +103,7 @@ pub(super) fn command_panel_text(section: HelpCommandSection) -> (String, Vec<Li
         HelpCommandSection::View => ("View commands", "View"),
         HelpCommandSection::Selection => ("Selection commands", "Selection"),
         HelpCommandSection::Attributes => ("Attribute commands", "Attributes"),
+        HelpCommandSection::Structure => ("Structure commands", "Structure"),
         HelpCommandSection::App => ("App commands", "App"),
         HelpCommandSection::MultiChart => ("Multichart commands", "MultiChart"),
         HelpCommandSection::Input => ("Input commands", "Input"),
@@ -339,6 +340,11 @@ fn describe_tree_target(target: &BoundAction<TreeAction>) -> String {
             TreeAction::Expand => "Expand the selected node".to_string(),
             TreeAction::Toggle => "Toggle expansion".to_string(),
             TreeAction::AddToMultiChart => "Add the current selection to multichart".to_string(),
+            TreeAction::CreateGroup => "Create a group in the selected group".to_string(),
+            TreeAction::CreateDataset => "Create a dataset in the selected group".to_string(),
+            TreeAction::MoveObject => "Rename or move the selected object".to_string(),
+            TreeAction::CreateSoftLink => "Create a soft link to the selected object".to_string(),
+            TreeAction::DeleteObject => "Delete the selected object".to_string(),
         }
     })
 }
@@ -349,7 +355,9 @@ fn describe_content_target(target: &BoundAction<ContentAction>) -> String {
             ContentAction::Move(direction, amount) => {
                 format!("Move {} by {}", direction_label(*direction), amount)
             }
-            ContentAction::Edit => "Edit the selected value".to_string(),
+            ContentAction::Edit => {
+                "Edit the selected value, or follow the selected reference".to_string()
+            }
             ContentAction::Copy => "Copy the selected value".to_string(),
             ContentAction::CyclePreviewChartMode => {
                 "Cycle the preview chart between line, scatter, histogram, and box plot".to_string()
@@ -378,6 +386,14 @@ fn describe_content_target(target: &BoundAction<ContentAction>) -> String {
                     direction_label(*direction)
                 )
             }
+            ContentAction::NextBitPlane => "Chart the next bit of a bitfield preview".to_string(),
+            ContentAction::PrevBitPlane => {
+                "Chart the previous bit of a bitfield preview".to_string()
+            }
+            ContentAction::CycleSortColumn => {
+                "Sort the group overview by the next column".to_string()
+            }
+            ContentAction::ReverseSort => "Reverse the group overview sort order".to_string(),
         }
     })
 }
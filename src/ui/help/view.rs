@@ -249,6 +249,7 @@ fn render_command_help(frame: &mut Frame<'_>, area: Rect, state: &mut AppState<'
             (HelpCommandSection::View, "View"),
             (HelpCommandSection::Selection, "Selection"),
             (HelpCommandSection::Attributes, "Attributes"),
+            (HelpCommandSection::Structure, "Structure"),
             (HelpCommandSection::App, "App"),
             (HelpCommandSection::MultiChart, "Multichart"),
             (HelpCommandSection::Input, "Input"),
@@ -770,6 +771,7 @@ fn warm_help_panel_cache() {
         HelpCommandSection::View,
         HelpCommandSection::Selection,
         HelpCommandSection::Attributes,
+        HelpCommandSection::Structure,
         HelpCommandSection::App,
         HelpCommandSection::MultiChart,
         HelpCommandSection::Input,
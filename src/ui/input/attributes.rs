@@ -23,9 +23,12 @@ use super::{
 
 mod references;
 mod selection;
+mod virtual_sources;
 
 use references::navigate_reference_attribute_value;
+pub(super) use references::navigate_reference_dataset_cell;
 use selection::{selected_attribute_edit_request, selected_custom_attribute_name};
+use virtual_sources::navigate_virtual_source;
 
 #[cfg(test)]
 use references::select_dataset_region_axes;
@@ -110,6 +113,17 @@ pub fn apply_attribute_edit_request(
     ))
 }
 
+/// Asks for the values of a new array attribute in the editor, one per line.
+pub fn edit_new_attribute_values(
+    state: &mut AppState<'_>,
+    attr_name: &str,
+) -> Result<String, AppError> {
+    state.editing = true;
+    let new_value = perform_edit(state, String::new(), Some(attr_name));
+    state.editing = false;
+    new_value
+}
+
 pub fn handle_normal_attributes(
     state: &mut AppState<'_>,
     event: Event,
@@ -261,6 +275,11 @@ pub fn handle_normal_attributes(
                     }
                 }
                 Some(BoundAction::Action(AttributesAction::Edit)) => {
+                    match navigate_virtual_source(state) {
+                        Ok(Some(event_result)) => return Ok(event_result),
+                        Ok(None) => {}
+                        Err(event_result) => return Ok(event_result),
+                    }
                     match navigate_reference_attribute_value(state) {
                         Ok(Some(event_result)) => return Ok(event_result),
                         Ok(None) => {}
@@ -355,6 +374,7 @@ pub fn handle_normal_attributes(
                         name: String::new(),
                         name_cursor: 0,
                         attr_type: AttributeCreateType::String,
+                        array: false,
                         value: String::new(),
                         value_cursor: 0,
                         active_field: AttributeCreateField::Name,
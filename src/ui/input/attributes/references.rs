@@ -1,213 +1,19 @@
-use std::{ffi::CStr, os::raw::c_char, ptr};
-
-use hdf5_metno::{types::Reference, Attribute, ObjectReference1, ReferencedObject};
-use hdf5_metno_sys::{
-    h5::hsize_t,
-    h5p::H5P_DEFAULT,
-    h5r::{
-        hdset_reg_ref_t, H5R_ref_t, H5Rget_attr_name, H5Rget_name, H5Rget_obj_name, H5Rget_region,
-        H5Rget_type, H5Ropen_region, H5R_ATTR, H5R_DATASET_REGION1, H5R_DATASET_REGION2,
-        H5R_OBJECT2,
-    },
-    h5s::{
-        H5Sclose, H5Sget_select_bounds, H5Sget_select_elem_npoints, H5Sget_select_hyper_nblocks,
-        H5Sget_select_type, H5Sget_simple_extent_ndims, H5S_SEL_ALL, H5S_SEL_HYPERSLABS,
-        H5S_SEL_POINTS,
-    },
-};
+use hdf5_metno::types::TypeDescriptor;
 
 use crate::{
-    error::AppError,
-    h5f::{read_attr_memory_bytes, MetadataRowKind},
+    h5f::{
+        read_reference_attr_target, read_reference_element, DatasetHandle, DatasetMetaState,
+        DatasetRegionTarget, MetadataRowKind, Node, ReferenceTarget,
+    },
     ui::{
-        render::attribute_type_descriptor,
+        dims::HasMatrixSelection,
+        render::{attribute_type_descriptor, MatrixRenderType},
         state::{AppState, AppToast, ContentShowMode, Focus},
     },
 };
 
 use super::{selection::selected_attribute, EventResult, Value};
 
-struct DatasetRegionNavigationTarget {
-    path: String,
-    start: Vec<usize>,
-    end: Vec<usize>,
-    approximate: bool,
-}
-
-enum ReferenceNavigationTarget {
-    Object {
-        path: String,
-        attr_name: Option<String>,
-    },
-    DatasetRegion(DatasetRegionNavigationTarget),
-}
-
-fn read_hdf5_name(
-    reader: impl Fn(*mut c_char, usize) -> isize,
-    context: &str,
-) -> Result<String, AppError> {
-    let len = reader(ptr::null_mut(), 0);
-    if len < 0 {
-        return Err(AppError::EditError(format!(
-            "Failed to query {context} length"
-        )));
-    }
-    let mut buffer = vec![0 as c_char; len as usize + 1];
-    let written = reader(buffer.as_mut_ptr(), buffer.len());
-    if written < 0 {
-        return Err(AppError::EditError(format!("Failed to read {context}")));
-    }
-    Ok(unsafe { CStr::from_ptr(buffer.as_ptr()) }
-        .to_string_lossy()
-        .into_owned())
-}
-
-fn referenced_object_path(object: ReferencedObject) -> Result<String, AppError> {
-    match object {
-        ReferencedObject::Group(group) => Ok(group.name()),
-        ReferencedObject::Dataset(dataset) => Ok(dataset.name()),
-        ReferencedObject::Datatype(datatype) => datatype
-            .as_location()
-            .map(|location| location.name())
-            .map_err(AppError::from),
-    }
-}
-
-fn read_single_reference_bytes<const N: usize>(attr: &Attribute) -> Result<[u8; N], AppError> {
-    let bytes = read_attr_memory_bytes(attr)?;
-    if bytes.len() != N {
-        return Err(AppError::EditError(
-            "Reference navigation requires a single target".to_string(),
-        ));
-    }
-    let mut reference = [0_u8; N];
-    reference.copy_from_slice(&bytes);
-    Ok(reference)
-}
-
-fn read_region_bounds(space_id: i64) -> Result<(Vec<usize>, Vec<usize>, bool), AppError> {
-    let ndims = unsafe { H5Sget_simple_extent_ndims(space_id) };
-    if ndims < 0 {
-        return Err(AppError::EditError(
-            "Failed to inspect referenced region".to_string(),
-        ));
-    }
-    let rank = ndims as usize;
-    let mut start = vec![0 as hsize_t; rank];
-    let mut end = vec![0 as hsize_t; rank];
-    if unsafe { H5Sget_select_bounds(space_id, start.as_mut_ptr(), end.as_mut_ptr()) } < 0 {
-        return Err(AppError::EditError(
-            "Failed to inspect referenced region bounds".to_string(),
-        ));
-    }
-
-    let selection_type = unsafe { H5Sget_select_type(space_id) };
-    let approximate = match selection_type {
-        H5S_SEL_POINTS => (unsafe { H5Sget_select_elem_npoints(space_id) }) > 1,
-        H5S_SEL_HYPERSLABS => (unsafe { H5Sget_select_hyper_nblocks(space_id) }) > 1,
-        H5S_SEL_ALL => false,
-        unsupported => {
-            return Err(AppError::EditError(format!(
-                "Unsupported referenced region selection type: {unsupported:?}"
-            )))
-        }
-    };
-
-    Ok((
-        start.into_iter().map(|idx| idx as usize).collect(),
-        end.into_iter().map(|idx| idx as usize).collect(),
-        approximate,
-    ))
-}
-
-fn read_region_target_from_space(
-    path: String,
-    space_id: i64,
-) -> Result<ReferenceNavigationTarget, AppError> {
-    let result = read_region_bounds(space_id);
-    unsafe {
-        H5Sclose(space_id);
-    }
-    let (start, end, approximate) = result?;
-    Ok(ReferenceNavigationTarget::DatasetRegion(
-        DatasetRegionNavigationTarget {
-            path,
-            start,
-            end,
-            approximate,
-        },
-    ))
-}
-
-fn resolve_std_reference_target(attr: &Attribute) -> Result<ReferenceNavigationTarget, AppError> {
-    let reference_bytes =
-        read_single_reference_bytes::<{ std::mem::size_of::<H5R_ref_t>() }>(attr)?;
-    let reference = reference_bytes.as_ptr().cast::<H5R_ref_t>();
-    match unsafe { H5Rget_type(reference) } {
-        H5R_OBJECT2 => Ok(ReferenceNavigationTarget::Object {
-            path: read_hdf5_name(
-                |name, size| unsafe {
-                    H5Rget_obj_name(reference, H5P_DEFAULT, name, size) as isize
-                },
-                "reference target path",
-            )?,
-            attr_name: None,
-        }),
-        H5R_DATASET_REGION2 => {
-            let path = read_hdf5_name(
-                |name, size| unsafe {
-                    H5Rget_obj_name(reference, H5P_DEFAULT, name, size) as isize
-                },
-                "reference target path",
-            )?;
-            let space_id = unsafe { H5Ropen_region(reference, H5P_DEFAULT, H5P_DEFAULT) };
-            if space_id < 0 {
-                return Err(AppError::EditError(
-                    "Failed to open referenced dataset region".to_string(),
-                ));
-            }
-            read_region_target_from_space(path, space_id)
-        }
-        H5R_ATTR => Ok(ReferenceNavigationTarget::Object {
-            path: read_hdf5_name(
-                |name, size| unsafe {
-                    H5Rget_obj_name(reference, H5P_DEFAULT, name, size) as isize
-                },
-                "attribute owner path",
-            )?,
-            attr_name: Some(read_hdf5_name(
-                |name, size| unsafe { H5Rget_attr_name(reference, name, size) as isize },
-                "attribute target name",
-            )?),
-        }),
-        _ => Err(AppError::EditError(
-            "Unsupported reference target type".to_string(),
-        )),
-    }
-}
-
-fn resolve_region_reference_target(
-    attr: &Attribute,
-) -> Result<ReferenceNavigationTarget, AppError> {
-    let file = attr.file()?;
-    let reference_bytes =
-        read_single_reference_bytes::<{ std::mem::size_of::<hdset_reg_ref_t>() }>(attr)?;
-    let reference = reference_bytes.as_ptr().cast();
-    let path = read_hdf5_name(
-        |name, size| unsafe {
-            H5Rget_name(file.id(), H5R_DATASET_REGION1, reference, name, size) as isize
-        },
-        "reference target path",
-    )?;
-    let space_id = unsafe { H5Rget_region(file.id(), H5R_DATASET_REGION1, reference) };
-    if space_id < 0 {
-        return Err(AppError::EditError(
-            "Failed to open referenced dataset region".to_string(),
-        ));
-    }
-    read_region_target_from_space(path, space_id)
-}
-
 pub(super) fn select_dataset_region_axes(
     shape: &[usize],
     start: &[usize],
@@ -257,7 +63,7 @@ pub(super) fn select_dataset_region_axes(
 
 fn navigate_dataset_region_target(
     state: &mut AppState<'_>,
-    target: &DatasetRegionNavigationTarget,
+    target: &DatasetRegionTarget,
 ) -> Result<Option<EventResult>, EventResult> {
     state
         .select_tree_node_by_path(target.path.as_str())
@@ -282,12 +88,12 @@ fn navigate_dataset_region_target(
 
     let current_node = &state.treeview[state.tree_view_cursor];
     let mut node = current_node.node.borrow_mut();
-    if matches!(node.node, crate::h5f::Node::Dataset(_, _)) {
+    if matches!(node.node, Node::Dataset(_, _)) {
         node.ensure_dataset_meta()
             .map_err(|error| EventResult::Toast(AppToast::Error(error.to_string()), false))?;
     }
     let (shape, available_modes) = match &node.node {
-        crate::h5f::Node::Dataset(_, crate::h5f::DatasetMetaState::Loaded(meta)) => (
+        Node::Dataset(_, DatasetMetaState::Loaded(meta)) => (
             meta.shape.clone(),
             state.filter_runtime_content_modes(node.content_show_modes()),
         ),
@@ -387,96 +193,82 @@ pub(super) fn navigate_reference_attribute_value(
             ))
         }
     };
-    let target = match type_desc {
-        hdf5_metno::types::TypeDescriptor::Reference(Reference::Object) => {
-            if attr.size() != 1 {
-                return Ok(Some(EventResult::Toast(
-                    AppToast::Warning(format!(
-                        "Attribute '{}' contains multiple references; navigation needs a single target",
-                        attr_name
-                    )),
-                    false,
-                )));
-            }
-            let reference = if attr.is_scalar() {
-                attr.read_scalar::<ObjectReference1>().map_err(|error| {
-                    EventResult::Toast(AppToast::Error(error.to_string()), false)
-                })?
-            } else {
-                attr.read_1d::<ObjectReference1>()
-                    .map_err(|error| EventResult::Toast(AppToast::Error(error.to_string()), false))?
-                    .into_iter()
-                    .next()
-                    .ok_or_else(|| {
-                        EventResult::Toast(
-                            AppToast::Error(
-                                "Reference attribute unexpectedly contained no values".to_string(),
-                            ),
-                            false,
-                        )
-                    })?
-            };
-            let file = attr
-                .file()
-                .map_err(|error| EventResult::Toast(AppToast::Error(error.to_string()), false))?;
-            let object = file
-                .dereference(&reference)
-                .map_err(|error| EventResult::Toast(AppToast::Error(error.to_string()), false))?;
-            Some(ReferenceNavigationTarget::Object {
-                path: referenced_object_path(object).map_err(|error| {
-                    EventResult::Toast(AppToast::Error(error.to_string()), false)
-                })?,
-                attr_name: None,
-            })
+    if !matches!(type_desc, TypeDescriptor::Reference(_)) {
+        return Ok(None);
+    }
+    if attr.size() != 1 {
+        return Ok(Some(EventResult::Toast(
+            AppToast::Warning(format!(
+                "Attribute '{}' contains multiple references; navigation needs a single target",
+                attr_name
+            )),
+            false,
+        )));
+    }
+    let target = read_reference_attr_target(&attr)
+        .map_err(|error| EventResult::Toast(AppToast::Error(error.to_string()), false))?;
+
+    let Some(target) = target else {
+        return Ok(Some(EventResult::Toast(
+            AppToast::Warning(format!("Attribute '{attr_name}' holds a null reference")),
+            false,
+        )));
+    };
+    navigate_reference_target(state, target)
+}
+
+/// Follows the reference under the matrix cursor, or the only element of a previewed dataset.
+pub(crate) fn navigate_reference_dataset_cell(
+    state: &mut AppState<'_>,
+) -> Result<Option<EventResult>, EventResult> {
+    let content_mode = state.active_content_mode();
+    let tree_item = state.treeview[state.tree_view_cursor].node.clone();
+    let (dataset, indexes) = {
+        let mut node = tree_item.borrow_mut();
+        if node.ensure_dataset_meta().is_err() {
+            return Ok(None);
         }
-        hdf5_metno::types::TypeDescriptor::Reference(Reference::Std) => {
-            if attr.size() != 1 {
-                return Ok(Some(EventResult::Toast(
-                    AppToast::Warning(format!(
-                        "Attribute '{}' contains multiple references; navigation needs a single target",
-                        attr_name
-                    )),
-                    false,
-                )));
+        let (dataset, meta) = match &node.node {
+            Node::Dataset(DatasetHandle::Loaded(dataset), DatasetMetaState::Loaded(meta))
+                if matches!(meta.matrixable, Some(MatrixRenderType::References))
+                    && !meta.is_compound_leaf() =>
+            {
+                (dataset.clone(), meta.clone())
             }
-            Some(
-                resolve_std_reference_target(&attr).map_err(|error| {
-                    EventResult::Toast(AppToast::Error(error.to_string()), false)
-                })?,
-            )
-        }
-        hdf5_metno::types::TypeDescriptor::Reference(Reference::Region) => {
-            if attr.size() != 1 {
-                return Ok(Some(EventResult::Toast(
-                    AppToast::Warning(format!(
-                        "Attribute '{}' contains multiple references; navigation needs a single target",
-                        attr_name
-                    )),
-                    false,
-                )));
+            _ => return Ok(None),
+        };
+        let indexes = match content_mode {
+            ContentShowMode::Matrix => {
+                node.sync_selection_rank(meta.shape.len());
+                state.matrix_cursor_indexes(&node, &meta.shape)
             }
-            Some(
-                resolve_region_reference_target(&attr).map_err(|error| {
-                    EventResult::Toast(AppToast::Error(error.to_string()), false)
-                })?,
-            )
-        }
-        _ => None,
+            ContentShowMode::Preview if meta.total_elems == 1 => vec![0; meta.shape.len()],
+            _ => return Ok(None),
+        };
+        (dataset, indexes)
     };
-
+    let target = read_reference_element(&dataset, &indexes)
+        .map_err(|error| EventResult::Toast(AppToast::Error(error.to_string()), false))?;
     let Some(target) = target else {
-        return Ok(None);
+        return Ok(Some(EventResult::Toast(
+            AppToast::Warning(format!("Element {indexes:?} holds a null reference")),
+            false,
+        )));
     };
+    navigate_reference_target(state, target)
+}
 
+fn navigate_reference_target(
+    state: &mut AppState<'_>,
+    target: ReferenceTarget,
+) -> Result<Option<EventResult>, EventResult> {
     match target {
-        ReferenceNavigationTarget::Object { path, attr_name } => {
+        ReferenceTarget::Object { path, attr_name } => {
             state
                 .navigate_to_attribute_target(path.as_str(), attr_name.as_deref())
                 .map_err(|error| EventResult::Toast(AppToast::Error(error.to_string()), false))?;
             Ok(Some(EventResult::Redraw))
         }
-        ReferenceNavigationTarget::DatasetRegion(target) => {
-            navigate_dataset_region_target(state, &target)
-        }
+        ReferenceTarget::DatasetRegion(target) => navigate_dataset_region_target(state, &target),
     }
 }
@@ -0,0 +1,93 @@
+use crate::{
+    h5f::{
+        DatasetMetaState, MetadataRowKind, Node, VirtualSource, VirtualSourceStatus,
+        VirtualSourceTarget,
+    },
+    ui::state::{AppState, AppToast},
+};
+
+use super::{selection::selected_metadata_row, EventResult};
+
+fn unresolved_source_message(source: &VirtualSource) -> String {
+    match &source.status {
+        VirtualSourceStatus::MissingFile => format!(
+            "Source file '{}' not found; searched {}",
+            source.src_filename,
+            source
+                .searched
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        VirtualSourceStatus::MissingDataset => format!(
+            "Source dataset '{}' not found in '{}'",
+            source.src_dataset,
+            source
+                .resolved_file
+                .as_ref()
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| source.src_filename.clone())
+        ),
+        VirtualSourceStatus::Unreadable(error) => format!(
+            "Source '{}:{}' could not be opened: {error}",
+            source.src_filename, source.src_dataset
+        ),
+        VirtualSourceStatus::Pattern => format!(
+            "Source '{}' is a %b pattern that expands to one file per block; open a matching file directly",
+            source.src_filename
+        ),
+        VirtualSourceStatus::Resolved => format!(
+            "Source '{}:{}' has no navigation target",
+            source.src_filename, source.src_dataset
+        ),
+    }
+}
+
+pub(super) fn navigate_virtual_source(
+    state: &mut AppState<'_>,
+) -> Result<Option<EventResult>, EventResult> {
+    let (row, _) = selected_metadata_row(state)?;
+    let MetadataRowKind::VirtualSource(index) = row.kind else {
+        return Ok(None);
+    };
+    let source = {
+        let node = state.treeview[state.tree_view_cursor].node.borrow();
+        match &node.node {
+            Node::Dataset(_, DatasetMetaState::Loaded(meta)) => {
+                meta.virtual_sources.get(index).cloned()
+            }
+            _ => None,
+        }
+    };
+    let Some(source) = source else {
+        return Err(EventResult::Toast(
+            AppToast::Error("Virtual source mapping is no longer available".to_string()),
+            true,
+        ));
+    };
+
+    match source.navigation_target() {
+        Some(VirtualSourceTarget::SameFile(path)) => {
+            state
+                .navigate_to_attribute_target(path.as_str(), None)
+                .map_err(|error| EventResult::Toast(AppToast::Error(error.to_string()), false))?;
+            Ok(Some(EventResult::Redraw))
+        }
+        Some(VirtualSourceTarget::OtherFile { file, dataset }) => Ok(Some(EventResult::OpenFile {
+            path: file.to_string_lossy().into_owned(),
+            target: Some(dataset),
+        })),
+        None => {
+            let message = unresolved_source_message(&source);
+            Ok(Some(EventResult::Toast(
+                if source.status.is_unresolved() {
+                    AppToast::Error(message)
+                } else {
+                    AppToast::Warning(message)
+                },
+                false,
+            )))
+        }
+    }
+}
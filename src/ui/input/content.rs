@@ -15,18 +15,23 @@ use crate::{
     error::AppError,
     h5f::{
         format_dataset_value_for_edit, write_dataset_value_from_text, DatasetHandle, DatasetMeta,
-        DatasetMetaState, H5FNode, HasPath, Node,
+        DatasetMetaState, H5FNode, HasPath, IndexScope, Node,
     },
     ui::{
+        chunk_map::selected_chunk_copy_text,
+        dims::HasMatrixSelection,
         edit::perform_edit,
-        preview::chart::render_image_chart,
+        group_overview::{group_overview_active, selected_group_overview_copy_text},
+        preview::chart::{render_image_chart, ImageChartAxes},
         preview::preview_text_for_compound_schema,
         render::MatrixRenderType,
         state::{preview_selection_for_node, AppState, AppToast, ContentShowMode},
+        usage::selected_usage_copy_text,
     },
 };
 
 use super::{
+    attributes::navigate_reference_dataset_cell,
     execute_bound_command, execute_bound_lua_callback, execute_bound_script,
     keymap::{
         content_action, heatmap_action, BoundAction, ContentAction, Direction, EffectiveKeymaps,
@@ -74,6 +79,25 @@ fn redraw_if(changed: bool) -> EventResult {
     }
 }
 
+/// Moves the charted bit of a bitfield preview, wrapping at either end.
+fn step_bit_plane(state: &mut AppState<'_>, forward: bool) -> bool {
+    let tree_item = state.treeview[state.tree_view_cursor].node.clone();
+    let mut node = tree_item.borrow_mut();
+    let bits = match &node.node {
+        Node::Dataset(_, DatasetMetaState::Loaded(meta)) => match &meta.bitfield {
+            Some(bitfield) => bitfield.bits(),
+            None => return false,
+        },
+        _ => return false,
+    };
+    node.selected_bit = if forward {
+        (node.selected_bit + 1) % bits
+    } else {
+        (node.selected_bit + bits - 1) % bits
+    };
+    true
+}
+
 fn histogram_history_direction(key: KeyCode) -> Option<bool> {
     match key {
         KeyCode::PageUp => Some(true),
@@ -329,6 +353,7 @@ fn copy_chart_preview(
     ds_path: &str,
     selection: PreviewSelection,
     data_preview: crate::data::DatasetPlotingData,
+    y_time: Option<&crate::h5f::TimeEncoding>,
 ) -> Result<EventResult, AppError> {
     if let Some(chart_area) = state.chart_preview_state.last_chart_area {
         if state.chart_preview_state.current_request_key()
@@ -342,6 +367,7 @@ fn copy_chart_preview(
                 roi: state.chart_preview_state.rendered_roi,
                 histogram_selection: state.chart_preview_state.histogram_selection,
                 histogram_range: state.chart_preview_state.histogram_range,
+                bit_plane: state.chart_preview_state.rendered_bit_plane,
                 width: chart_area.width,
                 height: chart_area.height,
             })
@@ -373,6 +399,7 @@ fn copy_chart_preview(
         SliceSelection::All => 0.0,
         SliceSelection::FromTo(start, _) => start as f64,
     };
+    let x_scale = state.dimension_scales.cached_scale(ds_path, selection.x);
     render_image_chart(
         &mut buffer,
         width,
@@ -380,8 +407,12 @@ fn copy_chart_preview(
         x_min,
         data_preview,
         state.chart_preview_state.mode,
-        state.chart_preview_state.x_axis_scale,
-        state.chart_preview_state.y_axis_scale,
+        ImageChartAxes {
+            x_axis_scale: state.chart_preview_state.x_axis_scale,
+            y_axis_scale: state.chart_preview_state.y_axis_scale,
+            x_dimension_scale: x_scale.as_deref(),
+            y_time,
+        },
         state.chart_preview_state.effective_viewport(),
         state.chart_preview_state.roi,
         state.chart_preview_state.histogram_selection,
@@ -487,7 +518,7 @@ fn copy_preview_content(state: &mut AppState<'_>) -> Result<EventResult, AppErro
             false,
         ));
     };
-    copy_chart_preview(state, &ds_path, selection, data_preview)
+    copy_chart_preview(state, &ds_path, selection, data_preview, meta.time.as_ref())
 }
 
 #[cfg(test)]
@@ -546,6 +577,12 @@ fn selected_content_edit_request(
             ))
         }
     };
+    if meta.bitfield.is_some() {
+        return Err(EventResult::Toast(
+            AppToast::Warning("Bitfield values are not editable".to_string()),
+            false,
+        ));
+    }
     let dataset_shape = dataset.shape();
     let dataset_rank = dataset_shape.len();
 
@@ -586,61 +623,13 @@ fn selected_content_edit_request(
                 ));
             }
 
-            let rank = dataset_rank;
-            node.sync_selection_rank(rank);
-            let mut indices = node.selected_indexes.clone();
-            indices.resize(rank, 0);
-
-            if rank == 0 {
+            node.sync_selection_rank(dataset_rank);
+            if dataset_rank == 0 {
                 None
-            } else if rank == 1 {
-                let visible_rows = state
-                    .matrix_view_state
-                    .rows_currently_available
-                    .max(1)
-                    .min(meta.shape[0]);
-                let base_row = state
-                    .matrix_view_state
-                    .row_offset
-                    .min(meta.shape[0].saturating_sub(visible_rows));
-                indices[0] = base_row
-                    + state
-                        .matrix_view_state
-                        .cursor_row
-                        .min(visible_rows.saturating_sub(1));
-                Some(exact_element_selection(&indices))
             } else {
-                let row_dim = node.selected_row.min(rank.saturating_sub(1));
-                let col_dim = node.selected_col.min(rank.saturating_sub(1));
-                let visible_rows = state
-                    .matrix_view_state
-                    .rows_currently_available
-                    .max(1)
-                    .min(meta.shape[row_dim]);
-                let visible_cols = state
-                    .matrix_view_state
-                    .cols_currently_available
-                    .max(1)
-                    .min(meta.shape[col_dim]);
-                let base_row = state
-                    .matrix_view_state
-                    .row_offset
-                    .min(meta.shape[row_dim].saturating_sub(visible_rows));
-                let base_col = state
-                    .matrix_view_state
-                    .col_offset
-                    .min(meta.shape[col_dim].saturating_sub(visible_cols));
-                indices[row_dim] = base_row
-                    + state
-                        .matrix_view_state
-                        .cursor_row
-                        .min(visible_rows.saturating_sub(1));
-                indices[col_dim] = base_col
-                    + state
-                        .matrix_view_state
-                        .cursor_col
-                        .min(visible_cols.saturating_sub(1));
-                Some(exact_element_selection(&indices))
+                Some(exact_element_selection(
+                    &state.matrix_cursor_indexes(&node, &meta.shape),
+                ))
             }
         }
         ContentShowMode::Heatmap => {
@@ -651,6 +640,20 @@ fn selected_content_edit_request(
                 false,
             ));
         }
+        ContentShowMode::Chunks => {
+            return Err(EventResult::Toast(
+                AppToast::Warning(
+                    "Chunk map is read-only; use Matrix mode to edit values".to_string(),
+                ),
+                false,
+            ));
+        }
+        ContentShowMode::Usage => {
+            return Err(EventResult::Toast(
+                AppToast::Warning("Usage mode is read-only".to_string()),
+                false,
+            ));
+        }
     };
     let edit_name_hint = meta.virtual_path().unwrap_or(&dataset.name()).to_string();
 
@@ -735,6 +738,16 @@ fn apply_content_edit_request(
     state.heatmap_render.page_window = None;
     state.heatmap_render.cached_pages.clear();
     state.heatmap_render.pending_keys.clear();
+    state.chunk_map.invalidate();
+    state.usage.invalidate();
+    state.group_overview.invalidate();
+    state.find.invalidate();
+    state.dimension_scales.invalidate();
+    // Writing values can allocate storage.
+    state.invalidate_search_index(IndexScope {
+        datasets: true,
+        ..IndexScope::default()
+    });
     state.acknowledge_file_write();
 
     if matches!(request.meta.matrixable, Some(MatrixRenderType::ByteArray)) {
@@ -749,6 +762,54 @@ fn apply_content_edit_request(
     ))
 }
 
+fn handle_group_overview_action(
+    state: &mut AppState<'_>,
+    action: ContentAction,
+) -> Result<EventResult, AppError> {
+    match action {
+        ContentAction::Move(Direction::Up, amount) => Ok(redraw_if(
+            state.group_overview.move_cursor(-(amount as isize)),
+        )),
+        ContentAction::Move(Direction::Down, amount) => {
+            Ok(redraw_if(state.group_overview.move_cursor(amount as isize)))
+        }
+        ContentAction::Edit => {
+            let Some(path) = state.group_overview.selected_path().map(str::to_string) else {
+                return Ok(EventResult::Continue);
+            };
+            match state.navigate_to_attribute_target(&path, None) {
+                Ok(()) => Ok(EventResult::Redraw),
+                Err(error) => Ok(EventResult::Toast(
+                    AppToast::Error(error.to_string()),
+                    false,
+                )),
+            }
+        }
+        ContentAction::Copy => {
+            let text = {
+                let node = state.treeview[state.tree_view_cursor].node.borrow();
+                selected_group_overview_copy_text(&node, state)
+            };
+            let Some(text) = text else {
+                return Ok(EventResult::Toast(
+                    AppToast::Warning("No member selected".to_string()),
+                    false,
+                ));
+            };
+            copy_text_to_clipboard(state, text, "Copied member summary to clipboard")
+        }
+        ContentAction::CycleSortColumn => {
+            state.group_overview.cycle_sort();
+            Ok(EventResult::Redraw)
+        }
+        ContentAction::ReverseSort => {
+            state.group_overview.reverse_sort();
+            Ok(EventResult::Redraw)
+        }
+        _ => Ok(EventResult::Continue),
+    }
+}
+
 pub fn handle_normal_content_event(
     state: &mut AppState<'_>,
     event: Event,
@@ -782,7 +843,17 @@ pub fn handle_normal_content_event(
                 } else {
                     content_action(&key_event, keymaps)
                 };
+                let group_overview = matches!(content_mode, ContentShowMode::Preview)
+                    && state
+                        .treeview
+                        .get(state.tree_view_cursor)
+                        .is_some_and(|item| group_overview_active(&item.node.borrow()));
                 match (action, content_mode) {
+                    (Some(BoundAction::Action(action)), ContentShowMode::Preview)
+                        if group_overview =>
+                    {
+                        handle_group_overview_action(state, action)
+                    }
                     (
                         Some(BoundAction::Action(ContentAction::Move(Direction::Left, amount))),
                         ContentShowMode::Matrix,
@@ -892,6 +963,23 @@ pub fn handle_normal_content_event(
                         Some(BoundAction::Action(ContentAction::Move(Direction::Down, amount))),
                         ContentShowMode::Heatmap,
                     ) => state.down(amount),
+                    (
+                        Some(BoundAction::Action(ContentAction::Move(direction, amount))),
+                        ContentShowMode::Chunks,
+                    ) => match direction {
+                        Direction::Up => state.up(amount),
+                        Direction::Down => state.down(amount),
+                        Direction::Left => state.left(amount as isize),
+                        Direction::Right => state.right(amount as isize),
+                    },
+                    (
+                        Some(BoundAction::Action(ContentAction::Move(direction, amount))),
+                        ContentShowMode::Usage,
+                    ) => match direction {
+                        Direction::Up => state.up(amount),
+                        Direction::Down => state.down(amount),
+                        Direction::Left | Direction::Right => Ok(EventResult::Continue),
+                    },
                     (
                         Some(BoundAction::Action(ContentAction::Move(Direction::Down, amount))),
                         ContentShowMode::Preview,
@@ -918,6 +1006,11 @@ pub fn handle_normal_content_event(
                                 false,
                             ));
                         }
+                        match navigate_reference_dataset_cell(state) {
+                            Ok(Some(event_result)) => return Ok(event_result),
+                            Ok(None) => {}
+                            Err(event_result) => return Ok(event_result),
+                        }
                         let request = match selected_content_edit_request(state) {
                             Ok(request) => request,
                             Err(event_result) => return Ok(event_result),
@@ -941,6 +1034,14 @@ pub fn handle_normal_content_event(
                     ) => Ok(redraw_if(
                         state.chart_preview_state.toggle_axis_scale(false),
                     )),
+                    (
+                        Some(BoundAction::Action(ContentAction::NextBitPlane)),
+                        ContentShowMode::Preview,
+                    ) => Ok(redraw_if(step_bit_plane(state, true))),
+                    (
+                        Some(BoundAction::Action(ContentAction::PrevBitPlane)),
+                        ContentShowMode::Preview,
+                    ) => Ok(redraw_if(step_bit_plane(state, false))),
                     (Some(BoundAction::Action(ContentAction::Copy)), ContentShowMode::Matrix) => {
                         let text = match selected_matrix_copy_text(state) {
                             Ok(text) => text,
@@ -948,6 +1049,32 @@ pub fn handle_normal_content_event(
                         };
                         copy_text_to_clipboard(state, text, "Copied matrix value to clipboard")
                     }
+                    (Some(BoundAction::Action(ContentAction::Copy)), ContentShowMode::Chunks) => {
+                        let text = {
+                            let node = state.treeview[state.tree_view_cursor].node.borrow();
+                            selected_chunk_copy_text(&node, state)
+                        };
+                        let Some(text) = text else {
+                            return Ok(EventResult::Toast(
+                                AppToast::Warning("No chunk selected".to_string()),
+                                false,
+                            ));
+                        };
+                        copy_text_to_clipboard(state, text, "Copied chunk details to clipboard")
+                    }
+                    (Some(BoundAction::Action(ContentAction::Copy)), ContentShowMode::Usage) => {
+                        let text = {
+                            let node = state.treeview[state.tree_view_cursor].node.borrow();
+                            selected_usage_copy_text(&node, state)
+                        };
+                        let Some(text) = text else {
+                            return Ok(EventResult::Toast(
+                                AppToast::Warning("No member selected".to_string()),
+                                false,
+                            ));
+                        };
+                        copy_text_to_clipboard(state, text, "Copied usage to clipboard")
+                    }
                     (Some(BoundAction::Action(ContentAction::Copy)), ContentShowMode::Heatmap) => {
                         let text = match selected_heatmap_copy_text(state) {
                             Ok(text) => text,
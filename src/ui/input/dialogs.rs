@@ -2,11 +2,14 @@ use ratatui::crossterm::event::{Event, KeyCode};
 
 use crate::{
     error::AppError,
-    h5f::FixedStringRewrite,
+    h5f::{AttributeSpec, FixedStringRewrite},
     ui::state::{AppToast, AttributeCreateField, FixedStringOverflowChoice, Mode},
 };
 
-use super::{super::state::AppState, is_handled_key_press, EventResult};
+use super::{
+    super::state::AppState, attributes::edit_new_attribute_values, is_handled_key_press,
+    EventResult,
+};
 
 pub(super) fn handle_fixed_string_overflow_dialog(
     state: &mut AppState<'_>,
@@ -128,10 +131,19 @@ pub(super) fn handle_attribute_create_dialog(
                 Ok(EventResult::Redraw)
             }
             AttributeCreateField::Value => {
-                let (name, attr_type, value) =
-                    (dialog.name.clone(), dialog.attr_type, dialog.value.clone());
+                let spec = if dialog.array {
+                    AttributeSpec::list(dialog.attr_type)
+                } else {
+                    AttributeSpec::scalar(dialog.attr_type)
+                };
+                let (name, value) = (dialog.name.clone(), dialog.value.clone());
+                let value = if spec.is_array() && value.trim().is_empty() {
+                    edit_new_attribute_values(state, &name)?
+                } else {
+                    value
+                };
                 let mut selected_node = state.treeview[state.tree_view_cursor].node.borrow_mut();
-                let created_type = selected_node.create_attribute(&name, attr_type, &value)?;
+                let created_type = selected_node.create_attribute(&name, &spec, &value)?;
                 drop(selected_node);
                 state.invalidate_selected_navigation_data();
                 state.attribute_create_dialog = None;
@@ -167,6 +179,10 @@ pub(super) fn handle_attribute_create_dialog(
                 [(idx + 1) % crate::h5f::AttributeCreateType::ALL.len()];
             Ok(EventResult::Redraw)
         }
+        KeyCode::Char(' ') if dialog.active_field == AttributeCreateField::Type => {
+            dialog.array = !dialog.array;
+            Ok(EventResult::Redraw)
+        }
         KeyCode::Backspace => {
             let (buffer, cursor) = match dialog.active_field {
                 AttributeCreateField::Name => (&mut dialog.name, &mut dialog.name_cursor),
@@ -283,6 +299,45 @@ pub(super) fn handle_attribute_delete_dialog(
     }
 }
 
+pub(super) fn handle_structure_edit_dialog(
+    state: &mut AppState<'_>,
+    event: Event,
+) -> Result<EventResult, AppError> {
+    let Event::Key(key_event) = event else {
+        return Ok(EventResult::Continue);
+    };
+    if !is_handled_key_press(&key_event) {
+        return Ok(EventResult::Continue);
+    }
+    let Some(dialog) = state.structure_edit_dialog.as_ref() else {
+        state.mode = Mode::Normal;
+        return Ok(EventResult::Toast(AppToast::Empty, true));
+    };
+
+    match key_event.code {
+        KeyCode::Esc => {
+            state.structure_edit_dialog = None;
+            state.mode = Mode::Normal;
+            Ok(EventResult::Toast(AppToast::Empty, true))
+        }
+        KeyCode::Enter => {
+            let edit = dialog.edit.clone();
+            state.structure_edit_dialog = None;
+            state.mode = Mode::Normal;
+            let Some(file) = state.file.as_ref() else {
+                return Err(AppError::EditError("No HDF5 file is open".to_string()));
+            };
+            edit.apply(file)?;
+            state.acknowledge_file_write();
+            state.applied_structure_edit = Some(edit);
+            Ok(EventResult::ReloadFile {
+                write: !state.readonly,
+            })
+        }
+        _ => Ok(EventResult::Continue),
+    }
+}
+
 pub(super) fn handle_fixed_string_resize_dialog(
     state: &mut AppState<'_>,
     event: Event,
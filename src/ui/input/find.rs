@@ -0,0 +1,112 @@
+use ratatui::crossterm::event::{Event, KeyCode, KeyModifiers};
+
+use crate::{
+    error::AppError,
+    ui::state::{AppState, AppToast, Focus, Mode},
+};
+
+use super::{is_handled_key_press, EventResult};
+
+const PAGE_ROWS: isize = 10;
+
+/// Typing edits the query and Enter scans the dataset for it. Once hits are
+/// listed, moving through them scrolls the content to each hit and Enter
+/// opens the selected one.
+pub fn handle_find_event(state: &mut AppState<'_>, event: Event) -> Result<EventResult, AppError> {
+    let Event::Key(key_event) = event else {
+        return Ok(EventResult::Continue);
+    };
+    if !is_handled_key_press(&key_event) {
+        return Ok(EventResult::Continue);
+    }
+    let find = &mut state.find;
+    match key_event.code {
+        KeyCode::Esc => {
+            state.mode = Mode::Normal;
+            Ok(EventResult::Redraw)
+        }
+        KeyCode::Enter if find.input_changed() => {
+            let query = find.input.clone();
+            match state.start_find(&query) {
+                Ok(()) => Ok(EventResult::Redraw),
+                Err(error) => Ok(EventResult::Toast(
+                    AppToast::Error(error.to_string()),
+                    false,
+                )),
+            }
+        }
+        KeyCode::Enter => {
+            if find.selected_hit().is_none() {
+                return Ok(EventResult::Continue);
+            }
+            let result = state.open_find_hit()?;
+            state.mode = Mode::Normal;
+            state.focus = Focus::Content;
+            Ok(match result {
+                EventResult::Continue => EventResult::Redraw,
+                result => result,
+            })
+        }
+        KeyCode::Up => move_hit_cursor(state, -1),
+        KeyCode::Down => move_hit_cursor(state, 1),
+        KeyCode::PageUp => move_hit_cursor(state, -PAGE_ROWS),
+        KeyCode::PageDown => move_hit_cursor(state, PAGE_ROWS),
+        KeyCode::Char('u') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            find.input.clear();
+            find.input_cursor = 0;
+            Ok(EventResult::Redraw)
+        }
+        KeyCode::Char(c) => {
+            find.input.insert(find.input_cursor, c);
+            find.input_cursor += c.len_utf8();
+            Ok(EventResult::Redraw)
+        }
+        KeyCode::Backspace => {
+            let Some(c) = find.input[..find.input_cursor].chars().next_back() else {
+                return Ok(EventResult::Continue);
+            };
+            find.input_cursor -= c.len_utf8();
+            find.input.remove(find.input_cursor);
+            Ok(EventResult::Redraw)
+        }
+        KeyCode::Delete => {
+            if find.input_cursor >= find.input.len() {
+                return Ok(EventResult::Continue);
+            }
+            find.input.remove(find.input_cursor);
+            Ok(EventResult::Redraw)
+        }
+        KeyCode::Left => {
+            let Some(c) = find.input[..find.input_cursor].chars().next_back() else {
+                return Ok(EventResult::Continue);
+            };
+            find.input_cursor -= c.len_utf8();
+            Ok(EventResult::Redraw)
+        }
+        KeyCode::Right => {
+            let Some(c) = find.input[find.input_cursor..].chars().next() else {
+                return Ok(EventResult::Continue);
+            };
+            find.input_cursor += c.len_utf8();
+            Ok(EventResult::Redraw)
+        }
+        KeyCode::Home => {
+            find.input_cursor = 0;
+            Ok(EventResult::Redraw)
+        }
+        KeyCode::End => {
+            find.input_cursor = find.input.len();
+            Ok(EventResult::Redraw)
+        }
+        _ => Ok(EventResult::Continue),
+    }
+}
+
+/// Moves to another hit and scrolls the content to it.
+fn move_hit_cursor(state: &mut AppState<'_>, delta: isize) -> Result<EventResult, AppError> {
+    if !state.find.move_cursor(delta) {
+        return Ok(EventResult::Continue);
+    }
+    state.open_find_hit()?;
+    Ok(EventResult::Redraw)
+}
@@ -71,6 +71,11 @@ pub enum TreeAction {
     Expand,
     Toggle,
     AddToMultiChart,
+    CreateGroup,
+    CreateDataset,
+    MoveObject,
+    CreateSoftLink,
+    DeleteObject,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -86,6 +91,10 @@ pub enum ContentAction {
     HeatmapResetView,
     HeatmapClearSelection,
     HeatmapPan(Direction),
+    NextBitPlane,
+    PrevBitPlane,
+    CycleSortColumn,
+    ReverseSort,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -338,7 +347,11 @@ pub fn search_action(key: &KeyEvent) -> Option<SearchAction> {
     match (key.code, key.modifiers) {
         (KeyCode::Char('w'), KeyModifiers::CONTROL)
         | (KeyCode::Backspace, KeyModifiers::CONTROL) => Some(SearchAction::ClearQuery),
-        (KeyCode::Char(c), _) if c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.' => {
+        // Attribute queries and metadata filters use punctuation and spaces.
+        (KeyCode::Char(c), modifiers)
+            if (c.is_ascii_graphic() || c == ' ')
+                && !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+        {
             Some(SearchAction::Insert(c))
         }
         (KeyCode::Backspace, _) => Some(SearchAction::Backspace),
@@ -476,6 +489,16 @@ mod tests {
         assert!(key.modifiers.contains(KeyModifiers::SHIFT));
     }
 
+    #[test]
+    fn search_inserts_query_syntax_characters() {
+        for c in [':', '>', '=', '~', '*', '/', ' ', '"', ','] {
+            let key = KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+            assert_eq!(search_action(&key), Some(SearchAction::Insert(c)));
+        }
+        let key = KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL);
+        assert_eq!(search_action(&key), Some(SearchAction::ClearQuery));
+    }
+
     #[test]
     fn multichart_enter_opens_expression_prompt() {
         let keymaps = EffectiveKeymaps::default();
@@ -327,6 +327,36 @@ const TREE_ACTION_CODES: &[ActionCode<TreeAction>] = &[
         action: TreeAction::AddToMultiChart,
         default_keys: &["m"],
     },
+    ActionCode {
+        symbol: "CreateGroup",
+        code: "create-group",
+        action: TreeAction::CreateGroup,
+        default_keys: &["a"],
+    },
+    ActionCode {
+        symbol: "CreateDataset",
+        code: "create-dataset",
+        action: TreeAction::CreateDataset,
+        default_keys: &["A"],
+    },
+    ActionCode {
+        symbol: "MoveObject",
+        code: "move-object",
+        action: TreeAction::MoveObject,
+        default_keys: &["n"],
+    },
+    ActionCode {
+        symbol: "CreateSoftLink",
+        code: "create-soft-link",
+        action: TreeAction::CreateSoftLink,
+        default_keys: &["S"],
+    },
+    ActionCode {
+        symbol: "DeleteObject",
+        code: "delete-object",
+        action: TreeAction::DeleteObject,
+        default_keys: &["d", "Delete"],
+    },
 ];
 
 const CONTENT_ACTION_CODES: &[ActionCode<ContentAction>] = &[
@@ -444,6 +474,30 @@ const CONTENT_ACTION_CODES: &[ActionCode<ContentAction>] = &[
         action: ContentAction::HeatmapPan(Direction::Down),
         default_keys: &["J"],
     },
+    ActionCode {
+        symbol: "NextBitPlane",
+        code: "next-bit-plane",
+        action: ContentAction::NextBitPlane,
+        default_keys: &["b"],
+    },
+    ActionCode {
+        symbol: "PrevBitPlane",
+        code: "prev-bit-plane",
+        action: ContentAction::PrevBitPlane,
+        default_keys: &["B"],
+    },
+    ActionCode {
+        symbol: "CycleSortColumn",
+        code: "cycle-sort-column",
+        action: ContentAction::CycleSortColumn,
+        default_keys: &["o"],
+    },
+    ActionCode {
+        symbol: "ReverseSort",
+        code: "reverse-sort",
+        action: ContentAction::ReverseSort,
+        default_keys: &["O"],
+    },
 ];
 
 const ATTRIBUTES_ACTION_CODES: &[ActionCode<AttributesAction>] = &[
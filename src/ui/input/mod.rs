@@ -5,6 +5,7 @@ use content::handle_normal_content_event;
 use dialogs::{
     handle_attribute_create_dialog, handle_attribute_delete_dialog,
     handle_fixed_string_overflow_dialog, handle_fixed_string_resize_dialog,
+    handle_structure_edit_dialog,
 };
 use keymap::{
     global_action, normal_action, window_action, BoundAction, Direction, GlobalAction,
@@ -14,14 +15,13 @@ use mouse::{
     handle_global_mouse_event, handle_help_mouse_event, handle_logs_mouse_event,
     handle_normal_mouse_event,
 };
-use ratatui::crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use tree::handle_normal_tree_event;
 
 use crate::{
     configure,
     error::AppError,
     h5f::{HasAttributes, HasPath, Node},
-    search::{full_traversal, Searcher},
     ui::command::{execute_command, parse_command_text, parse_startup_commands},
     ui::state::AppToast,
 };
@@ -32,6 +32,7 @@ pub mod attributes;
 pub mod command;
 pub mod content;
 mod dialogs;
+pub mod find;
 pub mod keymap;
 pub mod mchart;
 mod mouse;
@@ -42,8 +43,16 @@ pub enum EventResult {
     Quit,
     Redraw,
     Copying,
-    ReloadFile { write: bool },
-    Configure { reset: bool },
+    ReloadFile {
+        write: bool,
+    },
+    OpenFile {
+        path: String,
+        target: Option<String>,
+    },
+    Configure {
+        reset: bool,
+    },
     Continue,
     Error(String),
     Toast(AppToast, bool),
@@ -98,6 +107,7 @@ pub(crate) fn execute_bound_script(
                 }
                 EventResult::Quit
                 | EventResult::ReloadFile { .. }
+                | EventResult::OpenFile { .. }
                 | EventResult::Configure { .. }
                 | EventResult::Error(_) => return Ok(event_result),
             }
@@ -188,6 +198,7 @@ pub(crate) fn execute_bound_lua_callback(
                         crate::h5f::Node::File(_) => "file",
                         crate::h5f::Node::Group(_, _) => "group",
                         crate::h5f::Node::Dataset(_, _) => "dataset",
+                        crate::h5f::Node::Datatype(_, _) => "datatype",
                         crate::h5f::Node::Broken(_) => "broken",
                     };
                     let attribute_names = node.node.attribute_names().unwrap_or_default();
@@ -335,6 +346,7 @@ pub fn handle_input_event(state: &mut AppState<'_>, event: Event) -> Result<Even
         Mode::AttributeDeleteDialog => handle_attribute_delete_dialog(state, event),
         Mode::FixedStringOverflowDialog => handle_fixed_string_overflow_dialog(state, event),
         Mode::FixedStringResizeDialog => handle_fixed_string_resize_dialog(state, event),
+        Mode::StructureEditDialog => handle_structure_edit_dialog(state, event),
         Mode::Normal => match event {
             Event::Key(key_event) => {
                 if !is_handled_key_press(&key_event) {
@@ -394,18 +406,20 @@ pub fn handle_input_event(state: &mut AppState<'_>, event: Event) -> Result<Even
                         }
                         BoundAction::Action(NormalAction::EnterSearch) => {
                             if state.searcher.is_none() {
-                                let Node::File(ref file) = state.root.borrow().node else {
-                                    return Ok(EventResult::Error(
-                                        "Search only available for HDF5 files".to_string(),
-                                    ));
+                                let file_as_group = {
+                                    let Node::File(ref file) = state.root.borrow().node else {
+                                        return Ok(EventResult::Error(
+                                            "Search only available for HDF5 files".to_string(),
+                                        ));
+                                    };
+                                    let Ok(file_as_group) = file.as_group() else {
+                                        return Ok(EventResult::Error(
+                                            "Search only available for HDF5 files with roots that can polymorp as group.".to_string(),
+                                        ));
+                                    };
+                                    file_as_group
                                 };
-                                let Ok(file_as_group) = file.as_group() else {
-                                    return Ok(EventResult::Error(
-                                        "Search only available for HDF5 files with roots that can polymorp as group.".to_string(),
-                                    ));
-                                };
-                                let all_h5_paths = full_traversal(&file_as_group);
-                                state.searcher = Some(Searcher::new(all_h5_paths));
+                                state.start_search_index(file_as_group);
                             }
                             let Some(ref mut searcher) = state.searcher else {
                                 return Ok(EventResult::Error("Search not available".to_string()));
@@ -513,11 +527,23 @@ pub fn handle_input_event(state: &mut AppState<'_>, event: Event) -> Result<Even
                         state.mode = state.help_return_mode.clone();
                         return Ok(EventResult::Redraw);
                     }
+                    KeyCode::Char('c')
+                        if key_event.modifiers.contains(KeyModifiers::CONTROL)
+                            && state.search_index.pending_key.is_some() =>
+                    {
+                        state.cancel_search_index();
+                        state.mode = state.help_return_mode.clone();
+                        return Ok(EventResult::Toast(
+                            AppToast::Info("Search indexing cancelled".to_string()),
+                            false,
+                        ));
+                    }
                     _ => {}
                 }
             }
             search::handle_search_event(state, event)
         }
+        Mode::Find => find::handle_find_event(state, event),
         Mode::Logs => {
             match event {
                 Event::Key(key_event) => {
@@ -2,6 +2,7 @@ use ratatui::crossterm::event::Event;
 
 use crate::{
     error::AppError,
+    search::SearchTarget,
     ui::state::{AppState, Focus, LastFocused, Mode},
 };
 
@@ -121,7 +122,7 @@ pub fn handle_search_event(
                         return Ok(EventResult::Error("No searcher available".into()));
                     };
 
-                    let results = searcher.result_paths(&searcher.query);
+                    let results = searcher.results(&searcher.query);
 
                     let selected_node = searcher.select_cursor;
                     if results.is_empty() {
@@ -135,9 +136,22 @@ pub fn handle_search_event(
                         selected_node
                     };
 
-                    let selected_result = results[selected_index_corrected].to_string();
+                    let (selected_result, attribute_name) = match results[selected_index_corrected]
+                    {
+                        SearchTarget::Path(path) => (path.to_string(), None),
+                        SearchTarget::Dataset(dataset) => (dataset.path.clone(), None),
+                        SearchTarget::Attribute(attribute) => {
+                            (attribute.path.clone(), Some(attribute.name.clone()))
+                        }
+                    };
                     state.root.borrow_mut().collapse();
-                    state.select_tree_node_by_path(&selected_result)?;
+                    match attribute_name {
+                        // Highlights the attribute row once the attributes are read.
+                        Some(name) => {
+                            state.navigate_to_attribute_target(&selected_result, Some(&name))?
+                        }
+                        None => state.select_tree_node_by_path(&selected_result)?,
+                    }
 
                     state.mode = Mode::Normal;
                     state.focus = Focus::Tree(LastFocused::Attributes);
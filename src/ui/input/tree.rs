@@ -3,7 +3,14 @@ use std::rc::Rc;
 
 use ratatui::crossterm::event::{Event, KeyEventKind};
 
-use crate::{error::AppError, ui::state::AppState};
+use crate::{
+    error::AppError,
+    h5f::{parent_object_path, StructureEdit},
+    ui::{
+        command::quote_command_word,
+        state::{AppState, AppToast, Mode, StructureEditDialogState},
+    },
+};
 
 use super::{
     execute_bound_command, execute_bound_lua_callback, execute_bound_script,
@@ -131,6 +138,42 @@ pub fn handle_normal_tree_event(
                     state.compute_tree_view();
                     Ok(EventResult::Redraw)
                 }
+                Some(BoundAction::Action(TreeAction::CreateGroup)) => {
+                    let group = group_prefix(&state.selected_group_path());
+                    begin_object_command(state, &["group", &group])
+                }
+                Some(BoundAction::Action(TreeAction::CreateDataset)) => {
+                    let group = group_prefix(&state.selected_group_path());
+                    begin_object_command(state, &["dataset", &group])
+                }
+                Some(BoundAction::Action(TreeAction::MoveObject)) => {
+                    let Some(path) = state.selected_link_path() else {
+                        return Ok(no_link_selected("move"));
+                    };
+                    begin_object_command(state, &["move", &path, &path])
+                }
+                Some(BoundAction::Action(TreeAction::CreateSoftLink)) => {
+                    let Some(path) = state.selected_link_path() else {
+                        return Ok(no_link_selected("link to"));
+                    };
+                    let group = group_prefix(parent_object_path(&path));
+                    begin_object_command(state, &["soft-link", &path, &group])
+                }
+                Some(BoundAction::Action(TreeAction::DeleteObject)) => {
+                    if state.readonly {
+                        return Ok(read_only_toast());
+                    }
+                    let Some(path) = state.selected_link_path() else {
+                        return Ok(no_link_selected("delete"));
+                    };
+                    let edit = StructureEdit::Delete { path };
+                    if let Some(file) = state.file.as_ref() {
+                        edit.check(file)?;
+                    }
+                    state.structure_edit_dialog = Some(StructureEditDialogState { edit });
+                    state.mode = Mode::StructureEditDialog;
+                    Ok(EventResult::Redraw)
+                }
                 Some(BoundAction::Command(command)) => execute_bound_command(state, &command),
                 Some(BoundAction::Script(script)) => {
                     execute_bound_script(state, &script, "keybinding script")
@@ -147,3 +190,43 @@ pub fn handle_normal_tree_event(
         _ => Ok(EventResult::Continue),
     }
 }
+
+fn read_only_toast() -> EventResult {
+    EventResult::Toast(
+        AppToast::Warning(
+            "Cannot edit in read-only mode; reopen with -w to modify the file".to_string(),
+        ),
+        false,
+    )
+}
+
+fn no_link_selected(action: &str) -> EventResult {
+    EventResult::Toast(
+        AppToast::Warning(format!("Select a group, dataset or link to {action}")),
+        false,
+    )
+}
+
+/// `group` with a trailing slash, ready for a member name to be typed.
+fn group_prefix(group: &str) -> String {
+    format!("{}/", group.trim_end_matches('/'))
+}
+
+/// Opens the command minibuffer on an `object` command filled in up to the
+/// part left to type, which the confirmation dialog then shows.
+fn begin_object_command(state: &mut AppState<'_>, words: &[&str]) -> Result<EventResult, AppError> {
+    if state.readonly {
+        return Ok(read_only_toast());
+    }
+    let mut text = "object".to_string();
+    for word in words {
+        text.push(' ');
+        text.push_str(&quote_command_word(word));
+    }
+    // Leave the cursor inside the closing quote of a quoted last word.
+    let cursor = text.len() - usize::from(text.ends_with('"'));
+    state.command_return_mode = Mode::Normal;
+    state.mode = Mode::Command;
+    state.command_state.begin_prefilled_entry(text, cursor);
+    Ok(EventResult::Redraw)
+}
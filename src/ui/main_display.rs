@@ -15,18 +15,21 @@ use crate::{
     h5f::{DatasetHandle, DatasetMetaState, H5FNode, HasPath, Node},
     ui::{
         self,
+        chunk_map::render_chunk_map,
         custom_content::render_custom_content_mode,
         heatmap::render_heatmap,
         matrix::{DefaultMatrixResultRenderIntercept, EnumRenderer},
         render::MatrixRenderType,
+        usage::render_usage,
     },
 };
 
 use super::{
     attributes::{prepare_metadata_layout, render_info_attributes},
     matrix::{
-        render_compound_root_matrix, render_matrix, render_not_yet_implemented,
-        render_opaque_matrix, render_projected_matrix, render_varlen_u8_matrix,
+        render_bitfield_matrix, render_compound_root_matrix, render_matrix,
+        render_not_yet_implemented, render_opaque_matrix, render_projected_matrix,
+        render_ragged_matrix, render_reference_matrix, render_varlen_u8_matrix,
     },
     preview::render_preview,
     state::{AppState, ContentShowMode},
@@ -85,6 +88,12 @@ fn content_mode_title(handle: &crate::configure::registry::ContentModeHandle) ->
                 configure::configured_symbol(|symbols| symbols.title.matrix_tab)
             }
             ContentShowMode::Heatmap => "# Heatmap",
+            ContentShowMode::Chunks => {
+                configure::configured_symbol(|symbols| symbols.title.chunks_tab)
+            }
+            ContentShowMode::Usage => {
+                configure::configured_symbol(|symbols| symbols.title.usage_tab)
+            }
         }
         .to_string();
     }
@@ -168,6 +177,7 @@ pub fn render_main_display(
         Node::File(_) => "file",
         Node::Group(_, _) => "group",
         Node::Dataset(_, _) => "dataset",
+        Node::Datatype(_, _) => "datatype",
         Node::Broken(_) => "broken",
     };
     let attribute_names: Vec<String> = node
@@ -293,7 +303,8 @@ pub fn render_main_display(
             | ui::state::Mode::AttributeCreateDialog
             | ui::state::Mode::AttributeDeleteDialog
             | ui::state::Mode::FixedStringOverflowDialog
-            | ui::state::Mode::FixedStringResizeDialog,
+            | ui::state::Mode::FixedStringResizeDialog
+            | ui::state::Mode::StructureEditDialog,
         ) => configure::themed_color(|colors| colors.surface.focus_bg),
         _ => configure::themed_color(|colors| colors.surface.bg),
     };
@@ -304,7 +315,11 @@ pub fn render_main_display(
         content_area,
     );
     match ContentShowMode::parse_handle(display_mode.as_str()) {
-        Some(ContentShowMode::Preview) => render_preview(f, &content_area, &mut node, state),
+        Some(ContentShowMode::Preview) => {
+            render_preview(f, &content_area, &mut node, state);
+            drop(node);
+            state.request_group_overview(selected_node_no);
+        }
         Some(ContentShowMode::Matrix) => {
             //
             let (ds, attr) = match node.node.clone() {
@@ -472,10 +487,21 @@ pub fn render_main_display(
                     MatrixRenderType::ByteArray => {
                         render_varlen_u8_matrix(f, &content_area, &ds, &attr, &mut node, state)?
                     }
+                    MatrixRenderType::Ragged => {
+                        render_ragged_matrix(f, &content_area, &ds, &attr, &mut node, state)?
+                    }
+                    MatrixRenderType::References => {
+                        render_reference_matrix(f, &content_area, &ds, &attr, &mut node, state)?
+                    }
+                    MatrixRenderType::Bitfield => {
+                        render_bitfield_matrix(f, &content_area, &ds, &attr, &mut node, state)?
+                    }
                 },
             }
         }
         Some(ContentShowMode::Heatmap) => render_heatmap(f, &content_area, &mut node, state)?,
+        Some(ContentShowMode::Chunks) => render_chunk_map(f, &content_area, &mut node, state)?,
+        Some(ContentShowMode::Usage) => render_usage(f, &content_area, &node, state)?,
         None => render_custom_content_mode(f, &content_area, state, &display_mode)?,
     }
 
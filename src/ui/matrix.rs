@@ -11,7 +11,7 @@ use hdf5_metno::{
     },
     H5Type, Hyperslab, Selection, SliceOrIndex,
 };
-use ndarray::{Array1, Array2};
+use ndarray::{Array1, Array2, Ix1, Ix2};
 use ratatui::{
     layout::{Constraint, Layout, Offset, Rect},
     style::{Color, Style, Stylize},
@@ -22,13 +22,15 @@ use ratatui::{
 
 use crate::{
     configure,
-    data::{MatrixTable, MatrixValues},
+    data::{read_f64_values, MatrixTable, MatrixValues},
     error::AppError,
     h5f::{
-        read_opaque_values_1d, read_opaque_values_2d, read_projected_values_1d,
-        read_projected_values_2d, read_selected_values_bytes, read_varlen_u8_matrix_table,
-        read_varlen_u8_matrix_values, DatasetMeta, EnumRenderOverrides, H5FNode, HasPath,
-        ProjectionDecode, ResolvedOpenMode,
+        read_bitfield_matrix_table, read_bitfield_matrix_values, read_opaque_values_1d,
+        read_opaque_values_2d, read_projected_values_1d, read_projected_values_2d,
+        read_ragged_matrix_table, read_ragged_matrix_values, read_reference_matrix_table,
+        read_reference_matrix_values, read_selected_values_bytes, read_varlen_u8_matrix_table,
+        read_varlen_u8_matrix_values, DatasetMeta, DimensionScale, EnumRenderOverrides, H5FNode,
+        HasPath, ProjectionDecode, ResolvedOpenMode, TimeEncoding, TimestampValue,
     },
     ui::{render::sprint_typedescriptor, state::Focus},
 };
@@ -111,6 +113,51 @@ fn load_matrix_viewport(
         }
         return Ok(crate::ui::state::MatrixViewportData::Two(values));
     }
+    let decoding = request.meta.active_cf_decoding().filter(|_| {
+        matches!(
+            request.meta.matrixable,
+            Some(MatrixRenderType::Float64 | MatrixRenderType::Uint64 | MatrixRenderType::Int64)
+        )
+    });
+    let time = request.meta.time.as_ref();
+    if decoding.is_some() || request.meta.is_opaque_float16() {
+        return if request.meta.shape.len() == 1 {
+            let mut values = read_f64_values::<Ix1>(&request.dataset, request.selection)?;
+            if let Some(decoding) = decoding {
+                values.mapv_inplace(|value| decoding.decode(value));
+            }
+            Ok(crate::ui::state::MatrixViewportData::One(
+                values
+                    .into_iter()
+                    .map(|v| numeric_cell_text(v, time))
+                    .collect(),
+            ))
+        } else {
+            let mut values = read_f64_values::<Ix2>(&request.dataset, request.selection)?;
+            if let Some(decoding) = decoding {
+                values.mapv_inplace(|value| decoding.decode(value));
+            }
+            Ok(crate::ui::state::MatrixViewportData::Two(
+                values
+                    .into_iter()
+                    .map(|v| numeric_cell_text(v, time))
+                    .collect(),
+            ))
+        };
+    }
+    if request.meta.is_complex_value() {
+        // Complex values chart as their magnitude but are listed as `a+bj`.
+        let one_dimensional = request.meta.shape.len() == 1;
+        let values = if one_dimensional {
+            read_projected_values_1d::<String>(&request.dataset, &request.meta, request.selection)?
+                .to_vec()
+        } else {
+            read_projected_values_2d::<String>(&request.dataset, &request.meta, request.selection)?
+                .into_iter()
+                .collect()
+        };
+        return Ok(matrix_viewport_string_data(values, false, one_dimensional));
+    }
     let projected = request.meta.is_compound_leaf();
     macro_rules! read {
         ($t:ty) => {{
@@ -130,7 +177,10 @@ fn load_matrix_viewport(
                         .to_vec()
                 };
                 Ok(crate::ui::state::MatrixViewportData::One(
-                    values.into_iter().map(|v| v.to_string()).collect(),
+                    values
+                        .into_iter()
+                        .map(|v| numeric_cell_text(v, time))
+                        .collect(),
                 ))
             } else {
                 let values: Vec<$t> = if projected {
@@ -150,7 +200,10 @@ fn load_matrix_viewport(
                         .collect()
                 };
                 Ok(crate::ui::state::MatrixViewportData::Two(
-                    values.into_iter().map(|v| v.to_string()).collect(),
+                    values
+                        .into_iter()
+                        .map(|v| numeric_cell_text(v, time))
+                        .collect(),
                 ))
             }
         }};
@@ -286,6 +339,51 @@ fn load_matrix_viewport(
                 ))
             }
         }
+        Some(MatrixRenderType::Ragged) if !projected => {
+            if request.meta.shape.len() == 1 {
+                Ok(crate::ui::state::MatrixViewportData::One(
+                    read_ragged_matrix_values(&request.dataset, request.selection)?.to_vec(),
+                ))
+            } else {
+                Ok(crate::ui::state::MatrixViewportData::Two(
+                    read_ragged_matrix_table(&request.dataset, request.selection)?
+                        .into_iter()
+                        .collect(),
+                ))
+            }
+        }
+        Some(MatrixRenderType::References) if !projected => {
+            if request.meta.shape.len() == 1 {
+                Ok(crate::ui::state::MatrixViewportData::One(
+                    read_reference_matrix_values(&request.dataset, request.selection)?.to_vec(),
+                ))
+            } else {
+                Ok(crate::ui::state::MatrixViewportData::Two(
+                    read_reference_matrix_table(&request.dataset, request.selection)?
+                        .into_iter()
+                        .collect(),
+                ))
+            }
+        }
+        Some(MatrixRenderType::Bitfield) if !projected => {
+            let Some(bitfield) = &request.meta.bitfield else {
+                return Err(AppError::DrawingError(
+                    "Bitfield matrix metadata is unavailable".into(),
+                ));
+            };
+            if request.meta.shape.len() == 1 {
+                Ok(crate::ui::state::MatrixViewportData::One(
+                    read_bitfield_matrix_values(&request.dataset, bitfield, request.selection)?
+                        .to_vec(),
+                ))
+            } else {
+                Ok(crate::ui::state::MatrixViewportData::Two(
+                    read_bitfield_matrix_table(&request.dataset, bitfield, request.selection)?
+                        .into_iter()
+                        .collect(),
+                ))
+            }
+        }
         _ => Err(AppError::DrawingError("Unsupported matrix viewport".into())),
     }
 }
@@ -303,6 +401,11 @@ fn matrix_viewport_string_data(
     }
 }
 
+/// A numeric matrix cell; timestamp datasets list their values as datetimes.
+fn numeric_cell_text<T: Display + TimestampValue>(value: T, time: Option<&TimeEncoding>) -> String {
+    time.map_or_else(|| value.to_string(), |time| value.format_timestamp(time))
+}
+
 pub fn render_not_yet_implemented(f: &mut Frame, area: &Rect, desc: &str) {
     let inner_area = area.inner(ratatui::layout::Margin {
         horizontal: 2,
@@ -583,6 +686,26 @@ fn selected_matrix_bg_color(
     }
 }
 
+/// Header label for an index along a matrix axis: the dimension scale value when
+/// one is attached, otherwise the index itself.
+fn matrix_axis_label(scale: Option<&DimensionScale>, index: usize) -> String {
+    scale
+        .and_then(|scale| scale.label_at(index))
+        .unwrap_or_else(|| index.to_string())
+}
+
+fn matrix_corner_label(
+    row_scale: Option<&DimensionScale>,
+    col_scale: Option<&DimensionScale>,
+) -> Option<String> {
+    let parts = [
+        row_scale.map(|scale| format!("{}↓", scale.label)),
+        col_scale.map(|scale| format!("{}→", scale.label)),
+    ];
+    let label = parts.into_iter().flatten().collect::<Vec<_>>().join(" ");
+    (!label.is_empty()).then_some(label)
+}
+
 pub fn render_matrix<T: H5Type + Display>(
     f: &mut Frame,
     area: &Rect,
@@ -631,6 +754,41 @@ pub fn render_varlen_u8_matrix(
     render_cached_matrix(f, area, ds, attr, node, state)
 }
 
+/// One row per element, each cell listing the element's length and values.
+pub fn render_ragged_matrix(
+    f: &mut Frame,
+    area: &Rect,
+    ds: &hdf5_metno::Dataset,
+    attr: &DatasetMeta,
+    node: &mut H5FNode,
+    state: &mut AppState,
+) -> Result<(), AppError> {
+    render_cached_matrix(f, area, ds, attr, node, state)
+}
+
+/// One resolved target path per cell; Enter follows the reference under the cursor.
+pub fn render_reference_matrix(
+    f: &mut Frame,
+    area: &Rect,
+    ds: &hdf5_metno::Dataset,
+    attr: &DatasetMeta,
+    node: &mut H5FNode,
+    state: &mut AppState,
+) -> Result<(), AppError> {
+    render_cached_matrix(f, area, ds, attr, node, state)
+}
+
+pub fn render_bitfield_matrix(
+    f: &mut Frame,
+    area: &Rect,
+    ds: &hdf5_metno::Dataset,
+    attr: &DatasetMeta,
+    node: &mut H5FNode,
+    state: &mut AppState,
+) -> Result<(), AppError> {
+    render_cached_matrix(f, area, ds, attr, node, state)
+}
+
 fn render_cached_matrix(
     f: &mut Frame,
     area: &Rect,
@@ -659,6 +817,16 @@ fn render_cached_matrix(
         .unwrap_or_default();
     let cols = attr.shape.get(node.selected_col).copied().unwrap_or(1);
     let extent = visible_matrix_capacity(matrix_area, rows, cols);
+    let row_scale = state
+        .dimension_scales
+        .scale_for(&node.node.path(), ds, node.selected_row);
+    let col_scale = (attr.shape.len() > 1)
+        .then(|| {
+            state
+                .dimension_scales
+                .scale_for(&node.node.path(), ds, node.selected_col)
+        })
+        .flatten();
     let key = crate::ui::state::MatrixViewportKey {
         file_generation: state.content_generation,
         metadata_revision: state.navigation_generation,
@@ -734,6 +902,8 @@ fn render_cached_matrix(
                 move |_| Ok(one.clone()),
                 move |_| Ok(two.clone()),
                 $renderer,
+                row_scale.as_deref(),
+                col_scale.as_deref(),
             )
         }};
     }
@@ -865,6 +1035,9 @@ pub fn render_compound_root_matrix(
         .row_offset
         .min(row_count.saturating_sub(max_rows));
     let col_start = compound_root_field_window(state, field_count, max_cols).start;
+    let row_scale = state
+        .dimension_scales
+        .scale_for(&node.node.path(), ds, row_dim);
     let selection = compound_root_matrix_selection(node, attr, row_dim, row_start, max_rows);
     let key = crate::ui::state::MatrixViewportKey {
         file_generation: state.content_generation,
@@ -963,7 +1136,7 @@ pub fn render_compound_root_matrix(
             row: i,
         });
         let row_index = row_start + i;
-        let mut idx_line = Line::from(format!("{row_index}"))
+        let mut idx_line = Line::from(matrix_axis_label(row_scale.as_deref(), row_index))
             .style(Style::default().fg(configure::themed_color(|colors| colors.text.type_desc)))
             .left_aligned();
         if configure::prefers_strong_text() {
@@ -1272,6 +1445,8 @@ fn render_matrix_with_reader<T: Display>(
     read_values: impl Fn(Selection) -> Result<Array1<T>, AppError>,
     read_table: impl Fn(Selection) -> Result<Array2<T>, AppError>,
     result_render: impl RenderIntercept<T>,
+    row_scale: Option<&DimensionScale>,
+    col_scale: Option<&DimensionScale>,
 ) -> Result<(), AppError> {
     let area_inner = area.inner(ratatui::layout::Margin {
         horizontal: 2,
@@ -1393,7 +1568,7 @@ fn render_matrix_with_reader<T: Display>(
             } else {
                 val_bg_color
             };
-            let mut idx_line = Line::from(format!("{i}"))
+            let mut idx_line = Line::from(matrix_axis_label(row_scale, i))
                 .style(Style::default().fg(configure::themed_color(|colors| colors.text.type_desc)))
                 .left_aligned();
             if configure::prefers_strong_text() {
@@ -1411,6 +1586,14 @@ fn render_matrix_with_reader<T: Display>(
         col_constraint.push(Constraint::Length(15));
         (0..max_cols).for_each(|_| col_constraint.push(Constraint::Fill(1)));
         let col_header_areas = Layout::horizontal(col_constraint).split(rows_areas[0]);
+        if let Some(corner) = matrix_corner_label(row_scale, col_scale) {
+            f.render_widget(
+                Line::from(corner)
+                    .style(Style::default().fg(configure::themed_color(|colors| colors.help.muted)))
+                    .left_aligned(),
+                col_header_areas[0].offset(Offset { x: 0, y: -1 }),
+            );
+        }
 
         for col in 0..max_cols {
             let col_area = col_header_areas[col + 1];
@@ -1420,7 +1603,7 @@ fn render_matrix_with_reader<T: Display>(
                 .min(attr.shape[node.selected_col].saturating_sub(max_cols))
                 + col;
             f.render_widget(
-                Line::from(Span::styled(format!("{col_idx}"), {
+                Line::from(Span::styled(matrix_axis_label(col_scale, col_idx), {
                     let mut style = Style::default()
                         .fg(configure::themed_color(|colors| colors.text.type_desc));
                     if configure::prefers_strong_text() {
@@ -1450,7 +1633,7 @@ fn render_matrix_with_reader<T: Display>(
                 attr.shape[node.selected_row]
                     .saturating_sub(state.matrix_view_state.rows_currently_available),
             ) + i;
-            let mut idx_line = Line::from(format!("{idx}"))
+            let mut idx_line = Line::from(matrix_axis_label(row_scale, idx))
                 .style(Style::default().fg(configure::themed_color(|colors| colors.text.type_desc)))
                 .left_aligned();
             if configure::prefers_strong_text() {
@@ -1665,6 +1848,24 @@ mod tests {
         assert_eq!(selection.cols, 5);
     }
 
+    #[test]
+    fn matrix_headers_use_dimension_scale_values() {
+        let scale = DimensionScale {
+            path: "/wavelength".to_string(),
+            label: "wavelength".to_string(),
+            values: crate::h5f::DimensionScaleValues::Numeric(vec![400.0, 450.5]),
+            time: None,
+        };
+        assert_eq!(matrix_axis_label(Some(&scale), 1), "450.5");
+        assert_eq!(matrix_axis_label(Some(&scale), 7), "7");
+        assert_eq!(matrix_axis_label(None, 3), "3");
+        assert_eq!(
+            matrix_corner_label(None, Some(&scale)).as_deref(),
+            Some("wavelength→")
+        );
+        assert_eq!(matrix_corner_label(None, None), None);
+    }
+
     #[test]
     fn invisible_matrix_layout_is_skipped_for_zero_rows_or_cols() {
         assert!(!has_visible_matrix_cells(
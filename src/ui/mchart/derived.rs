@@ -71,6 +71,7 @@ impl MultiChartState {
             input_ids: evaluated.input_ids,
             len,
             kind: evaluated.kind,
+            x_time: expression_x_time(self, expression, file),
         };
         Ok((source, points))
     }
@@ -120,6 +121,7 @@ impl MultiChartState {
             input_ids: evaluated.input_ids,
             len,
             kind: evaluated.kind,
+            x_time: expression_x_time(self, expression, file),
         };
         let series = ChartSeries::from_points(points)
             .ok_or_else(|| "Expression resolved to no finite points".to_string())?;
@@ -155,18 +157,21 @@ impl MultiChartState {
                 input_ids,
                 len,
                 kind,
+                x_time,
                 ..
             }) => ChartSource::DerivedExpression {
                 expression,
                 input_ids: input_ids.clone(),
                 len: *len,
                 kind: *kind,
+                x_time: *x_time,
             },
             _ => ChartSource::DerivedExpression {
                 expression,
                 input_ids: Vec::new(),
                 len: 0,
                 kind: DerivedExpressionKind::YSeries,
+                x_time: None,
             },
         }
     }
@@ -1,4 +1,4 @@
-use super::tests::{make_dataset_ref_test_file, make_state, source};
+use super::tests::{make_dataset_ref_test_file, make_ragged_test_file, make_state, source};
 use super::*;
 use crate::ui::perf;
 use std::fs;
@@ -338,3 +338,36 @@ fn scalar_only_functions_reject_series_arguments() {
         MultiChartLoadState::Error(ref message) if message.contains("max2() requires scalar arguments")
     ));
 }
+
+#[test]
+fn ragged_dataset_loads_chart_one_element() {
+    let (file, path) = make_ragged_test_file();
+    let mut state = make_state();
+
+    state
+        .create_expression_derived_with_file("load(/events)[2]".to_string(), Some(&file))
+        .unwrap();
+    state
+        .create_expression_derived_with_file("load(/events)[0] * 2".to_string(), Some(&file))
+        .unwrap();
+
+    let element = &state.chart_items()[0];
+    assert_eq!(element.label, "load(/events)[2]");
+    assert_eq!(
+        element.series.points,
+        vec![(0.0, 3.0), (1.0, 5.0), (2.0, 7.0)]
+    );
+    assert_eq!(state.chart_items()[1].series.points, vec![(0.0, 2.0)]);
+
+    let error = state
+        .evaluate_expression_preview("load(/events)", Some(&file))
+        .unwrap_err();
+    assert!(error.contains("select one element"), "{error}");
+    let error = state
+        .evaluate_expression_preview("load(/events)[1]", Some(&file))
+        .unwrap_err();
+    assert!(error.contains("no finite points"), "{error}");
+
+    drop(file);
+    let _ = fs::remove_file(path);
+}
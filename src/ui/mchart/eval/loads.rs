@@ -71,31 +71,37 @@ fn validate_expression_dataset_load(
     load_ref: &ExpressionLoadRef,
 ) -> Result<ValidatedExpressionLoad, String> {
     let shape = dataset.shape();
+    if let Some(indexes) = ragged_element_indexes(dataset, load_ref)? {
+        return read_ragged_element_points(dataset, load_ref, &indexes)
+            .map(|points| ValidatedExpressionLoad::Series { len: points.len() });
+    }
     let selection = infer_expression_array_selection(
         &shape,
         load_ref.selectors.as_deref(),
         &load_ref.render(),
     )?;
-    let dtype = dataset.dtype().map_err(|error| {
-        format!(
-            "Failed to inspect dataset type for {}: {}",
-            load_ref.render(),
-            error
-        )
-    })?;
-    let type_desc = dtype.to_descriptor().map_err(|error| {
-        format!(
-            "Failed to inspect dataset type for {}: {}",
-            load_ref.render(),
-            error
-        )
-    })?;
-    if !is_numeric_type_descriptor(&type_desc) {
-        return Err(format!(
-            "Reference {} must be numeric; got {}",
-            load_ref.render(),
-            type_desc
-        ));
+    if Float16Type::read(dataset).is_none() {
+        let dtype = dataset.dtype().map_err(|error| {
+            format!(
+                "Failed to inspect dataset type for {}: {}",
+                load_ref.render(),
+                error
+            )
+        })?;
+        let type_desc = dtype.to_descriptor().map_err(|error| {
+            format!(
+                "Failed to inspect dataset type for {}: {}",
+                load_ref.render(),
+                error
+            )
+        })?;
+        if !is_numeric_type_descriptor(&type_desc) {
+            return Err(format!(
+                "Reference {} must be numeric; got {}",
+                load_ref.render(),
+                type_desc
+            ));
+        }
     }
     Ok(match selection {
         ExpressionArraySelection::Scalar(_) => ValidatedExpressionLoad::Scalar,
@@ -109,6 +115,10 @@ fn resolve_expression_dataset_load(
     dataset: &Dataset,
     load_ref: &ExpressionLoadRef,
 ) -> Result<ResolvedExpressionLoad, String> {
+    if let Some(indexes) = ragged_element_indexes(dataset, load_ref)? {
+        return read_ragged_element_points(dataset, load_ref, &indexes)
+            .map(ResolvedExpressionLoad::Series);
+    }
     let shape = dataset.shape();
     match infer_expression_array_selection(
         &shape,
@@ -129,6 +139,55 @@ fn resolve_expression_dataset_load(
     }
 }
 
+/// Element picked by a reference to a ragged dataset, e.g. `load(/events)[12]`,
+/// or `None` when the dataset is not ragged. Elements load as a series.
+fn ragged_element_indexes(
+    dataset: &Dataset,
+    load_ref: &ExpressionLoadRef,
+) -> Result<Option<Vec<usize>>, String> {
+    let ragged = dataset
+        .dtype()
+        .and_then(|dtype| dtype.to_descriptor())
+        .is_ok_and(|type_desc| is_ragged_numeric_type(&type_desc));
+    if !ragged {
+        return Ok(None);
+    }
+    match infer_expression_array_selection(
+        &dataset.shape(),
+        load_ref.selectors.as_deref(),
+        &load_ref.render(),
+    )? {
+        ExpressionArraySelection::Scalar(indexes) => Ok(Some(indexes)),
+        ExpressionArraySelection::Series(_) => Err(format!(
+            "Reference {} is a variable-length dataset; select one element with an index per dimension, e.g. [0]",
+            load_ref.render()
+        )),
+    }
+}
+
+fn read_ragged_element_points(
+    dataset: &Dataset,
+    load_ref: &ExpressionLoadRef,
+    indexes: &[usize],
+) -> Result<Vec<Point>, String> {
+    let values = read_ragged_element(dataset, indexes)
+        .map_err(|error| format!("Failed reading {}: {}", load_ref.render(), error))?;
+    let points = sanitize_chart_points(
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(idx, value)| (idx as f64, value))
+            .collect::<Vec<_>>(),
+    );
+    if points.is_empty() {
+        return Err(format!(
+            "Series reference {} resolved to no finite points",
+            load_ref.render()
+        ));
+    }
+    Ok(points)
+}
+
 fn validate_expression_attribute_load(
     attr: &Attribute,
     load_ref: &ExpressionLoadRef,
@@ -209,112 +268,120 @@ pub(crate) fn read_expression_dataset_points(
 ) -> Result<Vec<Point>, String> {
     let shape = dataset.shape();
     let selection = preview_selection_to_hyperslab(&shape, preview_selection)?;
-    let dtype = dataset.dtype().map_err(|error| {
-        format!(
-            "Failed to inspect dataset type for {}: {}",
-            load_ref.render(),
-            error
-        )
-    })?;
-    let type_desc = dtype.to_descriptor().map_err(|error| {
-        format!(
-            "Failed to inspect dataset type for {}: {}",
-            load_ref.render(),
-            error
-        )
-    })?;
+    // 16-bit floats have no type descriptor; they are widened to f64 on read.
+    let mut values = if Float16Type::read(dataset).is_some() {
+        read_f64_values::<Ix1>(dataset, selection).map(|values| values.to_vec())
+    } else {
+        let dtype = dataset.dtype().map_err(|error| {
+            format!(
+                "Failed to inspect dataset type for {}: {}",
+                load_ref.render(),
+                error
+            )
+        })?;
+        let type_desc = dtype.to_descriptor().map_err(|error| {
+            format!(
+                "Failed to inspect dataset type for {}: {}",
+                load_ref.render(),
+                error
+            )
+        })?;
 
-    let values = match type_desc {
-        TypeDescriptor::Integer(IntSize::U1) => dataset
-            .read_slice_1d::<i8, _>(selection.clone())
-            .map(|values| {
-                values
-                    .into_iter()
-                    .map(|value| value as f64)
-                    .collect::<Vec<_>>()
-            }),
-        TypeDescriptor::Integer(IntSize::U2) => dataset
-            .read_slice_1d::<i16, _>(selection.clone())
-            .map(|values| {
-                values
-                    .into_iter()
-                    .map(|value| value as f64)
-                    .collect::<Vec<_>>()
-            }),
-        TypeDescriptor::Integer(IntSize::U4) => dataset
-            .read_slice_1d::<i32, _>(selection.clone())
-            .map(|values| {
+        match type_desc {
+            TypeDescriptor::Integer(IntSize::U1) => dataset
+                .read_slice_1d::<i8, _>(selection.clone())
+                .map(|values| {
+                    values
+                        .into_iter()
+                        .map(|value| value as f64)
+                        .collect::<Vec<_>>()
+                }),
+            TypeDescriptor::Integer(IntSize::U2) => dataset
+                .read_slice_1d::<i16, _>(selection.clone())
+                .map(|values| {
+                    values
+                        .into_iter()
+                        .map(|value| value as f64)
+                        .collect::<Vec<_>>()
+                }),
+            TypeDescriptor::Integer(IntSize::U4) => dataset
+                .read_slice_1d::<i32, _>(selection.clone())
+                .map(|values| {
+                    values
+                        .into_iter()
+                        .map(|value| value as f64)
+                        .collect::<Vec<_>>()
+                }),
+            TypeDescriptor::Integer(IntSize::U8) => dataset
+                .read_slice_1d::<i64, _>(selection.clone())
+                .map(|values| {
+                    values
+                        .into_iter()
+                        .map(|value| value as f64)
+                        .collect::<Vec<_>>()
+                }),
+            TypeDescriptor::Unsigned(IntSize::U1) => dataset
+                .read_slice_1d::<u8, _>(selection.clone())
+                .map(|values| {
+                    values
+                        .into_iter()
+                        .map(|value| value as f64)
+                        .collect::<Vec<_>>()
+                }),
+            TypeDescriptor::Unsigned(IntSize::U2) => dataset
+                .read_slice_1d::<u16, _>(selection.clone())
+                .map(|values| {
+                    values
+                        .into_iter()
+                        .map(|value| value as f64)
+                        .collect::<Vec<_>>()
+                }),
+            TypeDescriptor::Unsigned(IntSize::U4) => dataset
+                .read_slice_1d::<u32, _>(selection.clone())
+                .map(|values| {
+                    values
+                        .into_iter()
+                        .map(|value| value as f64)
+                        .collect::<Vec<_>>()
+                }),
+            TypeDescriptor::Unsigned(IntSize::U8) => dataset
+                .read_slice_1d::<u64, _>(selection.clone())
+                .map(|values| {
+                    values
+                        .into_iter()
+                        .map(|value| value as f64)
+                        .collect::<Vec<_>>()
+                }),
+            TypeDescriptor::Float(FloatSize::U4) => dataset
+                .read_slice_1d::<f32, _>(selection.clone())
+                .map(|values| {
+                    values
+                        .into_iter()
+                        .map(|value| value as f64)
+                        .collect::<Vec<_>>()
+                }),
+            TypeDescriptor::Float(FloatSize::U8) => dataset
+                .read_slice_1d::<f64, _>(selection.clone())
+                .map(|values| values.into_iter().collect::<Vec<_>>()),
+            TypeDescriptor::Boolean => dataset.read_slice_1d::<bool, _>(selection).map(|values| {
                 values
                     .into_iter()
-                    .map(|value| value as f64)
+                    .map(|value| if value { 1.0 } else { 0.0 })
                     .collect::<Vec<_>>()
             }),
-        TypeDescriptor::Integer(IntSize::U8) => dataset
-            .read_slice_1d::<i64, _>(selection.clone())
-            .map(|values| {
-                values
-                    .into_iter()
-                    .map(|value| value as f64)
-                    .collect::<Vec<_>>()
-            }),
-        TypeDescriptor::Unsigned(IntSize::U1) => dataset
-            .read_slice_1d::<u8, _>(selection.clone())
-            .map(|values| {
-                values
-                    .into_iter()
-                    .map(|value| value as f64)
-                    .collect::<Vec<_>>()
-            }),
-        TypeDescriptor::Unsigned(IntSize::U2) => dataset
-            .read_slice_1d::<u16, _>(selection.clone())
-            .map(|values| {
-                values
-                    .into_iter()
-                    .map(|value| value as f64)
-                    .collect::<Vec<_>>()
-            }),
-        TypeDescriptor::Unsigned(IntSize::U4) => dataset
-            .read_slice_1d::<u32, _>(selection.clone())
-            .map(|values| {
-                values
-                    .into_iter()
-                    .map(|value| value as f64)
-                    .collect::<Vec<_>>()
-            }),
-        TypeDescriptor::Unsigned(IntSize::U8) => dataset
-            .read_slice_1d::<u64, _>(selection.clone())
-            .map(|values| {
-                values
-                    .into_iter()
-                    .map(|value| value as f64)
-                    .collect::<Vec<_>>()
-            }),
-        TypeDescriptor::Float(FloatSize::U4) => dataset
-            .read_slice_1d::<f32, _>(selection.clone())
-            .map(|values| {
-                values
-                    .into_iter()
-                    .map(|value| value as f64)
-                    .collect::<Vec<_>>()
-            }),
-        TypeDescriptor::Float(FloatSize::U8) => dataset
-            .read_slice_1d::<f64, _>(selection.clone())
-            .map(|values| values.into_iter().collect::<Vec<_>>()),
-        TypeDescriptor::Boolean => dataset.read_slice_1d::<bool, _>(selection).map(|values| {
-            values
-                .into_iter()
-                .map(|value| if value { 1.0 } else { 0.0 })
-                .collect::<Vec<_>>()
-        }),
-        other => {
-            return Err(format!(
-                "Series reference {} must be numeric; got {}",
-                load_ref.render(),
-                other
-            ))
+            other => {
+                return Err(format!(
+                    "Series reference {} must be numeric; got {}",
+                    load_ref.render(),
+                    other
+                ))
+            }
         }
     }
     .map_err(|error| format!("Failed reading {}: {}", load_ref.render(), error))?;
+    if let Some(decoding) = crate::data::active_cf_decoding(dataset) {
+        decoding.decode_in_place(&mut values);
+    }
 
     let points = sanitize_chart_points(
         values
@@ -585,6 +652,9 @@ fn read_expression_numeric_scalar_dataset(
     dataset: &Dataset,
     reference: &str,
 ) -> Result<f64, String> {
+    if Float16Type::read(dataset).is_some() {
+        return read_float16_dataset_value(dataset, reference, &[]);
+    }
     let dtype = dataset.dtype().map_err(|error| {
         format!("Failed to inspect scalar dataset type for {reference}: {error}")
     })?;
@@ -645,6 +715,9 @@ fn read_expression_numeric_scalar_dataset_value(
 ) -> Result<f64, String> {
     match indexes {
         None | Some([]) => read_expression_numeric_scalar_dataset(dataset, reference),
+        Some(indexes) if Float16Type::read(dataset).is_some() => {
+            read_float16_dataset_value(dataset, reference, indexes)
+        }
         Some(indexes) => {
             let dtype = dataset.dtype().map_err(|error| {
                 format!("Failed to inspect scalar dataset type for {reference}: {error}")
@@ -820,6 +893,20 @@ fn read_expression_numeric_scalar_attr_value(
     }
 }
 
+/// 16-bit floats have no type descriptor; HDF5, or the opaque decoder, widens
+/// them to f64.
+fn read_float16_dataset_value(
+    dataset: &Dataset,
+    reference: &str,
+    indexes: &[usize],
+) -> Result<f64, String> {
+    read_indexed_numeric_value(
+        read_f64_values::<IxDyn>(dataset, Selection::All),
+        indexes,
+        reference,
+    )
+}
+
 fn read_indexed_numeric_value(
     result: Result<ndarray::ArrayD<f64>, hdf5_metno::Error>,
     indexes: &[usize],
@@ -2,16 +2,20 @@ use hdf5_metno::{
     types::{FloatSize, IntSize, TypeDescriptor},
     Attribute, Dataset, File, Hyperslab, Selection, SliceOrIndex,
 };
-use ndarray::IxDyn;
+use ndarray::{Ix1, IxDyn};
 
-use crate::data::{
-    validate_preview_selection_shape, DatasetPlotingData, PreviewSelection, SliceSelection,
+use crate::{
+    data::{
+        read_f64_values, validate_preview_selection_shape, DatasetPlotingData, PreviewSelection,
+        SliceSelection,
+    },
+    h5f::{is_ragged_numeric_type, read_ragged_element, Float16Type, TimeEncoding},
 };
 
 use super::{
     expression::{
         ExprBinaryOp, ExpressionAst, ExpressionDatasetSelector, ExpressionItemRef,
-        ExpressionItemTarget, ExpressionLoadRef, ExpressionObjectTarget,
+        ExpressionItemTarget, ExpressionLoadRef, ExpressionObjectTarget, ParsedExpression,
     },
     sanitize_chart_points, ChartItemId, DerivedExpressionKind, MultiChartState, Point,
 };
@@ -75,6 +79,38 @@ impl ExpressionItemLookup for MultiChartState {
     }
 }
 
+/// Timestamp encoding of the x operand of an `(x, y)` expression, when it is
+/// a plain reference to a timestamp dataset or chart item.
+pub(super) fn expression_x_time(
+    lookup: &impl ExpressionItemLookup,
+    expression: &str,
+    file: Option<&File>,
+) -> Option<TimeEncoding> {
+    let tokens = super::tokenize_expression(expression).ok()?;
+    let ParsedExpression::XySeries(x_ast, _) = super::parse_derived_expression(&tokens).ok()?
+    else {
+        return None;
+    };
+    match x_ast {
+        ExpressionAst::LoadRef(ExpressionLoadRef {
+            target: ExpressionObjectTarget::AbsolutePath(path),
+            attr_name: None,
+            ..
+        }) => {
+            let path = normalize_absolute_object_path(&path).ok()?;
+            TimeEncoding::read(&file?.dataset(&path).ok()?)
+        }
+        ExpressionAst::ItemRef(item_ref) => {
+            let item = match &item_ref.target {
+                ExpressionItemTarget::Id(id) => lookup.item_by_id(*id),
+                ExpressionItemTarget::Name(name) => lookup.item_by_name(name),
+            }?;
+            item.source.axis_times().1
+        }
+        _ => None,
+    }
+}
+
 enum ExpressionArraySelection {
     Scalar(Vec<usize>),
     Series(PreviewSelection),
@@ -11,7 +11,10 @@ use std::{
 use crate::{
     configure,
     data::{plot_dataset_with_cap, DatasetPlotingData, PreviewSelection, SliceSelection},
-    h5f::{plot_projected_with_cap, DatasetMeta, ResolvedOpenMode},
+    h5f::{
+        is_ragged_numeric_type, plot_projected_with_cap, DatasetMeta, ResolvedOpenMode,
+        TimeEncoding,
+    },
     ui::{app::AppEvent, perf},
 };
 
@@ -29,15 +32,15 @@ mod types;
 pub use background::handle_mchart_expression_refresh;
 use eval::{
     dataset_ploting_data_from_points, eval_expression_at, eval_scalar_expression,
-    resolve_expression_item_value, resolve_expression_load_value, validate_expression_load_ref,
-    validate_expression_series_compatibility, EvaluatedExpression, ExpressionSeriesInput,
-    ExpressionSeriesResolution, ResolvedExpressionItemValue, ResolvedExpressionLoad,
-    ValidatedExpressionLoad,
+    expression_x_time, resolve_expression_item_value, resolve_expression_load_value,
+    validate_expression_load_ref, validate_expression_series_compatibility, EvaluatedExpression,
+    ExpressionSeriesInput, ExpressionSeriesResolution, ResolvedExpressionItemValue,
+    ResolvedExpressionLoad, ValidatedExpressionLoad,
 };
 use expression::{
     collect_parsed_expression_refs, parse_derived_expression, render_expression_name,
-    tokenize_expression, ExpressionAst, ExpressionObjectTarget, ExpressionRefs, ExpressionToken,
-    ParsedExpression,
+    tokenize_expression, ExpressionAst, ExpressionDatasetSelector, ExpressionLoadRef,
+    ExpressionObjectTarget, ExpressionRefs, ExpressionToken, ParsedExpression,
 };
 pub use load::{handle_mchart_load, handle_mchart_render};
 use model::sanitize_chart_points;
@@ -924,12 +927,29 @@ impl MultiChartState {
                 error
             )
         })?;
+        // Ragged elements are read whole by the expression, not sliced.
+        if dataset
+            .dtype()
+            .and_then(|dtype| dtype.to_descriptor())
+            .is_ok_and(|type_desc| is_ragged_numeric_type(&type_desc))
+        {
+            let (source, points) =
+                self.capture_expression_chart_item(&series_ref.render(), Some(file))?;
+            return Ok(CapturedMultiChartItem {
+                source,
+                source_len: points.len(),
+                initial_points: Some(points),
+                load_state: MultiChartLoadState::Ready,
+                request: None,
+            });
+        }
         let shape = dataset.shape();
         let selection = series_ref.to_series_preview_selection(&shape)?;
         let source = ChartSource::DatasetSelection(DatasetChartSource {
             dataset_path: dataset.name(),
             display_path: dataset.name(),
             selection: selection.clone(),
+            time: TimeEncoding::read(&dataset),
             shape,
             kind: DatasetChartKind::Dataset,
         });
@@ -946,6 +966,27 @@ impl MultiChartState {
         })
     }
 
+    /// Chart item for one element of a ragged dataset, kept as its
+    /// `load(/path)[i]` expression.
+    pub(crate) fn ragged_element_item(
+        &self,
+        dataset_path: &str,
+        indexes: &[usize],
+        file: Option<&File>,
+    ) -> Result<CapturedMultiChartItem, String> {
+        let load_ref = ExpressionLoadRef {
+            target: ExpressionObjectTarget::AbsolutePath(dataset_path.to_string()),
+            attr_name: None,
+            selectors: (!indexes.is_empty()).then(|| {
+                indexes
+                    .iter()
+                    .map(|index| ExpressionDatasetSelector::Index(*index))
+                    .collect()
+            }),
+        };
+        self.dataset_reference_item(&load_ref.render(), file)
+    }
+
     pub fn apply_load_failure(
         &mut self,
         item_id: ChartItemId,
@@ -1,5 +1,8 @@
 use super::expression::render_expression_name;
-use crate::data::{PreviewSelection, SliceSelection};
+use crate::{
+    data::{PreviewSelection, SliceSelection},
+    h5f::TimeEncoding,
+};
 
 pub type Point = (f64, f64);
 
@@ -113,6 +116,8 @@ pub struct DatasetChartSource {
     pub selection: PreviewSelection,
     pub shape: Vec<usize>,
     pub kind: DatasetChartKind,
+    /// Set when the dataset holds timestamps; its values chart on a datetime axis.
+    pub time: Option<TimeEncoding>,
 }
 
 impl DatasetChartSource {
@@ -238,6 +243,8 @@ pub enum ChartSource {
         input_ids: Vec<ChartItemId>,
         len: usize,
         kind: DerivedExpressionKind,
+        /// Set when the x operand of an `(x, y)` expression is a timestamp series.
+        x_time: Option<TimeEncoding>,
     },
 }
 
@@ -267,6 +274,14 @@ impl ChartSource {
         }
     }
 
+    /// Timestamp encodings of the x and y values this source charts.
+    pub(super) fn axis_times(&self) -> (Option<TimeEncoding>, Option<TimeEncoding>) {
+        match self {
+            ChartSource::DatasetSelection(source) => (None, source.time),
+            ChartSource::DerivedExpression { x_time, .. } => (*x_time, None),
+        }
+    }
+
     pub(super) fn dataset_source(&self) -> Option<&DatasetChartSource> {
         match self {
             ChartSource::DatasetSelection(source) => Some(source),
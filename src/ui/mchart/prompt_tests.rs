@@ -601,6 +601,7 @@ fn expression_derived_supports_scalar_attributes_from_dataset_and_paths() {
             selection,
             shape: vec![2],
             kind: DatasetChartKind::Dataset,
+            time: None,
         }),
         vec![(0.0, 1.0), (1.0, 2.0)],
     );
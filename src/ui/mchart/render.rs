@@ -511,18 +511,22 @@ impl MultiChartState {
             return false;
         };
 
+        // Datetime labels are wider than plain numbers, so they are spaced further apart.
+        let x_label_width = if prepared.x_time.is_some() { 24 } else { 12 };
         let x_label_count = if chart_area.width == 0 {
             0
         } else {
-            chart_area.width / 12
+            chart_area.width / x_label_width
         };
+        let x_step = (prepared.plot_x_max - prepared.plot_x_min) / (x_label_count.max(1) as f64);
         let x_labels = (0..=x_label_count)
             .map(|i| {
-                let x = prepared.plot_x_min
-                    + (prepared.plot_x_max - prepared.plot_x_min) * (i as f64)
-                        / (x_label_count.max(1) as f64);
+                let x = prepared.plot_x_min + x_step * (i as f64);
                 Span::styled(
-                    format_axis_number(x),
+                    match &prepared.x_time {
+                        Some(time) => time.format_tick(x, x_step),
+                        None => format_axis_number(x),
+                    },
                     configure::themed_color(|colors| colors.chart.label),
                 )
             })
@@ -532,13 +536,15 @@ impl MultiChartState {
             0 => 0,
             _ => chart_area.height / 4,
         };
+        let y_step = (prepared.y_max - prepared.y_min) / (y_label_count.max(1) as f64);
         let y_labels = (0..=y_label_count)
             .map(|i| {
-                let y = prepared.y_min
-                    + (prepared.y_max - prepared.y_min) * (i as f64)
-                        / (y_label_count.max(1) as f64);
+                let y = prepared.y_min + y_step * (i as f64);
                 Span::styled(
-                    format_axis_number(y),
+                    match &prepared.y_time {
+                        Some(time) => time.format_tick(y, y_step),
+                        None => format_axis_number(y),
+                    },
                     configure::themed_color(|colors| colors.chart.label),
                 )
             })
@@ -9,9 +9,11 @@ use plotters::{
 use crate::{
     configure,
     error::log_error,
+    h5f::{time_tick_step, TimeEncoding},
     ui::chart_math::{
         axis_label_area_size, axis_title_label_area_size, format_axis_number, raster_chart_layout,
-        symlog, symlog_inverse, RasterChartLayout, RasterChartLayoutHints,
+        symlog, symlog_inverse, time_axis_label_area_size, RasterChartLayout,
+        RasterChartLayoutHints, TimeTickAxis,
     },
 };
 
@@ -48,6 +50,29 @@ fn scale_value(scale: ChartAxisScale, value: f64) -> f64 {
     }
 }
 
+/// The timestamp encoding and tick spacing of a linear axis holding timestamps.
+fn axis_time(
+    scale: ChartAxisScale,
+    time: Option<TimeEncoding>,
+    min: f64,
+    max: f64,
+    target: usize,
+) -> Option<(TimeEncoding, f64)> {
+    let time = time.filter(|_| scale == ChartAxisScale::Linear)?;
+    Some((time, time_tick_step(&time, min, max, target)))
+}
+
+fn timed_axis_label(
+    scale: ChartAxisScale,
+    time: Option<&(TimeEncoding, f64)>,
+    value: f64,
+) -> String {
+    match time {
+        Some((time, step)) => time.format_tick(value, *step),
+        None => axis_label(scale, value),
+    }
+}
+
 fn axis_label(scale: ChartAxisScale, value: f64) -> String {
     format_axis_number(match scale {
         ChartAxisScale::Linear => value,
@@ -91,10 +116,45 @@ fn render_line_chart_request(
                 message: error.to_string(),
             };
         }
+        let x_tick_target = (request.width / 240).clamp(2, 6) as usize;
+        let y_tick_target = (request.height / 120).clamp(2, 8) as usize;
+        let x_time = axis_time(
+            prepared.x_axis_scale,
+            prepared.x_time,
+            prepared.plot_x_min,
+            prepared.plot_x_max,
+            x_tick_target,
+        );
+        let y_time = axis_time(
+            prepared.y_axis_scale,
+            prepared.y_time,
+            prepared.y_min,
+            prepared.y_max,
+            y_tick_target,
+        );
+        let x_axis = || {
+            TimeTickAxis::new(
+                prepared.plot_x_min,
+                prepared.plot_x_max,
+                x_time.as_ref().map(|(time, _)| time),
+                x_tick_target,
+            )
+        };
+        let y_axis = || {
+            TimeTickAxis::new(
+                prepared.y_min,
+                prepared.y_max,
+                y_time.as_ref().map(|(time, _)| time),
+                y_tick_target,
+            )
+        };
         let layout = line_chart_layout(
             request.width,
             request.height,
-            axis_label_area_size(&[prepared.y_min, prepared.y_max], 30),
+            match y_time {
+                Some(_) => time_axis_label_area_size(30),
+                None => axis_label_area_size(&[prepared.y_min, prepared.y_max], 30),
+            },
         );
         macro_rules! draw_line_chart {
             ($x:expr, $y:expr, $points:expr, $x_scale:expr, $y_scale:expr) => {{
@@ -117,8 +177,8 @@ fn render_line_chart_request(
                     .configure_mesh()
                     .x_desc("x values")
                     .y_desc("value")
-                    .x_label_formatter(&|value| axis_label($x_scale, *value))
-                    .y_label_formatter(&|value| axis_label($y_scale, *value))
+                    .x_label_formatter(&|value| timed_axis_label($x_scale, x_time.as_ref(), *value))
+                    .y_label_formatter(&|value| timed_axis_label($y_scale, y_time.as_ref(), *value))
                     .y_label_style(
                         ("sans-serif", layout.y_label_font_size)
                             .into_font()
@@ -205,21 +265,21 @@ fn render_line_chart_request(
                     && valid_log_range(prepared.y_min, prepared.y_max),
             ) {
                 (false, false) => draw_line_chart!(
-                    prepared.plot_x_min..prepared.plot_x_max,
-                    prepared.y_min..prepared.y_max,
+                    x_axis(),
+                    y_axis(),
                     |series: &super::super::PreparedLineChartSeries| series.points.clone(),
                     ChartAxisScale::Linear,
                     ChartAxisScale::Linear
                 ),
                 (true, false) => draw_line_chart!(
                     (prepared.plot_x_min..prepared.plot_x_max).log_scale(),
-                    prepared.y_min..prepared.y_max,
+                    y_axis(),
                     |series: &super::super::PreparedLineChartSeries| series.points.clone(),
                     ChartAxisScale::Linear,
                     ChartAxisScale::Linear
                 ),
                 (false, true) => draw_line_chart!(
-                    prepared.plot_x_min..prepared.plot_x_max,
+                    x_axis(),
                     (prepared.y_min..prepared.y_max).log_scale(),
                     |series: &super::super::PreparedLineChartSeries| series.points.clone(),
                     ChartAxisScale::Linear,
@@ -986,9 +1046,10 @@ pub(crate) fn render_prepared_chart_request(
 }
 
 #[cfg(test)]
+#[allow(clippy::expect_used)]
 mod tests {
-    use super::{axis_label, scale_value};
-    use crate::ui::mchart::ChartAxisScale;
+    use super::{axis_label, axis_time, scale_value, timed_axis_label};
+    use crate::{h5f::TimeEncoding, ui::mchart::ChartAxisScale};
 
     #[test]
     fn manual_log_transform_uses_natural_log_and_raw_labels() {
@@ -997,4 +1058,24 @@ mod tests {
         assert!((transformed - raw.ln()).abs() < f64::EPSILON);
         assert_eq!(axis_label(ChartAxisScale::Logarithmic, transformed), "100");
     }
+
+    #[test]
+    fn linear_timestamp_axes_label_with_dates() {
+        let time = TimeEncoding::parse("seconds since 2020-01-01").expect("time encoding");
+        let max = 4.0 * 86_400.0;
+        let linear = axis_time(ChartAxisScale::Linear, Some(time), 0.0, max, 4);
+        assert_eq!(linear, Some((time, 86_400.0)));
+        assert_eq!(
+            timed_axis_label(ChartAxisScale::Linear, linear.as_ref(), 86_400.0),
+            "2020-01-02"
+        );
+        assert_eq!(
+            axis_time(ChartAxisScale::Logarithmic, Some(time), 1.0, max, 4),
+            None
+        );
+        assert_eq!(
+            timed_axis_label(ChartAxisScale::Linear, None, 86_400.0),
+            axis_label(ChartAxisScale::Linear, 86_400.0)
+        );
+    }
 }
@@ -1,6 +1,9 @@
-use crate::ui::{
-    chart_math::{normalized_axis_bounds, normalized_log_axis_bounds},
-    chart_stats::{box_plot_summary, histogram_bin_count, histogram_bins, histogram_bounds},
+use crate::{
+    h5f::TimeEncoding,
+    ui::{
+        chart_math::{normalized_axis_bounds, normalized_log_axis_bounds},
+        chart_stats::{box_plot_summary, histogram_bin_count, histogram_bins, histogram_bounds},
+    },
 };
 
 use super::super::{
@@ -10,6 +13,12 @@ use super::super::{
     PreparedHistogramData, PreparedHistogramSeries, PreparedLineChartData, PreparedLineChartSeries,
 };
 
+/// The timestamp encoding every series agrees on, if any.
+fn shared_time(mut times: impl Iterator<Item = Option<TimeEncoding>>) -> Option<TimeEncoding> {
+    let first = times.next()??;
+    times.all(|time| time == Some(first)).then_some(first)
+}
+
 impl MultiChartState {
     fn effective_axis_scale(
         &self,
@@ -121,6 +130,8 @@ impl MultiChartState {
             return None;
         }
         let selected_item_id = self.selected_item().map(|item| item.id);
+        let x_time = shared_time(visible_items.iter().map(|item| item.source.axis_times().0));
+        let y_time = shared_time(visible_items.iter().map(|item| item.source.axis_times().1));
         let mut plot_x_min = f64::MAX;
         let mut plot_x_max = f64::MIN;
         let mut series = Vec::new();
@@ -201,6 +212,8 @@ impl MultiChartState {
             plot_x_max,
             y_min,
             y_max,
+            x_time,
+            y_time,
             series,
         })
     }
@@ -52,6 +52,7 @@ pub(super) fn source(path: &str, selection: PreviewSelection) -> ChartSource {
         selection,
         shape: vec![4, 8],
         kind: DatasetChartKind::Dataset,
+        time: None,
     })
 }
 
@@ -80,6 +81,7 @@ fn compact_selection_summary_uses_concise_array_notation() {
         },
         shape: vec![64],
         kind: DatasetChartKind::Dataset,
+        time: None,
     };
     assert_eq!(one_d.compact_selection_summary(), "chunked_dataset");
 
@@ -93,6 +95,7 @@ fn compact_selection_summary_uses_concise_array_notation() {
         },
         shape: vec![64, 32, 8],
         kind: DatasetChartKind::Dataset,
+        time: None,
     };
     assert_eq!(
         three_d.compact_selection_summary(),
@@ -136,6 +139,7 @@ fn chart_item_statistics_compute_mean_median_and_stddev() {
             input_ids: vec![],
             len: 4,
             kind: DerivedExpressionKind::YSeries,
+            x_time: None,
         },
         series: ChartSeries::from_points(vec![(1.0, 1.0), (2.0, 3.0), (3.0, 5.0), (4.0, 7.0)])
             .expect("series"),
@@ -284,6 +288,28 @@ pub(super) fn make_dataset_ref_test_file() -> (LockedTestFile, std::path::PathBu
     )
 }
 
+pub(super) fn make_ragged_test_file() -> (LockedTestFile, std::path::PathBuf) {
+    let guard = crate::test_support::hdf5_test_guard();
+    let path = temp_hdf5_path("mchart-ragged");
+    let file = File::create(&path).expect("failed creating temp hdf5 file");
+    file.new_dataset_builder()
+        .with_data(&[
+            hdf5_metno::types::VarLenArray::from_slice(&[1.0_f64]),
+            hdf5_metno::types::VarLenArray::from_slice(&[]),
+            hdf5_metno::types::VarLenArray::from_slice(&[3.0_f64, 5.0, 7.0]),
+        ])
+        .create("events")
+        .expect("failed creating ragged dataset");
+    file.flush().expect("failed flushing temp hdf5 file");
+    (
+        LockedTestFile {
+            file,
+            _guard: guard,
+        },
+        path,
+    )
+}
+
 #[test]
 fn reuses_exact_source_and_adds_distinct_selection_variants() {
     let mut state = make_state();
@@ -1,5 +1,7 @@
 use ratatui::layout::Rect;
 
+use crate::h5f::TimeEncoding;
+
 use super::{
     model::{ChartAxisScale, ChartSource, MultiChartLoadState, MultiChartViewMode, Point},
     MultiChartLoadRequest,
@@ -69,6 +71,9 @@ pub(super) struct PreparedLineChartData {
     pub(super) plot_x_max: f64,
     pub(super) y_min: f64,
     pub(super) y_max: f64,
+    /// Timestamp encodings shared by every visible series, for datetime axes.
+    pub(super) x_time: Option<TimeEncoding>,
+    pub(super) y_time: Option<TimeEncoding>,
     pub(super) series: Vec<PreparedLineChartSeries>,
 }
 
@@ -1004,6 +1004,7 @@ fn detail_window_targets_viewport_slice_for_large_dataset_series() {
         },
         shape: vec![100_000],
         kind: DatasetChartKind::Dataset,
+        time: None,
     };
     let window = MultiChartState::detail_window_for_viewport(
         &source,
@@ -1132,6 +1133,7 @@ fn clearing_zoom_discards_detail_series() {
                 selection,
                 shape: vec![10_000],
                 kind: DatasetChartKind::Dataset,
+                time: None,
             }),
             (0..8).map(|i| (i as f64, i as f64)).collect(),
         )
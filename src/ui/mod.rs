@@ -1,13 +1,17 @@
 pub mod app;
 pub mod attributes;
+pub mod canonical_paths;
 pub mod chart_math;
 pub mod chart_stats;
 pub mod chrome;
+pub mod chunk_map;
 pub mod command;
 pub mod cursor;
 pub mod custom_content;
 pub mod dims;
 pub mod edit;
+pub mod find;
+pub mod group_overview;
 pub mod heatmap;
 pub mod help;
 pub mod input;
@@ -19,7 +23,10 @@ pub mod page_scroll;
 pub mod perf;
 pub mod preview;
 pub mod render;
+pub mod search_index;
 pub mod state;
 pub mod std_comp_render;
 pub mod toast;
 pub mod tree_view;
+pub mod usage;
+pub mod walk_worker;
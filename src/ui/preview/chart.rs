@@ -1,4 +1,5 @@
-use hdf5_metno::types::TypeDescriptor;
+use hdf5_metno::{types::TypeDescriptor, Selection};
+use ndarray::IxDyn;
 use plotters::{
     chart::ChartBuilder,
     prelude::{BitMapBackend, IntoDrawingArea, IntoLogRange, Text},
@@ -19,14 +20,20 @@ use ratatui::{
 
 use crate::{
     configure,
-    data::{DatasetPlotingData, PreviewSelection, Previewable, SliceSelection},
+    data::{
+        plot_bit_plane, plot_ragged_element, read_f64_values, DatasetPlotingData, PreviewSelection,
+        Previewable, SliceSelection,
+    },
     error::AppError,
     h5f::{
-        plot_projected, read_projected_scalar, read_single_value_dataset, DatasetHandle,
-        DatasetMetaState, H5FNode, HasPath, Node,
+        plot_projected, read_bitfield_values, read_projected_scalar, read_single_value_dataset,
+        time_tick_step, BitfieldType, DatasetHandle, DatasetMetaState, DimensionScale, H5FNode,
+        HasPath, Node, TimeEncoding, TimestampValue,
     },
     ui::{
-        chart_math::{format_axis_number, normalized_log_axis_bounds, symlog, symlog_inverse},
+        chart_math::{
+            format_axis_number, normalized_log_axis_bounds, symlog, symlog_inverse, TimeTickAxis,
+        },
         chart_stats::{box_plot_summary, histogram_summary, histogram_summary_with_scale},
         matrix::{EnumRenderer, RenderIntercept},
         mchart::ChartAxisScale,
@@ -84,12 +91,39 @@ fn scale_value(scale: ChartAxisScale, value: f64) -> f64 {
         .unwrap_or(value)
 }
 
-fn axis_label(scale: ChartAxisScale, value: f64) -> String {
-    format_axis_number(match scale {
+fn axis_value(scale: ChartAxisScale, value: f64) -> f64 {
+    match scale {
         ChartAxisScale::SymLog => symlog_inverse(value),
         ChartAxisScale::Logarithmic => value.exp(),
         ChartAxisScale::Linear => value,
-    })
+    }
+}
+
+fn axis_label(scale: ChartAxisScale, value: f64) -> String {
+    format_axis_number(axis_value(scale, value))
+}
+
+/// X tick label for an index position, taken from the dimension scale when the
+/// plotted dimension has one.
+fn x_axis_label(
+    scale: ChartAxisScale,
+    value: f64,
+    dimension_scale: Option<&DimensionScale>,
+) -> String {
+    dimension_scale
+        .and_then(|dimension_scale| dimension_scale.label_at_position(axis_value(scale, value)))
+        .unwrap_or_else(|| axis_label(scale, value))
+}
+
+/// A scalar preview; timestamps show their datetime ahead of the raw value.
+fn scalar_text<T: std::fmt::Display + TimestampValue>(
+    value: T,
+    time: Option<&TimeEncoding>,
+) -> String {
+    match time {
+        Some(time) => format!("{}  ({value})", value.format_timestamp(time)),
+        None => value.to_string(),
+    }
 }
 
 fn transformed_scale_value(scale: ChartAxisScale, value: f64) -> f64 {
@@ -118,6 +152,7 @@ fn sync_direct_chart_preview(
     chart_area: Rect,
     data_preview: &DatasetPlotingData,
     x_min: f64,
+    y_time: Option<&TimeEncoding>,
 ) {
     state
         .chart_preview_state
@@ -125,7 +160,7 @@ fn sync_direct_chart_preview(
     state
         .chart_preview_state
         .set_current_data(Some(data_preview.clone()));
-    let plot_area = preview_chart_interaction_plot_area(state, chart_area, data_preview);
+    let plot_area = preview_chart_interaction_plot_area(state, chart_area, data_preview, y_time);
     state.chart_preview_state.set_chart_area(plot_area);
     state.chart_preview_state.set_plot_area(plot_area);
 }
@@ -153,6 +188,7 @@ fn preview_chart_interaction_plot_area(
     state: &AppState<'_>,
     chart_area: Rect,
     data: &DatasetPlotingData,
+    y_time: Option<&TimeEncoding>,
 ) -> Option<Rect> {
     if state.chart_preview_state.mode == PreviewChartMode::Histogram {
         let values = preview_windowed_values(
@@ -165,14 +201,14 @@ fn preview_chart_interaction_plot_area(
             .chart_preview_state
             .effective_axis_scale_for_renderer(true, state.image_protocol_enabled);
         return histogram_summary_with_scale(&values, scale).and_then(|summary| {
-            preview_chart_plot_area(chart_area, state.image_cell_size, summary.count_max)
+            preview_chart_plot_area(chart_area, state.image_cell_size, summary.count_max, None)
         });
     }
     state
         .chart_preview_state
         .mode
         .supports_roi()
-        .then(|| preview_chart_plot_area(chart_area, state.image_cell_size, data.max))
+        .then(|| preview_chart_plot_area(chart_area, state.image_cell_size, data.max, y_time))
         .flatten()
 }
 
@@ -322,6 +358,23 @@ pub fn render_precomputed_chart_preview(
     node: &mut H5FNode,
     state: &mut AppState,
     data_preview: DatasetPlotingData,
+) -> Result<(), AppError> {
+    let preview_selection = PreviewSelection {
+        x: 0,
+        index: vec![],
+        slice: SliceSelection::All,
+    };
+    render_precomputed_chart_preview_for(f, area, node, state, data_preview, preview_selection)
+}
+
+/// Precomputed chart whose cache identity follows `preview_selection`.
+fn render_precomputed_chart_preview_for(
+    f: &mut Frame,
+    area: &Rect,
+    node: &mut H5FNode,
+    state: &mut AppState,
+    data_preview: DatasetPlotingData,
+    preview_selection: PreviewSelection,
 ) -> Result<(), AppError> {
     let _chart_render_timer = perf::metrics().preview.chart_render.start();
     clear_chart_preview_layout(state);
@@ -329,23 +382,18 @@ pub fn render_precomputed_chart_preview(
         horizontal: 0,
         vertical: 1,
     });
-    let preview_selection = PreviewSelection {
-        x: 0,
-        index: vec![],
-        slice: SliceSelection::All,
-    };
     state
         .chart_preview_state
         .sync_selection_identity(&node.node.path(), &preview_selection);
     let x_min = preview_x_min(&state.page_state);
     if !state.image_protocol_enabled {
         clear_active_chart_preview(state);
-        sync_direct_chart_preview(state, chart_area, &data_preview, x_min);
+        sync_direct_chart_preview(state, chart_area, &data_preview, x_min, None);
         perf::metrics().preview.direct_widget_renders.increment();
-        render_chart_widget(f, &chart_area, state, data_preview, x_min);
+        render_chart_widget(f, &chart_area, state, data_preview, x_min, None, None);
         return Ok(());
     }
-    let plot_area = preview_chart_interaction_plot_area(state, chart_area, &data_preview);
+    let plot_area = preview_chart_interaction_plot_area(state, chart_area, &data_preview, None);
     state.chart_preview_state.set_chart_area(plot_area);
     state.chart_preview_state.set_plot_area(plot_area);
 
@@ -359,6 +407,7 @@ pub fn render_precomputed_chart_preview(
         roi: state.chart_preview_state.roi,
         histogram_selection: state.chart_preview_state.histogram_selection,
         histogram_range: state.chart_preview_state.histogram_range,
+        bit_plane: None,
         width: chart_area.width,
         height: chart_area.height,
     };
@@ -408,6 +457,9 @@ pub fn render_chart_preview(
         );
         return Ok(());
     }
+    if matches!(ds_meta.matrixable, Some(MatrixRenderType::Ragged)) && !ds_meta.is_compound_leaf() {
+        return render_ragged_chart_preview(f, area, node, state, ds);
+    }
     if ds_meta.is_compound_leaf() {
         return render_projected_chart_preview(f, area, node, state, ds, ds_meta);
     }
@@ -430,7 +482,17 @@ pub fn render_chart_preview(
         match ds_meta.matrixable {
             Some(t) => match t {
                 MatrixRenderType::Float64 => {
-                    let ds = read_single_value_dataset::<f64>(&ds);
+                    let ds = if ds_meta.is_opaque_float16() {
+                        read_f64_values::<IxDyn>(&ds, hdf5_metno::Selection::All)
+                            .map_err(AppError::from)
+                            .and_then(|values| {
+                                values.first().copied().ok_or_else(|| {
+                                    AppError::DrawingError("Dataset has no value".to_string())
+                                })
+                            })
+                    } else {
+                        read_single_value_dataset::<f64>(&ds)
+                    };
                     let ds = match ds {
                         Ok(ds) => ds,
                         Err(e) => {
@@ -438,7 +500,7 @@ pub fn render_chart_preview(
                             return Ok(());
                         }
                     };
-                    render_string(f, area, node, ds, None);
+                    render_string(f, area, node, scalar_text(ds, ds_meta.time.as_ref()), None);
                 }
                 MatrixRenderType::Uint64 => {
                     let ds = read_single_value_dataset::<u64>(&ds);
@@ -449,7 +511,7 @@ pub fn render_chart_preview(
                             return Ok(());
                         }
                     };
-                    render_string(f, area, node, ds, None);
+                    render_string(f, area, node, scalar_text(ds, ds_meta.time.as_ref()), None);
                 }
                 MatrixRenderType::Int64 => {
                     let ds = read_single_value_dataset::<i64>(&ds);
@@ -460,7 +522,7 @@ pub fn render_chart_preview(
                             return Ok(());
                         }
                     };
-                    render_string(f, area, node, ds, None);
+                    render_string(f, area, node, scalar_text(ds, ds_meta.time.as_ref()), None);
                 }
                 MatrixRenderType::Opaque => {
                     render_string_preview(f, area, node, state)?;
@@ -475,10 +537,31 @@ pub fn render_chart_preview(
                     );
                     return Ok(());
                 }
-                MatrixRenderType::Strings => {
+                MatrixRenderType::Strings | MatrixRenderType::References => {
                     render_string_preview(f, area, node, state)?;
                     return Ok(());
                 }
+                MatrixRenderType::Bitfield => {
+                    let Some(bitfield) = ds_meta.bitfield.as_ref() else {
+                        render_error(
+                            f,
+                            area,
+                            "Dataset preview bitfield metadata is unavailable".to_string(),
+                        );
+                        return Ok(());
+                    };
+                    let flags = match read_bitfield_values(&ds, bitfield, Selection::All) {
+                        Ok((values, _)) => values
+                            .first()
+                            .map(|value| bitfield.format_flags(*value))
+                            .unwrap_or_default(),
+                        Err(e) => {
+                            render_error(f, area, format!("Error reading scalar: {}", e));
+                            return Ok(());
+                        }
+                    };
+                    render_string(f, area, node, flags, None);
+                }
                 MatrixRenderType::ByteArray => {
                     render_unsupported_rendering(
                         f,
@@ -488,6 +571,15 @@ pub fn render_chart_preview(
                     );
                     return Ok(());
                 }
+                MatrixRenderType::Ragged => {
+                    render_unsupported_rendering(
+                        f,
+                        area,
+                        selected_node,
+                        "Variable-length fields of compounds are not previewable",
+                    );
+                    return Ok(());
+                }
                 MatrixRenderType::Enum => {
                     let TypeDescriptor::Enum(et) = ds.dtype()?.to_descriptor()? else {
                         render_error(
@@ -550,6 +642,10 @@ pub fn render_chart_preview(
             .cloned()
             .unwrap_or(0);
     }
+    let area = &match ds_meta.bitfield.as_ref() {
+        Some(bitfield) => render_bit_plane_header(f, area, node, bitfield),
+        None => *area,
+    };
 
     let page_info = if shape[node.selected_x] > MAX_PAGE_SIZE {
         state.page_state.paged = PageType::Chart;
@@ -623,6 +719,11 @@ pub fn render_chart_preview(
         .chart_preview_state
         .sync_selection_identity(&node.node.path(), &data_preview_selection);
     let x_min = preview_x_min(&state.page_state);
+    let x_scale = state
+        .dimension_scales
+        .scale_for(&node.node.path(), &ds, node.selected_x);
+    let y_time = ds_meta.time;
+    let y_time = y_time.as_ref();
     let current_key = ChartPreviewKey {
         ds_path: node.node.path(),
         selection: data_preview_selection.clone(),
@@ -633,27 +734,42 @@ pub fn render_chart_preview(
         roi: state.chart_preview_state.roi,
         histogram_selection: state.chart_preview_state.histogram_selection,
         histogram_range: state.chart_preview_state.histogram_range,
+        bit_plane: ds_meta.bitfield.as_ref().map(|_| node.selected_bit),
         width: chart_area.width,
         height: chart_area.height,
     };
     if !state.image_protocol_enabled {
         clear_active_chart_preview(state);
-        let data_preview = match ds.plot(&data_preview_selection) {
+        let data_preview = match ds_meta.bitfield.as_ref() {
+            Some(bitfield) => {
+                plot_bit_plane(&ds, bitfield, node.selected_bit, &data_preview_selection)
+            }
+            None => ds.plot(&data_preview_selection).map_err(AppError::from),
+        };
+        let data_preview = match data_preview {
             Ok(dp) => dp,
             Err(e) => {
                 render_error(f, &chart_area, format!("Error plotting data: {}", e));
                 return Ok(());
             }
         };
-        sync_direct_chart_preview(state, chart_area, &data_preview, x_min);
+        sync_direct_chart_preview(state, chart_area, &data_preview, x_min, y_time);
         perf::metrics().preview.direct_widget_renders.increment();
-        render_chart_widget(f, &chart_area, state, data_preview, x_min);
+        render_chart_widget(
+            f,
+            &chart_area,
+            state,
+            data_preview,
+            x_min,
+            x_scale.as_deref(),
+            y_time,
+        );
     } else {
         let plot_area = state
             .chart_preview_state
             .current_data
             .as_ref()
-            .and_then(|data| preview_chart_interaction_plot_area(state, chart_area, data));
+            .and_then(|data| preview_chart_interaction_plot_area(state, chart_area, data, y_time));
         state.chart_preview_state.set_chart_area(plot_area);
         state.chart_preview_state.set_plot_area(plot_area);
         queue_chart_preview_load(
@@ -662,9 +778,20 @@ pub fn render_chart_preview(
             state,
             &node.node,
             current_key,
-            ChartPreviewSource::Dataset {
-                ds,
-                selection: data_preview_selection,
+            match ds_meta.bitfield {
+                Some(bitfield) => ChartPreviewSource::BitPlane {
+                    ds,
+                    bitfield,
+                    bit: node.selected_bit,
+                    selection: data_preview_selection,
+                    x_scale,
+                },
+                None => ChartPreviewSource::Dataset {
+                    ds,
+                    selection: data_preview_selection,
+                    x_scale,
+                    y_time: ds_meta.time,
+                },
             },
         )?;
     }
@@ -682,6 +809,79 @@ pub fn render_chart_preview(
     Ok(())
 }
 
+/// Names the bit charted for a bitfield dataset on the first line of `area`
+/// and returns the rest.
+fn render_bit_plane_header(
+    f: &mut Frame,
+    area: &Rect,
+    node: &mut H5FNode,
+    bitfield: &BitfieldType,
+) -> Rect {
+    node.selected_bit = node.selected_bit.min(bitfield.bits() - 1);
+    let areas = Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).split(*area);
+    f.render_widget(
+        Paragraph::new(format!(
+            "Bit {} of 0..{} · {} · b/B to change bit",
+            node.selected_bit,
+            bitfield.bits() - 1,
+            bitfield.bit_label(node.selected_bit)
+        ))
+        .style(Style::default().fg(configure::themed_color(|colors| colors.text.type_desc))),
+        areas[0],
+    );
+    areas[1]
+}
+
+/// Charts the selected element of a ragged dataset against the position of
+/// each value in it. Index keys, or the matrix cursor, pick the element.
+fn render_ragged_chart_preview(
+    f: &mut Frame,
+    area: &Rect,
+    node: &mut H5FNode,
+    state: &mut AppState,
+    ds: hdf5_metno::Dataset,
+) -> Result<(), AppError> {
+    let shape = ds.shape();
+    node.sync_selection_rank(shape.len());
+    for (index, len) in node.selected_indexes.iter_mut().zip(&shape) {
+        *index = (*index).min(len.saturating_sub(1));
+    }
+    let element = node.selected_indexes.clone();
+    let data_preview = match plot_ragged_element(&ds, &element) {
+        Ok(data_preview) => data_preview,
+        Err(e) => {
+            render_error(f, area, format!("Error reading element: {e}"));
+            return Ok(());
+        }
+    };
+    let areas = Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).split(*area);
+    let position = element
+        .iter()
+        .zip(&shape)
+        .map(|(index, len)| format!("{index}/{len}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    f.render_widget(
+        Paragraph::new(format!(
+            "Element [{position}] · {} values",
+            data_preview.length
+        ))
+        .style(Style::default().fg(configure::themed_color(|colors| colors.text.type_desc))),
+        areas[0],
+    );
+    if data_preview.length == 0 {
+        clear_chart_preview_layout(state);
+        render_string(f, &areas[1], node, "Element is empty".to_string(), None);
+        return Ok(());
+    }
+    let preview_selection = PreviewSelection {
+        x: shape.len(),
+        index: element,
+        slice: SliceSelection::All,
+    };
+    render_precomputed_chart_preview_for(f, &areas[1], node, state, data_preview, preview_selection)
+}
+
 fn render_projected_chart_preview(
     f: &mut Frame,
     area: &Rect,
@@ -704,6 +904,15 @@ fn render_projected_chart_preview(
 
     if x_selectable_dims.is_empty() {
         match ds_meta.matrixable {
+            Some(MatrixRenderType::Float64) if ds_meta.is_complex_value() => {
+                render_string(
+                    f,
+                    area,
+                    node,
+                    read_projected_scalar::<String>(&ds, &ds_meta)?,
+                    None,
+                );
+            }
             Some(MatrixRenderType::Float64) => {
                 render_string(
                     f,
@@ -770,6 +979,24 @@ fn render_projected_chart_preview(
                 selected_node,
                 "Preview is only supported for vlen byte arrays when image attributes are present; use Matrix mode to inspect values",
             ),
+            Some(MatrixRenderType::Ragged) => render_unsupported_rendering(
+                f,
+                area,
+                selected_node,
+                "Variable-length fields of compounds are not previewable",
+            ),
+            Some(MatrixRenderType::References) => render_unsupported_rendering(
+                f,
+                area,
+                selected_node,
+                "Reference fields of compounds are not previewable",
+            ),
+            Some(MatrixRenderType::Bitfield) => render_unsupported_rendering(
+                f,
+                area,
+                selected_node,
+                "Bitfield fields of compounds are not previewable",
+            ),
             Some(MatrixRenderType::Compound) => render_unsupported_rendering(
                 f,
                 area,
@@ -882,6 +1109,11 @@ fn render_projected_chart_preview(
         .chart_preview_state
         .sync_selection_identity(&node.node.path(), &data_preview_selection);
     let x_min = preview_x_min(&state.page_state);
+    let x_scale = state
+        .dimension_scales
+        .scale_for(&node.node.path(), &ds, node.selected_x);
+    let y_time = ds_meta.time;
+    let y_time = y_time.as_ref();
     let current_key = ChartPreviewKey {
         ds_path: node.node.path(),
         selection: data_preview_selection.clone(),
@@ -892,6 +1124,7 @@ fn render_projected_chart_preview(
         roi: state.chart_preview_state.roi,
         histogram_selection: state.chart_preview_state.histogram_selection,
         histogram_range: state.chart_preview_state.histogram_range,
+        bit_plane: None,
         width: chart_area.width,
         height: chart_area.height,
     };
@@ -908,15 +1141,23 @@ fn render_projected_chart_preview(
                 return Ok(());
             }
         };
-        sync_direct_chart_preview(state, chart_area, &data_preview, x_min);
+        sync_direct_chart_preview(state, chart_area, &data_preview, x_min, y_time);
         perf::metrics().preview.direct_widget_renders.increment();
-        render_chart_widget(f, &chart_area, state, data_preview, x_min);
+        render_chart_widget(
+            f,
+            &chart_area,
+            state,
+            data_preview,
+            x_min,
+            x_scale.as_deref(),
+            y_time,
+        );
     } else {
         let plot_area = state
             .chart_preview_state
             .current_data
             .as_ref()
-            .and_then(|data| preview_chart_interaction_plot_area(state, chart_area, data));
+            .and_then(|data| preview_chart_interaction_plot_area(state, chart_area, data, y_time));
         state.chart_preview_state.set_chart_area(plot_area);
         state.chart_preview_state.set_plot_area(plot_area);
         queue_chart_preview_load(
@@ -929,6 +1170,7 @@ fn render_projected_chart_preview(
                 ds,
                 meta: Box::new(ds_meta),
                 selection: data_preview_selection,
+                x_scale,
             },
         )?;
     }
@@ -951,6 +1193,8 @@ fn render_chart_widget(
     state: &AppState,
     data_preview: DatasetPlotingData,
     x_min: f64,
+    x_scale: Option<&DimensionScale>,
+    y_time: Option<&TimeEncoding>,
 ) {
     let _widget_render_timer = perf::metrics().preview.chart_widget_render.start();
     let bounds = preview_chart_data_bounds(&data_preview, x_min);
@@ -1048,7 +1292,7 @@ fn render_chart_widget(
             let x = viewport.x_min
                 + (viewport.x_max - viewport.x_min) * (i as f64) / (x_label_count as f64);
             Span::styled(
-                format_axis_number(x),
+                x_axis_label(ChartAxisScale::Linear, x, x_scale),
                 configure::themed_color(|colors| colors.chart.label),
             )
         })
@@ -1059,12 +1303,12 @@ fn render_chart_widget(
         _ => chart_area.height / 4,
     };
 
+    let y_step = (viewport.y_max - viewport.y_min) / (y_label_count as f64);
     let y_labels = (0..=y_label_count)
         .map(|i| {
-            let y = viewport.y_min
-                + (viewport.y_max - viewport.y_min) * (i as f64) / (y_label_count as f64);
+            let y = viewport.y_min + y_step * i as f64;
             Span::styled(
-                format_axis_number(y),
+                y_time.map_or_else(|| format_axis_number(y), |time| time.format_tick(y, y_step)),
                 configure::themed_color(|colors| colors.chart.label),
             )
         })
@@ -1166,7 +1410,8 @@ fn render_chart_widget(
             | Mode::AttributeCreateDialog
             | Mode::AttributeDeleteDialog
             | Mode::FixedStringOverflowDialog
-            | Mode::FixedStringResizeDialog,
+            | Mode::FixedStringResizeDialog
+            | Mode::StructureEditDialog,
         ) => configure::themed_color(|colors| colors.surface.focus_bg),
         _ => configure::themed_color(|colors| colors.surface.bg),
     };
@@ -1174,7 +1419,7 @@ fn render_chart_widget(
         .style(Style::default().bg(bg))
         .x_axis(
             Axis::default()
-                .title("X axis")
+                .title(x_scale.map_or_else(|| "X axis".to_string(), |scale| scale.label.clone()))
                 .style(Style::default().fg(configure::themed_color(|colors| colors.chart.axis)))
                 .labels(x_labels)
                 .bounds((viewport.x_min, viewport.x_max).into()),
@@ -1189,6 +1434,17 @@ fn render_chart_widget(
     f.render_widget(chart, *chart_area);
 }
 
+/// How [`render_image_chart`] scales and labels its axes.
+#[derive(Debug, Clone, Copy)]
+pub struct ImageChartAxes<'a> {
+    pub x_axis_scale: ChartAxisScale,
+    pub y_axis_scale: ChartAxisScale,
+    /// Labels the x axis with dimension scale values.
+    pub x_dimension_scale: Option<&'a DimensionScale>,
+    /// Labels the y axis with datetimes.
+    pub y_time: Option<&'a TimeEncoding>,
+}
+
 pub fn render_image_chart(
     buffer: &mut [u8],
     width: u32,
@@ -1196,13 +1452,18 @@ pub fn render_image_chart(
     x_min: f64,
     data_preview: DatasetPlotingData,
     mode: PreviewChartMode,
-    x_axis_scale: ChartAxisScale,
-    y_axis_scale: ChartAxisScale,
+    axes: ImageChartAxes<'_>,
     viewport: Option<PreviewChartViewport>,
     roi: Option<PreviewChartRoi>,
     histogram_selection: Option<crate::ui::state::PreviewHistogramSelection>,
     histogram_range: Option<crate::ui::state::PreviewHistogramRange>,
 ) -> Result<(), AppError> {
+    let ImageChartAxes {
+        x_axis_scale,
+        y_axis_scale,
+        x_dimension_scale,
+        y_time,
+    } = axes;
     let _image_render_timer = perf::metrics().preview.chart_image_render.start();
     let (bg_r, bg_g, bg_b) =
         configure::rgb_channels(configure::themed_color(|colors| colors.chart.plot_bg));
@@ -1248,7 +1509,7 @@ pub fn render_image_chart(
         let root = BitMapBackend::with_buffer(buffer, (width, height)).into_drawing_area();
         root.fill(&plot_bg)
             .map_err(|e| AppError::DrawingError(format!("Error filling background: {}", e)))?;
-        let layout = preview_chart_layout(width, height, summary.count_max);
+        let layout = preview_chart_layout(width, height, summary.count_max, None);
         macro_rules! draw_histogram {
             ($x:expr) => {
                 let mut chart = ChartBuilder::on(&root)
@@ -1383,7 +1644,7 @@ pub fn render_image_chart(
         let root = BitMapBackend::with_buffer(buffer, (width, height)).into_drawing_area();
         root.fill(&plot_bg)
             .map_err(|e| AppError::DrawingError(format!("Error filling background: {}", e)))?;
-        let layout = preview_chart_layout(width, height, summary.value_max);
+        let layout = preview_chart_layout(width, height, summary.value_max, y_time);
         macro_rules! draw_box_plot {
             ($y:expr) => {
                 let mut chart = ChartBuilder::on(&root)
@@ -1398,7 +1659,12 @@ pub fn render_image_chart(
                     .y_desc("value")
                     .x_labels(1)
                     .x_label_formatter(&|_| "series".to_string())
-                    .y_label_formatter(&|value| axis_label(y_axis_scale, *value))
+                    .y_label_formatter(&|value| match y_time {
+                        Some(time) if y_axis_scale == ChartAxisScale::Linear => {
+                            time.format_tick(*value, (summary.value_max - summary.value_min) / 5.0)
+                        }
+                        _ => axis_label(y_axis_scale, *value),
+                    })
                     .x_label_style(
                         ("sans-serif", layout.x_label_font_size)
                             .into_font()
@@ -1502,7 +1768,12 @@ pub fn render_image_chart(
     root.margin(10, 10, 10, 10);
     root.fill(&plot_bg)
         .map_err(|e| AppError::DrawingError(format!("Error filling background: {}", e)))?;
-    let layout = preview_chart_layout(width, height, data_preview.max);
+    let layout = preview_chart_layout(width, height, data_preview.max, y_time);
+    // Timestamp y axes tick on calendar boundaries; ticks are placed for the
+    // linear ranges, where plotters accepts explicit key points.
+    let y_tick_target = (height / 120).clamp(2, 8) as usize;
+    let y_time_step =
+        y_time.map(|time| time_tick_step(time, viewport.y_min, viewport.y_max, y_tick_target));
     let x_log_bounds = (x_axis_scale == ChartAxisScale::Logarithmic)
         .then(|| preview_effective_x_domain(&data_preview, viewport, x_min, true))
         .flatten();
@@ -1516,10 +1787,17 @@ pub fn render_image_chart(
                 .build_cartesian_2d($x, $y)
                 .map_err(|e| AppError::DrawingError(format!("Error building chart: {}", e)))?;
 
-            chart
-                .configure_mesh()
-                .x_label_formatter(&|value| axis_label($x_scale, *value))
-                .y_label_formatter(&|value| axis_label($y_scale, *value))
+            let mut mesh = chart.configure_mesh();
+            if let Some(dimension_scale) = x_dimension_scale {
+                mesh.x_desc(dimension_scale.label.clone());
+            }
+            mesh.x_label_formatter(&|value| x_axis_label($x_scale, *value, x_dimension_scale))
+                .y_label_formatter(&|value| match (y_time, y_time_step) {
+                    (Some(time), Some(step)) if $y_scale == ChartAxisScale::Linear => {
+                        time.format_tick(*value, step)
+                    }
+                    _ => axis_label($y_scale, *value),
+                })
                 .x_label_style(
                     ("sans-serif", layout.x_label_font_size)
                         .into_font()
@@ -1686,14 +1964,14 @@ pub fn render_image_chart(
         ) {
             (None, false) => draw_xy_chart!(
                 viewport.x_min..viewport.x_max,
-                viewport.y_min..viewport.y_max,
+                TimeTickAxis::new(viewport.y_min, viewport.y_max, y_time, y_tick_target),
                 false,
                 ChartAxisScale::Linear,
                 ChartAxisScale::Linear
             ),
             (Some((x_min, x_max)), false) => draw_xy_chart!(
                 (x_min..x_max).log_scale(),
-                viewport.y_min..viewport.y_max,
+                TimeTickAxis::new(viewport.y_min, viewport.y_max, y_time, y_tick_target),
                 true,
                 ChartAxisScale::Linear,
                 ChartAxisScale::Linear
@@ -1725,7 +2003,7 @@ pub fn render_image_chart(
 mod tests {
     use super::{
         preview_chart_plot_area, preview_effective_x_domain, preview_visible_points,
-        preview_windowed_values, preview_x_axis_max, render_image_chart,
+        preview_windowed_values, preview_x_axis_max, render_image_chart, ImageChartAxes,
     };
     use crate::data::DatasetPlotingData;
     use crate::ui::{
@@ -1800,8 +2078,12 @@ mod tests {
             0.0,
             preview,
             PreviewChartMode::Line,
-            ChartAxisScale::Logarithmic,
-            ChartAxisScale::Linear,
+            ImageChartAxes {
+                x_axis_scale: ChartAxisScale::Logarithmic,
+                y_axis_scale: ChartAxisScale::Linear,
+                x_dimension_scale: None,
+                y_time: None,
+            },
             Some(viewport),
             None,
             None,
@@ -1810,6 +2092,37 @@ mod tests {
         .is_ok());
     }
 
+    #[test]
+    fn image_chart_renders_timestamp_y_axes() {
+        let preview = DatasetPlotingData {
+            data: vec![(0.0, 1_700_000_000.0), (1.0, 1_700_003_600.0)],
+            length: 2,
+            max: 1_700_003_600.0,
+            min: 1_700_000_000.0,
+        };
+        let time = crate::h5f::TimeEncoding::parse("s").expect("seconds encoding");
+        let mut buffer = vec![0; 320 * 240 * 3];
+        assert!(render_image_chart(
+            &mut buffer,
+            320,
+            240,
+            0.0,
+            preview,
+            PreviewChartMode::Line,
+            ImageChartAxes {
+                x_axis_scale: ChartAxisScale::Linear,
+                y_axis_scale: ChartAxisScale::Linear,
+                x_dimension_scale: None,
+                y_time: Some(&time),
+            },
+            None,
+            None,
+            None,
+            None,
+        )
+        .is_ok());
+    }
+
     #[test]
     fn preview_x_axis_max_keeps_single_point_visible() {
         let preview = DatasetPlotingData {
@@ -1857,8 +2170,8 @@ mod tests {
 
     #[test]
     fn preview_chart_plot_area_accounts_for_axis_offsets() {
-        let plot_area =
-            preview_chart_plot_area(Rect::new(10, 4, 40, 20), (8, 16), 1234.0).expect("plot area");
+        let plot_area = preview_chart_plot_area(Rect::new(10, 4, 40, 20), (8, 16), 1234.0, None)
+            .expect("plot area");
         assert!(plot_area.x > 10);
         assert!(plot_area.y >= 4);
         assert!(plot_area.width < 40);
@@ -1867,8 +2180,8 @@ mod tests {
 
     #[test]
     fn preview_chart_plot_area_keeps_multiple_rows_in_short_panels() {
-        let plot_area =
-            preview_chart_plot_area(Rect::new(0, 0, 40, 4), (8, 16), 1234.0).expect("plot area");
+        let plot_area = preview_chart_plot_area(Rect::new(0, 0, 40, 4), (8, 16), 1234.0, None)
+            .expect("plot area");
         assert!(plot_area.height >= 2);
     }
 }
@@ -9,11 +9,12 @@ use ratatui::{
 use crate::{
     configure,
     data::{DatasetPlotingData, SliceSelection},
-    h5f::H5FNode,
+    h5f::{H5FNode, TimeEncoding},
     ui::{
         chart_math::{
             axis_label_area_size, axis_title_label_area_size, format_axis_number,
-            normalized_axis_bounds, raster_chart_layout, RasterChartLayout, RasterChartLayoutHints,
+            normalized_axis_bounds, raster_chart_layout, time_axis_label_area_size,
+            RasterChartLayout, RasterChartLayoutHints,
         },
         chart_stats::histogram_summary_with_scale,
         mchart::chart_plot_area_in_rect,
@@ -36,8 +37,12 @@ pub(super) fn preview_chart_layout(
     width_px: u32,
     height_px: u32,
     max_value: f64,
+    y_time: Option<&TimeEncoding>,
 ) -> RasterChartLayout {
-    let y_label_area_size = axis_label_area_size(&[max_value], 30);
+    let y_label_area_size = match y_time {
+        Some(_) => time_axis_label_area_size(30),
+        None => axis_label_area_size(&[max_value], 30),
+    };
     raster_chart_layout(
         width_px,
         height_px,
@@ -712,10 +717,11 @@ pub(super) fn preview_chart_plot_area(
     chart_area: Rect,
     image_cell_size: (u16, u16),
     max_value: f64,
+    y_time: Option<&TimeEncoding>,
 ) -> Option<Rect> {
     let width_px = chart_area.width as u32 * image_cell_size.0.max(1) as u32;
     let height_px = chart_area.height as u32 * image_cell_size.1.max(1) as u32;
-    let layout = preview_chart_layout(width_px, height_px, max_value);
+    let layout = preview_chart_layout(width_px, height_px, max_value, y_time);
     chart_plot_area_in_rect(
         chart_area,
         width_px,
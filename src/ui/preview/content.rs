@@ -4,9 +4,13 @@ use std::{
 };
 
 use crate::{
-    h5f::{read_opaque_dataset_preview, read_string_dataset_preview},
+    h5f::{
+        read_matlab_char_text, read_opaque_dataset_preview, read_reference_dataset_preview,
+        read_string_dataset_preview,
+    },
     ui::{
         app::{AppEvent, ContentPreviewLoadedResult},
+        render::MatrixRenderType,
         state::{ContentPreviewKey, ContentPreviewWork},
     },
 };
@@ -34,6 +38,11 @@ pub(crate) fn handle_content_preview_load(
                 }
             }
             let key = request.key.clone();
+            let matlab_char = request
+                .meta
+                .matlab
+                .as_ref()
+                .filter(|matlab| matlab.is_char());
             let result = if key.opaque {
                 read_opaque_dataset_preview(
                     &request.dataset,
@@ -41,6 +50,10 @@ pub(crate) fn handle_content_preview_load(
                     key.value_start,
                     key.value_count,
                 )
+            } else if let Some(matlab) = matlab_char {
+                read_matlab_char_text(&request.dataset, matlab)
+            } else if matches!(request.meta.matrixable, Some(MatrixRenderType::References)) {
+                read_reference_dataset_preview(&request.dataset, key.value_start, key.value_count)
             } else {
                 read_string_dataset_preview(
                     &request.dataset,
@@ -21,13 +21,15 @@ use ratatui_image::{
 };
 
 use crate::{
-    data::Previewable,
+    data::{plot_bit_plane, Previewable},
     error::AppError,
     h5f::{plot_projected, DatasetHandle, H5FNode, ImageType, InterlaceMode, Node},
     ui::{
         app::AppEvent,
         page_scroll::render_position_scroll,
-        preview::chart::{preview_chart_data_bounds, render_image_chart, MAX_PAGE_SIZE},
+        preview::chart::{
+            preview_chart_data_bounds, render_image_chart, ImageChartAxes, MAX_PAGE_SIZE,
+        },
         state::{
             AppState, ChartPreviewLoadRequest, ChartPreviewSource, ClipboardImageData,
             DatasetImageLoadRequest, ImageLoadKey, ImageWindowAxis, ImageWindowState, PageType,
@@ -98,8 +98,17 @@ pub(crate) fn handle_chartpreview_load(
                 0.0
             };
 
+            let mut x_scale = None;
+            let mut y_time = None;
             let data_preview = match req.source {
-                ChartPreviewSource::Dataset { ds, selection } => {
+                ChartPreviewSource::Dataset {
+                    ds,
+                    selection,
+                    x_scale: scale,
+                    y_time: time,
+                } => {
+                    x_scale = scale;
+                    y_time = time;
                     let _plot_timer = perf::metrics().preview.chart_worker_plot.start();
                     match ds.plot(&selection) {
                         Ok(data_preview) => data_preview,
@@ -117,7 +126,10 @@ pub(crate) fn handle_chartpreview_load(
                     ds,
                     meta,
                     selection,
+                    x_scale: scale,
                 } => {
+                    x_scale = scale;
+                    y_time = meta.time;
                     let _plot_timer = perf::metrics().preview.chart_worker_plot.start();
                     match plot_projected(&ds, meta.as_ref(), &selection) {
                         Ok(data_preview) => data_preview,
@@ -132,6 +144,27 @@ pub(crate) fn handle_chartpreview_load(
                     }
                 }
                 ChartPreviewSource::Precomputed { data_preview } => data_preview,
+                ChartPreviewSource::BitPlane {
+                    ds,
+                    bitfield,
+                    bit,
+                    selection,
+                    x_scale: scale,
+                } => {
+                    x_scale = scale;
+                    let _plot_timer = perf::metrics().preview.chart_worker_plot.start();
+                    match plot_bit_plane(&ds, &bitfield, bit, &selection) {
+                        Ok(data_preview) => data_preview,
+                        Err(e) => {
+                            send_chart_failure(
+                                &tx_events,
+                                req.key.clone(),
+                                format!("Failed to plot bit plane for chart preview: {}", e),
+                            );
+                            continue;
+                        }
+                    }
+                }
             };
 
             if data_preview.min.is_nan()
@@ -162,8 +195,12 @@ pub(crate) fn handle_chartpreview_load(
                 x_min,
                 data_preview.clone(),
                 req.key.mode,
-                req.key.x_axis_scale,
-                req.key.y_axis_scale,
+                ImageChartAxes {
+                    x_axis_scale: req.key.x_axis_scale,
+                    y_axis_scale: req.key.y_axis_scale,
+                    x_dimension_scale: x_scale.as_deref(),
+                    y_time: y_time.as_ref(),
+                },
                 req.key.viewport,
                 req.key.roi,
                 req.key.histogram_selection,
@@ -32,8 +32,11 @@ use super::{
 use crate::{
     configure,
     error::AppError,
-    h5f::{DatasetHandle, DatasetMetaState, H5FNode, HasPath, Node, ResolvedOpenMode},
+    h5f::{
+        DatasetHandle, DatasetMetaState, FileInternals, H5FNode, HasPath, Node, ResolvedOpenMode,
+    },
     ui::{
+        group_overview::render_group_overview,
         perf,
         render::{sprint_type_schema, MatrixRenderType},
     },
@@ -405,6 +408,15 @@ fn render_file_preview(
     #[cfg(not(unix))]
     let rows = rows;
 
+    let mut rows = rows;
+    rows.extend(
+        FileInternals::read(file)
+            .map(|internals| internals.rows())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(label, value)| (label.to_string(), plain_file_value(value))),
+    );
+
     let outer = Block::default()
         .title(configure::configured_symbol(|symbols| {
             symbols.title.file_metadata
@@ -474,7 +486,7 @@ fn render_file_preview(
     .column_spacing(3)
     .block(
         Block::default()
-            .title(" paths, timestamps, ownership, and access ")
+            .title(" paths, timestamps, ownership, access, and hdf5 internals ")
             .title_style(
                 Style::default().fg(configure::themed_color(|colors| colors.file.section_title)),
             ),
@@ -490,6 +502,26 @@ fn compound_schema_preview_text(attr: &crate::h5f::DatasetMeta) -> String {
     )
 }
 
+fn named_type_preview_text(path: &str, meta: &crate::h5f::DatatypeMeta) -> String {
+    match &meta.type_descriptor {
+        Some(type_descriptor) => format!(
+            "Named datatype: {path}\n\n{}",
+            sprint_type_schema(type_descriptor)
+        ),
+        None => format!(
+            "Named datatype: {path}\n\n{}, which h5v cannot describe",
+            meta.data_type
+        ),
+    }
+}
+
+fn matlab_container_preview_text(matlab: &crate::h5f::MatlabVariable) -> String {
+    format!(
+        "MATLAB {}\n\nExpand it in the tree to browse its elements.",
+        matlab.describe()
+    )
+}
+
 pub fn render_preview(
     f: &mut Frame,
     area: &Rect,
@@ -572,11 +604,25 @@ pub fn render_preview(
                     render_group_preview_loading(f, &area_inner);
                 }
             }
-            None => render_empty_group_preview(f, &area_inner),
+            None if selected_node.read && selected_node.children.is_empty() => {
+                render_empty_group_preview(f, &area_inner)
+            }
+            None => render_group_overview(f, &area_inner, selected_node, state),
         }
         return;
     }
 
+    if let Node::Datatype(datatype, meta) = node {
+        render_string(
+            f,
+            &area_inner,
+            selected_node,
+            named_type_preview_text(&datatype.name(), &meta),
+            None,
+        );
+        return;
+    }
+
     if let Node::Dataset(DatasetHandle::Loaded(dataset), DatasetMetaState::Loaded(attr)) = node {
         if state.resolved_open_mode == ResolvedOpenMode::ReadSwmr {
             if let Err(error) = dataset.refresh() {
@@ -598,6 +644,16 @@ pub fn render_preview(
             );
             return;
         }
+        if let Some(matlab) = attr.matlab.as_ref().filter(|matlab| matlab.is_container()) {
+            render_string(
+                f,
+                &area_inner,
+                selected_node,
+                matlab_container_preview_text(matlab),
+                None,
+            );
+            return;
+        }
         if attr.is_opaque() {
             render_direct_content_preview(f, &area_inner, selected_node, state, &dataset, &attr);
             return;
@@ -612,7 +668,10 @@ pub fn render_preview(
                 }
             }
             None => {
-                if matches!(attr.matrixable, Some(MatrixRenderType::Strings)) {
+                if matches!(
+                    attr.matrixable,
+                    Some(MatrixRenderType::Strings | MatrixRenderType::References)
+                ) {
                     render_direct_content_preview(
                         f,
                         &area_inner,
@@ -70,29 +70,29 @@ fn render_fixed_unicode_scalar(attr: &Attribute, size: usize) -> Result<Span<'st
 
 fn render_fixed_ascii_array(attr: &Attribute, size: usize) -> Result<Vec<Span<'static>>, Error> {
     match size {
-        0..32 => Ok(render_values(attr.read_1d::<FixedAscii<32>>()?)),
-        32..64 => Ok(render_values(attr.read_1d::<FixedAscii<64>>()?)),
-        64..128 => Ok(render_values(attr.read_1d::<FixedAscii<128>>()?)),
-        128..256 => Ok(render_values(attr.read_1d::<FixedAscii<256>>()?)),
-        256..512 => Ok(render_values(attr.read_1d::<FixedAscii<512>>()?)),
-        512..1024 => Ok(render_values(attr.read_1d::<FixedAscii<1024>>()?)),
-        1024..2048 => Ok(render_values(attr.read_1d::<FixedAscii<2048>>()?)),
-        2048..4096 => Ok(render_values(attr.read_1d::<FixedAscii<4096>>()?)),
-        _ => Ok(render_values(attr.read_1d::<FixedAscii<8192>>()?)),
+        0..32 => Ok(render_values(attr.read_raw::<FixedAscii<32>>()?)),
+        32..64 => Ok(render_values(attr.read_raw::<FixedAscii<64>>()?)),
+        64..128 => Ok(render_values(attr.read_raw::<FixedAscii<128>>()?)),
+        128..256 => Ok(render_values(attr.read_raw::<FixedAscii<256>>()?)),
+        256..512 => Ok(render_values(attr.read_raw::<FixedAscii<512>>()?)),
+        512..1024 => Ok(render_values(attr.read_raw::<FixedAscii<1024>>()?)),
+        1024..2048 => Ok(render_values(attr.read_raw::<FixedAscii<2048>>()?)),
+        2048..4096 => Ok(render_values(attr.read_raw::<FixedAscii<4096>>()?)),
+        _ => Ok(render_values(attr.read_raw::<FixedAscii<8192>>()?)),
     }
 }
 
 fn render_fixed_unicode_array(attr: &Attribute, size: usize) -> Result<Vec<Span<'static>>, Error> {
     match size {
-        0..32 => Ok(render_values(attr.read_1d::<FixedUnicode<32>>()?)),
-        32..64 => Ok(render_values(attr.read_1d::<FixedUnicode<64>>()?)),
-        64..128 => Ok(render_values(attr.read_1d::<FixedUnicode<128>>()?)),
-        128..256 => Ok(render_values(attr.read_1d::<FixedUnicode<256>>()?)),
-        256..512 => Ok(render_values(attr.read_1d::<FixedUnicode<512>>()?)),
-        512..1024 => Ok(render_values(attr.read_1d::<FixedUnicode<1024>>()?)),
-        1024..2048 => Ok(render_values(attr.read_1d::<FixedUnicode<2048>>()?)),
-        2048..4096 => Ok(render_values(attr.read_1d::<FixedUnicode<4096>>()?)),
-        _ => Ok(render_values(attr.read_1d::<FixedUnicode<8192>>()?)),
+        0..32 => Ok(render_values(attr.read_raw::<FixedUnicode<32>>()?)),
+        32..64 => Ok(render_values(attr.read_raw::<FixedUnicode<64>>()?)),
+        64..128 => Ok(render_values(attr.read_raw::<FixedUnicode<128>>()?)),
+        128..256 => Ok(render_values(attr.read_raw::<FixedUnicode<256>>()?)),
+        256..512 => Ok(render_values(attr.read_raw::<FixedUnicode<512>>()?)),
+        512..1024 => Ok(render_values(attr.read_raw::<FixedUnicode<1024>>()?)),
+        1024..2048 => Ok(render_values(attr.read_raw::<FixedUnicode<2048>>()?)),
+        2048..4096 => Ok(render_values(attr.read_raw::<FixedUnicode<4096>>()?)),
+        _ => Ok(render_values(attr.read_raw::<FixedUnicode<8192>>()?)),
     }
 }
 
@@ -304,27 +304,27 @@ fn sprint_attribute_array(
 ) -> Result<Vec<Span<'static>>, Error> {
     match type_desc {
         TypeDescriptor::Integer(int_size) => match int_size {
-            types::IntSize::U1 => Ok(render_values(attr.read_1d::<i8>()?)),
-            types::IntSize::U2 => Ok(render_values(attr.read_1d::<i16>()?)),
-            types::IntSize::U4 => Ok(render_values(attr.read_1d::<i32>()?)),
-            types::IntSize::U8 => Ok(render_values(attr.read_1d::<i64>()?)),
+            types::IntSize::U1 => Ok(render_values(attr.read_raw::<i8>()?)),
+            types::IntSize::U2 => Ok(render_values(attr.read_raw::<i16>()?)),
+            types::IntSize::U4 => Ok(render_values(attr.read_raw::<i32>()?)),
+            types::IntSize::U8 => Ok(render_values(attr.read_raw::<i64>()?)),
         },
         TypeDescriptor::Unsigned(int_size) => match int_size {
-            types::IntSize::U1 => Ok(render_values(attr.read_1d::<u8>()?)),
-            types::IntSize::U2 => Ok(render_values(attr.read_1d::<u16>()?)),
-            types::IntSize::U4 => Ok(render_values(attr.read_1d::<u32>()?)),
-            types::IntSize::U8 => Ok(render_values(attr.read_1d::<u64>()?)),
+            types::IntSize::U1 => Ok(render_values(attr.read_raw::<u8>()?)),
+            types::IntSize::U2 => Ok(render_values(attr.read_raw::<u16>()?)),
+            types::IntSize::U4 => Ok(render_values(attr.read_raw::<u32>()?)),
+            types::IntSize::U8 => Ok(render_values(attr.read_raw::<u64>()?)),
         },
         TypeDescriptor::Float(float_size) => match float_size {
-            types::FloatSize::U4 => Ok(render_values(attr.read_1d::<f32>()?)),
-            types::FloatSize::U8 => Ok(render_values(attr.read_1d::<f64>()?)),
+            types::FloatSize::U4 => Ok(render_values(attr.read_raw::<f32>()?)),
+            types::FloatSize::U8 => Ok(render_values(attr.read_raw::<f64>()?)),
         },
         TypeDescriptor::FixedAscii(size) => render_fixed_ascii_array(attr, size),
-        TypeDescriptor::Boolean => Ok(render_values(attr.read_1d::<bool>()?)),
+        TypeDescriptor::Boolean => Ok(render_values(attr.read_raw::<bool>()?)),
         TypeDescriptor::Enum(enum_type) => {
             let enum_renderer = EnumRenderer::new(enum_type);
             Ok(comma_separated(
-                attr.read_1d::<u64>()?
+                attr.read_raw::<u64>()?
                     .into_iter()
                     .map(|value| enum_renderer.render_as_span(&value)),
             ))
@@ -339,8 +339,8 @@ fn sprint_attribute_array(
         TypeDescriptor::VarLenArray(type_desc) => {
             render_varlen_attr_values(attr, type_desc.as_ref(), false)
         }
-        TypeDescriptor::VarLenAscii => Ok(render_values(attr.read_1d::<VarLenAscii>()?)),
-        TypeDescriptor::VarLenUnicode => Ok(render_values(attr.read_1d::<VarLenUnicode>()?)),
+        TypeDescriptor::VarLenAscii => Ok(render_values(attr.read_raw::<VarLenAscii>()?)),
+        TypeDescriptor::VarLenUnicode => Ok(render_values(attr.read_raw::<VarLenUnicode>()?)),
         TypeDescriptor::Reference(Reference::Object) => {
             render_reference_array::<ObjectReference1>(attr)
         }
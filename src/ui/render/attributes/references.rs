@@ -69,7 +69,7 @@ pub(super) fn render_reference_array<R>(attr: &Attribute) -> Result<Vec<Span<'st
 where
     R: ObjectReference + H5Type,
 {
-    let references = attr.read_1d::<R>()?;
+    let references = attr.read_raw::<R>()?;
     let file = attr.file()?;
     let rendered = references
         .iter()
@@ -3,7 +3,7 @@ use hdf5_metno::{
     Attribute, Dataset,
 };
 
-use crate::h5f::{Encoding, ImageType, InterlaceMode};
+use crate::h5f::{is_ragged_numeric_type, Encoding, ImageType, InterlaceMode};
 
 use super::attributes::attribute_type_descriptor;
 
@@ -250,10 +250,13 @@ pub fn is_type_matrixable(type_desc: &TypeDescriptor) -> Option<MatrixRenderType
         TypeDescriptor::VarLenArray(_) if is_varlen_byte_array(type_desc) => {
             Some(MatrixRenderType::ByteArray)
         }
+        TypeDescriptor::VarLenArray(_) if is_ragged_numeric_type(type_desc) => {
+            Some(MatrixRenderType::Ragged)
+        }
         TypeDescriptor::VarLenArray(_) => None,
         TypeDescriptor::VarLenAscii => Some(MatrixRenderType::Strings),
         TypeDescriptor::VarLenUnicode => Some(MatrixRenderType::Strings),
-        TypeDescriptor::Reference(_) => None,
+        TypeDescriptor::Reference(_) => Some(MatrixRenderType::References),
     }
 }
 
@@ -292,6 +295,12 @@ pub enum MatrixRenderType {
     Strings,
     Enum,
     ByteArray,
+    /// Variable-length numeric arrays, one element per cell.
+    Ragged,
+    /// Object and region references, one resolved target per cell.
+    References,
+    /// Bitfields, one word of named flags per cell.
+    Bitfield,
 }
 
 #[cfg(test)]
@@ -0,0 +1,67 @@
+use std::sync::mpsc::Sender;
+
+use crate::{
+    h5f::index_objects,
+    search::{load_cached_paths, store_cached_paths},
+    ui::{
+        app::{AppEvent, SearchIndexLoadResult},
+        state::{SearchIndexRequest, SearchIndexWork},
+        walk_worker::spawn_walk_worker,
+    },
+};
+
+/// Reads what search needs of a file in one walk, sending what was read in
+/// batches. Paths are taken from the disk cache when it holds them, and a
+/// complete walk listing paths is stored in the cache.
+pub(crate) fn handle_search_index_load(tx_events: Sender<AppEvent>) -> Sender<SearchIndexWork> {
+    spawn_walk_worker(tx_events, |request: SearchIndexRequest, walk| {
+        let key = request.generation;
+        let mut scope = request.scope;
+        let mut batch = SearchIndexLoadResult::empty(key);
+        let cached = scope
+            .paths
+            .then(|| request.cache_key.as_ref().and_then(load_cached_paths))
+            .flatten();
+        if let Some(paths) = cached {
+            batch.paths = paths;
+            scope.paths = false;
+            if scope.is_empty() {
+                batch.done = true;
+                walk.send(AppEvent::SearchIndex(batch));
+                return;
+            }
+        }
+        let cache_key = request.cache_key.as_ref().filter(|_| scope.paths);
+        let mut listed = Vec::new();
+        let complete = index_objects(&request.root, scope, &mut |object| {
+            if !walk.keep_going() {
+                return false;
+            }
+            if let Some(path) = object.path {
+                if cache_key.is_some() {
+                    listed.push(path.clone());
+                }
+                batch.paths.push(path);
+            }
+            if !object.attributes.is_empty() {
+                batch.attributes.extend(object.attributes);
+                batch.objects += 1;
+            }
+            batch.datasets.extend(object.dataset);
+            if !walk.batch_due() {
+                return true;
+            }
+            let sent = std::mem::replace(&mut batch, SearchIndexLoadResult::empty(key));
+            walk.send(AppEvent::SearchIndex(sent))
+        });
+        if !complete {
+            return;
+        }
+        batch.done = true;
+        if walk.send(AppEvent::SearchIndex(batch)) {
+            if let Some(cache_key) = cache_key {
+                store_cached_paths(cache_key, listed);
+            }
+        }
+    })
+}
@@ -15,7 +15,10 @@ use crate::{
     configure::registry::ContentModeHandle,
     data::{PreviewSelection, SliceSelection},
     error::AppError,
-    h5f::{DatasetMetaState, H5FNode, HasPath, Node, RequestedOpenMode, ResolvedOpenMode},
+    h5f::{
+        DatasetMetaState, H5FNode, HasPath, Node, RequestedOpenMode, ResolvedOpenMode,
+        StructureEdit,
+    },
     search::Searcher,
     ui::mchart::{
         CapturedMultiChartItem, ChartSource, DatasetChartKind, DatasetChartSource,
@@ -31,21 +34,39 @@ use super::{
     tree_view::TreeItem,
 };
 
+mod canonical_paths;
+mod chunk_map;
 mod content_modes;
 mod core;
+mod dimension_scales;
+mod find;
+mod group_overview;
 mod heatmap;
 mod help_state;
+mod list_view;
 mod movement;
 mod navigation;
 mod preview;
+mod search_index;
 mod selection;
 mod ui_layout;
+mod usage;
+mod walk;
+pub use canonical_paths::{CanonicalPathsRequest, CanonicalPathsState, CanonicalPathsWork};
+pub use chunk_map::{chunk_plane_dims, ChunkMapKey, ChunkMapRequest, ChunkMapState, ChunkMapWork};
 #[allow(unused_imports)]
 pub use core::{
     AppToast, AttributeCreateDialogState, AttributeCreateField, AttributeCursor,
     AttributeDeleteDialogState, AttributeEditRequest, AttributeViewSelection, ContentShowMode,
     FileWatchState, FixedStringOverflowChoice, FixedStringOverflowDialogState, Focus, LastFocused,
     LogLevelFilter, LogsFilterFocus, LogsViewState, MatrixViewState, Mode, PendingChord,
+    StructureEditDialogState,
+};
+pub use dimension_scales::DimensionScaleState;
+pub use find::{FindKey, FindRequest, FindState, FindWork};
+pub use group_overview::{
+    GroupOverviewKey, GroupOverviewMember, GroupOverviewRequest, GroupOverviewSort,
+    GroupOverviewState, GroupOverviewWork,
 };
 #[allow(unused_imports)]
 use heatmap::heatmap_anchor_fraction;
@@ -63,6 +84,7 @@ pub use help_state::{
     HelpCommandSection, HelpCustomizationSection, HelpKeymapSection, HelpMultiChartSection,
     HelpTab, HelpViewState,
 };
+pub use list_view::ListView;
 pub use preview::{
     direct_content_preview_page, CachedContentPreview, CachedMatrixViewport, ChartPreviewKey,
     ChartPreviewLoadRequest, ChartPreviewSource, ChartPreviwState, ClipboardImageData,
@@ -76,12 +98,15 @@ pub use preview::{
     CHART_PREVIEW_CACHE_CAPACITY, CONTENT_CACHE_CAPACITY, MATRIX_VIEWPORT_CACHE_CAPACITY,
     PREVIEW_CHART_VISIBLE_POINT_LIMIT,
 };
+pub use search_index::{SearchIndexRequest, SearchIndexState, SearchIndexWork};
 pub use ui_layout::{
     AttributesHitbox, ContentTabHitbox, HeatmapSettingHitbox, HelpScrollbarHitbox,
     HelpSidebarHitbox, HelpSidebarTarget, HelpTabHitbox, LogsFilterHitbox, LogsFilterTarget,
     MatrixCellHitbox, MatrixRowHitbox, MetadataCellHitbox, PreviewAxisScaleHitbox, TreeHitbox,
     UiLayoutState,
 };
+pub use usage::{UsageKey, UsageRequest, UsageState, UsageWork};
+pub use walk::WalkWork;
 
 pub struct AppState<'a> {
     pub readonly: bool,
@@ -100,6 +125,9 @@ pub struct AppState<'a> {
     pub pending_tree_selection: Option<String>,
     pub pending_tree_selection_state: Option<TreeSelectionState>,
     pub pending_tree_attribute_selection: Option<Option<String>>,
+    /// Written since the last reload, which then selects what the edit
+    /// created or moved and keeps moved groups expanded.
+    pub applied_structure_edit: Option<StructureEdit>,
     pub treeview: Vec<TreeItem<'a>>,
     pub file: Option<File>,
     pub requested_open_mode: RequestedOpenMode,
@@ -123,6 +151,8 @@ pub struct AppState<'a> {
     pub help_return_mode: Mode,
     pub logs_return_mode: Mode,
     pub searcher: Option<Searcher>,
+    pub search_index: SearchIndexState,
+    pub find: FindState,
     pub help: HelpViewState,
     pub logs: LogsViewState,
     pub pending_chord: Option<PendingChord>,
@@ -140,6 +170,11 @@ pub struct AppState<'a> {
     pub heatmap_viewport_region: Option<HeatmapRegionSelection>,
     pub heatmap_region: Option<HeatmapRegionSelection>,
     pub heatmap_render: HeatmapRenderState,
+    pub chunk_map: ChunkMapState,
+    pub usage: UsageState,
+    pub group_overview: GroupOverviewState,
+    pub canonical_paths: CanonicalPathsState,
+    pub dimension_scales: DimensionScaleState,
     pub chart_preview_state: ChartPreviwState,
     pub preview_expression_state: PreviewExpressionState,
     pub content_preview_state: ContentPreviewState,
@@ -149,6 +184,7 @@ pub struct AppState<'a> {
     pub attribute_create_dialog: Option<AttributeCreateDialogState>,
     pub attribute_delete_dialog: Option<AttributeDeleteDialogState>,
     pub fixed_string_overflow_dialog: Option<FixedStringOverflowDialogState>,
+    pub structure_edit_dialog: Option<StructureEditDialogState>,
     pub ui_layout: UiLayoutState,
 }
 
@@ -0,0 +1,58 @@
+use std::sync::mpsc::Sender;
+
+use hdf5_metno::File;
+
+use crate::h5f::CanonicalPaths;
+
+use super::WalkWork;
+
+pub struct CanonicalPathsRequest {
+    pub generation: u64,
+    pub file: File,
+}
+
+pub type CanonicalPathsWork = WalkWork<CanonicalPathsRequest>;
+
+/// Canonical paths of the open file, which mark the tree nodes reached
+/// through other links. They are walked for in the background the first time
+/// an expanded group has children that may be aliases, and kept until the
+/// file is reopened.
+pub struct CanonicalPathsState {
+    /// Bumped whenever the file is reopened; results of older walks are
+    /// dropped.
+    pub generation: u64,
+    pub pending_key: Option<u64>,
+    pub paths: Option<CanonicalPaths>,
+    pub tx_load: Sender<CanonicalPathsWork>,
+}
+
+impl CanonicalPathsState {
+    pub fn new(tx_load: Sender<CanonicalPathsWork>) -> Self {
+        Self {
+            generation: 0,
+            pending_key: None,
+            paths: None,
+            tx_load,
+        }
+    }
+
+    pub fn invalidate(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+        self.pending_key = None;
+        self.paths = None;
+    }
+
+    /// Starts walking `file` unless its paths are known or being read.
+    pub fn request(&mut self, file: File) {
+        if self.paths.is_some() || self.pending_key == Some(self.generation) {
+            return;
+        }
+        self.pending_key = Some(self.generation);
+        let _ = self
+            .tx_load
+            .send(CanonicalPathsWork::Load(CanonicalPathsRequest {
+                generation: self.generation,
+                file,
+            }));
+    }
+}
@@ -0,0 +1,182 @@
+use std::{collections::VecDeque, rc::Rc, sync::mpsc::Sender};
+
+use hdf5_metno::Dataset;
+
+use crate::h5f::{ChunkMap, H5FNode};
+
+use super::WalkWork;
+
+pub const CHUNK_MAP_CACHE_CAPACITY: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkMapKey {
+    pub file_generation: u64,
+    pub ds_path: String,
+}
+
+pub struct ChunkMapRequest {
+    pub key: ChunkMapKey,
+    pub dataset: Dataset,
+}
+
+pub type ChunkMapWork = WalkWork<ChunkMapRequest>;
+
+/// Cursor and cached allocation data for the chunk map content mode. Maps are
+/// read in the background, one dataset at a time, and reused until the
+/// dataset or file changes.
+pub struct ChunkMapState {
+    pub pending_key: Option<ChunkMapKey>,
+    pub error: Option<(ChunkMapKey, String)>,
+    pub cached: VecDeque<(ChunkMapKey, Rc<ChunkMap>)>,
+    /// Dataset the cursor belongs to.
+    pub shown: Option<ChunkMapKey>,
+    /// Map of the shown dataset.
+    pub map: Option<Rc<ChunkMap>>,
+    pub cursor_row: usize,
+    pub cursor_col: usize,
+    pub row_offset: usize,
+    pub col_offset: usize,
+    pub plane_rows: usize,
+    pub plane_cols: usize,
+    pub tx_load: Sender<ChunkMapWork>,
+}
+
+impl ChunkMapState {
+    pub fn new(tx_load: Sender<ChunkMapWork>) -> Self {
+        Self {
+            pending_key: None,
+            error: None,
+            cached: VecDeque::new(),
+            shown: None,
+            map: None,
+            cursor_row: 0,
+            cursor_col: 0,
+            row_offset: 0,
+            col_offset: 0,
+            plane_rows: 0,
+            plane_cols: 0,
+            tx_load,
+        }
+    }
+
+    pub fn invalidate(&mut self) {
+        self.pending_key = None;
+        self.error = None;
+        self.cached.clear();
+        self.map = None;
+    }
+
+    pub fn cached_map(&self, key: &ChunkMapKey) -> Option<Rc<ChunkMap>> {
+        self.cached
+            .iter()
+            .find(|(cached, _)| cached == key)
+            .map(|(_, map)| map.clone())
+    }
+
+    pub fn store(&mut self, key: ChunkMapKey, map: ChunkMap) {
+        self.cached.retain(|(cached, _)| cached != &key);
+        self.cached.push_back((key, Rc::new(map)));
+        while self.cached.len() > CHUNK_MAP_CACHE_CAPACITY {
+            self.cached.pop_front();
+        }
+    }
+
+    /// Starts reading the map of `dataset` unless that walk is already
+    /// running.
+    pub fn request(&mut self, key: ChunkMapKey, dataset: &Dataset) {
+        if self.pending_key.as_ref() == Some(&key) {
+            return;
+        }
+        self.pending_key = Some(key.clone());
+        self.error = None;
+        let _ = self.tx_load.send(ChunkMapWork::Load(ChunkMapRequest {
+            key,
+            dataset: dataset.clone(),
+        }));
+    }
+
+    /// Shows the map of `key` once it was read, moving the cursor back to the
+    /// first chunk when the dataset changes.
+    pub fn show(&mut self, key: &ChunkMapKey) {
+        if self.shown.as_ref() != Some(key) {
+            self.shown = Some(key.clone());
+            self.cursor_row = 0;
+            self.cursor_col = 0;
+            self.row_offset = 0;
+            self.col_offset = 0;
+            self.plane_rows = 0;
+            self.plane_cols = 0;
+        }
+        self.map = self.cached_map(key);
+    }
+
+    pub fn move_cursor(&mut self, rows: isize, cols: isize) -> bool {
+        let row = self
+            .cursor_row
+            .saturating_add_signed(rows)
+            .min(self.plane_rows.saturating_sub(1));
+        let col = self
+            .cursor_col
+            .saturating_add_signed(cols)
+            .min(self.plane_cols.saturating_sub(1));
+        let moved = row != self.cursor_row || col != self.cursor_col;
+        self.cursor_row = row;
+        self.cursor_col = col;
+        moved
+    }
+
+    /// Moves the cursor to the chunk holding an element position. Each axis is an
+    /// `(element index, dataset dimension)` pair; `None` keeps the current chunk.
+    pub fn seek_element(
+        &mut self,
+        row: Option<(usize, usize)>,
+        col: Option<(usize, usize)>,
+    ) -> bool {
+        let Some(map) = self.map.as_ref() else {
+            return false;
+        };
+        let chunk_index = |(index, dim): (usize, usize)| {
+            index / map.chunk_shape.get(dim).copied().unwrap_or(1).max(1)
+        };
+        let row_chunk = row.map_or(self.cursor_row, chunk_index);
+        let col_chunk = col.map_or(self.cursor_col, chunk_index);
+        self.move_cursor(
+            row_chunk as isize - self.cursor_row as isize,
+            col_chunk as isize - self.cursor_col as isize,
+        )
+    }
+
+    /// Scrolls the visible window so the cursor stays inside it.
+    pub fn scroll_to_cursor(&mut self, visible_rows: usize, visible_cols: usize) {
+        let visible_rows = visible_rows.max(1);
+        let visible_cols = visible_cols.max(1);
+        self.cursor_row = self.cursor_row.min(self.plane_rows.saturating_sub(1));
+        self.cursor_col = self.cursor_col.min(self.plane_cols.saturating_sub(1));
+        if self.cursor_row < self.row_offset {
+            self.row_offset = self.cursor_row;
+        } else if self.cursor_row >= self.row_offset + visible_rows {
+            self.row_offset = self.cursor_row + 1 - visible_rows;
+        }
+        if self.cursor_col < self.col_offset {
+            self.col_offset = self.cursor_col;
+        } else if self.cursor_col >= self.col_offset + visible_cols {
+            self.col_offset = self.cursor_col + 1 - visible_cols;
+        }
+        self.row_offset = self
+            .row_offset
+            .min(self.plane_rows.saturating_sub(visible_rows));
+        self.col_offset = self
+            .col_offset
+            .min(self.plane_cols.saturating_sub(visible_cols));
+    }
+}
+
+pub fn chunk_plane_dims(node: &H5FNode, rank: usize) -> (Option<usize>, usize) {
+    if rank < 2 {
+        return (None, 0);
+    }
+    (
+        Some(node.selected_row.min(rank - 1)),
+        node.selected_col.min(rank - 1),
+    )
+}
@@ -1,4 +1,9 @@
-use crate::{configure, configure::registry::ContentModeHandle};
+use crate::{
+    configure,
+    configure::registry::ContentModeHandle,
+    h5f::{DatasetMetaState, Node},
+    ui::{dims::HasMatrixSelection, render::MatrixRenderType},
+};
 
 use super::{AppState, ContentShowMode};
 
@@ -25,6 +30,9 @@ impl AppState<'_> {
         {
             self.end_heatmap_drag();
         }
+        if mode != ContentShowMode::Matrix {
+            self.select_ragged_element_from_matrix();
+        }
         self.content_mode = mode.handle();
     }
 
@@ -34,9 +42,35 @@ impl AppState<'_> {
         {
             self.end_heatmap_drag();
         }
+        if handle != ContentShowMode::Matrix.handle() {
+            self.select_ragged_element_from_matrix();
+        }
         self.content_mode = handle;
     }
 
+    /// Leaving the matrix of a ragged dataset selects the element under the
+    /// cursor, so the preview charts that element.
+    fn select_ragged_element_from_matrix(&mut self) {
+        if self.active_content_mode() != ContentShowMode::Matrix {
+            return;
+        }
+        let Some(item) = self.treeview.get(self.tree_view_cursor) else {
+            return;
+        };
+        let Ok(mut node) = item.node.try_borrow_mut() else {
+            return;
+        };
+        let shape = match &node.node {
+            Node::Dataset(_, DatasetMetaState::Loaded(meta))
+                if matches!(meta.matrixable, Some(MatrixRenderType::Ragged)) =>
+            {
+                meta.shape.clone()
+            }
+            _ => return,
+        };
+        node.selected_indexes = self.matrix_cursor_indexes(&node, &shape);
+    }
+
     pub fn content_mode_handle_eval(&self, available: Vec<ContentModeHandle>) -> ContentModeHandle {
         if let Some(handle) = available
             .iter()
@@ -1,6 +1,9 @@
 use std::time::SystemTime;
 
-use crate::{error::FixedStringOverflow, h5f::AttributeCreateType};
+use crate::{
+    error::FixedStringOverflow,
+    h5f::{AttributeCreateType, StructureEdit},
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LastFocused {
@@ -19,6 +22,7 @@ pub enum Focus {
 pub enum Mode {
     Normal,
     Search,
+    Find,
     Help,
     Logs,
     Command,
@@ -27,6 +31,7 @@ pub enum Mode {
     AttributeDeleteDialog,
     FixedStringOverflowDialog,
     FixedStringResizeDialog,
+    StructureEditDialog,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -39,6 +44,8 @@ pub enum ContentShowMode {
     Preview,
     Matrix,
     Heatmap,
+    Chunks,
+    Usage,
 }
 
 impl ContentShowMode {
@@ -47,6 +54,8 @@ impl ContentShowMode {
             "preview" => Some(Self::Preview),
             "matrix" => Some(Self::Matrix),
             "heatmap" => Some(Self::Heatmap),
+            "chunks" => Some(Self::Chunks),
+            "usage" => Some(Self::Usage),
             _ => None,
         }
     }
@@ -56,6 +65,8 @@ impl ContentShowMode {
             Self::Preview => "preview",
             Self::Matrix => "matrix",
             Self::Heatmap => "heatmap",
+            Self::Chunks => "chunks",
+            Self::Usage => "usage",
         }
     }
 
@@ -101,6 +112,8 @@ pub struct AttributeCreateDialogState {
     pub name: String,
     pub name_cursor: usize,
     pub attr_type: AttributeCreateType,
+    /// Creates a 1-D array of `attr_type` from comma-separated values.
+    pub array: bool,
     pub value: String,
     pub value_cursor: usize,
     pub active_field: AttributeCreateField,
@@ -111,6 +124,10 @@ pub struct AttributeDeleteDialogState {
     pub attr_name: String,
 }
 
+#[derive(Clone)]
+pub struct StructureEditDialogState {
+    pub edit: StructureEdit,
+}
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FixedStringOverflowChoice {
     Cancel,
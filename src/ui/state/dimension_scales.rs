@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use hdf5_metno::Dataset;
+
+use crate::h5f::{read_dimension_scales, DimensionScale};
+
+/// Dimension scales of the dataset currently shown in the content panel. They
+/// are read once per dataset and shared with the chart worker thread.
+#[derive(Debug, Default)]
+pub struct DimensionScaleState {
+    pub ds_path: Option<String>,
+    pub scales: Vec<Option<Arc<DimensionScale>>>,
+}
+
+impl DimensionScaleState {
+    pub fn invalidate(&mut self) {
+        self.ds_path = None;
+        self.scales.clear();
+    }
+
+    pub fn scale_for(
+        &mut self,
+        ds_path: &str,
+        ds: &Dataset,
+        dim: usize,
+    ) -> Option<Arc<DimensionScale>> {
+        if self.ds_path.as_deref() != Some(ds_path) {
+            self.ds_path = Some(ds_path.to_string());
+            self.scales = read_dimension_scales(ds)
+                .into_iter()
+                .map(|scale| scale.map(Arc::new))
+                .collect();
+        }
+        self.scales.get(dim).cloned().flatten()
+    }
+
+    /// Scale of an already loaded dataset, without touching the file.
+    pub fn cached_scale(&self, ds_path: &str, dim: usize) -> Option<Arc<DimensionScale>> {
+        if self.ds_path.as_deref() != Some(ds_path) {
+            return None;
+        }
+        self.scales.get(dim).cloned().flatten()
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::mpsc::Sender;
+
+use hdf5_metno::Dataset;
+
+use crate::h5f::{FindEnd, FindHit, FindQuery};
+
+use super::{ListView, WalkWork};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FindKey {
+    pub file_generation: u64,
+    pub path: String,
+    pub query: String,
+}
+
+pub struct FindRequest {
+    pub key: FindKey,
+    pub dataset: Dataset,
+    pub query: FindQuery,
+}
+
+pub type FindWork = WalkWork<FindRequest>;
+
+/// Value search inside the selected dataset. One dataset is scanned at a
+/// time; starting another search cancels the scan in progress.
+pub struct FindState {
+    /// Search whose hits are listed.
+    pub key: Option<FindKey>,
+    pub pending_key: Option<FindKey>,
+    pub hits: Vec<FindHit>,
+    pub scanned: usize,
+    pub total: usize,
+    /// How the listed scan ended; meaningful once it is no longer scanning.
+    pub end: FindEnd,
+    /// Lists hit indexes reversed, in MATLAB order.
+    pub matlab_order: bool,
+    pub error: Option<String>,
+    /// Query being typed in the find panel.
+    pub input: String,
+    pub input_cursor: usize,
+    pub list: ListView,
+    pub tx_load: Sender<FindWork>,
+}
+
+impl FindState {
+    pub fn new(tx_load: Sender<FindWork>) -> Self {
+        Self {
+            key: None,
+            pending_key: None,
+            hits: Vec::new(),
+            scanned: 0,
+            total: 0,
+            end: FindEnd::Complete,
+            matlab_order: false,
+            error: None,
+            input: String::new(),
+            input_cursor: 0,
+            list: ListView::default(),
+            tx_load,
+        }
+    }
+
+    /// Drops the listed hits; they may point at data that changed.
+    pub fn invalidate(&mut self) {
+        self.key = None;
+        self.pending_key = None;
+        self.hits.clear();
+        self.scanned = 0;
+        self.total = 0;
+        self.end = FindEnd::Complete;
+        self.matlab_order = false;
+        self.error = None;
+        self.list.reset();
+    }
+
+    /// Starts scanning `dataset`, replacing the listed hits.
+    pub fn request(
+        &mut self,
+        key: FindKey,
+        dataset: Dataset,
+        query: FindQuery,
+        total: usize,
+        matlab_order: bool,
+    ) {
+        self.invalidate();
+        self.key = Some(key.clone());
+        self.pending_key = Some(key.clone());
+        self.total = total;
+        self.matlab_order = matlab_order;
+        let _ = self.tx_load.send(FindWork::Load(FindRequest {
+            key,
+            dataset,
+            query,
+        }));
+    }
+
+    pub fn is_scanning(&self) -> bool {
+        self.pending_key.is_some()
+    }
+
+    /// Whether the panel input differs from the listed search.
+    pub fn input_changed(&self) -> bool {
+        self.key
+            .as_ref()
+            .is_none_or(|key| key.query != self.input.trim())
+    }
+
+    pub fn selected_hit(&self) -> Option<&FindHit> {
+        self.hits.get(self.list.cursor)
+    }
+
+    pub fn move_cursor(&mut self, delta: isize) -> bool {
+        self.list.move_cursor(delta, self.hits.len())
+    }
+}
@@ -0,0 +1,210 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::mpsc::Sender};
+
+use crate::h5f::{DatasetMetaState, H5FNode, HasPath, Node};
+
+use super::{ListView, WalkWork};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupOverviewKey {
+    pub file_generation: u64,
+    pub group_path: String,
+}
+
+pub struct GroupOverviewRequest {
+    pub key: GroupOverviewKey,
+    /// Members whose metadata or attribute count is still unknown.
+    pub members: Vec<Node>,
+}
+
+/// What the worker read for one member.
+pub struct GroupOverviewMember {
+    pub path: String,
+    /// The dataset with its metadata loaded, for members that were pending.
+    pub node: Option<Node>,
+    pub attributes: Option<usize>,
+    pub error: Option<String>,
+}
+
+pub type GroupOverviewWork = WalkWork<GroupOverviewRequest>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupOverviewSort {
+    Name,
+    Kind,
+    Shape,
+    Dtype,
+    Storage,
+    Ratio,
+    Attributes,
+}
+
+impl GroupOverviewSort {
+    pub const ALL: [Self; 7] = [
+        Self::Name,
+        Self::Kind,
+        Self::Shape,
+        Self::Dtype,
+        Self::Storage,
+        Self::Ratio,
+        Self::Attributes,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Kind => "kind",
+            Self::Shape => "shape",
+            Self::Dtype => "dtype",
+            Self::Storage => "storage",
+            Self::Ratio => "ratio",
+            Self::Attributes => "attrs",
+        }
+    }
+
+    fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|sort| *sort == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Member table shown as the preview of groups without a preview expression.
+/// Member metadata is read in the background and written back into the tree.
+pub struct GroupOverviewState {
+    pub pending_key: Option<GroupOverviewKey>,
+    /// Tree node of the pending group; loaded members are written into its
+    /// children.
+    pub pending_group: Option<Rc<RefCell<H5FNode>>>,
+    /// Group whose members have all been read.
+    pub loaded_key: Option<GroupOverviewKey>,
+    /// Attribute counts by member path.
+    pub attribute_counts: HashMap<String, usize>,
+    /// Metadata errors by member path.
+    pub errors: HashMap<String, String>,
+    pub sort: GroupOverviewSort,
+    pub descending: bool,
+    /// Group the cursor belongs to.
+    pub shown: Option<String>,
+    /// Member paths in the order last drawn.
+    pub rows: Vec<String>,
+    pub list: ListView,
+    pub tx_load: Sender<GroupOverviewWork>,
+}
+
+impl GroupOverviewState {
+    pub fn new(tx_load: Sender<GroupOverviewWork>) -> Self {
+        Self {
+            pending_key: None,
+            pending_group: None,
+            loaded_key: None,
+            attribute_counts: HashMap::new(),
+            errors: HashMap::new(),
+            sort: GroupOverviewSort::Name,
+            descending: false,
+            shown: None,
+            rows: Vec::new(),
+            list: ListView::default(),
+            tx_load,
+        }
+    }
+
+    pub fn invalidate(&mut self) {
+        self.pending_key = None;
+        self.pending_group = None;
+        self.loaded_key = None;
+        self.attribute_counts.clear();
+        self.errors.clear();
+    }
+
+    /// Queues the members still missing data, unless this group was already
+    /// read or is being read.
+    pub fn request(
+        &mut self,
+        key: GroupOverviewKey,
+        group: Rc<RefCell<H5FNode>>,
+        members: Vec<Node>,
+    ) {
+        if self.loaded_key.as_ref() == Some(&key) || self.pending_key.as_ref() == Some(&key) {
+            return;
+        }
+        if members.is_empty() {
+            self.loaded_key = Some(key);
+            return;
+        }
+        self.pending_key = Some(key.clone());
+        self.pending_group = Some(group);
+        let _ = self
+            .tx_load
+            .send(GroupOverviewWork::Load(GroupOverviewRequest {
+                key,
+                members,
+            }));
+    }
+
+    /// Stores a batch of members read for the pending group. Datasets still
+    /// pending in the tree take the loaded metadata, as a selection would.
+    pub fn apply(&mut self, members: Vec<GroupOverviewMember>) {
+        let children = self
+            .pending_group
+            .as_ref()
+            .map(|group| group.borrow().children.clone())
+            .unwrap_or_default();
+        for member in members {
+            let mut path = member.path;
+            let child = children
+                .iter()
+                .find(|child| child.borrow().node.path() == path);
+            if let (Some(child), Some(loaded)) = (child, member.node) {
+                let mut child = child.borrow_mut();
+                if matches!(child.node, Node::Dataset(_, DatasetMetaState::Pending(_))) {
+                    let rank = match &loaded {
+                        Node::Dataset(_, DatasetMetaState::Loaded(meta)) => meta.shape.len(),
+                        _ => 0,
+                    };
+                    child.node = loaded;
+                    child.sync_selection_rank(rank);
+                    path = child.node.path();
+                }
+            }
+            if let Some(count) = member.attributes {
+                self.attribute_counts.insert(path.clone(), count);
+            }
+            if let Some(error) = member.error {
+                self.errors.insert(path, error);
+            }
+        }
+    }
+
+    pub fn finish(&mut self) {
+        self.loaded_key = self.pending_key.take();
+        self.pending_group = None;
+    }
+
+    /// Shows the members of `group_path`, moving the cursor back to the top
+    /// when the group changes.
+    pub fn show(&mut self, group_path: &str, rows: Vec<String>) {
+        if self.shown.as_deref() != Some(group_path) {
+            self.shown = Some(group_path.to_string());
+            self.list.reset();
+        }
+        self.list.clamp(rows.len());
+        self.rows = rows;
+    }
+
+    pub fn selected_path(&self) -> Option<&str> {
+        self.rows.get(self.list.cursor).map(String::as_str)
+    }
+
+    pub fn move_cursor(&mut self, delta: isize) -> bool {
+        self.list.move_cursor(delta, self.rows.len())
+    }
+
+    /// Sorts by the next column; the first column is sorted ascending.
+    pub fn cycle_sort(&mut self) {
+        self.sort = self.sort.next();
+        self.descending = false;
+    }
+
+    pub fn reverse_sort(&mut self) {
+        self.descending = !self.descending;
+    }
+}
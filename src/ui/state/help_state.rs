@@ -65,17 +65,19 @@ pub enum HelpCommandSection {
     View,
     Selection,
     Attributes,
+    Structure,
     App,
     MultiChart,
     Input,
 }
 
 impl HelpCommandSection {
-    const ALL: [Self; 7] = [
+    const ALL: [Self; 8] = [
         Self::Navigation,
         Self::View,
         Self::Selection,
         Self::Attributes,
+        Self::Structure,
         Self::App,
         Self::MultiChart,
         Self::Input,
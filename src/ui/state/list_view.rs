@@ -0,0 +1,63 @@
+/// Cursor and scroll position of a list drawn one row per entry.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListView {
+    pub cursor: usize,
+    /// First visible row.
+    pub row_offset: usize,
+}
+
+impl ListView {
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Keeps the cursor on one of `rows` entries.
+    pub fn clamp(&mut self, rows: usize) {
+        self.cursor = self.cursor.min(rows.saturating_sub(1));
+    }
+
+    pub fn move_cursor(&mut self, delta: isize, rows: usize) -> bool {
+        self.set_cursor(self.cursor.saturating_add_signed(delta), rows)
+    }
+
+    pub fn set_cursor(&mut self, row: usize, rows: usize) -> bool {
+        let row = row.min(rows.saturating_sub(1));
+        let moved = row != self.cursor;
+        self.cursor = row;
+        moved
+    }
+
+    /// Scrolls the visible rows so the cursor stays inside them.
+    pub fn scroll_to_cursor(&mut self, visible_rows: usize) {
+        let visible_rows = visible_rows.max(1);
+        if self.cursor < self.row_offset {
+            self.row_offset = self.cursor;
+        } else if self.cursor >= self.row_offset + visible_rows {
+            self.row_offset = self.cursor + 1 - visible_rows;
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::ListView;
+
+    #[test]
+    fn cursor_stays_in_the_list_and_in_view() {
+        let mut list = ListView::default();
+        assert!(!list.move_cursor(-1, 5));
+        assert!(list.move_cursor(10, 5));
+        assert_eq!(list.cursor, 4);
+        list.scroll_to_cursor(2);
+        assert_eq!(list.row_offset, 3);
+        assert!(list.set_cursor(1, 5));
+        list.scroll_to_cursor(2);
+        assert_eq!(list.row_offset, 1);
+        list.clamp(0);
+        assert_eq!(list.cursor, 0);
+        list.reset();
+        assert_eq!(list, ListView::default());
+    }
+}
@@ -89,6 +89,8 @@ impl AppState<'_> {
                     .min(HEATMAP_SETTING_FIELDS.len().saturating_sub(1));
                 Ok(EventResult::Redraw)
             }
+            ContentShowMode::Chunks => Ok(self.move_chunk_cursor(-(dec as isize), 0)),
+            ContentShowMode::Usage => Ok(self.move_usage_cursor(-(dec as isize))),
         }
     }
 
@@ -175,6 +177,8 @@ impl AppState<'_> {
                     .min(HEATMAP_SETTING_FIELDS.len().saturating_sub(1));
                 Ok(EventResult::Redraw)
             }
+            ContentShowMode::Chunks => Ok(self.move_chunk_cursor(inc as isize, 0)),
+            ContentShowMode::Usage => Ok(self.move_usage_cursor(inc as isize)),
         }
     }
 
@@ -237,6 +241,12 @@ impl AppState<'_> {
                     idx.min(HEATMAP_SETTING_FIELDS.len().saturating_sub(1));
                 Ok(EventResult::Redraw)
             }
+            ContentShowMode::Chunks => {
+                Ok(self.move_chunk_cursor(idx as isize - self.chunk_map.cursor_row as isize, 0))
+            }
+            ContentShowMode::Usage => {
+                Ok(self.move_usage_cursor(idx as isize - self.usage.list.cursor as isize))
+            }
         }
     }
 
@@ -249,7 +259,8 @@ impl AppState<'_> {
             ContentShowMode::Preview => {
                 if secondary.is_some() {
                     return Err(AppError::InvalidCommand(
-                        "seek <x> <y> is only available in matrix or heatmap mode".to_string(),
+                        "seek <x> <y> is only available in matrix, heatmap, or chunks mode"
+                            .to_string(),
                     ));
                 }
                 if matches!(self.page_state.paged, PageType::Unpaged)
@@ -285,6 +296,29 @@ impl AppState<'_> {
                     self.seek_heatmap_row(primary)
                 }
             }
+            ContentShowMode::Chunks => {
+                let (row_dim, col_dim) = self.chunk_plane_dims()?;
+                Ok(match (secondary, row_dim) {
+                    (Some(row), Some(row_dim)) => {
+                        self.seek_chunk_element(Some((row, row_dim)), Some((primary, col_dim)))
+                    }
+                    (None, Some(row_dim)) if self.chunk_map.plane_rows > 1 => {
+                        self.seek_chunk_element(Some((primary, row_dim)), None)
+                    }
+                    _ => self.seek_chunk_element(None, Some((primary, col_dim))),
+                })
+            }
+            ContentShowMode::Usage => {
+                if secondary.is_some() {
+                    return Err(AppError::InvalidCommand(
+                        "seek <x> <y> is only available in matrix, heatmap, or chunks mode"
+                            .to_string(),
+                    ));
+                }
+                Ok(self.move_usage_cursor(
+                    primary.saturating_sub(1) as isize - self.usage.list.cursor as isize,
+                ))
+            }
         }
     }
 
@@ -292,8 +326,14 @@ impl AppState<'_> {
         match self.active_content_mode() {
             ContentShowMode::Matrix => self.seek_matrix_row(row),
             ContentShowMode::Heatmap => self.seek_heatmap_row(row),
+            ContentShowMode::Chunks => match self.chunk_plane_dims()? {
+                (Some(row_dim), _) => Ok(self.seek_chunk_element(Some((row, row_dim)), None)),
+                (None, _) => Err(AppError::InvalidCommand(
+                    "seek-row needs a dataset with at least two dimensions".to_string(),
+                )),
+            },
             _ => Err(AppError::InvalidCommand(
-                "seek-row is only available in matrix or heatmap mode".to_string(),
+                "seek-row is only available in matrix, heatmap, or chunks mode".to_string(),
             )),
         }
     }
@@ -302,8 +342,12 @@ impl AppState<'_> {
         match self.active_content_mode() {
             ContentShowMode::Matrix => self.seek_matrix_col(col),
             ContentShowMode::Heatmap => self.seek_heatmap_col(col),
+            ContentShowMode::Chunks => {
+                let (_, col_dim) = self.chunk_plane_dims()?;
+                Ok(self.seek_chunk_element(None, Some((col, col_dim))))
+            }
             _ => Err(AppError::InvalidCommand(
-                "seek-col is only available in matrix or heatmap mode".to_string(),
+                "seek-col is only available in matrix, heatmap, or chunks mode".to_string(),
             )),
         }
     }
@@ -351,6 +395,76 @@ impl AppState<'_> {
         }
     }
 
+    /// Moves the view of the selected dataset to the element at `index`: the
+    /// slice indexes take its position and the matrix, heatmap, preview, or
+    /// chunk map scrolls to show it.
+    pub fn jump_to_element(&mut self, index: &[usize]) -> Result<EventResult> {
+        let (row_dim, col_dim, x_dim) = {
+            let current_node = &self.treeview[self.tree_view_cursor];
+            let mut node = current_node.node.borrow_mut();
+            if matches!(node.node, Node::Dataset(_, _)) {
+                node.ensure_dataset_meta()?;
+            }
+            let Node::Dataset(_, DatasetMetaState::Loaded(dsattr)) = &node.node else {
+                return Ok(EventResult::Continue);
+            };
+            if dsattr.shape.len() != index.len() {
+                return Err(AppError::InvalidCommand(format!(
+                    "Index {index:?} does not match the shape of the selected dataset"
+                )));
+            }
+            node.sync_selection_rank(index.len());
+            node.selected_indexes = index.to_vec();
+            (node.selected_row, node.selected_col, node.selected_x)
+        };
+        if index.is_empty() {
+            return Ok(EventResult::Redraw);
+        }
+        match self.active_content_mode() {
+            ContentShowMode::Preview => {
+                if matches!(self.page_state.paged, PageType::Chart) {
+                    if let Some(max_index) = self.page_state.max_index() {
+                        self.page_state.idx =
+                            ((index[x_dim] / MAX_PAGE_SIZE) as i32).clamp(0, max_index);
+                    }
+                } else if matches!(self.page_state.paged, PageType::Unpaged)
+                    && self.active_image_window_mut().is_none()
+                {
+                    let current_node = &self.treeview[self.tree_view_cursor];
+                    current_node.node.borrow_mut().line_offset = index[x_dim];
+                }
+                Ok(EventResult::Redraw)
+            }
+            ContentShowMode::Matrix => {
+                self.seek_matrix_row(index[row_dim])?;
+                self.matrix_view_state.cursor_row =
+                    index[row_dim].saturating_sub(self.matrix_view_state.row_offset);
+                if index.len() > 1 {
+                    self.seek_matrix_col(index[col_dim])?;
+                    self.matrix_view_state.cursor_col =
+                        index[col_dim].saturating_sub(self.matrix_view_state.col_offset);
+                }
+                Ok(EventResult::Redraw)
+            }
+            ContentShowMode::Heatmap => {
+                self.seek_heatmap_col(index[col_dim])?;
+                self.seek_heatmap_row(index[row_dim])?;
+                self.heatmap_render.selected_cells =
+                    Some(HeatmapSelectedCells::single(index[row_dim], index[col_dim]));
+                Ok(EventResult::Redraw)
+            }
+            ContentShowMode::Chunks => {
+                let (row_dim, col_dim) = self.chunk_plane_dims()?;
+                self.seek_chunk_element(
+                    row_dim.map(|row_dim| (index[row_dim], row_dim)),
+                    Some((index[col_dim], col_dim)),
+                );
+                Ok(EventResult::Redraw)
+            }
+            ContentShowMode::Usage => Ok(EventResult::Redraw),
+        }
+    }
+
     fn smart_2d_seek_axis(&mut self) -> Result<Option<SeekAxis>> {
         match self.active_content_mode() {
             ContentShowMode::Matrix => {
@@ -679,6 +793,8 @@ impl AppState<'_> {
                 }
                 Ok(EventResult::Redraw)
             }
+            ContentShowMode::Chunks => Ok(self.move_chunk_cursor(0, inc)),
+            ContentShowMode::Usage => Ok(EventResult::Continue),
         }
     }
 
@@ -754,6 +870,50 @@ impl AppState<'_> {
                 }
                 Ok(EventResult::Redraw)
             }
+            ContentShowMode::Chunks => Ok(self.move_chunk_cursor(0, -inc)),
+            ContentShowMode::Usage => Ok(EventResult::Continue),
         }
     }
+
+    fn move_chunk_cursor(&mut self, rows: isize, cols: isize) -> EventResult {
+        if self.chunk_map.move_cursor(rows, cols) {
+            EventResult::Redraw
+        } else {
+            EventResult::Continue
+        }
+    }
+
+    fn move_usage_cursor(&mut self, rows: isize) -> EventResult {
+        if self.usage.move_cursor(rows) {
+            EventResult::Redraw
+        } else {
+            EventResult::Continue
+        }
+    }
+
+    fn seek_chunk_element(
+        &mut self,
+        row: Option<(usize, usize)>,
+        col: Option<(usize, usize)>,
+    ) -> EventResult {
+        if self.chunk_map.seek_element(row, col) {
+            EventResult::Redraw
+        } else {
+            EventResult::Continue
+        }
+    }
+
+    /// Dataset dimensions drawn as rows and columns of the chunk map; 1-D datasets
+    /// have a single row of chunks.
+    pub fn chunk_plane_dims(&self) -> Result<(Option<usize>, usize)> {
+        let mut node = self.treeview[self.tree_view_cursor].node.borrow_mut();
+        if matches!(node.node, Node::Dataset(_, _)) {
+            node.ensure_dataset_meta()?;
+        }
+        let rank = match &node.node {
+            Node::Dataset(_, DatasetMetaState::Loaded(dsattr)) => dsattr.shape.len(),
+            _ => 0,
+        };
+        Ok(chunk_plane_dims(&node, rank))
+    }
 }
@@ -1,8 +1,16 @@
-use std::{rc::Rc, sync::mpsc::channel};
+use std::{path::Path, rc::Rc, sync::mpsc::channel};
+
+use hdf5_metno::Group;
 
 use crate::{
     configure,
-    h5f::{H5FNode, Node},
+    error::AppError,
+    h5f::{
+        check_find_query, DatasetHandle, DatasetMetaState, FindQuery, H5FNode, HasPath, IndexScope,
+        Node,
+    },
+    search::{PathCacheKey, Searcher},
+    ui::{app::CanonicalPathsLoadResult, input::EventResult},
 };
 
 use super::{
@@ -27,12 +35,221 @@ impl AppState<'_> {
             .send(super::MatrixViewportWork::Drain(tx));
         let _ = rx.recv();
     }
+
+    pub fn drain_chunk_map(&mut self) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let _ = self.chunk_map.tx_load.send(super::ChunkMapWork::Drain(tx));
+        let _ = rx.recv();
+    }
+
+    pub fn drain_canonical_paths(&mut self) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let _ = self
+            .canonical_paths
+            .tx_load
+            .send(super::CanonicalPathsWork::Drain(tx));
+        let _ = rx.recv();
+    }
+
+    pub fn drain_usage(&mut self) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let _ = self.usage.tx_load.send(super::UsageWork::Drain(tx));
+        let _ = rx.recv();
+    }
+
+    pub fn drain_group_overview(&mut self) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let _ = self
+            .group_overview
+            .tx_load
+            .send(super::GroupOverviewWork::Drain(tx));
+        let _ = rx.recv();
+    }
+
+    pub fn drain_search_index(&mut self) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let _ = self
+            .search_index
+            .tx_load
+            .send(super::SearchIndexWork::Drain(tx));
+        let _ = rx.recv();
+    }
+
+    pub fn drain_find(&mut self) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let _ = self.find.tx_load.send(super::FindWork::Drain(tx));
+        let _ = rx.recv();
+    }
+
     pub fn invalidate_selected_navigation_data(&mut self) {
         self.navigation_generation = self.navigation_generation.wrapping_add(1);
         self.pending_navigation_request = None;
         self.content_preview_state.pending_key = None;
         self.content_preview_state.error = None;
         self.content_preview_state.cached.clear();
+        // Called after attribute edits, which also leave attribute search
+        // stale.
+        self.invalidate_search_index(IndexScope {
+            attributes: true,
+            ..IndexScope::default()
+        });
+    }
+
+    /// Drops what search read of `stale` so the next query needing it reads
+    /// it again. A running walk reading any of it is stopped, and what it
+    /// read of the other parts is dropped too.
+    pub fn invalidate_search_index(&mut self, stale: IndexScope) {
+        let running = self.search_index.running();
+        let mut dropped = stale;
+        if running.intersects(stale) {
+            self.search_index.cancel();
+            dropped = dropped.union(running);
+        }
+        if let Some(searcher) = self.searcher.as_mut() {
+            searcher.clear(dropped);
+        }
+    }
+
+    /// Starts walking the file for canonical paths, unless they are known or
+    /// being read.
+    pub fn request_canonical_paths(&mut self) {
+        let file = match &self.root.borrow().node {
+            Node::File(file) => Some(file.clone()),
+            _ => None,
+        };
+        if let Some(file) = file {
+            self.canonical_paths.request(file);
+        }
+    }
+
+    /// Keeps canonical paths read for the open file and marks the aliases
+    /// among the tree nodes read so far.
+    pub fn apply_canonical_paths(&mut self, result: CanonicalPathsLoadResult) {
+        if self.canonical_paths.pending_key != Some(result.key) {
+            return;
+        }
+        self.canonical_paths.pending_key = None;
+        self.root.borrow_mut().mark_aliases(&result.paths);
+        self.canonical_paths.paths = Some(result.paths);
+        self.compute_tree_view();
+    }
+
+    /// Creates the searcher and starts listing the paths of the file into
+    /// it.
+    pub fn start_search_index(&mut self, root: Group) {
+        self.searcher = Some(Searcher::indexing());
+        self.request_search_index_from(root, Searcher::index_scope(""));
+    }
+
+    /// Starts reading what `wanted` asks for that the searcher lacks, unless
+    /// the running walk reads it. A running walk that does not is replaced
+    /// by one reading both, and the searcher drops what the replaced walk
+    /// had sent.
+    pub fn request_search_index(&mut self, wanted: IndexScope) {
+        let root = match &self.root.borrow().node {
+            Node::File(file) => file.as_group().ok(),
+            _ => None,
+        };
+        if let Some(root) = root {
+            self.request_search_index_from(root, wanted);
+        }
+    }
+
+    fn request_search_index_from(&mut self, root: Group, wanted: IndexScope) {
+        let Some(searcher) = self.searcher.as_mut() else {
+            return;
+        };
+        let running = self.search_index.running();
+        let missing = searcher.missing(wanted);
+        if running.covers(missing) {
+            return;
+        }
+        let scope = running.union(missing);
+        searcher.clear(scope);
+        // Files opened read-only list their paths from the disk cache when
+        // it holds them.
+        let cache_key = if scope.paths && self.readonly {
+            PathCacheKey::of(Path::new(&self.file_watch.path))
+        } else {
+            None
+        };
+        self.search_index.request(root, scope, cache_key);
+    }
+
+    /// Stops reading for search. The searcher is dropped when its paths
+    /// are incomplete, so the next search starts over; otherwise it keeps
+    /// them and drops only the other parts the walk was reading.
+    pub fn cancel_search_index(&mut self) {
+        let running = self.search_index.running();
+        self.search_index.cancel();
+        if running.paths {
+            self.searcher = None;
+        } else if let Some(searcher) = self.searcher.as_mut() {
+            searcher.clear(running);
+        }
+    }
+
+    /// Starts scanning the selected dataset for `query`, replacing the hits
+    /// of the previous search.
+    pub fn start_find(&mut self, query: &str) -> Result<(), AppError> {
+        let find_query = FindQuery::parse(query)?;
+        let (path, dataset, total, matlab_order) = {
+            let Some(item) = self.treeview.get(self.tree_view_cursor) else {
+                return Err(AppError::InvalidCommand(
+                    "find needs a dataset to be selected".to_string(),
+                ));
+            };
+            let mut node = item.node.borrow_mut();
+            if matches!(node.node, Node::Dataset(_, _)) {
+                node.ensure_dataset_meta()?;
+            }
+            let Node::Dataset(DatasetHandle::Loaded(dataset), DatasetMetaState::Loaded(meta)) =
+                &node.node
+            else {
+                return Err(AppError::InvalidCommand(
+                    "find needs a dataset to be selected".to_string(),
+                ));
+            };
+            if meta.compound_projection.is_some() {
+                return Err(AppError::InvalidCommand(
+                    "find does not search compound fields".to_string(),
+                ));
+            }
+            check_find_query(&find_query, &meta.type_descriptor)?;
+            (
+                node.node.path(),
+                dataset.clone(),
+                meta.shape.iter().product(),
+                meta.uses_matlab_axis_order(),
+            )
+        };
+        let key = super::FindKey {
+            file_generation: self.content_generation,
+            path,
+            query: query.trim().to_string(),
+        };
+        self.find.input = key.query.clone();
+        self.find.input_cursor = self.find.input.len();
+        self.find
+            .request(key, dataset, find_query, total, matlab_order);
+        Ok(())
+    }
+
+    /// Moves the content view to the hit under the find cursor, selecting
+    /// the searched dataset again if another node was selected since.
+    pub fn open_find_hit(&mut self) -> Result<EventResult, AppError> {
+        let (Some(key), Some(hit)) = (self.find.key.clone(), self.find.selected_hit()) else {
+            return Ok(EventResult::Continue);
+        };
+        let index = hit.index.clone();
+        let selected_path = self
+            .treeview
+            .get(self.tree_view_cursor)
+            .map(|item| item.node.borrow().node.path());
+        if selected_path.as_deref() != Some(key.path.as_str()) {
+            self.select_tree_node_by_path(&key.path)?;
+        }
+        self.jump_to_element(&index)
     }
 
     /// Queues the selected node's lazy metadata and attributes.  One worker keeps HDF5 access
@@ -42,6 +259,7 @@ impl AppState<'_> {
             return;
         };
         let mut node = item.node.borrow_mut();
+        node.apply_initial_axis_order();
         if node.computed_attributes.is_some()
             && self.pending_tree_selection_state.is_none()
             && self.pending_tree_attribute_selection.is_none()
@@ -100,15 +318,98 @@ impl AppState<'_> {
     }
 
     pub fn request_tree_children(&mut self, node: Rc<std::cell::RefCell<H5FNode>>) {
+        self.enumerate_tree_children(node, true);
+    }
+
+    /// Reads the members of the selected group for its overview, leaving the
+    /// group collapsed in the tree, then the metadata the overview still lacks.
+    pub fn request_group_overview(&mut self, node: &Rc<std::cell::RefCell<H5FNode>>) {
+        if !matches!(&node.borrow().node, Node::Group(_, meta) if meta.preview_expr.is_none()) {
+            return;
+        }
+        self.request_member_metadata(node);
+    }
+
+    /// Reads the metadata of the datasets listed around the tree cursor when
+    /// the tree columns or the size order show it. The group overview worker
+    /// reads them, so a group already read for its overview is not read again;
+    /// while it is busy, the overview of the selected group goes first.
+    pub fn request_tree_metadata(&mut self) {
+        let settings = configure::current_tree_settings();
+        let shows_metadata =
+            settings.columns.any() || settings.sort == configure::TreeSortOrder::Size;
+        if !shows_metadata || self.group_overview.pending_key.is_some() {
+            return;
+        }
+        let Some(item) = self.treeview.get(self.tree_view_cursor) else {
+            return;
+        };
+        let open_group = {
+            let node = item.node.borrow();
+            node.expanded && node.read && matches!(node.node, Node::File(_) | Node::Group(_, _))
+        };
+        let group = if item.load_more || open_group {
+            item.node.clone()
+        } else {
+            match &item.parent {
+                Some(parent) => parent.clone(),
+                None => return,
+            }
+        };
+        self.request_member_metadata(&group);
+    }
+
+    fn request_member_metadata(&mut self, node: &Rc<std::cell::RefCell<H5FNode>>) {
+        let node_ref = node.borrow();
+        let group_path = match &node_ref.node {
+            Node::File(_) => "/".to_string(),
+            Node::Group(group, _) => group.name(),
+            _ => return,
+        };
+        if node_ref.is_cycle() || node_ref.load_error.is_some() {
+            return;
+        }
+        if !node_ref.read {
+            drop(node_ref);
+            self.enumerate_tree_children(node.clone(), false);
+            return;
+        }
+        let key = super::GroupOverviewKey {
+            file_generation: self.content_generation,
+            group_path,
+        };
+        let members = node_ref
+            .children
+            .iter()
+            .filter_map(|child| {
+                let child = child.borrow();
+                let pending = matches!(
+                    child.node,
+                    Node::Dataset(_, crate::h5f::DatasetMetaState::Pending(_))
+                );
+                let path = child.node.path();
+                if self.group_overview.errors.contains_key(&path) {
+                    return None;
+                }
+                let counted = child.computed_attributes.is_some()
+                    || self.group_overview.attribute_counts.contains_key(&path);
+                (pending || !counted).then(|| child.node.clone())
+            })
+            .collect();
+        drop(node_ref);
+        self.group_overview.request(key, node.clone(), members);
+    }
+
+    fn enumerate_tree_children(&mut self, node: Rc<std::cell::RefCell<H5FNode>>, expand: bool) {
         let mut node_ref = node.borrow_mut();
         if node_ref.read || node_ref.loading {
-            node_ref.expanded = true;
+            node_ref.expanded |= expand;
             return;
         }
         let Some(enumeration_node) = node_ref.enumeration_node() else {
             return;
         };
-        node_ref.expanded = true;
+        node_ref.expanded |= expand;
         node_ref.loading = true;
         node_ref.load_error = None;
         let request_id = self.next_tree_load_request_id;
@@ -122,6 +423,7 @@ impl AppState<'_> {
                     generation: self.tree_load_generation,
                     request_id,
                     node: enumeration_node,
+                    canonical_paths: self.canonical_paths.paths.clone(),
                 },
             ))
             .is_err()
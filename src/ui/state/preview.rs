@@ -1,7 +1,7 @@
 use std::{
     collections::{HashSet, VecDeque},
     io::BufReader,
-    sync::mpsc::Sender,
+    sync::{mpsc::Sender, Arc},
 };
 
 use hdf5_metno::{ByteReader, Dataset};
@@ -11,7 +11,7 @@ use ratatui_image::thread::{ResizeRequest, ThreadProtocol};
 
 use crate::{
     data::{DatasetPlotingData, PreviewSelection},
-    h5f::{DatasetMeta, ImageType, ResolvedOpenMode},
+    h5f::{BitfieldType, DatasetMeta, DimensionScale, ImageType, ResolvedOpenMode, TimeEncoding},
     ui::mchart::{ChartAxisScale, ChartItem},
 };
 
@@ -156,15 +156,26 @@ pub enum ChartPreviewSource {
     Dataset {
         ds: Dataset,
         selection: PreviewSelection,
+        x_scale: Option<Arc<DimensionScale>>,
+        /// Set for timestamp datasets, whose y axis shows datetimes.
+        y_time: Option<TimeEncoding>,
     },
     ProjectedDataset {
         ds: Dataset,
         meta: Box<DatasetMeta>,
         selection: PreviewSelection,
+        x_scale: Option<Arc<DimensionScale>>,
     },
     Precomputed {
         data_preview: DatasetPlotingData,
     },
+    BitPlane {
+        ds: Dataset,
+        bitfield: BitfieldType,
+        bit: usize,
+        selection: PreviewSelection,
+        x_scale: Option<Arc<DimensionScale>>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -186,6 +197,7 @@ pub struct ChartPreviwState {
     pub rendered_mode: Option<PreviewChartMode>,
     pub rendered_viewport: Option<PreviewChartViewport>,
     pub rendered_roi: Option<PreviewChartRoi>,
+    pub rendered_bit_plane: Option<usize>,
     pub rendered_size: Option<(u16, u16)>,
     pub pending_key: Option<ChartPreviewKey>,
     pub tx_resize_chartpreview: Sender<ResizeRequest>,
@@ -233,6 +245,7 @@ mod log_scale_tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview: resize_tx,
@@ -446,6 +459,8 @@ pub struct ChartPreviewKey {
     pub roi: Option<PreviewChartRoi>,
     pub histogram_selection: Option<PreviewHistogramSelection>,
     pub histogram_range: Option<PreviewHistogramRange>,
+    /// Bit charted for bitfield datasets.
+    pub bit_plane: Option<usize>,
     pub width: u16,
     pub height: u16,
 }
@@ -548,6 +563,7 @@ mod tests {
             roi: None,
             histogram_selection: None,
             histogram_range: None,
+            bit_plane: None,
             width: 10,
             height: 10,
         }
@@ -594,6 +610,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -629,6 +646,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -688,6 +706,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -758,6 +777,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: Some(bounds()),
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -813,6 +833,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -939,6 +960,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -985,6 +1007,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -1029,6 +1052,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -1083,6 +1107,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -1129,6 +1154,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -1192,6 +1218,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -1241,6 +1268,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -1294,6 +1322,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -1352,6 +1381,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -1398,6 +1428,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -1446,6 +1477,7 @@ mod tests {
             rendered_mode: None,
             rendered_viewport: None,
             rendered_roi: None,
+            rendered_bit_plane: None,
             rendered_size: None,
             pending_key: None,
             tx_resize_chartpreview,
@@ -61,6 +61,7 @@ impl ChartPreviwState {
             roi: self.rendered_roi,
             histogram_selection: self.histogram_selection,
             histogram_range: self.histogram_range,
+            bit_plane: self.rendered_bit_plane,
             width,
             height,
         };
@@ -115,6 +116,7 @@ impl ChartPreviwState {
         self.rendered_mode = Some(key.mode);
         self.rendered_viewport = key.viewport;
         self.rendered_roi = key.roi;
+        self.rendered_bit_plane = key.bit_plane;
         self.rendered_size = Some((key.width, key.height));
         self.error = None;
         self.current_data = None;
@@ -127,6 +129,7 @@ impl ChartPreviwState {
         self.rendered_mode = None;
         self.rendered_viewport = None;
         self.rendered_roi = None;
+        self.rendered_bit_plane = None;
         self.rendered_size = None;
         self.protocol = None;
         self.clipboard_image = None;
@@ -0,0 +1,79 @@
+use std::sync::mpsc::Sender;
+
+use hdf5_metno::Group;
+
+use crate::{h5f::IndexScope, search::PathCacheKey};
+
+use super::WalkWork;
+
+pub struct SearchIndexRequest {
+    pub generation: u64,
+    pub root: Group,
+    pub scope: IndexScope,
+    /// Set when the path list may be read from and stored in the disk
+    /// cache.
+    pub cache_key: Option<PathCacheKey>,
+}
+
+pub type SearchIndexWork = WalkWork<SearchIndexRequest>;
+
+/// Background walk reading the paths, attributes and dataset metadata
+/// search needs, in one pass over the file. What it reads is kept by the
+/// searcher.
+pub struct SearchIndexState {
+    /// Bumped for every walk; batches of older walks are dropped.
+    pub generation: u64,
+    pub pending_key: Option<u64>,
+    /// What the pending walk reads.
+    pub scope: IndexScope,
+    pub tx_load: Sender<SearchIndexWork>,
+}
+
+impl SearchIndexState {
+    pub fn new(tx_load: Sender<SearchIndexWork>) -> Self {
+        Self {
+            generation: 0,
+            pending_key: None,
+            scope: IndexScope::default(),
+            tx_load,
+        }
+    }
+
+    pub fn invalidate(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+        self.finish();
+    }
+
+    pub fn finish(&mut self) {
+        self.pending_key = None;
+        self.scope = IndexScope::default();
+    }
+
+    /// What the running walk reads; empty when none is running.
+    pub fn running(&self) -> IndexScope {
+        if self.pending_key.is_some() {
+            self.scope
+        } else {
+            IndexScope::default()
+        }
+    }
+
+    /// Starts walking `root` for `scope`, replacing the running walk.
+    pub fn request(&mut self, root: Group, scope: IndexScope, cache_key: Option<PathCacheKey>) {
+        self.invalidate();
+        self.pending_key = Some(self.generation);
+        self.scope = scope;
+        let _ = self.tx_load.send(SearchIndexWork::Load(SearchIndexRequest {
+            generation: self.generation,
+            root,
+            scope,
+            cache_key,
+        }));
+    }
+
+    /// Stops the running walk and drops what it still sends.
+    pub fn cancel(&mut self) {
+        self.invalidate();
+        let _ = self.tx_load.send(SearchIndexWork::Cancel);
+    }
+}
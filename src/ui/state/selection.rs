@@ -79,6 +79,40 @@ impl AppState<'_> {
             .and_then(|item| item.node.try_borrow().ok().map(|node| node.node.path()))
     }
 
+    /// Path of the link the selected tree row stands for; `None` for the file
+    /// root, compound fields and `Load more` rows.
+    pub fn selected_link_path(&self) -> Option<String> {
+        let item = self.treeview.get(self.tree_view_cursor)?;
+        if item.load_more {
+            return None;
+        }
+        let parent = item.parent.as_ref()?.borrow();
+        if !matches!(parent.node, Node::File(_) | Node::Group(_, _)) {
+            return None;
+        }
+        Some(crate::h5f::resolve_object_path(
+            &parent.node.path(),
+            &item.node.borrow().name(),
+        ))
+    }
+
+    /// The selected group, or the group holding the selected object.
+    pub fn selected_group_path(&self) -> String {
+        let Some(item) = self.treeview.get(self.tree_view_cursor) else {
+            return "/".to_string();
+        };
+        if let Node::File(_) | Node::Group(_, _) = &item.node.borrow().node {
+            return item.node.borrow().node.path();
+        }
+        match item.parent.as_ref().map(|parent| parent.borrow()) {
+            Some(parent) if matches!(parent.node, Node::File(_) | Node::Group(_, _)) => {
+                parent.node.path()
+            }
+            Some(parent) => crate::h5f::parent_object_path(&parent.node.path()).to_string(),
+            None => "/".to_string(),
+        }
+    }
+
     pub fn cancel_pending_tree_selection(&mut self) {
         clear_pending_tree_selection(
             &mut self.pending_tree_selection,
@@ -249,7 +283,7 @@ fn select_cached_attribute_by_name(node: &mut H5FNode, attr_name: &str) -> Resul
     let Some(index) = attributes
         .rendered_rows
         .iter()
-        .position(|row| row.key.as_deref() == Some(attr_name))
+        .position(|row| row.is_attribute(attr_name))
     else {
         return Err(AppError::ChildNotFound(attr_name.to_string()));
     };
@@ -318,7 +352,7 @@ impl AppState<'_> {
     pub fn change_row(&mut self, delta: isize) -> Result<EventResult> {
         let active_mode = self.active_content_mode();
         match active_mode {
-            ContentShowMode::Matrix | ContentShowMode::Heatmap => {
+            ContentShowMode::Matrix | ContentShowMode::Heatmap | ContentShowMode::Chunks => {
                 let current_node = &self.treeview[self.tree_view_cursor];
                 let mut current_node = current_node.node.borrow_mut();
                 if matches!(current_node.node, Node::Dataset(_, _)) {
@@ -413,6 +447,21 @@ impl AppState<'_> {
             {
                 Ok(None)
             }
+            Node::Dataset(
+                crate::h5f::DatasetHandle::Loaded(ds),
+                DatasetMetaState::Loaded(dsattr),
+            ) if matches!(
+                dsattr.matrixable,
+                Some(crate::ui::render::MatrixRenderType::Ragged)
+            ) && !dsattr.is_compound_leaf() =>
+            {
+                let mut indexes = node.selected_indexes.clone();
+                indexes.resize(dsattr.shape.len(), 0);
+                self.multi_chart
+                    .ragged_element_item(&ds.name(), &indexes, self.file.as_ref())
+                    .map(Some)
+                    .map_err(AppError::InvalidCommand)
+            }
             Node::Dataset(
                 crate::h5f::DatasetHandle::Loaded(ds),
                 DatasetMetaState::Loaded(dsattr),
@@ -435,6 +484,7 @@ impl AppState<'_> {
                     } else {
                         DatasetChartKind::Dataset
                     },
+                    time: meta.time,
                 });
                 Ok(Some(CapturedMultiChartItem {
                     source,
@@ -501,7 +551,7 @@ impl AppState<'_> {
                 }
                 Ok(EventResult::Redraw)
             }
-            ContentShowMode::Matrix | ContentShowMode::Heatmap => {
+            ContentShowMode::Matrix | ContentShowMode::Heatmap | ContentShowMode::Chunks => {
                 let is_compound_root_matrix = matches!(
                     &node.node,
                     Node::Dataset(_, DatasetMetaState::Loaded(dsattr))
@@ -545,6 +595,7 @@ impl AppState<'_> {
                 }
                 Ok(EventResult::Redraw)
             }
+            ContentShowMode::Usage => Ok(EventResult::Continue),
         }
     }
 
@@ -572,7 +623,7 @@ impl AppState<'_> {
     pub fn change_col(&mut self, delta: isize) -> Result<EventResult> {
         let active_mode = self.active_content_mode();
         match active_mode {
-            ContentShowMode::Matrix | ContentShowMode::Heatmap => {
+            ContentShowMode::Matrix | ContentShowMode::Heatmap | ContentShowMode::Chunks => {
                 let current_node = &self.treeview[self.tree_view_cursor];
                 let mut current_node = current_node.node.borrow_mut();
                 if matches!(current_node.node, Node::Dataset(_, _)) {
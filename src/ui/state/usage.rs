@@ -0,0 +1,103 @@
+use std::{collections::VecDeque, rc::Rc, sync::mpsc::Sender};
+
+use hdf5_metno::Group;
+
+use crate::h5f::{UsageReport, UsageTotals};
+
+use super::{ListView, WalkWork};
+
+pub const USAGE_CACHE_CAPACITY: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageKey {
+    pub file_generation: u64,
+    pub group_path: String,
+}
+
+pub struct UsageRequest {
+    pub key: UsageKey,
+    pub group: Group,
+}
+
+pub type UsageWork = WalkWork<UsageRequest>;
+
+/// Disk usage summaries for the usage content mode. One group is summed at a
+/// time; selecting another group cancels the walk in progress.
+pub struct UsageState {
+    pub pending_key: Option<UsageKey>,
+    /// Running totals of the pending walk.
+    pub progress: Option<UsageTotals>,
+    pub error: Option<(UsageKey, String)>,
+    pub cached: VecDeque<(UsageKey, Rc<UsageReport>)>,
+    /// Group whose breakdown the cursor belongs to.
+    pub shown: Option<UsageKey>,
+    pub rows: usize,
+    pub list: ListView,
+    pub tx_load: Sender<UsageWork>,
+}
+
+impl UsageState {
+    pub fn new(tx_load: Sender<UsageWork>) -> Self {
+        Self {
+            pending_key: None,
+            progress: None,
+            error: None,
+            cached: VecDeque::new(),
+            shown: None,
+            rows: 0,
+            list: ListView::default(),
+            tx_load,
+        }
+    }
+
+    pub fn invalidate(&mut self) {
+        self.pending_key = None;
+        self.progress = None;
+        self.error = None;
+        self.cached.clear();
+    }
+
+    pub fn report(&self, key: &UsageKey) -> Option<Rc<UsageReport>> {
+        self.cached
+            .iter()
+            .find(|(cached, _)| cached == key)
+            .map(|(_, report)| report.clone())
+    }
+
+    pub fn store(&mut self, key: UsageKey, report: UsageReport) {
+        self.cached.retain(|(cached, _)| cached != &key);
+        self.cached.push_back((key, Rc::new(report)));
+        while self.cached.len() > USAGE_CACHE_CAPACITY {
+            self.cached.pop_front();
+        }
+    }
+
+    /// Starts summing `group` unless that walk is already running.
+    pub fn request(&mut self, key: UsageKey, group: &Group) {
+        if self.pending_key.as_ref() == Some(&key) {
+            return;
+        }
+        self.pending_key = Some(key.clone());
+        self.progress = None;
+        self.error = None;
+        let _ = self.tx_load.send(UsageWork::Load(UsageRequest {
+            key,
+            group: group.clone(),
+        }));
+    }
+
+    /// Shows the breakdown of `key`, moving the cursor back to the top when
+    /// the group changes.
+    pub fn show(&mut self, key: &UsageKey, rows: usize) {
+        if self.shown.as_ref() != Some(key) {
+            self.shown = Some(key.clone());
+            self.list.reset();
+        }
+        self.rows = rows;
+        self.list.clamp(rows);
+    }
+
+    pub fn move_cursor(&mut self, delta: isize) -> bool {
+        self.list.move_cursor(delta, self.rows)
+    }
+}
@@ -0,0 +1,13 @@
+use std::sync::mpsc::Sender;
+
+/// Work sent to a background walk started with
+/// [`spawn_walk_worker`](crate::ui::walk_worker::spawn_walk_worker).
+pub enum WalkWork<R> {
+    /// Starts walking for `R`, stopping the walk in progress.
+    Load(R),
+    /// Stops the walk in progress without starting another.
+    Cancel,
+    /// Stops the walk in progress and drops queued requests; the sender is
+    /// notified once HDF5 is no longer being read.
+    Drain(Sender<()>),
+}
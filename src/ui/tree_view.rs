@@ -1,4 +1,11 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    cmp::{Ordering, Reverse},
+    collections::HashMap,
+    iter::Peekable,
+    rc::Rc,
+    str::Chars,
+};
 
 use ratatui::{
     layout::{Alignment, Margin, Offset, Rect},
@@ -9,8 +16,9 @@ use ratatui::{
 };
 
 use crate::{
-    configure,
-    h5f::{H5FNode, HasPath},
+    configure::{self, TreeColumns, TreeSettings, TreeSortOrder},
+    h5f::{human_bytes, DatasetMetaState, H5FNode, HasPath, Node},
+    search::Searcher,
     ui::{cursor::set_input_cursor, mchart::MultiChartState, std_comp_render::render_error},
 };
 
@@ -67,6 +75,134 @@ fn restored_tree_cursor(treeview: &[TreeItem<'_>], selection: &TreeSelection) ->
         })
 }
 
+/// What the tree shows besides member names.
+struct TreeAnnotations<'s> {
+    settings: TreeSettings,
+    /// Metadata errors by member path, for datasets whose columns stay empty.
+    metadata_errors: &'s HashMap<String, String>,
+}
+
+/// Compares names case-insensitively, with runs of digits compared by value,
+/// so `run2` sorts before `run10`.
+pub(crate) fn natural_cmp(a: &str, b: &str) -> Ordering {
+    fn take_digits(chars: &mut Peekable<Chars<'_>>) -> String {
+        let mut digits = String::new();
+        while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+            digits.push(digit);
+        }
+        digits
+    }
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+    loop {
+        let ordering = match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let x_digits = take_digits(&mut a_chars);
+                let y_digits = take_digits(&mut b_chars);
+                let x_value = x_digits.trim_start_matches('0');
+                let y_value = y_digits.trim_start_matches('0');
+                x_value
+                    .len()
+                    .cmp(&y_value.len())
+                    .then_with(|| x_value.cmp(y_value))
+            }
+            (Some(x), Some(y)) => {
+                a_chars.next();
+                b_chars.next();
+                x.to_lowercase().cmp(y.to_lowercase())
+            }
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// Groups first, then datasets by dtype with unread ones last, then named
+/// datatypes and broken links.
+fn type_key(node: &H5FNode) -> (u8, bool, Option<&str>) {
+    match &node.node {
+        Node::File(_) | Node::Group(_, _) => (0, false, None),
+        Node::Dataset(_, DatasetMetaState::Loaded(meta)) => (1, false, Some(&meta.data_type)),
+        Node::Dataset(_, DatasetMetaState::Pending(_)) => (1, true, None),
+        Node::Datatype(_, _) => (2, false, None),
+        Node::Broken(_) => (3, false, None),
+    }
+}
+
+fn storage_size(node: &H5FNode) -> Option<u64> {
+    match &node.node {
+        Node::Dataset(_, DatasetMetaState::Loaded(meta)) => Some(meta.storage_required),
+        _ => None,
+    }
+}
+
+/// Members of a group in `sort` order. The sort is stable, so members the
+/// order cannot tell apart keep their file order.
+fn sorted_children(
+    children: &[Rc<RefCell<H5FNode>>],
+    sort: TreeSortOrder,
+) -> Vec<Rc<RefCell<H5FNode>>> {
+    let mut children = children.to_vec();
+    match sort {
+        TreeSortOrder::File => {}
+        TreeSortOrder::Name => {
+            children.sort_by(|a, b| natural_cmp(&a.borrow().name(), &b.borrow().name()))
+        }
+        TreeSortOrder::Creation => children.sort_by_key(|child| {
+            let order = child.borrow().creation_order;
+            (order.is_none(), order)
+        }),
+        // `None` sorts below every size, so unread sizes end up last.
+        TreeSortOrder::Size => children.sort_by_key(|child| Reverse(storage_size(&child.borrow()))),
+        TreeSortOrder::Type => children.sort_by(|a, b| {
+            let (a, b) = (a.borrow(), b.borrow());
+            type_key(&a)
+                .cmp(&type_key(&b))
+                .then_with(|| natural_cmp(&a.name(), &b.name()))
+        }),
+    }
+    children
+}
+
+/// Inline columns of a member, or `None` when it has nothing to show.
+fn column_text(node: &H5FNode, columns: TreeColumns, failed: bool) -> Option<String> {
+    if !columns.any() {
+        return None;
+    }
+    let parts = match &node.node {
+        Node::Dataset(_, DatasetMetaState::Loaded(meta)) => {
+            let mut parts = Vec::new();
+            if columns.shape {
+                parts.push(if meta.shape.is_empty() {
+                    "scalar".to_string()
+                } else {
+                    meta.shape
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(" x ")
+                });
+            }
+            if columns.dtype {
+                parts.push(meta.data_type.clone());
+            }
+            if columns.size {
+                parts.push(human_bytes(meta.storage_required));
+            }
+            parts
+        }
+        Node::Dataset(_, DatasetMetaState::Pending(_)) if failed => vec!["!".to_string()],
+        Node::Dataset(_, DatasetMetaState::Pending(_)) => vec!["…".to_string()],
+        Node::Datatype(_, meta) if columns.dtype => vec![meta.data_type.clone()],
+        _ => Vec::new(),
+    };
+    (!parts.is_empty()).then(|| parts.join("  "))
+}
+
 fn group_load_marker(loading: bool, load_error: bool) -> Option<&'static str> {
     if loading {
         Some("[loading]")
@@ -102,11 +238,16 @@ impl AppState<'_> {
             line: text,
         };
         tree_view.push(root_tree_item);
+        let annotations = TreeAnnotations {
+            settings: configure::current_tree_settings(),
+            metadata_errors: &self.group_overview.errors,
+        };
         let children = compute_tree_view_rec(
             &self.root,
             vec![Span::raw("".to_string())],
             0,
             &self.multi_chart,
+            &annotations,
             None,
         );
         tree_view.extend(children);
@@ -128,6 +269,7 @@ fn compute_tree_view_rec<'a>(
     prefix: Vec<Span<'a>>,
     indent: u8,
     mchart: &MultiChartState,
+    annotations: &TreeAnnotations<'_>,
     parent: Option<Rc<RefCell<H5FNode>>>,
 ) -> Vec<TreeItem<'a>> {
     let mut tree_view = Vec::new();
@@ -137,7 +279,8 @@ fn compute_tree_view_rec<'a>(
 
     let node_binding = node.borrow();
 
-    let mut groups = node_binding.children.iter().peekable();
+    let children = sorted_children(&node_binding.children, annotations.settings.sort);
+    let mut groups = children.iter().peekable();
     let mut loading = 0;
     while let Some(child) = groups.next() {
         let c = child.borrow();
@@ -270,13 +413,42 @@ fn compute_tree_view_rec<'a>(
             child.borrow().name(),
             Style::default().fg(name_color),
         ));
+        if let Some(dimension_names) = c.dimension_names() {
+            line_vec.push(Span::styled(
+                format!("({})", dimension_names.join(", ")),
+                Style::default().fg(configure::themed_color(|colors| colors.text.type_desc)),
+            ));
+        }
+        if let Some(matlab) = c.matlab_variable() {
+            line_vec.push(Span::styled(
+                format!(" {}", matlab.describe()),
+                Style::default().fg(configure::themed_color(|colors| colors.text.type_desc)),
+            ));
+        }
+        let path = c.node.path();
+        let failed = annotations.metadata_errors.contains_key(&path);
+        if let Some(columns) = column_text(&c, annotations.settings.columns, failed) {
+            line_vec.push(Span::styled(
+                format!("  {columns}"),
+                Style::default().fg(configure::themed_color(|colors| colors.text.type_desc)),
+            ));
+        }
+        if let Some(alias) = c.alias() {
+            line_vec.push(Span::styled(
+                format!(
+                    " {} {}",
+                    configure::configured_symbol(|symbols| symbols.tree.alias_marker),
+                    alias.describe()
+                ),
+                Style::default().fg(configure::themed_color(|colors| colors.text.type_desc)),
+            ));
+        }
         if let Some(marker) = group_load_marker(c.loading, c.load_error.is_some()) {
             line_vec.push(Span::styled(
                 format!(" {marker}"),
                 Style::default().fg(configure::themed_color(|colors| colors.text.error)),
             ));
         }
-        let path = child.borrow().node.path();
         let memberships = mchart
             .chart_items()
             .iter()
@@ -331,8 +503,14 @@ fn compute_tree_view_rec<'a>(
         };
 
         if child.borrow().is_expandable() {
-            let children =
-                compute_tree_view_rec(child, prefix_clone, indent, mchart, Some(node.clone()));
+            let children = compute_tree_view_rec(
+                child,
+                prefix_clone,
+                indent,
+                mchart,
+                annotations,
+                Some(node.clone()),
+            );
             tree_view.extend(children);
         }
     }
@@ -349,7 +527,8 @@ pub fn render_tree(f: &mut Frame, area: Rect, state: &mut AppState) {
             | Mode::AttributeCreateDialog
             | Mode::AttributeDeleteDialog
             | Mode::FixedStringOverflowDialog
-            | Mode::FixedStringResizeDialog,
+            | Mode::FixedStringResizeDialog
+            | Mode::StructureEditDialog,
         ) => configure::themed_color(|colors| colors.surface.focus_bg),
         _ => configure::themed_color(|colors| colors.surface.bg),
     };
@@ -379,10 +558,13 @@ pub fn render_tree(f: &mut Frame, area: Rect, state: &mut AppState) {
     match mode {
         Mode::Normal
         | Mode::Command
+        | Mode::Find
         | Mode::AttributeCreateDialog
         | Mode::AttributeDeleteDialog
         | Mode::FixedStringOverflowDialog
-        | Mode::FixedStringResizeDialog => {
+        | Mode::FixedStringResizeDialog
+        | Mode::StructureEditDialog => {
+            state.request_tree_metadata();
             let mut tree_view_skip_offset = 0;
             let mut highlight_index = state.tree_view_cursor;
             if area.height <= state.tree_view_cursor as u16 {
@@ -429,6 +611,13 @@ pub fn render_tree(f: &mut Frame, area: Rect, state: &mut AppState) {
             }
         }
         Mode::Search => {
+            let wanted = state
+                .searcher
+                .as_ref()
+                .map(|searcher| Searcher::index_scope(&searcher.query));
+            if let Some(wanted) = wanted {
+                state.request_search_index(wanted);
+            }
             let Some(ref mut searcher) = state.searcher else {
                 // render error
                 render_error(f, &area, "Error: Searcher not initialized.".to_string());
@@ -467,11 +656,29 @@ pub fn render_tree(f: &mut Frame, area: Rect, state: &mut AppState) {
                 format!(" {}", search_query),
                 Style::default().fg(configure::themed_color(|colors| colors.text.search_text)),
             );
-            let results_str = match results_count {
+            let mut results_str = match results_count {
                 0 => " (No results)".to_string(),
                 1 => format!(" ({} result)", results_count),
                 _ => format!(" ({} results)", results_count),
             };
+            if let Some(error) = searcher.query_error(&search_query) {
+                results_str = format!(" ({error})");
+            } else if !searcher.paths_complete {
+                results_str.push_str(&format!(
+                    " listing paths… {} objects · Ctrl+C cancels",
+                    searcher.indexed_paths()
+                ));
+            } else if searcher.is_indexing_attributes(&search_query) {
+                results_str.push_str(&format!(
+                    " reading attributes… {} objects · Ctrl+C cancels",
+                    searcher.attribute_objects
+                ));
+            } else if searcher.is_indexing_datasets(&search_query) {
+                results_str.push_str(&format!(
+                    " reading metadata… {} datasets · Ctrl+C cancels",
+                    searcher.indexed_datasets()
+                ));
+            }
             let search_count_span = Span::styled(
                 results_str,
                 Style::default().fg(configure::themed_color(|colors| colors.text.search_count)),
@@ -515,8 +722,14 @@ mod tests {
 
     use ratatui::text::Line;
 
-    use super::{group_load_marker, restored_tree_cursor, tree_selection, TreeItem};
-    use crate::h5f::{H5FNode, Node};
+    use super::{
+        group_load_marker, natural_cmp, restored_tree_cursor, sorted_children, tree_selection,
+        TreeItem,
+    };
+    use crate::{
+        configure::TreeSortOrder,
+        h5f::{H5FNode, Node},
+    };
 
     fn item(
         node: &Rc<RefCell<H5FNode>>,
@@ -586,4 +799,33 @@ mod tests {
         assert_eq!(group_load_marker(true, false), Some("[loading]"));
         assert_eq!(group_load_marker(false, true), Some("[! retry]"));
     }
+
+    #[test]
+    fn natural_order_compares_digit_runs_by_value() {
+        let mut names = vec!["run10", "Run2", "run1", "run02b", "alpha", "run2"];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(names, ["alpha", "run1", "Run2", "run2", "run02b", "run10"]);
+    }
+
+    #[test]
+    fn sorting_by_creation_keeps_untracked_members_last_in_file_order() {
+        let children = [("b", Some(1)), ("c", None), ("a", Some(0)), ("d", None)]
+            .into_iter()
+            .map(|(name, order)| {
+                let mut node = H5FNode::new(Node::Broken(name.to_string()));
+                node.creation_order = order;
+                Rc::new(RefCell::new(node))
+            })
+            .collect::<Vec<_>>();
+        let names = |sort| {
+            sorted_children(&children, sort)
+                .iter()
+                .map(|child| child.borrow().name())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(TreeSortOrder::File), ["b", "c", "a", "d"]);
+        assert_eq!(names(TreeSortOrder::Creation), ["a", "b", "c", "d"]);
+        assert_eq!(names(TreeSortOrder::Name), ["a", "b", "c", "d"]);
+    }
 }
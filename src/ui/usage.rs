@@ -0,0 +1,267 @@
+use std::sync::mpsc::Sender;
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::{
+    configure,
+    error::AppError,
+    h5f::{human_bytes, read_usage, H5FNode, Node, UsageEntry, UsageReport, UsageTotals},
+    ui::{
+        app::{AppEvent, UsageLoadResult},
+        chrome::rounded_panel,
+        matrix::render_not_yet_implemented,
+        state::{AppState, UsageKey, UsageRequest, UsageWork},
+        std_comp_render::render_error,
+        walk_worker::spawn_walk_worker,
+    },
+};
+
+const BAR_WIDTH: usize = 16;
+const SIZE_WIDTH: usize = 10;
+const RATIO_WIDTH: usize = 8;
+const COUNT_WIDTH: usize = 9;
+
+/// Sums one group at a time, sending the running totals as progress.
+pub(crate) fn handle_usage_load(tx_events: Sender<AppEvent>) -> Sender<UsageWork> {
+    spawn_walk_worker(tx_events, |request: UsageRequest, walk| {
+        let key = request.key;
+        let result = read_usage(&request.group, &mut |totals| {
+            if !walk.keep_going() {
+                return false;
+            }
+            if !walk.batch_due() {
+                return true;
+            }
+            walk.send(AppEvent::Usage(UsageLoadResult::Progress {
+                key: key.clone(),
+                totals: *totals,
+            }))
+        });
+        let event = match result {
+            Ok(Some(report)) => UsageLoadResult::Success { key, report },
+            Ok(None) => return,
+            Err(error) => UsageLoadResult::Failure {
+                key,
+                message: error.to_string(),
+            },
+        };
+        walk.send(AppEvent::Usage(event));
+    })
+}
+
+fn usage_key(node: &H5FNode, state: &AppState) -> Option<(UsageKey, hdf5_metno::Group)> {
+    let group = match &node.node {
+        Node::File(file) => file.as_group().ok()?,
+        Node::Group(group, _) => group.clone(),
+        _ => return None,
+    };
+    let key = UsageKey {
+        file_generation: state.content_generation,
+        group_path: group.name(),
+    };
+    Some((key, group))
+}
+
+pub fn render_usage(
+    f: &mut Frame,
+    area: &Rect,
+    node: &H5FNode,
+    state: &mut AppState,
+) -> Result<(), AppError> {
+    let Some((key, group)) = usage_key(node, state) else {
+        render_not_yet_implemented(f, area, "Usage is only available for groups");
+        return Ok(());
+    };
+    let area_inner = area.inner(ratatui::layout::Margin {
+        horizontal: 1,
+        vertical: 1,
+    });
+    let Some(report) = state.usage.report(&key) else {
+        if let Some((error_key, message)) = &state.usage.error {
+            if error_key == &key {
+                render_error(f, &area_inner, format!("Error: {message}"));
+                return Ok(());
+            }
+        }
+        state.usage.request(key, &group);
+        let progress = state.usage.progress.unwrap_or_default();
+        render_error(
+            f,
+            &area_inner,
+            format!(
+                "Summing dataset sizes... {} datasets, {} stored, {} logical so far",
+                progress.datasets,
+                human_bytes(progress.storage),
+                human_bytes(progress.logical)
+            ),
+        );
+        return Ok(());
+    };
+    if area_inner.height < 4 {
+        return Ok(());
+    }
+    state.usage.show(&key, report.children.len());
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(3)])
+        .split(area_inner);
+    let block = rounded_panel(format!(
+        "Usage {} · {} stored · {} logical{} · {} datasets",
+        report.path,
+        human_bytes(report.totals.storage),
+        human_bytes(report.totals.logical),
+        format_ratio(&report.totals)
+            .map(|ratio| format!(" · {ratio}"))
+            .unwrap_or_default(),
+        report.totals.datasets
+    ));
+    let inner = block.inner(layout[0]);
+    f.render_widget(block, layout[0]);
+    if report.children.is_empty() {
+        render_error(f, &inner, "No datasets below this group");
+        return Ok(());
+    }
+
+    let visible_rows = inner.height.saturating_sub(1) as usize;
+    state.usage.list.scroll_to_cursor(visible_rows);
+    let name_width = (inner.width as usize)
+        .saturating_sub(BAR_WIDTH + 2 * SIZE_WIDTH + RATIO_WIDTH + COUNT_WIDTH + 6)
+        .max(8);
+    let muted = Style::default().fg(configure::themed_color(|colors| colors.help.muted));
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            "{:<name_width$} {:<BAR_WIDTH$} {:>SIZE_WIDTH$} {:>SIZE_WIDTH$} {:>RATIO_WIDTH$} {:>COUNT_WIDTH$}",
+            "member", "share", "stored", "logical", "ratio", "datasets"
+        ),
+        muted.bold(),
+    ))];
+    for (index, entry) in report
+        .children
+        .iter()
+        .enumerate()
+        .skip(state.usage.list.row_offset)
+        .take(visible_rows)
+    {
+        lines.push(usage_row(
+            entry,
+            &report,
+            name_width,
+            index == state.usage.list.cursor,
+        ));
+    }
+    f.render_widget(Paragraph::new(lines), inner);
+
+    let detail_block = rounded_panel("Selected member");
+    let detail_inner = detail_block.inner(layout[1]);
+    f.render_widget(detail_block, layout[1]);
+    if let Some(entry) = report.children.get(state.usage.list.cursor) {
+        f.render_widget(
+            Paragraph::new(usage_detail(entry, &report))
+                .style(Style::default().fg(configure::themed_color(|colors| colors.text.primary))),
+            detail_inner,
+        );
+    }
+    Ok(())
+}
+
+fn format_ratio(totals: &UsageTotals) -> Option<String> {
+    totals.ratio().map(|ratio| format!("{ratio:.2}x"))
+}
+
+fn share(entry: &UsageEntry, report: &UsageReport) -> f64 {
+    if report.totals.storage == 0 {
+        return 0.0;
+    }
+    entry.totals.storage as f64 / report.totals.storage as f64
+}
+
+fn usage_row(
+    entry: &UsageEntry,
+    report: &UsageReport,
+    name_width: usize,
+    selected: bool,
+) -> Line<'static> {
+    let icon = if entry.is_group {
+        configure::configured_symbol(|symbols| symbols.tree.folder_closed_branch)
+    } else {
+        configure::configured_symbol(|symbols| symbols.tree.dataset_icon)
+    };
+    let name = truncate_name(&format!("{icon}{}", entry.name), name_width);
+    let filled = (share(entry, report) * BAR_WIDTH as f64).round() as usize;
+    let (text_style, bar_style) = if selected {
+        let style = Style::default()
+            .fg(configure::themed_color(|colors| colors.accent.selection_fg))
+            .bg(configure::themed_color(|colors| colors.accent.selection_bg))
+            .bold();
+        (style, style)
+    } else {
+        (
+            Style::default().fg(configure::themed_color(|colors| colors.text.primary)),
+            Style::default().fg(configure::themed_color(|colors| colors.accent.selection_bg)),
+        )
+    };
+    Line::from(vec![
+        Span::styled(format!("{name:<name_width$} "), text_style),
+        Span::styled(
+            format!(
+                "{}{} ",
+                "█".repeat(filled.min(BAR_WIDTH)),
+                " ".repeat(BAR_WIDTH - filled.min(BAR_WIDTH))
+            ),
+            bar_style,
+        ),
+        Span::styled(
+            format!(
+                "{:>SIZE_WIDTH$} {:>SIZE_WIDTH$} {:>RATIO_WIDTH$} {:>COUNT_WIDTH$}",
+                human_bytes(entry.totals.storage),
+                human_bytes(entry.totals.logical),
+                format_ratio(&entry.totals).unwrap_or_else(|| "-".to_string()),
+                entry.totals.datasets
+            ),
+            text_style,
+        ),
+    ])
+}
+
+fn truncate_name(name: &str, width: usize) -> String {
+    if name.chars().count() <= width {
+        return name.to_string();
+    }
+    let kept = name
+        .chars()
+        .take(width.saturating_sub(1))
+        .collect::<String>();
+    format!("{kept}…")
+}
+
+fn usage_detail(entry: &UsageEntry, report: &UsageReport) -> String {
+    let path = if report.path == "/" {
+        format!("/{}", entry.name)
+    } else {
+        format!("{}/{}", report.path, entry.name)
+    };
+    format!(
+        "{path}: {} stored ({:.1}% of group), {} logical{}, {} datasets",
+        human_bytes(entry.totals.storage),
+        share(entry, report) * 100.0,
+        human_bytes(entry.totals.logical),
+        format_ratio(&entry.totals)
+            .map(|ratio| format!(", {ratio}"))
+            .unwrap_or_default(),
+        entry.totals.datasets
+    )
+}
+
+pub fn selected_usage_copy_text(node: &H5FNode, state: &AppState) -> Option<String> {
+    let (key, _) = usage_key(node, state)?;
+    let report = state.usage.report(&key)?;
+    let entry = report.children.get(state.usage.list.cursor)?;
+    Some(usage_detail(entry, &report))
+}
@@ -0,0 +1,118 @@
+use std::{
+    sync::mpsc::{channel, Receiver, Sender},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::ui::{app::AppEvent, state::WalkWork};
+
+const BATCH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Lets a walk run by [`spawn_walk_worker`] notice newer work and send what
+/// it read.
+pub(crate) struct WalkControl<'a, R> {
+    rx_worker: &'a Receiver<WalkWork<R>>,
+    tx_events: &'a Sender<AppEvent>,
+    next: Option<WalkWork<R>>,
+    pending_drains: Vec<Sender<()>>,
+    superseded: bool,
+    closed: bool,
+    last_batch: Instant,
+}
+
+impl<R> WalkControl<'_, R> {
+    /// Whether the walk should go on. It should stop once a newer request, a
+    /// cancel or a drain was queued, or once the app stopped listening.
+    pub fn keep_going(&mut self) -> bool {
+        while let Ok(queued) = self.rx_worker.try_recv() {
+            match queued {
+                WalkWork::Load(_) => self.next = Some(queued),
+                WalkWork::Cancel => self.next = None,
+                WalkWork::Drain(done) => self.pending_drains.push(done),
+            }
+            self.superseded = true;
+        }
+        !self.superseded && !self.closed
+    }
+
+    /// Whether the walk was stopped by newer work; its result is then not
+    /// wanted.
+    pub fn superseded(&self) -> bool {
+        self.superseded
+    }
+
+    /// Whether it is time to send what was read since the previous batch.
+    pub fn batch_due(&mut self) -> bool {
+        if self.last_batch.elapsed() < BATCH_INTERVAL {
+            return false;
+        }
+        self.last_batch = Instant::now();
+        true
+    }
+
+    /// Sends `event` to the app, returning `false` once it stopped listening.
+    pub fn send(&mut self, event: AppEvent) -> bool {
+        if !self.closed {
+            self.closed = self.tx_events.send(event).is_err();
+        }
+        !self.closed
+    }
+}
+
+/// Runs `walk` for one request at a time on a background thread. The walk
+/// checks [`WalkControl::keep_going`] between steps so a newer request, a
+/// cancel or a drain stops it; drains are acknowledged once HDF5 is no longer
+/// being read.
+pub(crate) fn spawn_walk_worker<R, F>(
+    tx_events: Sender<AppEvent>,
+    mut walk: F,
+) -> Sender<WalkWork<R>>
+where
+    R: Send + 'static,
+    F: FnMut(R, &mut WalkControl<R>) + Send + 'static,
+{
+    let (tx_worker, rx_worker) = channel::<WalkWork<R>>();
+    thread::spawn(move || {
+        let mut next = None;
+        loop {
+            let work = match next.take() {
+                Some(work) => work,
+                None => match rx_worker.recv() {
+                    Ok(work) => work,
+                    Err(_) => return,
+                },
+            };
+            let request = match work {
+                WalkWork::Load(request) => request,
+                WalkWork::Cancel => continue,
+                WalkWork::Drain(done) => {
+                    let _ = done.send(());
+                    continue;
+                }
+            };
+            let mut control = WalkControl {
+                rx_worker: &rx_worker,
+                tx_events: &tx_events,
+                next: None,
+                pending_drains: Vec::new(),
+                superseded: false,
+                closed: false,
+                last_batch: Instant::now(),
+            };
+            walk(request, &mut control);
+            if control.closed {
+                return;
+            }
+            next = control.next;
+            if !control.pending_drains.is_empty() {
+                // Requests queued before a drain belong to the file being
+                // closed.
+                next = None;
+            }
+            for done in control.pending_drains {
+                let _ = done.send(());
+            }
+        }
+    });
+    tx_worker
+}